@@ -1,69 +1,82 @@
 //! AI analyzer implementation for transaction insights and optimization
 
-use std::collections::HashMap;
-use tracing::{debug, error, info};
-
-use crate::types::{
-    SimulationResult, BundleOptimization, AIInsights, RiskLevel,
-    GasOptimization, SecurityAnalysis, PerformanceInsights, MarketAnalysis,
-    Recommendation, Pattern, Wei, TransactionOptimization,
-};
-use crate::error::{HyperSimError, Result};
-
-/// AI analyzer for providing insights and optimization suggestions
+use tracing::{debug, info};
+
+use crate::ai::cache::{decode_record, encode_record, InsightMerkleTree, MerkleProof};
+use crate::plugins::{AnalysisProvider, HeuristicAnalysisProvider, InMemoryAnalysisCacheStore, PluginSystem};
+use crate::plugins::cache::AnalysisCacheStore;
+use crate::types::{SimulationResult, BundleOptimization, AIInsights, MarketAnalysis, RiskLevel, Wei};
+use crate::error::Result;
+
+const CACHE_TTL_SECS: u64 = 300; // 5 minutes
+
+/// AI analyzer for providing insights and optimization suggestions.
+///
+/// Delegates to the highest-priority enabled [`AnalysisProvider`] registered
+/// on an attached [`PluginSystem`] (see [`Self::set_plugin_system`]),
+/// falling back down the priority chain on error, and finally to
+/// `default_provider` — a dependency-free [`HeuristicAnalysisProvider`] —
+/// when no plugin system is attached or every registered provider failed.
+/// This keeps the analysis engine swappable (OpenAI, a local ONNX/ML model,
+/// a custom risk engine, ...) without `AIAnalyzer` hardcoding one of them.
+///
+/// The analysis cache itself is pluggable the same way: reads and writes go
+/// through the highest-priority [`AnalysisCacheStore`] registered on the
+/// plugin system (Redis, an embedded KV store, ...), falling back to
+/// `default_cache` — a process-local, non-persistent map — when none is
+/// registered. Every cached insight is also folded into `merkle`, a
+/// tamper-evident, append-only audit trail of every analysis this
+/// `AIAnalyzer` has served, independent of which backend holds the data.
 pub struct AIAnalyzer {
-    /// OpenAI API key
-    api_key: String,
-    /// HTTP client for API requests
-    client: reqwest::Client,
-    /// Analysis cache
-    cache: std::sync::Arc<tokio::sync::RwLock<HashMap<String, CachedAnalysis>>>,
-}
-
-/// Cached analysis result
-#[derive(Debug, Clone)]
-struct CachedAnalysis {
-    insights: AIInsights,
-    timestamp: std::time::Instant,
-    ttl: std::time::Duration,
-}
-
-impl CachedAnalysis {
-    fn is_expired(&self) -> bool {
-        self.timestamp.elapsed() > self.ttl
-    }
+    /// Plugin system to source analysis providers and cache stores from, if
+    /// one has been attached (mirrors `WebSocketClient::set_plugin_system`)
+    plugin_system: std::sync::Arc<tokio::sync::RwLock<Option<std::sync::Arc<PluginSystem>>>>,
+    /// Always-available deterministic provider used when no plugin system
+    /// is attached, or every registered provider errors
+    default_provider: HeuristicAnalysisProvider,
+    /// Always-available cache store used when no plugin system is
+    /// attached, or no loaded plugin offers a cache store
+    default_cache: InMemoryAnalysisCacheStore,
+    /// Append-only Merkle accumulator over every insight this analyzer has
+    /// cached, keyed by the same cache key used to store it
+    merkle: std::sync::Arc<tokio::sync::RwLock<InsightMerkleTree>>,
 }
 
 impl AIAnalyzer {
-    /// Create a new AI analyzer
-    pub async fn new(api_key: String) -> Result<Self> {
-        let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .map_err(|e| HyperSimError::ai_analysis(format!("Failed to create HTTP client: {}", e)))?;
-
+    /// Create a new AI analyzer with no plugin system attached yet — it
+    /// will use `default_provider`/`default_cache` until
+    /// [`Self::set_plugin_system`] is called
+    pub async fn new() -> Result<Self> {
         Ok(Self {
-            api_key,
-            client,
-            cache: std::sync::Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            plugin_system: std::sync::Arc::new(tokio::sync::RwLock::new(None)),
+            default_provider: HeuristicAnalysisProvider::new(),
+            default_cache: InMemoryAnalysisCacheStore::new(),
+            merkle: std::sync::Arc::new(tokio::sync::RwLock::new(InsightMerkleTree::new())),
         })
     }
 
+    /// Attach a plugin system to source analysis providers from, tried in
+    /// ascending `PluginConfig.priority` order with fallback to the next
+    /// one on error. Mirrors `WebSocketClient::set_plugin_system`.
+    pub async fn set_plugin_system(&self, plugin_system: std::sync::Arc<PluginSystem>) {
+        *self.plugin_system.write().await = Some(plugin_system);
+    }
+
     /// Analyze a simulation result and provide AI insights
     pub async fn analyze_simulation(&self, simulation_result: SimulationResult) -> Result<AIInsights> {
         let start_time = std::time::Instant::now();
-        
+
         // Check cache first
         let cache_key = self.generate_cache_key(&simulation_result);
-        if let Some(cached) = self.get_cached_analysis(&cache_key).await {
+        if let Some(insights) = self.get_cached_analysis(&cache_key).await {
             debug!("Returning cached AI analysis");
-            return Ok(cached.insights);
+            return Ok(insights);
         }
 
         info!("Performing AI analysis for simulation result");
 
         // Perform analysis
-        let insights = self.perform_analysis(&simulation_result).await?;
+        let insights = self.analyze_via_providers(&simulation_result).await?;
 
         // Cache the result
         self.cache_analysis(&cache_key, insights.clone()).await;
@@ -75,18 +88,17 @@ impl AIAnalyzer {
     /// Optimize a bundle of transactions
     pub async fn optimize_bundle(&self, simulations: Vec<SimulationResult>) -> Result<BundleOptimization> {
         let start_time = std::time::Instant::now();
-        
+
         info!("Performing AI-powered bundle optimization for {} transactions", simulations.len());
 
-        // Analyze each transaction in the bundle
-        let mut transaction_analyses = Vec::new();
-        for (i, sim) in simulations.iter().enumerate() {
-            let analysis = self.analyze_simulation(sim.clone()).await?;
-            transaction_analyses.push((i, analysis));
+        // Warm the per-transaction cache the same way a standalone
+        // `analyze_simulation` call would
+        for sim in &simulations {
+            self.analyze_simulation(sim.clone()).await?;
         }
 
         // Perform bundle optimization
-        let optimization = self.optimize_bundle_internal(&simulations, &transaction_analyses).await?;
+        let optimization = self.optimize_via_providers(&simulations).await?;
 
         debug!("Bundle optimization completed in {}ms", start_time.elapsed().as_millis());
         Ok(optimization)
@@ -95,23 +107,19 @@ impl AIAnalyzer {
     /// Get AI-powered market analysis
     pub async fn get_market_analysis(&self) -> Result<MarketAnalysis> {
         info!("Fetching AI-powered market analysis");
-        
-        // In a real implementation, this would call external APIs or ML models
-        // For now, return a mock analysis
+
+        // In a real implementation, the pool reserves would come from a live
+        // feed; `crate::ai::dex::default_pools` stands in for that until one
+        // is wired in, but the constant-product pricing itself is real.
+        let pools = crate::ai::dex::default_pools();
+        let venue = crate::ai::dex::default_venue();
+        let (liquidity_assessment, price_impact, _bottlenecks) =
+            crate::ai::dex::assess_market(&pools, 0.01, Some(&venue));
+
         Ok(MarketAnalysis {
             volatility: 0.25,
-            liquidity_assessment: crate::types::LiquidityAssessment {
-                liquidity_score: 0.85,
-                available_liquidity: Wei::new("50000000000000000000000"),
-                depth_analysis: HashMap::new(),
-                sources: vec!["Uniswap V3".to_string(), "Balancer".to_string()],
-            },
-            price_impact: crate::types::PriceImpact {
-                impact_percentage: 0.02,
-                before_price: "1000.00".to_string(),
-                after_price: "1000.20".to_string(),
-                slippage: 0.001,
-            },
+            liquidity_assessment,
+            price_impact,
             sentiment_score: 0.15,
             market_events: Vec::new(),
         })
@@ -119,203 +127,110 @@ impl AIAnalyzer {
 
     // Private implementation methods
 
-    async fn perform_analysis(&self, simulation_result: &SimulationResult) -> Result<AIInsights> {
-        // In a real implementation, this would call OpenAI API or use local ML models
-        // For now, provide a comprehensive mock analysis
-        
-        let risk_level = if simulation_result.success {
-            if simulation_result.gas_used.parse::<u64>().unwrap_or(0) > 500000 {
-                RiskLevel::Medium
-            } else {
-                RiskLevel::Low
+    /// Delegate to the highest-priority enabled analysis provider
+    /// registered on the attached plugin system, falling back to
+    /// `default_provider` if no plugin system is attached or every
+    /// registered provider errored.
+    async fn analyze_via_providers(&self, simulation_result: &SimulationResult) -> Result<AIInsights> {
+        if let Some(ref plugin_system) = *self.plugin_system.read().await {
+            if let Ok(insights) = plugin_system.analyze_with_providers(simulation_result).await {
+                return Ok(insights);
             }
-        } else {
-            RiskLevel::High
-        };
-
-        let gas_used = simulation_result.gas_used.parse::<u64>().unwrap_or(0);
-        let optimized_gas = gas_used.saturating_sub(gas_used / 10); // 10% reduction
-        
-        Ok(AIInsights {
-            risk_level,
-            risk_score: risk_level.as_score(),
-            success_probability: if simulation_result.success { 0.95 } else { 0.15 },
-            gas_optimization: GasOptimization {
-                current_gas_estimate: simulation_result.gas_used.clone(),
-                optimized_gas_estimate: optimized_gas.to_string(),
-                gas_savings: (gas_used - optimized_gas).to_string(),
-                cost_savings: Wei::new("1000000000000000"),
-                suggested_gas_price: Some(Wei::new("20000000000")),
-                suggested_max_fee_per_gas: Some(Wei::new("25000000000")),
-                suggested_max_priority_fee_per_gas: Some(Wei::new("2000000000")),
-                optimization_techniques: vec![
-                    "Use more efficient opcodes".to_string(),
-                    "Optimize storage operations".to_string(),
-                ],
-            },
-            security_analysis: SecurityAnalysis {
-                security_score: 0.85,
-                vulnerabilities: Vec::new(),
-                contract_analysis: Vec::new(),
-                transaction_patterns: Vec::new(),
-                anomalies: Vec::new(),
-            },
-            performance_insights: PerformanceInsights {
-                expected_execution_time: 200.0,
-                congestion_factor: 1.2,
-                optimal_timing: Vec::new(),
-                bottlenecks: Vec::new(),
-                scalability_concerns: Vec::new(),
-            },
-            market_analysis: MarketAnalysis {
-                volatility: 0.25,
-                liquidity_assessment: crate::types::LiquidityAssessment {
-                    liquidity_score: 0.85,
-                    available_liquidity: Wei::new("1000000000000000000000"),
-                    depth_analysis: HashMap::new(),
-                    sources: vec!["Uniswap".to_string()],
-                },
-                price_impact: crate::types::PriceImpact {
-                    impact_percentage: 0.01,
-                    before_price: "100.00".to_string(),
-                    after_price: "100.01".to_string(),
-                    slippage: 0.0001,
-                },
-                sentiment_score: 0.1,
-                market_events: Vec::new(),
-            },
-            recommendations: vec![
-                Recommendation {
-                    recommendation_type: crate::types::RecommendationType::GasOptimization,
-                    description: "Consider reducing gas limit by 10%".to_string(),
-                    priority: crate::types::Priority::Medium,
-                    expected_impact: "Reduce transaction cost by ~10%".to_string(),
-                    difficulty: crate::types::Difficulty::Easy,
-                    confidence: 0.8,
-                },
-            ],
-            patterns: vec![
-                Pattern {
-                    id: "standard_transfer".to_string(),
-                    name: "Standard Token Transfer".to_string(),
-                    description: "Basic ERC-20 token transfer pattern".to_string(),
-                    category: "Token Operations".to_string(),
-                    confidence: 0.95,
-                    success_rate: 0.99,
-                    insights: vec![
-                        "Low risk operation".to_string(),
-                        "Predictable gas usage".to_string(),
-                    ],
-                },
-            ],
-            confidence_score: 0.85,
-        })
+        }
+        self.default_provider.analyze(simulation_result).await
     }
 
-    async fn optimize_bundle_internal(
-        &self,
-        simulations: &[SimulationResult],
-        _analyses: &[(usize, AIInsights)],
-    ) -> Result<BundleOptimization> {
-        let original_order: Vec<usize> = (0..simulations.len()).collect();
-        
-        // In a real implementation, this would use AI to optimize the order
-        // For now, use a simple heuristic: successful transactions first
-        let mut optimized_order = original_order.clone();
-        optimized_order.sort_by(|&a, &b| {
-            let success_a = simulations[a].success;
-            let success_b = simulations[b].success;
-            success_b.cmp(&success_a) // successful transactions first
-        });
-
-        let total_gas_original: u64 = simulations
-            .iter()
-            .map(|s| s.gas_used.parse().unwrap_or(0))
-            .sum();
-
-        let gas_savings = total_gas_original / 20; // Mock 5% savings
-
-        let mut transaction_optimizations = Vec::new();
-        for (i, simulation) in simulations.iter().enumerate() {
-            let current_gas = simulation.gas_used.parse::<u64>().unwrap_or(0);
-            let optimized_gas = current_gas.saturating_sub(current_gas / 10);
-            
-            transaction_optimizations.push(TransactionOptimization {
-                index: i,
-                suggested_gas_limit: Some(optimized_gas.to_string()),
-                suggested_gas_price: Some(Wei::new("20000000000")),
-                suggested_max_fee_per_gas: Some(Wei::new("25000000000")),
-                suggested_max_priority_fee_per_gas: Some(Wei::new("2000000000")),
-                recommendations: vec![
-                    "Optimize gas limit".to_string(),
-                    "Consider timing optimization".to_string(),
-                ],
-                warnings: if simulation.success {
-                    Vec::new()
-                } else {
-                    vec!["Transaction may fail".to_string()]
-                },
-            });
+    /// Same provider-then-fallback behavior as [`Self::analyze_via_providers`],
+    /// for bundle optimization.
+    async fn optimize_via_providers(&self, simulations: &[SimulationResult]) -> Result<BundleOptimization> {
+        if let Some(ref plugin_system) = *self.plugin_system.read().await {
+            if let Ok(optimization) = plugin_system.optimize_with_providers(simulations).await {
+                return Ok(optimization);
+            }
         }
+        self.default_provider.optimize(simulations).await
+    }
 
-        Ok(BundleOptimization {
-            original_order,
-            optimized_order,
-            gas_savings: gas_savings.to_string(),
-            time_savings: 2.5,
-            success_probability: 0.92,
-            transaction_optimizations,
-            recommendations: vec![
-                "Execute successful transactions first".to_string(),
-                "Consider adjusting gas prices based on network congestion".to_string(),
-                "Monitor for MEV opportunities".to_string(),
-            ],
-        })
+
+    /// The current Merkle root over every insight this analyzer has cached
+    /// so far, for audit purposes
+    pub async fn cache_merkle_root(&self) -> [u8; 32] {
+        self.merkle.read().await.root()
+    }
+
+    /// A Merkle inclusion proof that `cache_key` (as produced by
+    /// [`Self::generate_cache_key`]) was genuinely cached by this analyzer,
+    /// or `None` if it never was
+    pub async fn verify_cached_insight(&self, cache_key: &str) -> Option<MerkleProof> {
+        self.merkle.read().await.verify(cache_key)
     }
 
     fn generate_cache_key(&self, simulation_result: &SimulationResult) -> String {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
-        
+
         let mut hasher = DefaultHasher::new();
         simulation_result.success.hash(&mut hasher);
         simulation_result.gas_used.hash(&mut hasher);
         if let Some(ref error) = simulation_result.error {
             error.hash(&mut hasher);
         }
-        
+
         format!("sim_{:x}", hasher.finish())
     }
 
-    async fn get_cached_analysis(&self, cache_key: &str) -> Option<CachedAnalysis> {
-        let cache = self.cache.read().await;
-        cache.get(cache_key)
-            .filter(|cached| !cached.is_expired())
-            .cloned()
+    /// Read a cached analysis through the highest-priority registered cache
+    /// store, falling back to `default_cache`, discarding it (and evicting
+    /// it from the store) if it has expired.
+    async fn get_cached_analysis(&self, cache_key: &str) -> Option<AIInsights> {
+        let raw = match &*self.plugin_system.read().await {
+            Some(plugin_system) => match plugin_system.read_cached_insight(cache_key).await {
+                Ok(Some(raw)) => Some(raw),
+                Ok(None) => None,
+                Err(_) => self.default_cache.read(cache_key).await.ok().flatten(),
+            },
+            None => self.default_cache.read(cache_key).await.ok().flatten(),
+        }?;
+
+        match decode_record(&raw) {
+            Ok(Some(insights)) => Some(insights),
+            Ok(None) => {
+                self.evict_cached_analysis(cache_key).await;
+                None
+            }
+            Err(_) => None,
+        }
     }
 
+    /// Write a cached analysis through the highest-priority registered
+    /// cache store (falling back to `default_cache` if none is registered
+    /// or the write fails), and fold it into the Merkle audit trail.
     async fn cache_analysis(&self, cache_key: &str, insights: AIInsights) {
-        let cached = CachedAnalysis {
-            insights,
-            timestamp: std::time::Instant::now(),
-            ttl: std::time::Duration::from_secs(300), // 5 minutes
+        self.merkle.write().await.insert(cache_key, &insights).ok();
+
+        let Ok(encoded) = encode_record(insights, CACHE_TTL_SECS) else { return };
+
+        let wrote_via_plugin = match &*self.plugin_system.read().await {
+            Some(plugin_system) => plugin_system.write_cached_insight(cache_key, encoded.clone()).await.is_ok(),
+            None => false,
         };
-        
-        let mut cache = self.cache.write().await;
-        cache.insert(cache_key.to_string(), cached);
-        
-        // Simple cache cleanup: remove expired entries if cache is too large
-        if cache.len() > 1000 {
-            cache.retain(|_, v| !v.is_expired());
+
+        if !wrote_via_plugin {
+            let _ = self.default_cache.write(cache_key, encoded).await;
+        }
+    }
+
+    async fn evict_cached_analysis(&self, cache_key: &str) {
+        if let Some(plugin_system) = &*self.plugin_system.read().await {
+            let _ = plugin_system.evict_cached_insight(cache_key).await;
         }
+        let _ = self.default_cache.evict(cache_key).await;
     }
 }
 
 impl std::fmt::Debug for AIAnalyzer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("AIAnalyzer")
-            .field("api_key", &"<redacted>")
-            .finish()
+        f.debug_struct("AIAnalyzer").finish_non_exhaustive()
     }
 }
 
@@ -326,13 +241,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_ai_analyzer_creation() {
-        let analyzer = AIAnalyzer::new("test-api-key".to_string()).await.unwrap();
-        assert!(!analyzer.api_key.is_empty());
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        assert!(analyzer.merkle.read().await.is_empty());
     }
 
     #[tokio::test]
     async fn test_simulation_analysis() {
-        let analyzer = AIAnalyzer::new("test-api-key".to_string()).await.unwrap();
+        let analyzer = AIAnalyzer::new().await.unwrap();
         
         let simulation_result = SimulationResult {
             success: true,
@@ -347,6 +262,17 @@ mod tests {
             state_changes: Vec::new(),
             events: Vec::new(),
             tx_hash: None,
+            verification: crate::verification::VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
         };
 
         let insights = analyzer.analyze_simulation(simulation_result).await.unwrap();
@@ -355,9 +281,40 @@ mod tests {
         assert!(!insights.recommendations.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_analyze_simulation_caches_through_registered_plugin_store() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let plugin_system = std::sync::Arc::new(crate::plugins::PluginSystem::new().await.unwrap());
+        plugin_system
+            .load_plugin(crate::plugins::PluginConfig::new("in-memory-cache").priority(50))
+            .await
+            .unwrap();
+        analyzer.set_plugin_system(std::sync::Arc::clone(&plugin_system)).await;
+
+        let simulation_result = simulation_with_accesses(Vec::new());
+        let cache_key = analyzer.generate_cache_key(&simulation_result);
+
+        analyzer.analyze_simulation(simulation_result).await.unwrap();
+
+        assert!(plugin_system.read_cached_insight(&cache_key).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cached_insight_is_provable_against_the_merkle_root() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let simulation_result = simulation_with_accesses(Vec::new());
+        let cache_key = analyzer.generate_cache_key(&simulation_result);
+
+        analyzer.analyze_simulation(simulation_result).await.unwrap();
+
+        let root = analyzer.cache_merkle_root().await;
+        let proof = analyzer.verify_cached_insight(&cache_key).await.unwrap();
+        assert!(proof.verify(root));
+    }
+
     #[tokio::test]
     async fn test_bundle_optimization() {
-        let analyzer = AIAnalyzer::new("test-api-key".to_string()).await.unwrap();
+        let analyzer = AIAnalyzer::new().await.unwrap();
         
         let simulations = vec![
             SimulationResult {
@@ -373,6 +330,17 @@ mod tests {
                 state_changes: Vec::new(),
                 events: Vec::new(),
                 tx_hash: None,
+                verification: crate::verification::VerificationStatus::Unverified,
+                base_fee_per_gas: None,
+                effective_gas_price: None,
+                burned_fee: None,
+                gas_limit: None,
+                max_fee_per_gas: None,
+                blob_count: None,
+                blob_base_fee: None,
+                max_priority_fee_per_gas: None,
+                calldata_size: None,
+                tx_type: None,
             },
             SimulationResult {
                 success: false,
@@ -387,13 +355,316 @@ mod tests {
                 state_changes: Vec::new(),
                 events: Vec::new(),
                 tx_hash: None,
+                verification: crate::verification::VerificationStatus::Unverified,
+                base_fee_per_gas: None,
+                effective_gas_price: None,
+                burned_fee: None,
+                gas_limit: None,
+                max_fee_per_gas: None,
+                blob_count: None,
+                blob_base_fee: None,
+                max_priority_fee_per_gas: None,
+                calldata_size: None,
+                tx_type: None,
             },
         ];
 
         let optimization = analyzer.optimize_bundle(simulations).await.unwrap();
         assert_eq!(optimization.original_order, vec![0, 1]);
-        // Successful transaction should be first in optimized order
-        assert_eq!(optimization.optimized_order[0], 0);
+        // Neither transaction has a trace, so there's no conflict between
+        // them and the identity order is kept.
+        assert_eq!(optimization.optimized_order, vec![0, 1]);
         assert!(optimization.success_probability > 0.8);
     }
+
+    fn simulation_with_accesses(
+        accesses: Vec<crate::types::StorageAccess>,
+    ) -> SimulationResult {
+        SimulationResult {
+            success: true,
+            gas_used: "21000".to_string(),
+            return_data: None,
+            error: None,
+            revert_reason: None,
+            block_type: BlockType::Fast,
+            estimated_block: 12345,
+            trace: Some(crate::types::ExecutionTrace {
+                calls: Vec::new(),
+                gas_breakdown: crate::types::GasBreakdown {
+                    intrinsic: "0".to_string(),
+                    execution: "0".to_string(),
+                    cold_access: "0".to_string(),
+                    warm_access: "0".to_string(),
+                    refund: "0".to_string(),
+                    total: "0".to_string(),
+                },
+                storage_accesses: accesses,
+                opcode_steps: Vec::new(),
+            }),
+            hypercore_data: None,
+            state_changes: Vec::new(),
+            events: Vec::new(),
+            tx_hash: None,
+            verification: crate::verification::VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
+        }
+    }
+
+    fn storage_access(
+        address: &Address,
+        slot: &str,
+        access_type: crate::types::StorageAccessType,
+    ) -> crate::types::StorageAccess {
+        crate::types::StorageAccess {
+            address: address.clone(),
+            slot: slot.to_string(),
+            access_type,
+            original_value: None,
+            new_value: None,
+            cold: false,
+            gas_cost: "0".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bundle_optimization_preserves_raw_dependency_order() {
+        use crate::types::StorageAccessType;
+
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let contract = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        // tx0 writes the slot tx1 later reads: tx1 must stay after tx0.
+        let simulations = vec![
+            simulation_with_accesses(vec![storage_access(&contract, slot, StorageAccessType::Write)]),
+            simulation_with_accesses(vec![storage_access(&contract, slot, StorageAccessType::Read)]),
+        ];
+
+        let optimization = analyzer.optimize_bundle(simulations).await.unwrap();
+        assert_eq!(optimization.optimized_order, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_bundle_optimization_flags_write_write_conflicts() {
+        use crate::types::StorageAccessType;
+
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let contract = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        let simulations = vec![
+            simulation_with_accesses(vec![storage_access(&contract, slot, StorageAccessType::Write)]),
+            simulation_with_accesses(vec![storage_access(&contract, slot, StorageAccessType::Write)]),
+        ];
+
+        let optimization = analyzer.optimize_bundle(simulations).await.unwrap();
+        assert!(optimization.transaction_optimizations[0]
+            .warnings
+            .iter()
+            .any(|w| w.contains("write-write conflict")));
+        assert!(optimization.transaction_optimizations[1]
+            .warnings
+            .iter()
+            .any(|w| w.contains("write-write conflict")));
+    }
+
+    #[tokio::test]
+    async fn test_bundle_optimization_groups_overlapping_addresses() {
+        use crate::types::StorageAccessType;
+
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let a = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let b = Address::new("0x0000000000000000000000000000000000000001").unwrap();
+        let slot_a = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let slot_b = "0x0000000000000000000000000000000000000000000000000000000000000002";
+
+        // tx0 touches `a`, tx1 touches unrelated `b`, tx2 touches `a` again.
+        // None of these conflict, so the optimizer is free to group the two
+        // `a` transactions adjacently instead of re-warming `a` twice.
+        let simulations = vec![
+            simulation_with_accesses(vec![storage_access(&a, slot_a, StorageAccessType::Read)]),
+            simulation_with_accesses(vec![storage_access(&b, slot_b, StorageAccessType::Read)]),
+            simulation_with_accesses(vec![storage_access(&a, slot_a, StorageAccessType::Write)]),
+        ];
+
+        let optimization = analyzer.optimize_bundle(simulations).await.unwrap();
+        assert_eq!(optimization.optimized_order, vec![0, 2, 1]);
+        assert!(optimization.gas_savings.parse::<u64>().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_access_list_includes_repeatedly_touched_slot() {
+        use crate::types::StorageAccessType;
+
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let contract = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        // Three touches to the same slot: declaring it upfront pays for
+        // itself (2 warm discounts outweigh the declaration cost).
+        let simulation = simulation_with_accesses(vec![
+            storage_access(&contract, slot, StorageAccessType::Read),
+            storage_access(&contract, slot, StorageAccessType::Write),
+            storage_access(&contract, slot, StorageAccessType::Read),
+        ]);
+
+        let insights = analyzer.analyze_simulation(simulation).await.unwrap();
+        let access_list = &insights.gas_optimization.access_list;
+        assert_eq!(access_list.len(), 1);
+        assert_eq!(access_list[0].address, contract);
+        assert_eq!(access_list[0].storage_keys, vec![crate::types::Hash::new(slot).unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_access_list_omits_slot_touched_once() {
+        use crate::types::StorageAccessType;
+
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let contract = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        let simulation =
+            simulation_with_accesses(vec![storage_access(&contract, slot, StorageAccessType::Read)]);
+
+        let insights = analyzer.analyze_simulation(simulation).await.unwrap();
+        assert!(insights.gas_optimization.access_list.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_access_list_empty_without_trace() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let simulation = SimulationResult {
+            success: true,
+            gas_used: "21000".to_string(),
+            return_data: None,
+            error: None,
+            revert_reason: None,
+            block_type: BlockType::Fast,
+            estimated_block: 12345,
+            trace: None,
+            hypercore_data: None,
+            state_changes: Vec::new(),
+            events: Vec::new(),
+            tx_hash: None,
+            verification: crate::verification::VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
+        };
+
+        let insights = analyzer.analyze_simulation(simulation).await.unwrap();
+        assert!(insights.gas_optimization.access_list.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fee_breakdown_invariant_holds() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+
+        // base_fee + priority (20 gwei) exactly meets max_fee_per_gas, so
+        // effective_gas_price == max_fee_per_gas and the refund/burn split
+        // accounts for every wei of gas_limit * effective_gas_price.
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.gas_used = "21000".to_string();
+        simulation.gas_limit = Some("30000".to_string());
+        simulation.base_fee_per_gas = Some(Wei::new("10000000000"));
+        simulation.effective_gas_price = Some(Wei::new("30000000000"));
+        simulation.max_fee_per_gas = Some(Wei::new("30000000000"));
+
+        let insights = analyzer.analyze_simulation(simulation).await.unwrap();
+        let gas_optimization = insights.gas_optimization;
+
+        let effective_gas_price: u128 = gas_optimization.effective_gas_price.unwrap().as_str().parse().unwrap();
+        let base_fee_burn: u128 = gas_optimization.base_fee_burn.unwrap().as_str().parse().unwrap();
+        let priority_tip: u128 = gas_optimization.priority_tip.unwrap().as_str().parse().unwrap();
+        let over_estimation_burn: u128 = gas_optimization.over_estimation_burn.unwrap().as_str().parse().unwrap();
+        let refund: u128 = gas_optimization.refund.unwrap().as_str().parse().unwrap();
+
+        assert!(over_estimation_burn > 0);
+        assert_eq!(
+            base_fee_burn + priority_tip + refund,
+            30_000u128 * effective_gas_price - over_estimation_burn
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fee_breakdown_absent_without_eip1559_context() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let simulation = simulation_with_accesses(Vec::new());
+
+        let insights = analyzer.analyze_simulation(simulation).await.unwrap();
+        assert!(insights.gas_optimization.effective_gas_price.is_none());
+        assert!(insights.gas_optimization.base_fee_burn.is_none());
+        assert!(insights.gas_optimization.priority_tip.is_none());
+        assert!(insights.gas_optimization.over_estimation_burn.is_none());
+        assert!(insights.gas_optimization.refund.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_gas_optimization_computes_blob_fee() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.blob_count = Some(2);
+        simulation.blob_base_fee = Some(Wei::new("1000000000"));
+
+        let insights = analyzer.analyze_simulation(simulation).await.unwrap();
+        let gas_optimization = insights.gas_optimization;
+
+        assert_eq!(gas_optimization.blob_gas_used, Some((2 * 131_072).to_string()));
+        assert_eq!(
+            gas_optimization.blob_fee.unwrap().as_str(),
+            (2u128 * 131_072 * 1_000_000_000).to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recommendation_flags_blobs_in_excess_of_calldata_need() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.calldata_size = Some(100); // needs only 1 blob's worth of space
+        simulation.blob_count = Some(3);
+        simulation.blob_base_fee = Some(Wei::new("1000000000"));
+
+        let insights = analyzer.analyze_simulation(simulation).await.unwrap();
+        assert!(insights
+            .recommendations
+            .iter()
+            .any(|r| r.description.contains("3 blob(s)") && r.description.contains("needs 1")));
+    }
+
+    #[tokio::test]
+    async fn test_bundle_warns_when_reordering_exceeds_blob_limit() {
+        let analyzer = AIAnalyzer::new().await.unwrap();
+
+        let mut simulations = Vec::new();
+        for _ in 0..4 {
+            let mut simulation = simulation_with_accesses(Vec::new());
+            simulation.blob_count = Some(2);
+            simulations.push(simulation);
+        }
+
+        // 4 independent transactions at 2 blobs each: cumulative blobs for
+        // the (shared) Fast block type hit 8, over both the target (3) and
+        // the max (6), by the last transaction processed in bundle order.
+        let optimization = analyzer.optimize_bundle(simulations).await.unwrap();
+        assert!(optimization
+            .transaction_optimizations
+            .iter()
+            .any(|t| t.warnings.iter().any(|w| w.contains("per-block max"))));
+    }
 }
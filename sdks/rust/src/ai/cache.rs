@@ -0,0 +1,310 @@
+//! Merkle-backed audit trail for the analysis cache
+//!
+//! [`InsightMerkleTree`] gives [`AIAnalyzer`](super::AIAnalyzer) a
+//! tamper-evident, append-only record of every [`AIInsights`] it has
+//! cached, independent of which [`AnalysisCacheStore`](crate::plugins::cache::AnalysisCacheStore)
+//! backend actually holds the data: the root commits to the full history
+//! of insights served, and [`InsightMerkleTree::verify`] produces a proof
+//! a caller can check without trusting the SDK process.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{HyperSimError, Result};
+use crate::types::AIInsights;
+use crate::utils::abi::keccak256_hash;
+
+/// Which side of a hashed pair a [`MerkleProof`] sibling sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// A Merkle inclusion proof for one leaf of an [`InsightMerkleTree`]:
+/// the leaf's own hash, plus the sibling hash at each level needed to
+/// reconstruct the root.
+#[derive(Debug, Clone)]
+pub struct MerkleProof {
+    pub leaf_hash: [u8; 32],
+    pub siblings: Vec<([u8; 32], Side)>,
+}
+
+impl MerkleProof {
+    /// Recompute the root implied by this proof and compare it to `root`
+    pub fn verify(&self, root: [u8; 32]) -> bool {
+        let mut acc = self.leaf_hash;
+        for (sibling, side) in &self.siblings {
+            acc = match side {
+                Side::Left => hash_pair(*sibling, acc),
+                Side::Right => hash_pair(acc, *sibling),
+            };
+        }
+        acc == root
+    }
+}
+
+/// Insert-only binary Merkle tree accumulating `key -> hash(serialized AIInsights)`
+/// leaves in insertion order. Every cached analysis becomes a leaf, so the
+/// root commits to the full history of insights the analyzer has served —
+/// a caller who only sees the root can still verify, via [`Self::verify`],
+/// that a specific cached entry was genuinely served and hasn't been
+/// altered after the fact.
+#[derive(Debug, Default)]
+pub struct InsightMerkleTree {
+    leaves: Vec<[u8; 32]>,
+    index: HashMap<String, usize>,
+}
+
+impl InsightMerkleTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash `insights` and append it as the next leaf under `key`, updating
+    /// the root. Returns the new root.
+    pub fn insert(&mut self, key: &str, insights: &AIInsights) -> Result<[u8; 32]> {
+        let serialized = serde_json::to_vec(insights)
+            .map_err(|e| HyperSimError::serialization(format!("Failed to serialize AIInsights for Merkle leaf: {}", e)))?;
+        let leaf_hash = leaf_hash(key.as_bytes(), &serialized);
+
+        self.index.insert(key.to_string(), self.leaves.len());
+        self.leaves.push(leaf_hash);
+
+        Ok(self.root())
+    }
+
+    /// The current Merkle root over every leaf inserted so far
+    pub fn root(&self) -> [u8; 32] {
+        compute_root(&self.leaves)
+    }
+
+    /// Number of leaves accumulated so far
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Build an inclusion proof for `key`, or `None` if it was never inserted
+    pub fn verify(&self, key: &str) -> Option<MerkleProof> {
+        let &index = self.index.get(key)?;
+        Some(build_proof(&self.leaves, index))
+    }
+}
+
+fn leaf_hash(key: &[u8], serialized_insights: &[u8]) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(key.len() + serialized_insights.len() + 1);
+    preimage.extend_from_slice(key);
+    preimage.push(0); // separator so a key/value split can't be re-arranged into a collision
+    preimage.extend_from_slice(serialized_insights);
+    keccak256_hash(&preimage)
+}
+
+fn hash_pair(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(64);
+    preimage.extend_from_slice(&left);
+    preimage.extend_from_slice(&right);
+    keccak256_hash(&preimage)
+}
+
+/// Reduce one level of a binary Merkle tree: pairs hash together, an
+/// unpaired trailing node is promoted unchanged rather than duplicated
+/// (avoiding the classic duplicate-last-leaf ambiguity).
+fn reduce_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    let mut next = Vec::with_capacity((level.len() + 1) / 2);
+    let mut i = 0;
+    while i < level.len() {
+        if i + 1 < level.len() {
+            next.push(hash_pair(level[i], level[i + 1]));
+        } else {
+            next.push(level[i]);
+        }
+        i += 2;
+    }
+    next
+}
+
+fn compute_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = reduce_level(&level);
+    }
+    level[0]
+}
+
+fn build_proof(leaves: &[[u8; 32]], mut index: usize) -> MerkleProof {
+    let leaf_hash = leaves[index];
+    let mut siblings = Vec::new();
+    let mut level = leaves.to_vec();
+
+    while level.len() > 1 {
+        let sibling_index = index ^ 1;
+        if sibling_index < level.len() {
+            let side = if index % 2 == 0 { Side::Right } else { Side::Left };
+            siblings.push((level[sibling_index], side));
+        }
+        level = reduce_level(&level);
+        index /= 2;
+    }
+
+    MerkleProof { leaf_hash, siblings }
+}
+
+/// A cached analysis plus the wall-clock metadata needed to expire it, in a
+/// form that can round-trip through a persistable [`AnalysisCacheStore`](crate::plugins::cache::AnalysisCacheStore)
+/// and still be interpreted correctly after a process restart (unlike
+/// `std::time::Instant`, which only makes sense within one process's
+/// lifetime).
+#[derive(Serialize, Deserialize)]
+struct CachedAnalysisRecord {
+    insights: AIInsights,
+    cached_at_unix_secs: u64,
+    ttl_secs: u64,
+}
+
+impl CachedAnalysisRecord {
+    fn new(insights: AIInsights, ttl_secs: u64) -> Self {
+        let cached_at_unix_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self { insights, cached_at_unix_secs, ttl_secs }
+    }
+
+    fn is_expired(&self) -> bool {
+        let now_unix_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        now_unix_secs.saturating_sub(self.cached_at_unix_secs) >= self.ttl_secs
+    }
+}
+
+/// Serialize `insights` as a TTL-stamped record suitable for any
+/// [`AnalysisCacheStore`](crate::plugins::cache::AnalysisCacheStore)
+pub(super) fn encode_record(insights: AIInsights, ttl_secs: u64) -> Result<String> {
+    serde_json::to_string(&CachedAnalysisRecord::new(insights, ttl_secs))
+        .map_err(|e| HyperSimError::serialization(format!("Failed to serialize cached analysis: {}", e)))
+}
+
+/// Decode a stored record, returning `None` if it has expired
+pub(super) fn decode_record(raw: &str) -> Result<Option<AIInsights>> {
+    let record: CachedAnalysisRecord = serde_json::from_str(raw)
+        .map_err(|e| HyperSimError::serialization(format!("Failed to deserialize cached analysis: {}", e)))?;
+    Ok(if record.is_expired() { None } else { Some(record.insights) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::analysis::{AnalysisProvider, HeuristicAnalysisProvider};
+    use crate::types::{BlockType, SimulationResult};
+
+    fn minimal_simulation_result() -> SimulationResult {
+        SimulationResult {
+            success: true,
+            gas_used: "21000".to_string(),
+            return_data: None,
+            error: None,
+            revert_reason: None,
+            block_type: BlockType::Fast,
+            estimated_block: 12345,
+            trace: None,
+            hypercore_data: None,
+            state_changes: Vec::new(),
+            events: Vec::new(),
+            tx_hash: None,
+            verification: crate::verification::VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
+        }
+    }
+
+    async fn sample_insights(confidence_score: f64) -> AIInsights {
+        let mut insights = HeuristicAnalysisProvider::new()
+            .analyze(&minimal_simulation_result())
+            .await
+            .unwrap();
+        insights.confidence_score = confidence_score;
+        insights
+    }
+
+    #[tokio::test]
+    async fn test_merkle_tree_single_leaf_proof_verifies_against_root() {
+        let mut tree = InsightMerkleTree::new();
+        let root = tree.insert("sim_1", &sample_insights(0.5).await).unwrap();
+
+        let proof = tree.verify("sim_1").unwrap();
+        assert!(proof.verify(root));
+    }
+
+    #[tokio::test]
+    async fn test_merkle_tree_proof_verifies_for_every_leaf_in_odd_sized_tree() {
+        let mut tree = InsightMerkleTree::new();
+        let mut root = [0u8; 32];
+        for i in 0..5 {
+            root = tree.insert(&format!("sim_{}", i), &sample_insights(i as f64 / 10.0).await).unwrap();
+        }
+
+        for i in 0..5 {
+            let proof = tree.verify(&format!("sim_{}", i)).unwrap();
+            assert!(proof.verify(root), "leaf sim_{} failed to verify", i);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merkle_tree_root_changes_after_insert() {
+        let mut tree = InsightMerkleTree::new();
+        let root_after_one = tree.insert("sim_1", &sample_insights(0.1).await).unwrap();
+        let root_after_two = tree.insert("sim_2", &sample_insights(0.2).await).unwrap();
+        assert_ne!(root_after_one, root_after_two);
+    }
+
+    #[tokio::test]
+    async fn test_merkle_tree_verify_returns_none_for_unknown_key() {
+        let mut tree = InsightMerkleTree::new();
+        tree.insert("sim_1", &sample_insights(0.1).await).unwrap();
+        assert!(tree.verify("sim_unknown").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_merkle_proof_fails_against_wrong_root() {
+        let mut tree = InsightMerkleTree::new();
+        tree.insert("sim_1", &sample_insights(0.1).await).unwrap();
+        tree.insert("sim_2", &sample_insights(0.2).await).unwrap();
+
+        let proof = tree.verify("sim_1").unwrap();
+        assert!(!proof.verify([0xAB; 32]));
+    }
+
+    #[tokio::test]
+    async fn test_record_round_trips_through_json() {
+        let encoded = encode_record(sample_insights(0.7).await, 300).unwrap();
+        let decoded = decode_record(&encoded).unwrap();
+        assert_eq!(decoded.unwrap().confidence_score, 0.7);
+    }
+
+    #[tokio::test]
+    async fn test_record_reports_expired_past_ttl() {
+        let encoded = encode_record(sample_insights(0.7).await, 0).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let decoded = decode_record(&encoded).unwrap();
+        assert!(decoded.is_none());
+    }
+}
@@ -0,0 +1,386 @@
+//! Constant-product AMM pricing engine backing `PriceImpact` and
+//! `LiquidityAssessment`
+//!
+//! Until the SDK wires up a live pool-reserve feed, [`default_pools`]
+//! stands in for [`assess_market`]'s inputs — the formulas themselves are
+//! the real thing: the same `x*y=k` math any constant-product DEX (Uniswap
+//! V2/V3, a Balancer weighted-50/50 pool, ...) uses to price a swap.
+
+use std::collections::HashMap;
+
+use crate::types::{LiquidityAssessment, PriceImpact, VenueConstraints, Wei};
+
+/// Input sizes swept for `LiquidityAssessment::depth_analysis`, as a
+/// fraction of the combined pool's `x` reserve
+const DEPTH_SWEEP_FRACTIONS: [f64; 4] = [0.01, 0.05, 0.10, 0.25];
+
+/// Number of increments [`aggregate_across_pools`] splits a trade into when
+/// greedily routing across pools. Finer steps track the true optimal split
+/// more closely, at the cost of more marginal-price comparisons.
+const AGGREGATION_STEPS: u32 = 50;
+
+/// One constant-product liquidity source: `reserve_in`/`reserve_out` are
+/// the pool's token reserves (smallest units) on the input/output side of
+/// the swap, `fee` is the swap fee as a fraction (e.g. `0.003` for 0.3%).
+#[derive(Debug, Clone)]
+pub struct PoolReserves {
+    pub name: String,
+    pub reserve_in: f64,
+    pub reserve_out: f64,
+    pub fee: f64,
+}
+
+/// A stand-in set of pools representative of typical HyperEVM DEX
+/// liquidity, used until a live reserve feed is wired in
+pub fn default_pools() -> Vec<PoolReserves> {
+    vec![
+        PoolReserves {
+            name: "Uniswap V3".to_string(),
+            reserve_in: 1_000_000.0 * 1e18,
+            reserve_out: 100_000_000.0 * 1e18,
+            fee: 0.003,
+        },
+        PoolReserves {
+            name: "Balancer".to_string(),
+            reserve_in: 400_000.0 * 1e18,
+            reserve_out: 39_800_000.0 * 1e18,
+            fee: 0.003,
+        },
+    ]
+}
+
+/// A stand-in venue order-book filter, representative of a typical HyperEVM
+/// DEX front-end, used until a live venue-constraints feed is wired in
+pub fn default_venue() -> VenueConstraints {
+    VenueConstraints {
+        name: "HyperEVM DEX aggregator".to_string(),
+        rate_limit: 10,
+        rate_limit_interval_secs: 60,
+        min_lot: 1e15,
+        tick_size: 1e12,
+        min_notional: 1e16,
+    }
+}
+
+/// Round `trade_size` down to the nearest `tick_size` multiple and check the
+/// result against `min_lot`/`min_notional`. Returns the (possibly rounded)
+/// size, or `Err` with a human-readable reason when no size respecting the
+/// venue's filters can be formed.
+fn apply_venue_filters(trade_size: f64, price: f64, venue: &VenueConstraints) -> Result<f64, String> {
+    if venue.tick_size <= 0.0 {
+        return Err(format!("{}: non-positive tick_size", venue.name));
+    }
+
+    let rounded = (trade_size / venue.tick_size).floor() * venue.tick_size;
+    if rounded < venue.min_lot {
+        return Err(format!(
+            "{}: trade size {:.4} rounds to {:.4}, below min_lot {:.4}",
+            venue.name, trade_size, rounded, venue.min_lot
+        ));
+    }
+
+    let notional = rounded * price;
+    if notional < venue.min_notional {
+        return Err(format!(
+            "{}: notional {:.4} below min_notional {:.4}",
+            venue.name, notional, venue.min_notional
+        ));
+    }
+
+    Ok(rounded)
+}
+
+/// The constant-product swap output for trading `dx` of the input token
+/// into a pool with reserves `(x, y)` and fee `fee`:
+/// `dy = (y * dx * (1 - fee)) / (x + dx * (1 - fee))`
+fn swap_output(x: f64, y: f64, fee: f64, dx: f64) -> f64 {
+    let dx_after_fee = dx * (1.0 - fee);
+    let denominator = x + dx_after_fee;
+    if denominator <= 0.0 {
+        return 0.0;
+    }
+    (y * dx_after_fee) / denominator
+}
+
+/// Price impact and slippage for trading `dx` into a single pool `(x, y, fee)`.
+/// `before_price`/`after_price` are the pool's spot price (`y / x`) before
+/// and after the trade; `slippage` compares the actual output to what the
+/// pre-trade spot rate would have implied (`dx * before_price`).
+fn price_impact(x: f64, y: f64, fee: f64, dx: f64) -> PriceImpact {
+    let before_price = y / x;
+    let dy = swap_output(x, y, fee, dx);
+    let after_price = (y - dy) / (x + dx);
+
+    let impact_percentage = if before_price == 0.0 {
+        0.0
+    } else {
+        (after_price - before_price) / before_price * 100.0
+    };
+
+    let spot_implied_dy = dx * before_price;
+    let slippage = if spot_implied_dy == 0.0 {
+        0.0
+    } else {
+        (spot_implied_dy - dy) / spot_implied_dy
+    };
+
+    PriceImpact {
+        impact_percentage,
+        before_price: format!("{:.6}", before_price),
+        after_price: format!("{:.6}", after_price),
+        slippage,
+    }
+}
+
+/// A monotonically decreasing liquidity score in `(0.0, 1.0]`: zero impact
+/// scores 1.0, and the score falls toward 0 as impact grows
+fn liquidity_score_from_impact(impact_percentage: f64) -> f64 {
+    1.0 / (1.0 + impact_percentage.abs())
+}
+
+/// Greedily split `total_dx` across `pools` in [`AGGREGATION_STEPS`]
+/// increments, routing each increment to whichever pool currently offers
+/// the best marginal output — i.e. a discrete approximation of the
+/// marginal-price-equalizing allocation that minimizes total price impact.
+/// Returns `(amount_in, amount_out)` per pool, aligned with `pools`, and
+/// the combined output.
+fn aggregate_across_pools(pools: &[PoolReserves], total_dx: f64) -> (Vec<(f64, f64)>, f64) {
+    if pools.is_empty() || total_dx <= 0.0 {
+        return (vec![(0.0, 0.0); pools.len()], 0.0);
+    }
+
+    let mut state: Vec<PoolReserves> = pools.to_vec();
+    let mut allocated = vec![(0.0, 0.0); pools.len()];
+    let step_size = total_dx / AGGREGATION_STEPS as f64;
+
+    for _ in 0..AGGREGATION_STEPS {
+        let best = state
+            .iter()
+            .enumerate()
+            .map(|(i, pool)| (i, swap_output(pool.reserve_in, pool.reserve_out, pool.fee, step_size)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let Some((best_idx, dy)) = best else { break };
+
+        state[best_idx].reserve_in += step_size;
+        state[best_idx].reserve_out -= dy;
+        allocated[best_idx].0 += step_size;
+        allocated[best_idx].1 += dy;
+    }
+
+    let total_dy = allocated.iter().map(|(_, dy)| dy).sum();
+    (allocated, total_dy)
+}
+
+/// Build a `LiquidityAssessment`/`PriceImpact` pair from a set of
+/// constant-product pools: `reference_trade_fraction` of the combined `x`
+/// reserve is split across `pools` via [`aggregate_across_pools`], and
+/// `depth_analysis` sweeps [`DEPTH_SWEEP_FRACTIONS`] of the same combined
+/// reserve through the single-pool formula to show how impact grows with
+/// trade size.
+///
+/// When `venue` is given, the reference trade size is first rounded/rejected
+/// via [`apply_venue_filters`]; any rounding or rejection is returned as a
+/// bottleneck string alongside the usual result, so callers can surface it
+/// through `PerformanceInsights::bottlenecks`.
+pub fn assess_market(
+    pools: &[PoolReserves],
+    reference_trade_fraction: f64,
+    venue: Option<&VenueConstraints>,
+) -> (LiquidityAssessment, PriceImpact, Vec<String>) {
+    let total_reserve_in: f64 = pools.iter().map(|p| p.reserve_in).sum();
+    let total_reserve_out: f64 = pools.iter().map(|p| p.reserve_out).sum();
+    let weighted_fee = if total_reserve_in > 0.0 {
+        pools.iter().map(|p| p.fee * p.reserve_in).sum::<f64>() / total_reserve_in
+    } else {
+        0.0
+    };
+
+    let spot_price = if total_reserve_in > 0.0 { total_reserve_out / total_reserve_in } else { 0.0 };
+    let mut bottlenecks = Vec::new();
+    let reference_dx = match venue {
+        Some(venue) => {
+            let requested = total_reserve_in * reference_trade_fraction;
+            match apply_venue_filters(requested, spot_price, venue) {
+                Ok(rounded) => {
+                    if rounded != requested {
+                        bottlenecks.push(format!(
+                            "{}: rounded reference trade size {:.4} down to {:.4} for tick_size {:.4}",
+                            venue.name, requested, rounded, venue.tick_size
+                        ));
+                    }
+                    rounded
+                }
+                Err(reason) => {
+                    bottlenecks.push(reason);
+                    0.0
+                }
+            }
+        }
+        None => total_reserve_in * reference_trade_fraction,
+    };
+
+    let (allocations, total_dy) = aggregate_across_pools(pools, reference_dx);
+
+    let before_price = spot_price;
+    let after_price = if total_reserve_in + reference_dx > 0.0 {
+        (total_reserve_out - total_dy) / (total_reserve_in + reference_dx)
+    } else {
+        0.0
+    };
+    let impact_percentage = if before_price == 0.0 { 0.0 } else { (after_price - before_price) / before_price * 100.0 };
+    let spot_implied_dy = reference_dx * before_price;
+    let slippage = if spot_implied_dy == 0.0 { 0.0 } else { (spot_implied_dy - total_dy) / spot_implied_dy };
+
+    let mut depth_analysis = HashMap::new();
+    for &fraction in &DEPTH_SWEEP_FRACTIONS {
+        let dx = total_reserve_in * fraction;
+        let sweep = price_impact(total_reserve_in, total_reserve_out, weighted_fee, dx);
+        depth_analysis.insert(format!("{}%", (fraction * 100.0) as u32), format!("{:.4}%", sweep.impact_percentage));
+    }
+
+    let sources = pools
+        .iter()
+        .zip(allocations.iter())
+        .filter(|(_, (amount_in, _))| *amount_in > 0.0)
+        .map(|(pool, _)| pool.name.clone())
+        .collect();
+
+    let aggregate_impact = PriceImpact {
+        impact_percentage,
+        before_price: format!("{:.6}", before_price),
+        after_price: format!("{:.6}", after_price),
+        slippage,
+    };
+
+    let liquidity_assessment = LiquidityAssessment {
+        liquidity_score: liquidity_score_from_impact(aggregate_impact.impact_percentage),
+        available_liquidity: Wei::new((total_reserve_out.max(0.0).round() as u128).to_string()),
+        depth_analysis,
+        sources,
+    };
+
+    (liquidity_assessment, aggregate_impact, bottlenecks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single_pool() -> PoolReserves {
+        PoolReserves { name: "test-pool".to_string(), reserve_in: 1_000_000.0, reserve_out: 100_000_000.0, fee: 0.003 }
+    }
+
+    #[test]
+    fn test_swap_output_is_positive_and_below_spot_rate() {
+        let pool = single_pool();
+        let dy = swap_output(pool.reserve_in, pool.reserve_out, pool.fee, 10_000.0);
+        let spot_rate = pool.reserve_out / pool.reserve_in;
+        assert!(dy > 0.0);
+        assert!(dy < 10_000.0 * spot_rate, "constant-product output must fall short of the spot rate");
+    }
+
+    #[test]
+    fn test_price_impact_after_price_is_lower_for_buying_pressure_on_output_token() {
+        let pool = single_pool();
+        let impact = price_impact(pool.reserve_in, pool.reserve_out, pool.fee, 50_000.0);
+        let before: f64 = impact.before_price.parse().unwrap();
+        let after: f64 = impact.after_price.parse().unwrap();
+        assert!(after < before);
+        assert!(impact.impact_percentage < 0.0);
+        assert!(impact.slippage > 0.0);
+    }
+
+    #[test]
+    fn test_price_impact_grows_with_trade_size() {
+        let pool = single_pool();
+        let small = price_impact(pool.reserve_in, pool.reserve_out, pool.fee, 1_000.0);
+        let large = price_impact(pool.reserve_in, pool.reserve_out, pool.fee, 100_000.0);
+        assert!(large.impact_percentage.abs() > small.impact_percentage.abs());
+    }
+
+    #[test]
+    fn test_liquidity_score_decreases_monotonically_with_impact() {
+        let low = liquidity_score_from_impact(0.1);
+        let high = liquidity_score_from_impact(5.0);
+        assert!(low > high);
+        assert!(liquidity_score_from_impact(0.0) == 1.0);
+    }
+
+    #[test]
+    fn test_aggregate_across_pools_uses_both_pools_when_comparable() {
+        let pools = vec![
+            PoolReserves { name: "a".to_string(), reserve_in: 1_000_000.0, reserve_out: 100_000_000.0, fee: 0.003 },
+            PoolReserves { name: "b".to_string(), reserve_in: 1_000_000.0, reserve_out: 100_000_000.0, fee: 0.003 },
+        ];
+        let (allocations, total_dy) = aggregate_across_pools(&pools, 200_000.0);
+        assert!(total_dy > 0.0);
+        assert!(allocations.iter().all(|(dx, _)| *dx > 0.0), "identical pools should split the trade");
+    }
+
+    #[test]
+    fn test_aggregate_across_pools_favors_deeper_pool() {
+        let pools = vec![
+            PoolReserves { name: "deep".to_string(), reserve_in: 10_000_000.0, reserve_out: 1_000_000_000.0, fee: 0.003 },
+            PoolReserves { name: "shallow".to_string(), reserve_in: 10_000.0, reserve_out: 1_000_000.0, fee: 0.003 },
+        ];
+        let (allocations, _) = aggregate_across_pools(&pools, 50_000.0);
+        assert!(allocations[0].0 > allocations[1].0, "the deeper pool should absorb more of the trade");
+    }
+
+    #[test]
+    fn test_assess_market_liquidity_score_decreases_with_trade_size() {
+        let pools = default_pools();
+        let (small_trade, _, _) = assess_market(&pools, 0.001, None);
+        let (large_trade, _, _) = assess_market(&pools, 0.25, None);
+        assert!(small_trade.liquidity_score > large_trade.liquidity_score);
+    }
+
+    #[test]
+    fn test_assess_market_depth_analysis_has_all_sweep_levels() {
+        let pools = default_pools();
+        let (assessment, _, _) = assess_market(&pools, 0.01, None);
+        assert_eq!(assessment.depth_analysis.len(), DEPTH_SWEEP_FRACTIONS.len());
+        assert!(assessment.depth_analysis.contains_key("1%"));
+        assert!(assessment.depth_analysis.contains_key("25%"));
+    }
+
+    #[test]
+    fn test_assess_market_sources_lists_pools_that_received_allocation() {
+        let pools = default_pools();
+        let (assessment, _, _) = assess_market(&pools, 0.01, None);
+        assert!(!assessment.sources.is_empty());
+        assert!(assessment.sources.iter().all(|name| pools.iter().any(|p| &p.name == name)));
+    }
+
+    #[test]
+    fn test_assess_market_rounds_reference_trade_to_venue_tick_size() {
+        let pools = default_pools();
+        let venue = VenueConstraints {
+            name: "test-venue".to_string(),
+            rate_limit: 10,
+            rate_limit_interval_secs: 60,
+            min_lot: 1.0,
+            tick_size: 1e18,
+            min_notional: 1.0,
+        };
+        let (_, _, bottlenecks) = assess_market(&pools, 0.0123456, Some(&venue));
+        assert!(bottlenecks.iter().any(|b| b.contains("rounded reference trade size")));
+    }
+
+    #[test]
+    fn test_assess_market_rejects_trade_below_venue_min_lot() {
+        let pools = default_pools();
+        let venue = VenueConstraints {
+            name: "test-venue".to_string(),
+            rate_limit: 10,
+            rate_limit_interval_secs: 60,
+            min_lot: f64::MAX,
+            tick_size: 1.0,
+            min_notional: 0.0,
+        };
+        let (assessment, _, bottlenecks) = assess_market(&pools, 0.01, Some(&venue));
+        assert!(bottlenecks.iter().any(|b| b.contains("below min_lot")));
+        assert_eq!(assessment.liquidity_score, 1.0);
+    }
+}
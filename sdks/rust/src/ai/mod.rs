@@ -0,0 +1,9 @@
+//! AI-powered transaction analysis and bundle optimization
+
+pub mod analyzer;
+pub mod cache;
+pub mod dex;
+
+pub use analyzer::AIAnalyzer;
+pub use cache::{InsightMerkleTree, MerkleProof};
+pub use dex::{assess_market, default_pools, default_venue, PoolReserves};
@@ -0,0 +1,9 @@
+//! An active security layer over the passive [`crate::types::BridgeOperation`]
+//! types: tracks each operation's fraud-proof challenge window and lets a
+//! relayer submit a [`crate::types::FraudProof`] to dispute one, the same
+//! way an optimistic-rollup watcher races a validator's claim against the
+//! challenge period before it finalizes.
+
+pub mod watcher;
+
+pub use watcher::BridgeWatcher;
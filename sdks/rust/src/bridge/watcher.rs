@@ -0,0 +1,258 @@
+//! Tracks [`BridgeOperation`] fraud-proof challenge windows and accepts
+//! [`FraudProof`] challenges against them.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::error::{HyperSimError, Result};
+use crate::types::{BridgeOperation, BridgeStatus, FraudProof, FraudProofClaim};
+
+struct TrackedOperation {
+    operation: BridgeOperation,
+    /// When this watcher first observed `operation` in `BridgeStatus::Validated`.
+    /// The wire format carries no such timestamp itself, so the watcher's own
+    /// clock is the source of truth for how long the challenge window has been open.
+    validated_at: Option<Instant>,
+}
+
+impl TrackedOperation {
+    fn remaining_window(&self) -> Duration {
+        let Some(validated_at) = self.validated_at else {
+            return Duration::MAX;
+        };
+        let window = Duration::from_secs(self.operation.security.fraud_proof_period);
+        window.checked_sub(validated_at.elapsed()).unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Watches a stream of [`BridgeOperation`] updates and turns the passive
+/// `fraud_proof_period`/`confirmations` fields into an active challenge
+/// window: tracking elapsed time since `Validated`, surfacing operations a
+/// relayer should prioritize disputing, and recording submitted
+/// [`FraudProof`] challenges.
+#[derive(Default)]
+pub struct BridgeWatcher {
+    operations: HashMap<String, TrackedOperation>,
+    challenges: HashMap<String, Vec<FraudProof>>,
+}
+
+impl BridgeWatcher {
+    pub fn new() -> Self {
+        Self { operations: HashMap::new(), challenges: HashMap::new() }
+    }
+
+    /// Ingest the latest known state of a bridge operation. If this is the
+    /// first time `operation` is observed with `status: Validated`, the
+    /// fraud-proof window is stamped as starting now.
+    pub fn observe(&mut self, operation: BridgeOperation) {
+        let entry = self
+            .operations
+            .entry(operation.operation_id.clone())
+            .or_insert_with(|| TrackedOperation { operation: operation.clone(), validated_at: None });
+
+        if matches!(operation.status, BridgeStatus::Validated) && entry.validated_at.is_none() {
+            entry.validated_at = Some(Instant::now());
+        }
+        entry.operation = operation;
+    }
+
+    /// Time elapsed since this watcher observed `operation_id` enter
+    /// `BridgeStatus::Validated`, or `None` if the operation is unknown or
+    /// has not been observed as `Validated` yet.
+    pub fn elapsed_since_validated(&self, operation_id: &str) -> Option<Duration> {
+        self.operations.get(operation_id)?.validated_at.map(|at| at.elapsed())
+    }
+
+    /// Whether `operation_id` is still within its `fraud_proof_period`.
+    /// `None` if the operation is unknown or hasn't entered `Validated` yet.
+    pub fn is_within_fraud_proof_period(&self, operation_id: &str) -> Option<bool> {
+        let tracked = self.operations.get(operation_id)?;
+        let elapsed = tracked.validated_at?.elapsed();
+        Some(elapsed < Duration::from_secs(tracked.operation.security.fraud_proof_period))
+    }
+
+    /// Operations still short of `required_validators` confirmations and
+    /// still within their fraud-proof window, ordered by how soon that
+    /// window closes (soonest first) so a relayer can prioritize which to
+    /// challenge.
+    pub fn poll_challengeable(&self) -> Vec<&BridgeOperation> {
+        let mut challengeable: Vec<&TrackedOperation> = self
+            .operations
+            .values()
+            .filter(|tracked| tracked.validated_at.is_some())
+            .filter(|tracked| tracked.operation.security.confirmations < tracked.operation.security.required_validators)
+            .filter(|tracked| tracked.remaining_window() > Duration::ZERO)
+            .collect();
+
+        challengeable.sort_by_key(|tracked| tracked.remaining_window());
+        challengeable.into_iter().map(|tracked| &tracked.operation).collect()
+    }
+
+    /// Submit a fraud-proof challenge against `operation_id`, transitioning
+    /// it to `BridgeStatus::Disputed`. The claim is checked for bridge-domain
+    /// plausibility against the tracked operation (e.g. a `MintExceedsLock`
+    /// claim must actually claim less than what was minted) but `state_proof`
+    /// itself is not re-verified here — callers should have already called
+    /// [`crate::types::StateProof::verify`]/`verify_storage` on it.
+    pub fn submit_fraud_proof(&mut self, operation_id: &str, challenge: FraudProof) -> Result<()> {
+        let tracked = self.operations.get_mut(operation_id).ok_or_else(|| {
+            HyperSimError::validation_with_field(
+                format!("Unknown bridge operation: {}", operation_id),
+                "operation_id",
+            )
+        })?;
+
+        if let FraudProofClaim::MintExceedsLock { locked_amount } = &challenge.claim {
+            let locked: u128 = locked_amount
+                .parse()
+                .map_err(|_| HyperSimError::validation_with_field("Invalid locked_amount in fraud proof claim", "claim"))?;
+            let minted: u128 = tracked
+                .operation
+                .asset
+                .amount
+                .parse()
+                .map_err(|_| HyperSimError::validation_with_field("Bridge operation has a non-numeric asset amount", "claim"))?;
+
+            if minted <= locked {
+                return Err(HyperSimError::validation_with_field(
+                    "Fraud proof claims a locked amount that does not exceed the minted amount",
+                    "claim",
+                ));
+            }
+        }
+
+        tracked.operation.status = BridgeStatus::Disputed;
+        self.challenges.entry(operation_id.to_string()).or_default().push(challenge);
+        Ok(())
+    }
+
+    /// Fraud-proof challenges submitted against `operation_id` so far
+    pub fn challenges_for(&self, operation_id: &str) -> &[FraudProof] {
+        self.challenges.get(operation_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BridgeFeeInfo, BridgeSecurityInfo, BridgeType, BridgedAsset, ProofType, StateProof};
+    use std::collections::HashMap as StdHashMap;
+
+    fn operation(id: &str, status: BridgeStatus, confirmations: u32, required_validators: u32, fraud_proof_period: u64, amount: &str) -> BridgeOperation {
+        BridgeOperation {
+            operation_id: id.to_string(),
+            bridge_type: BridgeType::TokenBridge,
+            source: "hyperevm".to_string(),
+            target: "hypercore".to_string(),
+            asset: BridgedAsset {
+                asset_type: "erc20".to_string(),
+                asset_id: "0x0".to_string(),
+                amount: amount.to_string(),
+                metadata: StdHashMap::new(),
+            },
+            status,
+            security: BridgeSecurityInfo { required_validators, confirmations, security_threshold: 0.66, fraud_proof_period },
+            fees: BridgeFeeInfo {
+                base_fee: "0".to_string(),
+                source_gas_fee: "0".to_string(),
+                target_gas_fee: None,
+                total_fee: "0".to_string(),
+                fee_token: "ETH".to_string(),
+            },
+        }
+    }
+
+    fn state_proof() -> StateProof {
+        StateProof {
+            address: crate::types::Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string()).unwrap(),
+            proof_type: ProofType::StorageProof,
+            proof: vec![],
+            root: crate::types::Hash::new("0x".to_string() + &"ab".repeat(32)).unwrap(),
+            block_number: 1,
+            layer: "hyperevm".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_observe_stamps_validated_at_on_first_validated_sighting() {
+        let mut watcher = BridgeWatcher::new();
+        watcher.observe(operation("op1", BridgeStatus::Locked, 0, 3, 3600, "100"));
+        assert!(watcher.elapsed_since_validated("op1").is_none());
+
+        watcher.observe(operation("op1", BridgeStatus::Validated, 1, 3, 3600, "100"));
+        assert!(watcher.elapsed_since_validated("op1").is_some());
+        assert_eq!(watcher.is_within_fraud_proof_period("op1"), Some(true));
+    }
+
+    #[test]
+    fn test_is_within_fraud_proof_period_false_once_window_elapses() {
+        let mut watcher = BridgeWatcher::new();
+        watcher.observe(operation("op1", BridgeStatus::Validated, 1, 3, 0, "100"));
+        assert_eq!(watcher.is_within_fraud_proof_period("op1"), Some(false));
+    }
+
+    #[test]
+    fn test_is_within_fraud_proof_period_none_for_unknown_operation() {
+        let watcher = BridgeWatcher::new();
+        assert_eq!(watcher.is_within_fraud_proof_period("missing"), None);
+    }
+
+    #[test]
+    fn test_poll_challengeable_excludes_fully_confirmed_and_unvalidated() {
+        let mut watcher = BridgeWatcher::new();
+        watcher.observe(operation("under-confirmed", BridgeStatus::Validated, 1, 3, 3600, "100"));
+        watcher.observe(operation("fully-confirmed", BridgeStatus::Validated, 3, 3, 3600, "100"));
+        watcher.observe(operation("not-yet-validated", BridgeStatus::Locked, 0, 3, 3600, "100"));
+
+        let challengeable = watcher.poll_challengeable();
+        let ids: Vec<&str> = challengeable.iter().map(|op| op.operation_id.as_str()).collect();
+        assert_eq!(ids, vec!["under-confirmed"]);
+    }
+
+    #[test]
+    fn test_poll_challengeable_excludes_windows_that_have_closed() {
+        let mut watcher = BridgeWatcher::new();
+        watcher.observe(operation("expired", BridgeStatus::Validated, 1, 3, 0, "100"));
+        assert!(watcher.poll_challengeable().is_empty());
+    }
+
+    #[test]
+    fn test_submit_fraud_proof_transitions_to_disputed() {
+        let mut watcher = BridgeWatcher::new();
+        watcher.observe(operation("op1", BridgeStatus::Validated, 1, 3, 3600, "100"));
+
+        let challenge = FraudProof { state_proof: state_proof(), claim: FraudProofClaim::LockNeverOccurred };
+        watcher.submit_fraud_proof("op1", challenge).unwrap();
+
+        assert!(matches!(watcher.operations.get("op1").unwrap().operation.status, BridgeStatus::Disputed));
+        assert_eq!(watcher.challenges_for("op1").len(), 1);
+    }
+
+    #[test]
+    fn test_submit_fraud_proof_rejects_unknown_operation() {
+        let mut watcher = BridgeWatcher::new();
+        let challenge = FraudProof { state_proof: state_proof(), claim: FraudProofClaim::LockNeverOccurred };
+        assert!(watcher.submit_fraud_proof("missing", challenge).is_err());
+    }
+
+    #[test]
+    fn test_submit_fraud_proof_rejects_implausible_mint_exceeds_lock_claim() {
+        let mut watcher = BridgeWatcher::new();
+        watcher.observe(operation("op1", BridgeStatus::Validated, 1, 3, 3600, "100"));
+
+        // Claims only 50 was locked while the operation minted 100 — plausible fraud.
+        let plausible = FraudProof {
+            state_proof: state_proof(),
+            claim: FraudProofClaim::MintExceedsLock { locked_amount: "50".to_string() },
+        };
+        assert!(watcher.submit_fraud_proof("op1", plausible).is_ok());
+
+        watcher.observe(operation("op2", BridgeStatus::Validated, 1, 3, 3600, "100"));
+        // Claims 100 was locked, which does not exceed the 100 minted — not fraud.
+        let implausible = FraudProof {
+            state_proof: state_proof(),
+            claim: FraudProofClaim::MintExceedsLock { locked_amount: "100".to_string() },
+        };
+        assert!(watcher.submit_fraud_proof("op2", implausible).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Pluggable cache backend for simulation and RPC results
+
+pub mod store;
+
+pub use store::{CacheStore, InMemoryStore, RedisStore};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{HyperSimError, Result};
+
+/// Where cached simulation/RPC results are stored
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CacheBackend {
+    /// Process-local in-memory cache
+    InMemory {
+        /// Maximum number of entries retained; past it, expired entries are
+        /// purged first, then the entries nearest expiry are evicted
+        max_entries: usize,
+    },
+    /// Shared cache backed by Redis, so multiple SDK instances can reuse
+    /// cached results across processes
+    Redis {
+        /// Redis connection URL (`redis://` or `rediss://`)
+        url: String,
+        /// Connection pool size
+        pool_size: u32,
+        /// Key prefix namespacing entries from this SDK instance/deployment
+        key_prefix: String,
+    },
+    /// Caching disabled entirely
+    Disabled,
+}
+
+impl Default for CacheBackend {
+    fn default() -> Self {
+        CacheBackend::InMemory { max_entries: 1000 }
+    }
+}
+
+impl CacheBackend {
+    /// Whether this backend actually caches anything
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, CacheBackend::Disabled)
+    }
+
+    /// Validate backend-specific settings, e.g. the Redis URL scheme
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            CacheBackend::Redis { url, .. } => {
+                if !url.starts_with("redis://") && !url.starts_with("rediss://") {
+                    return Err(HyperSimError::configuration(
+                        "Redis cache URL must use the redis:// or rediss:// scheme",
+                    ));
+                }
+                Ok(())
+            }
+            CacheBackend::InMemory { .. } | CacheBackend::Disabled => Ok(()),
+        }
+    }
+
+    /// Build the [`CacheStore`] this backend resolves to, or `None` when
+    /// caching is disabled
+    pub fn build_store(&self) -> Result<Option<std::sync::Arc<dyn CacheStore>>> {
+        store::build_store(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_backend_is_bounded_in_memory() {
+        let backend = CacheBackend::default();
+        assert!(backend.is_enabled());
+        assert!(backend.validate().is_ok());
+    }
+
+    #[test]
+    fn test_disabled_backend_reports_not_enabled() {
+        assert!(!CacheBackend::Disabled.is_enabled());
+    }
+
+    #[test]
+    fn test_redis_backend_rejects_bad_scheme() {
+        let backend = CacheBackend::Redis {
+            url: "http://localhost:6379".to_string(),
+            pool_size: 4,
+            key_prefix: "hypersim".to_string(),
+        };
+        assert!(backend.validate().is_err());
+    }
+
+    #[test]
+    fn test_redis_backend_accepts_tls_scheme() {
+        let backend = CacheBackend::Redis {
+            url: "rediss://localhost:6380".to_string(),
+            pool_size: 4,
+            key_prefix: "hypersim".to_string(),
+        };
+        assert!(backend.validate().is_ok());
+    }
+}
@@ -0,0 +1,170 @@
+//! `CacheStore` trait and the built-in backends a [`super::CacheBackend`] resolves to
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::error::{HyperSimError, Result};
+use super::CacheBackend;
+
+/// A keyed, TTL-aware cache. Implement this to supply a custom backend
+/// instead of the built-in [`InMemoryStore`]/[`RedisStore`].
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    /// Fetch a cached value by key, or `None` if missing or expired
+    async fn get(&self, key: &str) -> Result<Option<String>>;
+
+    /// Store `value` under `key`, expiring after `ttl`
+    async fn set_with_ttl(&self, key: &str, value: String, ttl: Duration) -> Result<()>;
+
+    /// Remove a cached value
+    async fn invalidate(&self, key: &str) -> Result<()>;
+}
+
+struct Entry {
+    value: String,
+    expires_at: Instant,
+}
+
+/// Process-local in-memory cache store
+pub struct InMemoryStore {
+    max_entries: usize,
+    entries: RwLock<HashMap<String, Entry>>,
+}
+
+impl InMemoryStore {
+    pub fn new(max_entries: usize) -> Self {
+        Self { max_entries, entries: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl CacheStore for InMemoryStore {
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let entries = self.entries.read().await;
+        let now = Instant::now();
+        Ok(entries.get(key).filter(|entry| entry.expires_at > now).map(|entry| entry.value.clone()))
+    }
+
+    async fn set_with_ttl(&self, key: &str, value: String, ttl: Duration) -> Result<()> {
+        let mut entries = self.entries.write().await;
+        entries.insert(key.to_string(), Entry { value, expires_at: Instant::now() + ttl });
+
+        if entries.len() > self.max_entries {
+            let now = Instant::now();
+            entries.retain(|_, entry| entry.expires_at > now);
+        }
+        while entries.len() > self.max_entries {
+            let Some(soonest) = entries.iter().min_by_key(|(_, entry)| entry.expires_at).map(|(k, _)| k.clone())
+            else {
+                break;
+            };
+            entries.remove(&soonest);
+        }
+
+        Ok(())
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<()> {
+        self.entries.write().await.remove(key);
+        Ok(())
+    }
+}
+
+/// Redis-backed cache store, so multiple SDK instances can share cached
+/// results across processes
+pub struct RedisStore {
+    client: redis::Client,
+    key_prefix: String,
+}
+
+impl RedisStore {
+    pub fn new(url: &str, key_prefix: impl Into<String>) -> Result<Self> {
+        let client = redis::Client::open(url)
+            .map_err(|e| HyperSimError::configuration(format!("Invalid Redis URL: {}", e)))?;
+        Ok(Self { client, key_prefix: key_prefix.into() })
+    }
+
+    fn namespaced(&self, key: &str) -> String {
+        format!("{}:{}", self.key_prefix, key)
+    }
+}
+
+#[async_trait]
+impl CacheStore for RedisStore {
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        use redis::AsyncCommands;
+        let mut conn = self.client.get_multiplexed_async_connection().await
+            .map_err(|e| HyperSimError::network(format!("Redis connection failed: {}", e)))?;
+        conn.get(self.namespaced(key)).await
+            .map_err(|e| HyperSimError::network(format!("Redis GET failed: {}", e)))
+    }
+
+    async fn set_with_ttl(&self, key: &str, value: String, ttl: Duration) -> Result<()> {
+        use redis::AsyncCommands;
+        let mut conn = self.client.get_multiplexed_async_connection().await
+            .map_err(|e| HyperSimError::network(format!("Redis connection failed: {}", e)))?;
+        conn.set_ex(self.namespaced(key), value, ttl.as_secs().max(1)).await
+            .map_err(|e| HyperSimError::network(format!("Redis SETEX failed: {}", e)))
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<()> {
+        use redis::AsyncCommands;
+        let mut conn = self.client.get_multiplexed_async_connection().await
+            .map_err(|e| HyperSimError::network(format!("Redis connection failed: {}", e)))?;
+        conn.del(self.namespaced(key)).await
+            .map_err(|e| HyperSimError::network(format!("Redis DEL failed: {}", e)))
+    }
+}
+
+/// Build the [`CacheStore`] `backend` resolves to, or `None` when caching is disabled
+pub fn build_store(backend: &CacheBackend) -> Result<Option<Arc<dyn CacheStore>>> {
+    match backend {
+        CacheBackend::InMemory { max_entries } => Ok(Some(Arc::new(InMemoryStore::new(*max_entries)))),
+        CacheBackend::Redis { url, key_prefix, .. } => {
+            Ok(Some(Arc::new(RedisStore::new(url, key_prefix.clone())?)))
+        }
+        CacheBackend::Disabled => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_store_round_trips_value() {
+        let store = InMemoryStore::new(10);
+        store.set_with_ttl("key", "value".to_string(), Duration::from_secs(60)).await.unwrap();
+        assert_eq!(store.get("key").await.unwrap(), Some("value".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_expires_entries() {
+        let store = InMemoryStore::new(10);
+        store.set_with_ttl("key", "value".to_string(), Duration::from_millis(0)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        assert_eq!(store.get("key").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_invalidate_removes_entry() {
+        let store = InMemoryStore::new(10);
+        store.set_with_ttl("key", "value".to_string(), Duration::from_secs(60)).await.unwrap();
+        store.invalidate("key").await.unwrap();
+        assert_eq!(store.get("key").await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_build_store_returns_none_when_disabled() {
+        assert!(build_store(&CacheBackend::Disabled).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_build_store_returns_in_memory_store() {
+        assert!(build_store(&CacheBackend::InMemory { max_entries: 10 }).unwrap().is_some());
+    }
+}
@@ -0,0 +1,184 @@
+//! Runtime endpoint discovery: when a pool's endpoints are unhealthy, probe an
+//! operator-supplied static fallback list and/or an externally-published JSON
+//! list of candidate endpoints, keep the ones that actually respond, and merge
+//! them into the pool so the SDK can ride out a provider's outage without a
+//! config redeploy.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::error::{HyperSimError, Result};
+
+/// Fallback discovery policy for an endpoint pool
+#[derive(Debug, Clone, Default)]
+pub struct FallbackDiscoveryConfig {
+    /// Operator-supplied static list of candidate endpoints, probed alongside
+    /// any externally-published list
+    pub fallback_endpoints: Vec<String>,
+    /// Fetch and probe the published candidate list at `fallback_url`
+    pub load_external_fallback: bool,
+    /// URL serving a JSON document of candidate endpoints (see [`FallbackList`])
+    pub fallback_url: Option<String>,
+}
+
+impl FallbackDiscoveryConfig {
+    /// Whether this policy has anything to probe at all
+    pub fn is_configured(&self) -> bool {
+        !self.fallback_endpoints.is_empty() || (self.load_external_fallback && self.fallback_url.is_some())
+    }
+}
+
+/// Published fallback endpoint list, e.g. `{"endpoints": ["https://...", "https://..."]}`
+#[derive(Debug, Deserialize)]
+struct FallbackList {
+    endpoints: Vec<String>,
+}
+
+/// A fallback candidate that responded to a liveness probe, with the block
+/// height it reported so it can be ranked against the rest of the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredEndpoint {
+    pub url: String,
+    pub block_height: u64,
+}
+
+/// Gather every candidate endpoint from `config`'s static list and (if
+/// enabled) its externally-published list, deduplicated.
+async fn collect_candidates(config: &FallbackDiscoveryConfig, http_client: &reqwest::Client) -> Vec<String> {
+    let mut candidates = config.fallback_endpoints.clone();
+
+    if config.load_external_fallback {
+        if let Some(ref url) = config.fallback_url {
+            match fetch_external_list(http_client, url).await {
+                Ok(external) => candidates.extend(external),
+                Err(error) => {
+                    tracing::debug!("Failed to fetch external fallback list from {}: {}", url, error);
+                }
+            }
+        }
+    }
+
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
+async fn fetch_external_list(http_client: &reqwest::Client, url: &str) -> Result<Vec<String>> {
+    let response = http_client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| HyperSimError::network(format!("Failed to fetch fallback list: {}", e)))?;
+
+    let list: FallbackList = response
+        .json()
+        .await
+        .map_err(|e| HyperSimError::serialization(format!("Invalid fallback list: {}", e)))?;
+
+    Ok(list.endpoints)
+}
+
+/// Probe `url` with a lightweight `eth_blockNumber` request, returning its
+/// reported block height if it responds within `timeout`.
+async fn probe_endpoint(http_client: &reqwest::Client, url: &str, timeout: Duration) -> Option<DiscoveredEndpoint> {
+    let request_body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_blockNumber",
+        "params": [],
+        "id": 1
+    });
+
+    let response = tokio::time::timeout(timeout, http_client.post(url).json(&request_body).send())
+        .await
+        .ok()?
+        .ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let body: serde_json::Value = response.json().await.ok()?;
+    let block_height = body
+        .get("result")
+        .and_then(|v| v.as_str())
+        .and_then(|s| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+        .unwrap_or(0);
+
+    Some(DiscoveredEndpoint { url: url.to_string(), block_height })
+}
+
+/// Discover usable fallback endpoints: collect every candidate, probe each in
+/// turn, keep the ones that answered within `probe_timeout`, and sort them
+/// highest-block-first so the most up-to-date candidate merges into the pool
+/// first (the same "prefer the freshest node" rule the primary selector uses).
+pub async fn discover_fallback_endpoints(
+    config: &FallbackDiscoveryConfig,
+    http_client: &reqwest::Client,
+    probe_timeout: Duration,
+) -> Vec<DiscoveredEndpoint> {
+    if !config.is_configured() {
+        return Vec::new();
+    }
+
+    let candidates = collect_candidates(config, http_client).await;
+
+    let mut discovered = Vec::new();
+    for url in &candidates {
+        if let Some(endpoint) = probe_endpoint(http_client, url, probe_timeout).await {
+            discovered.push(endpoint);
+        }
+    }
+
+    discovered.sort_by(|a, b| b.block_height.cmp(&a.block_height));
+    discovered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_configured_false_when_empty() {
+        let config = FallbackDiscoveryConfig::default();
+        assert!(!config.is_configured());
+    }
+
+    #[test]
+    fn test_is_configured_true_with_static_list() {
+        let config = FallbackDiscoveryConfig {
+            fallback_endpoints: vec!["https://fallback.example.com".to_string()],
+            ..Default::default()
+        };
+        assert!(config.is_configured());
+    }
+
+    #[test]
+    fn test_is_configured_requires_url_for_external_fallback() {
+        let config = FallbackDiscoveryConfig {
+            load_external_fallback: true,
+            fallback_url: None,
+            ..Default::default()
+        };
+        assert!(!config.is_configured());
+    }
+
+    #[tokio::test]
+    async fn test_discover_returns_empty_when_not_configured() {
+        let config = FallbackDiscoveryConfig::default();
+        let http_client = reqwest::Client::new();
+        let discovered = discover_fallback_endpoints(&config, &http_client, Duration::from_millis(100)).await;
+        assert!(discovered.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_discover_drops_unreachable_candidates() {
+        let config = FallbackDiscoveryConfig {
+            fallback_endpoints: vec!["http://127.0.0.1:1".to_string()],
+            ..Default::default()
+        };
+        let http_client = reqwest::Client::new();
+        let discovered = discover_fallback_endpoints(&config, &http_client, Duration::from_millis(200)).await;
+        assert!(discovered.is_empty());
+    }
+}
@@ -0,0 +1,589 @@
+//! On-demand dispatcher for querying a pool of RPC endpoints with failover.
+//!
+//! Modeled on the latency-aware node selection used by production RPC
+//! proxies: each endpoint's round-trip latency is tracked as an
+//! exponentially-weighted moving average (EWMA), and a query is routed to
+//! the lowest-EWMA endpoint among those whose last attempt succeeded, falling
+//! back through the rest of the pool — ranked the same way, unhealthy
+//! endpoints last — on an empty or erroring reply. The whole pool is wrapped
+//! as a single future, so callers await one [`DispatchOutcome`] regardless of
+//! how many endpoints were tried underneath.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use crate::clients::discovery::{discover_fallback_endpoints, FallbackDiscoveryConfig};
+use crate::error::{HyperSimError, Result};
+use crate::types::{EndpointLatency, EndpointPoolMetrics};
+
+/// Window size for the per-endpoint latency EWMA: `alpha = 2 / (N + 1)`.
+const EWMA_WINDOW: f64 = 10.0;
+const EWMA_ALPHA: f64 = 2.0 / (EWMA_WINDOW + 1.0);
+
+/// Outcome of a dispatched query: the value and which endpoint produced it.
+#[derive(Debug, Clone)]
+pub struct DispatchOutcome {
+    /// Endpoint that produced the returned value
+    pub endpoint: String,
+    /// The JSON-RPC response. A null/absent `result` here means every
+    /// endpoint that replied did so with no usable result, as distinct from
+    /// every endpoint failing outright (which surfaces as an `Err`).
+    pub value: serde_json::Value,
+}
+
+/// Latency/failure counters tracked per endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct EndpointMetrics {
+    /// Number of queries attempted against this endpoint
+    pub requests: u64,
+    /// Number of attempts that errored (transport or RPC error)
+    pub failures: u64,
+    /// Sum of round-trip latency across all attempts, for averaging
+    pub total_latency_ms: u64,
+    /// Exponentially-weighted moving average of round-trip latency, in
+    /// milliseconds, updated on every completed request with
+    /// `ewma = alpha * sample_ms + (1 - alpha) * ewma` (`alpha = 2/(N+1)`,
+    /// `N` = [`EWMA_WINDOW`]). Reflects how fast the endpoint is *right now*,
+    /// unlike [`average_latency_ms`](Self::average_latency_ms)'s lifetime average.
+    pub ewma_ms: f64,
+    /// Whether the endpoint's most recently completed request succeeded
+    pub healthy: bool,
+}
+
+impl EndpointMetrics {
+    /// Mean latency across all attempts against this endpoint, in milliseconds
+    pub fn average_latency_ms(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.total_latency_ms as f64 / self.requests as f64
+        }
+    }
+
+    fn record_sample(&mut self, latency_ms: f64, failed: bool) {
+        self.ewma_ms = if self.requests == 0 {
+            latency_ms
+        } else {
+            EWMA_ALPHA * latency_ms + (1.0 - EWMA_ALPHA) * self.ewma_ms
+        };
+        self.requests += 1;
+        self.total_latency_ms += latency_ms as u64;
+        self.healthy = !failed;
+        if failed {
+            self.failures += 1;
+        }
+    }
+}
+
+/// Dispatches JSON-RPC queries across a pool of endpoints, ranking them by
+/// EWMA latency/health on every call and failing over through the rest of
+/// the ranked pool on an empty or erroring reply.
+pub struct EndpointDispatcher {
+    endpoints: Vec<String>,
+    discovered: RwLock<Vec<String>>,
+    fallback: Option<(FallbackDiscoveryConfig, reqwest::Client, Duration)>,
+    metrics: Arc<RwLock<HashMap<String, EndpointMetrics>>>,
+}
+
+impl EndpointDispatcher {
+    /// Create a dispatcher over the given endpoint pool
+    pub fn new(endpoints: Vec<String>) -> Result<Self> {
+        if endpoints.is_empty() {
+            return Err(HyperSimError::configuration("Endpoint pool must not be empty"));
+        }
+
+        Ok(Self {
+            endpoints,
+            discovered: RwLock::new(Vec::new()),
+            fallback: None,
+            metrics: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
+    /// Attach a fallback discovery policy: once the configured pool is fully
+    /// unhealthy, `dispatch` probes `config`'s candidates with `http_client`
+    /// (bounded by `probe_timeout`) and merges the ones that respond into the
+    /// pool before retrying.
+    pub fn with_fallback_discovery(
+        mut self,
+        config: FallbackDiscoveryConfig,
+        http_client: reqwest::Client,
+        probe_timeout: Duration,
+    ) -> Self {
+        self.fallback = Some((config, http_client, probe_timeout));
+        self
+    }
+
+    /// The originally configured endpoint pool (not including any endpoints
+    /// merged in later via fallback discovery)
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
+    }
+
+    /// Endpoints merged into the pool so far via fallback discovery
+    pub async fn discovered_endpoints(&self) -> Vec<String> {
+        self.discovered.read().await.clone()
+    }
+
+    /// Snapshot of the latency/failure counters recorded so far, keyed by endpoint
+    pub async fn metrics(&self) -> HashMap<String, EndpointMetrics> {
+        self.metrics.read().await.clone()
+    }
+
+    /// Probe this dispatcher's configured fallback candidates right away and
+    /// merge any that respond into the pool, without waiting for the
+    /// configured pool to fail first. A no-op if no fallback policy is attached.
+    pub async fn warm_fallback_discovery(&self) {
+        let _ = self.discover_and_merge_fallbacks().await;
+    }
+
+    /// Dispatch `query` across the pool as a single future.
+    ///
+    /// Tries the lowest-EWMA-latency endpoint among those whose last attempt
+    /// succeeded first, then walks the rest of the pool in the same
+    /// ranked order on an empty or erroring reply. A reply with a
+    /// null/absent `result` is remembered but not returned immediately — the
+    /// dispatcher keeps trying the rest of the pool for a usable result, and
+    /// only falls back to the empty reply once every endpoint has answered.
+    /// The query is reported as failed (`Err`) only when every endpoint
+    /// errored outright, with no empty-but-valid reply to fall back to.
+    ///
+    /// If every endpoint in the pool fails outright and a fallback discovery
+    /// policy is attached, the candidates it discovers are merged into the
+    /// pool and the query is retried once across them before giving up.
+    pub async fn dispatch<F, Fut>(&self, mut query: F) -> Result<DispatchOutcome>
+    where
+        F: FnMut(&str) -> Fut,
+        Fut: Future<Output = Result<serde_json::Value>>,
+    {
+        let pool = self.effective_pool().await;
+        match self.dispatch_over_pool(&mut query, &pool).await {
+            Ok(outcome) => Ok(outcome),
+            Err(error) => {
+                let fallback_pool = self.discover_and_merge_fallbacks().await;
+                if fallback_pool.is_empty() {
+                    return Err(error);
+                }
+
+                debug!(
+                    "All {} configured endpoint(s) failed; retrying over {} endpoint(s) including discovered fallbacks",
+                    pool.len(),
+                    fallback_pool.len()
+                );
+                self.dispatch_over_pool(&mut query, &fallback_pool).await
+            }
+        }
+    }
+
+    async fn dispatch_over_pool<F, Fut>(&self, query: &mut F, pool: &[String]) -> Result<DispatchOutcome>
+    where
+        F: FnMut(&str) -> Fut,
+        Fut: Future<Output = Result<serde_json::Value>>,
+    {
+        let ranked = self.ranked_order(pool).await;
+        let mut empty_reply: Option<DispatchOutcome> = None;
+        let mut last_error: Option<HyperSimError> = None;
+
+        for endpoint in ranked {
+            let attempt_start = Instant::now();
+
+            match query(&endpoint).await {
+                Ok(value) => {
+                    self.record(&endpoint, attempt_start.elapsed(), false).await;
+
+                    let is_empty = value.get("result").map(|r| r.is_null()).unwrap_or(true);
+                    if is_empty {
+                        empty_reply.get_or_insert(DispatchOutcome { endpoint, value });
+                    } else {
+                        return Ok(DispatchOutcome { endpoint, value });
+                    }
+                }
+                Err(error) => {
+                    self.record(&endpoint, attempt_start.elapsed(), true).await;
+                    debug!("Endpoint {} failed: {}", endpoint, error);
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        empty_reply
+            .ok_or_else(|| last_error.unwrap_or_else(|| HyperSimError::network("All RPC endpoints failed")))
+    }
+
+    /// Query every endpoint in the pool independently and report each one's
+    /// own result, instead of stopping at the first usable reply the way
+    /// [`dispatch`](Self::dispatch) does. Used by callers that need to
+    /// compare every endpoint's answer against each other (e.g. a
+    /// consensus-based head-block check) rather than just route to the
+    /// fastest one.
+    pub async fn query_all<F, Fut>(&self, mut query: F) -> HashMap<String, Result<serde_json::Value>>
+    where
+        F: FnMut(&str) -> Fut,
+        Fut: Future<Output = Result<serde_json::Value>>,
+    {
+        let pool = self.effective_pool().await;
+        let mut results = HashMap::with_capacity(pool.len());
+
+        for endpoint in pool {
+            let attempt_start = Instant::now();
+            let outcome = query(&endpoint).await;
+            self.record(&endpoint, attempt_start.elapsed(), outcome.is_err()).await;
+            results.insert(endpoint, outcome);
+        }
+
+        results
+    }
+
+    /// Force `endpoint`'s recorded health to unhealthy without touching its
+    /// EWMA latency samples, so [`ranked_order`](Self::ranked_order) — and
+    /// thus `dispatch`/`selected_endpoint` — stops routing to it until a
+    /// future request against it succeeds again. Used by checks that detect
+    /// a problem no individual request to this endpoint has surfaced, such
+    /// as falling behind the pool's consensus head block.
+    pub async fn mark_unhealthy(&self, endpoint: &str) {
+        let mut metrics = self.metrics.write().await;
+        metrics.entry(endpoint.to_string()).or_default().healthy = false;
+    }
+
+    /// The configured pool plus any endpoints already merged in via fallback discovery
+    async fn effective_pool(&self) -> Vec<String> {
+        let mut pool = self.endpoints.clone();
+        for endpoint in self.discovered.read().await.iter() {
+            if !pool.contains(endpoint) {
+                pool.push(endpoint.clone());
+            }
+        }
+        pool
+    }
+
+    /// Probe the attached fallback policy (if any), merge newly-responsive
+    /// candidates into `discovered`, and return the full effective pool
+    /// (empty if no policy is attached or no candidate responded).
+    async fn discover_and_merge_fallbacks(&self) -> Vec<String> {
+        let Some((config, http_client, probe_timeout)) = self.fallback.as_ref() else {
+            return Vec::new();
+        };
+
+        let candidates = discover_fallback_endpoints(config, http_client, *probe_timeout).await;
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+
+        let mut discovered = self.discovered.write().await;
+        for candidate in &candidates {
+            if !self.endpoints.contains(&candidate.url) && !discovered.contains(&candidate.url) {
+                discovered.push(candidate.url.clone());
+            }
+        }
+
+        let mut pool = self.endpoints.clone();
+        pool.extend(discovered.iter().cloned());
+        pool
+    }
+
+    async fn record(&self, endpoint: &str, latency: std::time::Duration, failed: bool) {
+        let mut metrics = self.metrics.write().await;
+        let entry = metrics.entry(endpoint.to_string()).or_default();
+        entry.record_sample(latency.as_millis() as f64, failed);
+    }
+
+    /// Order `pool` by ascending EWMA latency, with endpoints whose last
+    /// attempt failed sorted after every healthy one. Endpoints with no
+    /// recorded attempts yet are treated as healthy with unknown (infinite)
+    /// latency, so a cold pool is tried in its original order and a proven
+    /// fast endpoint always outranks an untested one.
+    async fn ranked_order(&self, pool: &[String]) -> Vec<String> {
+        let metrics = self.metrics.read().await;
+        let mut ranked = pool.to_vec();
+        ranked.sort_by(|a, b| {
+            let rank_key = |endpoint: &str| match metrics.get(endpoint) {
+                Some(m) => (!m.healthy, m.ewma_ms),
+                None => (false, f64::INFINITY),
+            };
+            rank_key(a).partial_cmp(&rank_key(b)).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        ranked
+    }
+
+    /// The endpoint `dispatch` would currently route to: the lowest-EWMA
+    /// endpoint among those whose last attempt succeeded, or `None` if the
+    /// pool is empty.
+    pub async fn selected_endpoint(&self) -> Option<String> {
+        let pool = self.effective_pool().await;
+        self.ranked_order(&pool).await.into_iter().next()
+    }
+
+    /// Per-endpoint EWMA/health snapshot plus the endpoint `dispatch` would
+    /// currently pick, suitable for surfacing in [`PerformanceMetrics`](crate::types::PerformanceMetrics).
+    pub async fn pool_metrics(&self) -> EndpointPoolMetrics {
+        let pool = self.effective_pool().await;
+        let metrics = self.metrics.read().await;
+        let endpoints = pool
+            .iter()
+            .map(|endpoint| {
+                let m = metrics.get(endpoint);
+                EndpointLatency {
+                    endpoint: endpoint.clone(),
+                    ewma_ms: m.map(|m| m.ewma_ms).unwrap_or(0.0),
+                    healthy: m.map(|m| m.healthy).unwrap_or(true),
+                }
+            })
+            .collect();
+        drop(metrics);
+
+        EndpointPoolMetrics {
+            endpoints,
+            selected: self.ranked_order(&pool).await.into_iter().next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_dispatch_returns_first_usable_result() {
+        let dispatcher = EndpointDispatcher::new(vec![
+            "http://a".to_string(),
+            "http://b".to_string(),
+        ])
+        .unwrap();
+
+        let outcome = dispatcher
+            .dispatch(|endpoint| {
+                let endpoint = endpoint.to_string();
+                async move { Ok(serde_json::json!({ "result": format!("from {endpoint}") })) }
+            })
+            .await
+            .unwrap();
+
+        assert!(dispatcher.endpoints().contains(&outcome.endpoint));
+        assert_eq!(outcome.value["result"], format!("from {}", outcome.endpoint));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_fails_over_past_errors() {
+        let dispatcher = EndpointDispatcher::new(vec![
+            "http://bad".to_string(),
+            "http://good".to_string(),
+        ])
+        .unwrap();
+
+        let outcome = dispatcher
+            .dispatch(|endpoint| {
+                let endpoint = endpoint.to_string();
+                async move {
+                    if endpoint == "http://bad" {
+                        Err(HyperSimError::network("connection refused"))
+                    } else {
+                        Ok(serde_json::json!({ "result": "ok" }))
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.endpoint, "http://good");
+
+        let metrics = dispatcher.metrics().await;
+        assert_eq!(metrics["http://bad"].failures, 1);
+        assert_eq!(metrics["http://good"].failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_reports_empty_over_error_when_both_occur() {
+        let dispatcher = EndpointDispatcher::new(vec![
+            "http://empty".to_string(),
+            "http://erroring".to_string(),
+        ])
+        .unwrap();
+
+        let outcome = dispatcher
+            .dispatch(|endpoint| {
+                let endpoint = endpoint.to_string();
+                async move {
+                    if endpoint == "http://empty" {
+                        Ok(serde_json::json!({ "result": null }))
+                    } else {
+                        Err(HyperSimError::network("timeout"))
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.endpoint, "http://empty");
+        assert!(outcome.value["result"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_fails_only_when_every_endpoint_errors() {
+        let dispatcher = EndpointDispatcher::new(vec![
+            "http://a".to_string(),
+            "http://b".to_string(),
+        ])
+        .unwrap();
+        let attempts = AtomicUsize::new(0);
+
+        let result = dispatcher
+            .dispatch(|_endpoint| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async move { Err(HyperSimError::network("down")) }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_includes_previously_discovered_endpoints_in_pool() {
+        let dispatcher = EndpointDispatcher::new(vec!["http://a".to_string()]).unwrap();
+        dispatcher.discovered.write().await.push("http://discovered".to_string());
+
+        let outcome = dispatcher
+            .dispatch(|endpoint| {
+                let endpoint = endpoint.to_string();
+                async move {
+                    if endpoint == "http://discovered" {
+                        Ok(serde_json::json!({ "result": "ok" }))
+                    } else {
+                        Err(HyperSimError::network("down"))
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.endpoint, "http://discovered");
+    }
+
+    #[tokio::test]
+    async fn test_warm_fallback_discovery_is_noop_without_policy() {
+        let dispatcher = EndpointDispatcher::new(vec!["http://a".to_string()]).unwrap();
+        dispatcher.warm_fallback_discovery().await;
+        assert!(dispatcher.discovered_endpoints().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_stays_failed_when_fallback_candidates_unreachable() {
+        let dispatcher = EndpointDispatcher::new(vec!["http://a".to_string()])
+            .unwrap()
+            .with_fallback_discovery(
+                FallbackDiscoveryConfig {
+                    fallback_endpoints: vec!["http://127.0.0.1:1".to_string()],
+                    ..Default::default()
+                },
+                reqwest::Client::new(),
+                Duration::from_millis(200),
+            );
+
+        let result = dispatcher
+            .dispatch(|_endpoint| async move { Err(HyperSimError::network("down")) })
+            .await;
+
+        assert!(result.is_err());
+        assert!(dispatcher.discovered_endpoints().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_prefers_lowest_ewma_healthy_endpoint() {
+        let dispatcher = EndpointDispatcher::new(vec![
+            "http://slow".to_string(),
+            "http://fast".to_string(),
+        ])
+        .unwrap();
+
+        dispatcher.record("http://slow", Duration::from_millis(200), false).await;
+        dispatcher.record("http://fast", Duration::from_millis(5), false).await;
+
+        let attempted = Arc::new(RwLock::new(Vec::new()));
+        let outcome = dispatcher
+            .dispatch(|endpoint| {
+                let attempted = Arc::clone(&attempted);
+                let endpoint = endpoint.to_string();
+                async move {
+                    attempted.write().await.push(endpoint.clone());
+                    Ok(serde_json::json!({ "result": endpoint }))
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.endpoint, "http://fast");
+        assert_eq!(attempted.read().await[0], "http://fast");
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skips_unhealthy_endpoint_for_a_slower_healthy_one() {
+        let dispatcher = EndpointDispatcher::new(vec![
+            "http://fast-but-down".to_string(),
+            "http://slow-but-up".to_string(),
+        ])
+        .unwrap();
+
+        dispatcher.record("http://fast-but-down", Duration::from_millis(1), true).await;
+        dispatcher.record("http://slow-but-up", Duration::from_millis(200), false).await;
+
+        let outcome = dispatcher
+            .dispatch(|endpoint| {
+                let endpoint = endpoint.to_string();
+                async move {
+                    if endpoint == "http://fast-but-down" {
+                        Err(HyperSimError::network("still down"))
+                    } else {
+                        Ok(serde_json::json!({ "result": "ok" }))
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.endpoint, "http://slow-but-up");
+    }
+
+    #[tokio::test]
+    async fn test_pool_metrics_reports_ewma_and_selection() {
+        let dispatcher = EndpointDispatcher::new(vec![
+            "http://a".to_string(),
+            "http://b".to_string(),
+        ])
+        .unwrap();
+
+        dispatcher.record("http://a", Duration::from_millis(100), false).await;
+        dispatcher.record("http://b", Duration::from_millis(10), false).await;
+
+        let pool_metrics = dispatcher.pool_metrics().await;
+        assert_eq!(pool_metrics.selected.as_deref(), Some("http://b"));
+        assert_eq!(dispatcher.selected_endpoint().await.as_deref(), Some("http://b"));
+
+        let b = pool_metrics.endpoints.iter().find(|e| e.endpoint == "http://b").unwrap();
+        assert_eq!(b.ewma_ms, 10.0);
+        assert!(b.healthy);
+    }
+
+    #[tokio::test]
+    async fn test_ewma_blends_samples_instead_of_tracking_the_lifetime_average() {
+        let dispatcher = EndpointDispatcher::new(vec!["http://a".to_string()]).unwrap();
+
+        dispatcher.record("http://a", Duration::from_millis(100), false).await;
+        dispatcher.record("http://a", Duration::from_millis(100), false).await;
+        dispatcher.record("http://a", Duration::from_millis(0), false).await;
+
+        let metrics = dispatcher.metrics().await;
+        let a = &metrics["http://a"];
+        // alpha = 2/11; after two 100ms samples the ewma is already 100, then
+        // a 0ms sample pulls it down by alpha * 100 rather than snapping to
+        // the new lifetime average (~66.7ms).
+        let expected = 100.0 - EWMA_ALPHA * 100.0;
+        assert!((a.ewma_ms - expected).abs() < 1e-9);
+        assert_eq!(a.average_latency_ms(), 200.0 / 3.0);
+    }
+}
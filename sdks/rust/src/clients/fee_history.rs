@@ -0,0 +1,155 @@
+//! Sliding-window base-fee tracker feeding `SubscriptionType::FeeHistory`
+
+use std::collections::VecDeque;
+
+use tokio::sync::RwLock;
+
+use crate::types::PriceTrend;
+
+/// Default number of blocks kept in the window
+pub const DEFAULT_WINDOW_BLOCKS: usize = 20;
+/// Default deviation from the EWMA base fee, as a fraction, before the
+/// trend classifies as `Increase`/`Decrease` rather than `Stable`
+pub const DEFAULT_TREND_THRESHOLD: f64 = 0.05;
+/// Smoothing factor for the base-fee EWMA; higher weights recent blocks more
+const EWMA_ALPHA: f64 = 0.2;
+
+#[derive(Debug, Clone, Copy)]
+struct BlockFeeSample {
+    base_fee_wei: u128,
+    gas_used_ratio: f64,
+}
+
+/// Maintains a bounded window of per-block base-fee/gas-used-ratio samples
+/// and derives [`PriceTrend`] from an exponentially weighted moving average
+/// of the base fee, following the same piggyback-on-subscriptions design as
+/// [`GasEstimator`](crate::clients::gas_estimator::GasEstimator). One
+/// instance is created per `SubscriptionType::FeeHistory` forwarder task, so
+/// the window size can vary per subscription via `SubscriptionParams::limit`.
+pub struct FeeHistoryTracker {
+    window_blocks: usize,
+    trend_threshold: f64,
+    samples: RwLock<VecDeque<BlockFeeSample>>,
+    ewma_base_fee: RwLock<Option<f64>>,
+}
+
+impl FeeHistoryTracker {
+    pub fn new(window_blocks: usize, trend_threshold: f64) -> Self {
+        let window_blocks = window_blocks.max(1);
+        Self {
+            window_blocks,
+            trend_threshold,
+            samples: RwLock::new(VecDeque::with_capacity(window_blocks)),
+            ewma_base_fee: RwLock::new(None),
+        }
+    }
+
+    /// Record a new block's base fee and gas-used ratio, evicting the
+    /// oldest sample once the window is full, and return the trend of this
+    /// base fee versus the moving average observed so far.
+    pub async fn observe_block(&self, base_fee_wei: u128, gas_used_ratio: f64) -> PriceTrend {
+        {
+            let mut samples = self.samples.write().await;
+            samples.push_back(BlockFeeSample { base_fee_wei, gas_used_ratio });
+            while samples.len() > self.window_blocks {
+                samples.pop_front();
+            }
+        }
+
+        let base_fee = base_fee_wei as f64;
+        let mut ewma = self.ewma_base_fee.write().await;
+        let trend = match *ewma {
+            Some(previous) if previous > 0.0 => {
+                let deviation = (base_fee - previous) / previous;
+                if deviation > self.trend_threshold {
+                    PriceTrend::Increase
+                } else if deviation < -self.trend_threshold {
+                    PriceTrend::Decrease
+                } else {
+                    PriceTrend::Stable
+                }
+            }
+            _ => PriceTrend::Stable,
+        };
+        *ewma = Some(match *ewma {
+            Some(previous) => EWMA_ALPHA * base_fee + (1.0 - EWMA_ALPHA) * previous,
+            None => base_fee,
+        });
+
+        trend
+    }
+
+    /// Base fee (wei) for each block currently in the window, oldest first
+    pub async fn base_fee_history(&self) -> Vec<String> {
+        self.samples.read().await.iter().map(|sample| sample.base_fee_wei.to_string()).collect()
+    }
+
+    /// Gas-used ratio for each block currently in the window, oldest first
+    pub async fn gas_used_ratio_history(&self) -> Vec<f64> {
+        self.samples.read().await.iter().map(|sample| sample.gas_used_ratio).collect()
+    }
+}
+
+impl Default for FeeHistoryTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_WINDOW_BLOCKS, DEFAULT_TREND_THRESHOLD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_first_observation_is_stable() {
+        let tracker = FeeHistoryTracker::default();
+        assert!(matches!(tracker.observe_block(1_000_000_000, 0.5).await, PriceTrend::Stable));
+    }
+
+    #[tokio::test]
+    async fn test_large_increase_is_detected() {
+        let tracker = FeeHistoryTracker::new(20, 0.05);
+        tracker.observe_block(1_000_000_000, 0.5).await;
+
+        let trend = tracker.observe_block(2_000_000_000, 0.5).await;
+        assert!(matches!(trend, PriceTrend::Increase));
+    }
+
+    #[tokio::test]
+    async fn test_large_decrease_is_detected() {
+        let tracker = FeeHistoryTracker::new(20, 0.05);
+        tracker.observe_block(1_000_000_000, 0.5).await;
+
+        let trend = tracker.observe_block(500_000_000, 0.5).await;
+        assert!(matches!(trend, PriceTrend::Decrease));
+    }
+
+    #[tokio::test]
+    async fn test_small_deviation_is_stable() {
+        let tracker = FeeHistoryTracker::new(20, 0.05);
+        tracker.observe_block(1_000_000_000, 0.5).await;
+
+        let trend = tracker.observe_block(1_010_000_000, 0.5).await;
+        assert!(matches!(trend, PriceTrend::Stable));
+    }
+
+    #[tokio::test]
+    async fn test_window_evicts_oldest_block_past_capacity() {
+        let tracker = FeeHistoryTracker::new(3, 0.05);
+        for base_fee in [100u128, 200, 300, 400] {
+            tracker.observe_block(base_fee, 0.5).await;
+        }
+
+        assert_eq!(tracker.base_fee_history().await, vec!["200", "300", "400"]);
+    }
+
+    #[tokio::test]
+    async fn test_gas_used_ratio_history_aligns_with_base_fee_history() {
+        let tracker = FeeHistoryTracker::new(20, 0.05);
+        tracker.observe_block(100, 0.25).await;
+        tracker.observe_block(200, 0.75).await;
+
+        assert_eq!(tracker.base_fee_history().await, vec!["100", "200"]);
+        assert_eq!(tracker.gas_used_ratio_history().await, vec![0.25, 0.75]);
+    }
+}
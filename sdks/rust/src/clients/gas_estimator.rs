@@ -0,0 +1,197 @@
+//! Rolling-window gas price estimator fed by the pending-transaction subscription
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+
+use crate::types::NewTransaction;
+
+/// Default number of samples kept in the rolling window
+pub const DEFAULT_MAX_SAMPLES: usize = 500;
+/// Default maximum sample age before it's evicted from the window
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Clone, Copy)]
+struct GasSample {
+    price_wei: u128,
+    observed_at: Instant,
+}
+
+/// Slow/standard/fast gas price tier, in wei
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasPriceTiers {
+    pub slow: u128,
+    pub standard: u128,
+    pub fast: u128,
+}
+
+/// Maintains a bounded ring buffer of gas prices observed from pending
+/// transactions and computes percentile estimates on demand, following
+/// web3-proxy's approach of subscribing to pending transactions to build an
+/// intelligent gas estimator instead of relying on a single static value.
+/// The window is bounded by both a sample count and a max age, whichever is
+/// reached first.
+pub struct GasEstimator {
+    max_samples: usize,
+    max_age: Duration,
+    samples: RwLock<VecDeque<GasSample>>,
+}
+
+impl GasEstimator {
+    pub fn new(max_samples: usize, max_age: Duration) -> Self {
+        Self { max_samples, max_age, samples: RwLock::new(VecDeque::with_capacity(max_samples)) }
+    }
+
+    /// Record a pending transaction's gas price as a new sample. Legacy
+    /// transactions are observed via `gas_price` directly; transactions that
+    /// don't carry a parseable gas price are ignored rather than treated as
+    /// zero, since a missing/invalid value says nothing about market price.
+    pub async fn observe(&self, transaction: &NewTransaction) {
+        let Ok(price_wei) = transaction.gas_price.parse::<u128>() else { return };
+        self.observe_price(price_wei).await;
+    }
+
+    /// Record a raw gas price sample in wei
+    pub async fn observe_price(&self, price_wei: u128) {
+        let mut samples = self.samples.write().await;
+        samples.push_back(GasSample { price_wei, observed_at: Instant::now() });
+        Self::evict_stale(&mut samples, self.max_samples, self.max_age);
+    }
+
+    fn evict_stale(samples: &mut VecDeque<GasSample>, max_samples: usize, max_age: Duration) {
+        while samples.len() > max_samples {
+            samples.pop_front();
+        }
+        while samples.front().map(|sample| sample.observed_at.elapsed() > max_age).unwrap_or(false) {
+            samples.pop_front();
+        }
+    }
+
+    /// Percentile gas price estimate in wei from the current window, or
+    /// `None` if no samples have been observed yet. `percentile` is clamped
+    /// to `[0.0, 100.0]`.
+    pub async fn estimate(&self, percentile: f64) -> Option<u128> {
+        let mut samples = self.samples.write().await;
+        Self::evict_stale(&mut samples, self.max_samples, self.max_age);
+
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut prices: Vec<u128> = samples.iter().map(|sample| sample.price_wei).collect();
+        prices.sort_unstable();
+
+        let percentile = percentile.clamp(0.0, 100.0);
+        // Nearest-rank percentile: the smallest sample with at least
+        // `percentile`% of the window at or below it
+        let rank = ((percentile / 100.0) * prices.len() as f64).ceil() as usize;
+        Some(prices[rank.saturating_sub(1).min(prices.len() - 1)])
+    }
+
+    /// Convenience p10/p50/p90 slow/standard/fast estimate, suitable for
+    /// emitting as a `WSEvent::GasPrices` notification
+    pub async fn tiered_estimate(&self) -> Option<GasPriceTiers> {
+        let standard = self.estimate(50.0).await?;
+        let slow = self.estimate(10.0).await.unwrap_or(standard);
+        let fast = self.estimate(90.0).await.unwrap_or(standard);
+        Some(GasPriceTiers { slow, standard, fast })
+    }
+
+    /// Number of samples currently in the window
+    pub async fn sample_count(&self) -> usize {
+        let mut samples = self.samples.write().await;
+        Self::evict_stale(&mut samples, self.max_samples, self.max_age);
+        samples.len()
+    }
+}
+
+impl Default for GasEstimator {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_SAMPLES, DEFAULT_MAX_AGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_estimate_returns_none_without_samples() {
+        let estimator = GasEstimator::default();
+        assert_eq!(estimator.estimate(50.0).await, None);
+        assert_eq!(estimator.tiered_estimate().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_computes_percentiles() {
+        let estimator = GasEstimator::new(100, Duration::from_secs(60));
+        for price in [10u128, 20, 30, 40, 50, 60, 70, 80, 90, 100] {
+            estimator.observe_price(price).await;
+        }
+
+        assert_eq!(estimator.estimate(50.0).await, Some(50));
+        assert_eq!(estimator.estimate(0.0).await, Some(10));
+        assert_eq!(estimator.estimate(100.0).await, Some(100));
+    }
+
+    #[tokio::test]
+    async fn test_tiered_estimate_orders_slow_standard_fast() {
+        let estimator = GasEstimator::new(100, Duration::from_secs(60));
+        for price in [10u128, 20, 30, 40, 50, 60, 70, 80, 90, 100] {
+            estimator.observe_price(price).await;
+        }
+
+        let tiers = estimator.tiered_estimate().await.unwrap();
+        assert!(tiers.slow <= tiers.standard);
+        assert!(tiers.standard <= tiers.fast);
+    }
+
+    #[tokio::test]
+    async fn test_window_evicts_oldest_sample_past_max_count() {
+        let estimator = GasEstimator::new(3, Duration::from_secs(60));
+        for price in [10u128, 20, 30, 40] {
+            estimator.observe_price(price).await;
+        }
+
+        assert_eq!(estimator.sample_count().await, 3);
+        // The oldest sample (10) should have been evicted
+        assert_eq!(estimator.estimate(0.0).await, Some(20));
+    }
+
+    #[tokio::test]
+    async fn test_window_evicts_samples_past_max_age() {
+        let estimator = GasEstimator::new(100, Duration::from_millis(10));
+        estimator.observe_price(10).await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert_eq!(estimator.sample_count().await, 0);
+        assert_eq!(estimator.estimate(50.0).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_observe_ignores_unparseable_gas_price() {
+        use crate::types::Address;
+
+        let estimator = GasEstimator::default();
+        let transaction = NewTransaction {
+            hash: crate::types::Hash::new(
+                "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
+            )
+            .unwrap(),
+            from: Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string()).unwrap(),
+            to: None,
+            value: "0".to_string(),
+            gas_price: "not-a-number".to_string(),
+            gas_limit: "21000".to_string(),
+            nonce: 0,
+            input: "0x".to_string(),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+        };
+
+        estimator.observe(&transaction).await;
+        assert_eq!(estimator.sample_count().await, 0);
+    }
+}
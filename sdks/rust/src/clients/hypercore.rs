@@ -2,26 +2,25 @@
 
 use std::sync::Arc;
 use std::collections::HashMap;
-use tokio::sync::RwLock;
 use tracing::{debug, info};
 
+use crate::clients::hypercore_cache::HyperCoreCache;
 use crate::types::{
-    HyperCoreConfig, TransactionRequest, CrossLayerData, CrossLayerQuery, 
+    HyperCoreConfig, TransactionRequest, CrossLayerData, CrossLayerQuery,
     QueryType, BlockRange, QueryFilters, StateData, CrossLayerTransaction,
 };
 use crate::error::{HyperSimError, Result};
+use crate::security::{verify_response_certificate, SecurityManager};
+
+/// Maximum number of `CrossLayerQuery` results the LRU cache holds at once.
+const CACHE_MAX_ENTRIES: usize = 500;
 
 /// HyperCore client for cross-layer data access
 pub struct HyperCoreClient {
     config: HyperCoreConfig,
     http_client: reqwest::Client,
-    cache: Arc<RwLock<HashMap<String, CachedData>>>,
-}
-
-#[derive(Debug, Clone)]
-struct CachedData {
-    data: CrossLayerData,
-    expires_at: std::time::Instant,
+    cache: HyperCoreCache,
+    security: Option<Arc<SecurityManager>>,
 }
 
 impl HyperCoreClient {
@@ -37,13 +36,26 @@ impl HyperCoreClient {
         let http_client = reqwest::Client::builder()
             .timeout(std::time::Duration::from_millis(config.timeout))
             .default_headers(headers)
+            .tls_info(true)
+            .tcp_nodelay(config.transport.tcp_nodelay)
+            .tcp_keepalive(config.transport.keep_alive_enabled.then(|| {
+                std::time::Duration::from_secs(config.transport.keep_alive_interval_secs)
+            }))
             .build()
             .map_err(|e| HyperSimError::network(format!("Failed to create HyperCore client: {}", e)))?;
 
+        let security = config.security.clone().map(SecurityManager::new).map(Arc::new);
+        let cache = HyperCoreCache::new(
+            config.cache_enabled,
+            std::time::Duration::from_secs(config.cache_ttl),
+            CACHE_MAX_ENTRIES,
+        );
+
         Ok(Self {
             config,
             http_client,
-            cache: Arc::new(RwLock::new(HashMap::new())),
+            cache,
+            security,
         })
     }
 
@@ -74,12 +86,12 @@ impl HyperCoreClient {
 
     /// Query cross-layer data with custom parameters
     pub async fn query_cross_layer_data(&self, query: CrossLayerQuery) -> Result<CrossLayerData> {
-        let cache_key = self.generate_cache_key(&query);
-        
-        // Check cache first
-        if let Some(cached) = self.get_cached_data(&cache_key).await {
+        if let Some(cached) = self.cache.get(&query).await {
             debug!("Cache hit for HyperCore query");
-            return Ok(cached.data);
+            let mut data = cached.data;
+            data.metadata.cache_hit_ratio = self.cache.hit_ratio();
+            data.metadata.data_age_seconds = cached.age_seconds;
+            return Ok(data);
         }
 
         // For demo purposes, return mock cross-layer data
@@ -103,14 +115,13 @@ impl HyperCoreClient {
             metadata: crate::types::CrossLayerMetadata {
                 execution_time_ms: 150,
                 data_sources: vec!["HyperCore".to_string()],
-                cache_hit_ratio: 0.75,
-                data_age_seconds: 5,
+                cache_hit_ratio: self.cache.hit_ratio(),
+                data_age_seconds: 0,
                 api_version: "v1".to_string(),
             },
         };
 
-        // Cache the result
-        self.cache_data(&cache_key, &cross_layer_data).await;
+        self.cache.insert(&query, cross_layer_data.clone()).await;
 
         Ok(cross_layer_data)
     }
@@ -118,11 +129,23 @@ impl HyperCoreClient {
     /// Get bridge operations for addresses
     pub async fn get_bridge_operations(&self, addresses: &[crate::types::Address]) -> Result<Vec<crate::types::BridgeOperation>> {
         info!("Fetching bridge operations for {} addresses", addresses.len());
-        
+
         // Mock bridge operations for demo
         Ok(Vec::new())
     }
 
+    /// Purge cached cross-layer data touching `address`, e.g. after observing
+    /// a new block that may have changed its state
+    pub async fn invalidate_cache_for_address(&self, address: &crate::types::Address) {
+        self.cache.invalidate(address).await;
+    }
+
+    /// Purge cached cross-layer data whose query overlaps `range`, e.g. after
+    /// a reorg invalidates previously queried blocks
+    pub async fn invalidate_cache_for_range(&self, range: &BlockRange) {
+        self.cache.invalidate_range(range).await;
+    }
+
     /// Health check
     pub async fn health_check(&self) -> Result<()> {
         let response = self.http_client
@@ -131,58 +154,16 @@ impl HyperCoreClient {
             .await
             .map_err(|e| HyperSimError::network(format!("Health check failed: {}", e)))?;
 
+        if let Some(security) = self.security.as_deref() {
+            verify_response_certificate(security, &response)?;
+        }
+
         if response.status().is_success() {
             Ok(())
         } else {
             Err(HyperSimError::network("HyperCore health check failed"))
         }
     }
-
-    // Private helper methods
-
-    fn generate_cache_key(&self, query: &CrossLayerQuery) -> String {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        format!("{:?}", query.query_type).hash(&mut hasher);
-        for addr in &query.addresses {
-            addr.as_str().hash(&mut hasher);
-        }
-        
-        format!("hypercore_{:x}", hasher.finish())
-    }
-
-    async fn get_cached_data(&self, cache_key: &str) -> Option<CachedData> {
-        let cache = self.cache.read().await;
-        
-        if let Some(entry) = cache.get(cache_key) {
-            if entry.expires_at > std::time::Instant::now() {
-                return Some(entry.clone());
-            }
-        }
-        
-        None
-    }
-
-    async fn cache_data(&self, cache_key: &str, data: &CrossLayerData) {
-        if self.config.cache_enabled {
-            let entry = CachedData {
-                data: data.clone(),
-                expires_at: std::time::Instant::now() + 
-                    std::time::Duration::from_secs(self.config.cache_ttl),
-            };
-            
-            let mut cache = self.cache.write().await;
-            cache.insert(cache_key.to_string(), entry);
-            
-            // Simple cache cleanup
-            if cache.len() > 500 {
-                let now = std::time::Instant::now();
-                cache.retain(|_, v| v.expires_at > now);
-            }
-        }
-    }
 }
 
 impl std::fmt::Debug for HyperCoreClient {
@@ -207,11 +188,12 @@ mod tests {
         assert!(client.is_ok());
     }
 
-    #[test]
-    fn test_cache_key_generation() {
-        let config = HyperCoreConfig::new(Network::Local);
-        let client = tokio_test::block_on(HyperCoreClient::new(config)).unwrap();
-        
+    #[tokio::test]
+    async fn test_query_cross_layer_data_is_cached_and_reports_real_hit_ratio() {
+        let mut config = HyperCoreConfig::new(Network::Local);
+        config.cache_enabled = true;
+        let client = HyperCoreClient::new(config).await.unwrap();
+
         let query = CrossLayerQuery {
             query_type: QueryType::AccountState,
             addresses: vec![Address("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string())],
@@ -228,8 +210,44 @@ mod tests {
             },
             include_history: false,
         };
-        
-        let key = client.generate_cache_key(&query);
-        assert!(key.starts_with("hypercore_"));
+
+        let first = client.query_cross_layer_data(query.clone()).await.unwrap();
+        assert_eq!(first.metadata.cache_hit_ratio, 0.0);
+
+        let second = client.query_cross_layer_data(query).await.unwrap();
+        assert!(second.metadata.cache_hit_ratio > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_cache_for_address_forces_a_fresh_query() {
+        let mut config = HyperCoreConfig::new(Network::Local);
+        config.cache_enabled = true;
+        let client = HyperCoreClient::new(config).await.unwrap();
+        let address = Address("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string());
+
+        let query = CrossLayerQuery {
+            query_type: QueryType::AccountState,
+            addresses: vec![address.clone()],
+            block_range: BlockRange {
+                from_block: None,
+                to_block: None,
+                include_pending: true,
+            },
+            filters: QueryFilters {
+                topics: None,
+                min_value: None,
+                tx_types: None,
+                include_internal: false,
+            },
+            include_history: false,
+        };
+
+        client.query_cross_layer_data(query.clone()).await.unwrap();
+        client.invalidate_cache_for_address(&address).await;
+
+        let after_invalidate = client.query_cross_layer_data(query).await.unwrap();
+        assert_eq!(after_invalidate.metadata.data_age_seconds, 0);
+        // Both lookups missed: a cache hit here would have reported 1 hit in 2.
+        assert_eq!(after_invalidate.metadata.cache_hit_ratio, 0.0);
     }
 }
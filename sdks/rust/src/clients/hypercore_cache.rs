@@ -0,0 +1,339 @@
+//! Bounded LRU + per-entry TTL cache for `HyperCoreClient::query_cross_layer_data`,
+//! keyed on a normalized hash of the `CrossLayerQuery` that produced each
+//! `CrossLayerData`. Honors `HyperCoreConfig.cache_enabled`/`cache_ttl`; with
+//! caching disabled the whole thing is a no-op passthrough.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+
+use crate::types::{Address, BlockRange, CrossLayerData, CrossLayerQuery};
+
+struct Entry {
+    data: CrossLayerData,
+    inserted_at: Instant,
+}
+
+/// A cache hit, bundling the cached data with how long ago it was inserted
+/// so callers can populate `CrossLayerMetadata.data_age_seconds`.
+pub(crate) struct CachedLookup {
+    pub data: CrossLayerData,
+    pub age_seconds: u64,
+}
+
+pub(crate) struct HyperCoreCache {
+    enabled: bool,
+    ttl: Duration,
+    max_entries: usize,
+    entries: RwLock<HashMap<u64, Entry>>,
+    /// Recency ring: least-recently-used at the front, most-recently-used at the back
+    recency: RwLock<VecDeque<u64>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl HyperCoreCache {
+    pub fn new(enabled: bool, ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            enabled,
+            ttl,
+            max_entries,
+            entries: RwLock::new(HashMap::new()),
+            recency: RwLock::new(VecDeque::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Hash `query_type` (no `Hash` derive) by its `Debug` form, and every
+    /// other field directly, so two structurally equal queries always
+    /// normalize to the same key regardless of field order of construction.
+    fn cache_key(query: &CrossLayerQuery) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        format!("{:?}", query.query_type).hash(&mut hasher);
+        for address in &query.addresses {
+            address.as_str().hash(&mut hasher);
+        }
+        query.block_range.from_block.hash(&mut hasher);
+        query.block_range.to_block.hash(&mut hasher);
+        query.block_range.include_pending.hash(&mut hasher);
+        query.filters.topics.hash(&mut hasher);
+        query.filters.min_value.hash(&mut hasher);
+        query.filters.tx_types.hash(&mut hasher);
+        query.filters.include_internal.hash(&mut hasher);
+        query.include_history.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Look up `query`, treating an entry older than `ttl` as a miss and
+    /// evicting it. Updates the running hit/miss counters either way.
+    pub async fn get(&self, query: &CrossLayerQuery) -> Option<CachedLookup> {
+        if !self.enabled {
+            return None;
+        }
+
+        let key = Self::cache_key(query);
+        let mut entries = self.entries.write().await;
+
+        let expired = entries.get(&key).map(|entry| entry.inserted_at.elapsed() > self.ttl).unwrap_or(false);
+        if expired {
+            entries.remove(&key);
+        }
+
+        let Some(entry) = entries.get(&key) else {
+            drop(entries);
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            if expired {
+                self.remove_from_recency(key).await;
+            }
+            return None;
+        };
+
+        let lookup = CachedLookup { data: entry.data.clone(), age_seconds: entry.inserted_at.elapsed().as_secs() };
+        drop(entries);
+
+        self.touch(key).await;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(lookup)
+    }
+
+    /// Insert `data` under the key derived from `query`, evicting the
+    /// least-recently-used entry if this insertion pushes the cache over
+    /// `max_entries`.
+    pub async fn insert(&self, query: &CrossLayerQuery, data: CrossLayerData) {
+        if !self.enabled {
+            return;
+        }
+
+        let key = Self::cache_key(query);
+        self.entries.write().await.insert(key, Entry { data, inserted_at: Instant::now() });
+        self.touch(key).await;
+        self.evict_over_capacity().await;
+    }
+
+    /// Purge every cached entry whose originating query touched `address`
+    pub async fn invalidate(&self, address: &Address) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut entries = self.entries.write().await;
+        let stale: Vec<u64> =
+            entries.iter().filter(|(_, entry)| entry.data.query.addresses.contains(address)).map(|(key, _)| *key).collect();
+        for key in &stale {
+            entries.remove(key);
+        }
+        drop(entries);
+
+        if !stale.is_empty() {
+            let mut recency = self.recency.write().await;
+            recency.retain(|key| !stale.contains(key));
+        }
+    }
+
+    /// Purge every cached entry whose originating query's block range
+    /// overlaps `range`, so a reorg or new block can invalidate stale data
+    pub async fn invalidate_range(&self, range: &BlockRange) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut entries = self.entries.write().await;
+        let stale: Vec<u64> = entries
+            .iter()
+            .filter(|(_, entry)| ranges_overlap(&entry.data.query.block_range, range))
+            .map(|(key, _)| *key)
+            .collect();
+        for key in &stale {
+            entries.remove(key);
+        }
+        drop(entries);
+
+        if !stale.is_empty() {
+            let mut recency = self.recency.write().await;
+            recency.retain(|key| !stale.contains(key));
+        }
+    }
+
+    /// Fraction of `get` calls that were hits since this cache was created
+    pub fn hit_ratio(&self) -> f64 {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        if total == 0 {
+            0.0
+        } else {
+            hits as f64 / total as f64
+        }
+    }
+
+    async fn touch(&self, key: u64) {
+        let mut recency = self.recency.write().await;
+        recency.retain(|existing| *existing != key);
+        recency.push_back(key);
+    }
+
+    async fn remove_from_recency(&self, key: u64) {
+        self.recency.write().await.retain(|existing| *existing != key);
+    }
+
+    async fn evict_over_capacity(&self) {
+        let mut recency = self.recency.write().await;
+        while recency.len() > self.max_entries {
+            if let Some(oldest) = recency.pop_front() {
+                self.entries.write().await.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Whether two (possibly open-ended) block ranges could include a common block number
+fn ranges_overlap(a: &BlockRange, b: &BlockRange) -> bool {
+    let a_from = a.from_block.unwrap_or(0);
+    let a_to = a.to_block.unwrap_or(u64::MAX);
+    let b_from = b.from_block.unwrap_or(0);
+    let b_to = b.to_block.unwrap_or(u64::MAX);
+    a_from <= b_to && b_from <= a_to
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CrossLayerMetadata, QueryFilters, QueryType, StateData, StateSyncInfo, SyncStatus};
+
+    fn query(address: &str, from_block: Option<u64>, to_block: Option<u64>) -> CrossLayerQuery {
+        CrossLayerQuery {
+            query_type: QueryType::AccountState,
+            addresses: vec![Address::new(address.to_string()).unwrap()],
+            block_range: BlockRange { from_block, to_block, include_pending: true },
+            filters: QueryFilters { topics: None, min_value: None, tx_types: None, include_internal: false },
+            include_history: false,
+        }
+    }
+
+    fn data(query: CrossLayerQuery) -> CrossLayerData {
+        CrossLayerData {
+            query,
+            state_data: StateData {
+                account_states: HashMap::new(),
+                storage_states: HashMap::new(),
+                layer_mappings: Vec::new(),
+                sync_info: StateSyncInfo {
+                    last_sync_block: 0,
+                    sync_status: SyncStatus::Synced,
+                    pending_syncs: 0,
+                    sync_lag: 0,
+                    health_score: 1.0,
+                },
+            },
+            transactions: Vec::new(),
+            bridge_operations: Vec::new(),
+            state_proofs: None,
+            metadata: CrossLayerMetadata {
+                execution_time_ms: 0,
+                data_sources: vec![],
+                cache_hit_ratio: 0.0,
+                data_age_seconds: 0,
+                api_version: "v1".to_string(),
+            },
+        }
+    }
+
+    const ADDR: &str = "0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1";
+
+    #[tokio::test]
+    async fn test_cache_key_is_deterministic_and_field_sensitive() {
+        let a = query(ADDR, Some(1), Some(2));
+        let b = query(ADDR, Some(1), Some(2));
+        let c = query(ADDR, Some(1), Some(3));
+
+        assert_eq!(HyperCoreCache::cache_key(&a), HyperCoreCache::cache_key(&b));
+        assert_ne!(HyperCoreCache::cache_key(&a), HyperCoreCache::cache_key(&c));
+    }
+
+    #[tokio::test]
+    async fn test_miss_then_hit_after_insert() {
+        let cache = HyperCoreCache::new(true, Duration::from_secs(60), 10);
+        let q = query(ADDR, None, None);
+
+        assert!(cache.get(&q).await.is_none());
+        cache.insert(&q, data(q.clone())).await;
+
+        let hit = cache.get(&q).await.unwrap();
+        assert_eq!(hit.data.query.addresses, q.addresses);
+        assert!(cache.hit_ratio() > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_is_a_passthrough() {
+        let cache = HyperCoreCache::new(false, Duration::from_secs(60), 10);
+        let q = query(ADDR, None, None);
+
+        cache.insert(&q, data(q.clone())).await;
+        assert!(cache.get(&q).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_entries_expire_after_ttl() {
+        let cache = HyperCoreCache::new(true, Duration::from_millis(0), 10);
+        let q = query(ADDR, None, None);
+
+        cache.insert(&q, data(q.clone())).await;
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        assert!(cache.get(&q).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_lru_eviction_drops_least_recently_used_entry() {
+        let cache = HyperCoreCache::new(true, Duration::from_secs(60), 2);
+
+        let q1 = query("0x1111111111111111111111111111111111111111", None, None);
+        let q2 = query("0x2222222222222222222222222222222222222222", None, None);
+        let q3 = query("0x3333333333333333333333333333333333333333", None, None);
+
+        cache.insert(&q1, data(q1.clone())).await;
+        cache.insert(&q2, data(q2.clone())).await;
+        // Touch q1 so q2 becomes the least-recently-used entry.
+        cache.get(&q1).await;
+        cache.insert(&q3, data(q3.clone())).await;
+
+        assert!(cache.get(&q1).await.is_some());
+        assert!(cache.get(&q2).await.is_none());
+        assert!(cache.get(&q3).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_purges_entries_touching_address() {
+        let cache = HyperCoreCache::new(true, Duration::from_secs(60), 10);
+        let q = query(ADDR, None, None);
+        cache.insert(&q, data(q.clone())).await;
+
+        cache.invalidate(&Address::new(ADDR.to_string()).unwrap()).await;
+        assert!(cache.get(&q).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_range_purges_overlapping_entries() {
+        let cache = HyperCoreCache::new(true, Duration::from_secs(60), 10);
+        let q = query(ADDR, Some(100), Some(200));
+        cache.insert(&q, data(q.clone())).await;
+
+        cache.invalidate_range(&BlockRange { from_block: Some(150), to_block: Some(160), include_pending: true }).await;
+        assert!(cache.get(&q).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_range_ignores_non_overlapping_entries() {
+        let cache = HyperCoreCache::new(true, Duration::from_secs(60), 10);
+        let q = query(ADDR, Some(100), Some(200));
+        cache.insert(&q, data(q.clone())).await;
+
+        cache.invalidate_range(&BlockRange { from_block: Some(300), to_block: Some(400), include_pending: true }).await;
+        assert!(cache.get(&q).await.is_some());
+    }
+}
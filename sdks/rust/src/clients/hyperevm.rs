@@ -1,22 +1,42 @@
 //! HyperEVM client implementation
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::collections::HashMap;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info};
 
+use crate::retry::{retry_with_policy, RetryPolicy};
+
 use crate::types::{
     HyperEVMConfig, TransactionRequest, SimulationResult, NetworkStatus,
-    BlockType, HyperEVMBlock, Address, Hash, Wei,
+    BlockType, HyperEVMBlock, HyperEVMGasEstimate, AccessListGasComparison,
+    AccessListEntry, Address, ExecutionTrace, GasBreakdown, Hash, OpcodeStep,
+    StorageAccess, StorageAccessType, TraceCall, TraceConfig, Wei,
 };
+use crate::clients::discovery::FallbackDiscoveryConfig;
+use crate::clients::dispatcher::{EndpointDispatcher, EndpointMetrics};
 use crate::error::{HyperSimError, Result};
+use crate::utils::constants::gas as gas_constants;
+use crate::verification::{verify_account_proof, AccountProof, HeaderTracker, VerificationStatus};
+use crate::security::{verify_response_certificate, SecurityManager};
+
+/// Timeout for the lightweight liveness probe issued to a candidate fallback
+/// endpoint during discovery; short because a candidate that can't answer an
+/// `eth_blockNumber` this fast isn't worth failing over to anyway.
+const FALLBACK_PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
 
 /// High-performance HyperEVM client for transaction simulation
 pub struct HyperEVMClient {
     config: HyperEVMConfig,
     http_client: reqwest::Client,
+    dispatcher: EndpointDispatcher,
     cache: Arc<RwLock<HashMap<String, CacheEntry>>>,
     metrics: Arc<RwLock<ClientMetrics>>,
+    header_tracker: Arc<RwLock<HeaderTracker>>,
+    security: Option<Arc<SecurityManager>>,
+    next_batch_id: AtomicU64,
+    circuit_state: Arc<RwLock<HashMap<String, EndpointCircuitState>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -30,6 +50,24 @@ struct ClientMetrics {
     total_requests: u64,
     cache_hits: u64,
     errors: u64,
+    /// Transactions in a [`HyperEVMClient::simulate_batch`] call served from
+    /// the local cache, never bundled into the JSON-RPC batch
+    batch_cache_hits: u64,
+    /// Transactions in a [`HyperEVMClient::simulate_batch`] call that missed
+    /// the cache and were bundled into the JSON-RPC batch
+    batch_cache_misses: u64,
+    /// Retries issued after a transient, retryable RPC failure
+    retries: u64,
+    /// Times a per-endpoint circuit breaker tripped open
+    circuit_trips: u64,
+}
+
+/// Per-endpoint circuit breaker bookkeeping: consecutive failures since the
+/// last success, and when the breaker tripped (if it's currently open).
+#[derive(Debug, Clone, Default)]
+struct EndpointCircuitState {
+    consecutive_failures: u32,
+    opened_at: Option<std::time::Instant>,
 }
 
 impl HyperEVMClient {
@@ -46,17 +84,92 @@ impl HyperEVMClient {
         let http_client = reqwest::Client::builder()
             .timeout(std::time::Duration::from_millis(config.timeout))
             .default_headers(headers)
+            .tls_info(true)
+            .tcp_nodelay(config.transport.tcp_nodelay)
+            .tcp_keepalive(config.transport.keep_alive_enabled.then(|| {
+                std::time::Duration::from_secs(config.transport.keep_alive_interval_secs)
+            }))
             .build()
             .map_err(|e| HyperSimError::network(format!("Failed to create HTTP client: {}", e)))?;
 
+        let fallback_discovery = FallbackDiscoveryConfig {
+            fallback_endpoints: config.fallback_endpoints.clone(),
+            load_external_fallback: config.load_external_fallback,
+            fallback_url: config.fallback_url.clone(),
+        };
+        let dispatcher = EndpointDispatcher::new(config.rpc_endpoint_pool())?
+            .with_fallback_discovery(fallback_discovery, http_client.clone(), FALLBACK_PROBE_TIMEOUT);
+        dispatcher.warm_fallback_discovery().await;
+
+        let security = config.security.clone().map(SecurityManager::new).map(Arc::new);
+
         Ok(Self {
             config,
             http_client,
+            dispatcher,
             cache: Arc::new(RwLock::new(HashMap::new())),
             metrics: Arc::new(RwLock::new(ClientMetrics::default())),
+            header_tracker: Arc::new(RwLock::new(HeaderTracker::new())),
+            security,
+            next_batch_id: AtomicU64::new(1),
+            circuit_state: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
+    /// Endpoints merged into the dispatch pool via fallback discovery (at
+    /// startup, or after the configured pool failed outright)
+    pub async fn discovered_fallback_endpoints(&self) -> Vec<String> {
+        self.dispatcher.discovered_endpoints().await
+    }
+
+    /// Per-endpoint latency/failure counters recorded by the RPC dispatcher
+    pub async fn endpoint_metrics(&self) -> HashMap<String, EndpointMetrics> {
+        self.dispatcher.metrics().await
+    }
+
+    /// Per-endpoint EWMA latency ranking and which endpoint the dispatcher
+    /// would currently route to, for surfacing in [`PerformanceMetrics`](crate::types::PerformanceMetrics)
+    pub async fn endpoint_pool_metrics(&self) -> crate::types::EndpointPoolMetrics {
+        self.dispatcher.pool_metrics().await
+    }
+
+    /// Query `eth_blockNumber` against every endpoint in the pool
+    /// independently (unlike [`simulate`](Self::simulate)'s RPC calls, which
+    /// fail over to the next endpoint on failure), keyed by endpoint URL.
+    /// Endpoints that errored or returned an unparseable reply are omitted.
+    /// Used by the SDK's consensus finder to compare every endpoint's
+    /// reported head in one pass.
+    pub async fn endpoint_block_heights(&self) -> HashMap<String, u64> {
+        let request_body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "eth_blockNumber",
+            "params": serde_json::Value::Null,
+            "id": 1
+        });
+
+        let results = self
+            .dispatcher
+            .query_all(|endpoint| self.request_endpoint(endpoint, request_body.clone()))
+            .await;
+
+        results
+            .into_iter()
+            .filter_map(|(endpoint, result)| {
+                let response = result.ok()?;
+                let hex = response.get("result")?.as_str()?;
+                let height = u64::from_str_radix(hex.trim_start_matches("0x"), 16).ok()?;
+                Some((endpoint, height))
+            })
+            .collect()
+    }
+
+    /// Mark `endpoint` unhealthy in the dispatcher's ranking, e.g. because
+    /// the consensus finder found it lagging the pool's consensus head block
+    /// by more than the configured threshold
+    pub async fn mark_endpoint_unhealthy(&self, endpoint: &str) {
+        self.dispatcher.mark_unhealthy(endpoint).await;
+    }
+
     /// Simulate a transaction on HyperEVM
     pub async fn simulate(&self, transaction: TransactionRequest) -> Result<SimulationResult> {
         let start_time = std::time::Instant::now();
@@ -72,15 +185,91 @@ impl HyperEVMClient {
             return Ok(cached_result);
         }
 
+        // Touched accounts to verify trustlessly, if enabled
+        let touched_addresses: Vec<Address> = std::iter::once(transaction.from.clone())
+            .chain(transaction.to.clone())
+            .collect();
+
+        // Needed after the transaction is moved into the request, to charge
+        // EIP-2929 warm/cold access gas against the returned trace and to
+        // compute the EIP-1559 fee fields below.
+        let gas_accounting_tx = transaction.clone();
+
+        // Predict the base fee the transaction would actually be included
+        // under, and reject up front if it couldn't possibly cover it.
+        let parent_block = self.get_latest_block().await?;
+        let parent_base_fee: u128 = parent_block
+            .base_fee_per_gas
+            .as_ref()
+            .and_then(|w| w.as_str().parse().ok())
+            .unwrap_or(0);
+        let parent_gas_limit: u64 = parent_block.gas_limit.parse().unwrap_or(30_000_000);
+        let parent_gas_used: u64 = parent_block.gas_used.parse().unwrap_or(0);
+        let base_fee = crate::types::compute_next_base_fee(parent_base_fee, parent_gas_limit, parent_gas_used);
+
+        if let Some(ref max_fee) = gas_accounting_tx.max_fee_per_gas {
+            let max_fee_val: u128 = max_fee.as_str().parse().unwrap_or(0);
+            if max_fee_val < base_fee {
+                return Err(HyperSimError::validation(
+                    "max_fee_per_gas is below the predicted base fee for the next block"
+                ));
+            }
+        }
+
         // Prepare simulation request
         let simulation_request = self.build_simulation_request(transaction)?;
-        
+
         // Send RPC request
         let response = self.send_rpc_request("hyperevm_simulate", simulation_request).await?;
-        
+
         // Parse response
-        let simulation_result = self.parse_simulation_response(response)?;
-        
+        let mut simulation_result = self.parse_simulation_response(response)?;
+
+        if let Some(ref mut trace) = simulation_result.trace {
+            apply_access_journal(trace, &gas_accounting_tx);
+        }
+
+        let effective_gas_price = if gas_accounting_tx.tx_type == Some(2) {
+            let max_fee_val: u128 = gas_accounting_tx
+                .max_fee_per_gas
+                .as_ref()
+                .and_then(|w| w.as_str().parse().ok())
+                .unwrap_or(0);
+            let priority_fee_val: u128 = gas_accounting_tx
+                .max_priority_fee_per_gas
+                .as_ref()
+                .and_then(|w| w.as_str().parse().ok())
+                .unwrap_or(0);
+            crate::types::effective_gas_price(base_fee, max_fee_val, priority_fee_val)
+        } else {
+            gas_accounting_tx
+                .gas_price
+                .as_ref()
+                .and_then(|w| w.as_str().parse().ok())
+                .unwrap_or(0)
+        };
+        let burned_fee = Wei::new(base_fee.to_string())
+            .checked_mul(&Wei::new(simulation_result.gas_used.clone()))
+            .unwrap_or_else(|_| Wei::new("0"));
+
+        simulation_result.base_fee_per_gas = Some(Wei::new(base_fee.to_string()));
+        simulation_result.effective_gas_price = Some(Wei::new(effective_gas_price.to_string()));
+        simulation_result.burned_fee = Some(burned_fee);
+        simulation_result.gas_limit = gas_accounting_tx.gas_limit.clone();
+        simulation_result.max_fee_per_gas = gas_accounting_tx.max_fee_per_gas.clone();
+        simulation_result.max_priority_fee_per_gas = gas_accounting_tx.max_priority_fee_per_gas.clone();
+        simulation_result.tx_type = gas_accounting_tx.tx_type;
+        simulation_result.blob_count = gas_accounting_tx.blob_count;
+        simulation_result.blob_base_fee = gas_accounting_tx.blob_base_fee.clone();
+        simulation_result.calldata_size = gas_accounting_tx
+            .data
+            .as_ref()
+            .map(|data| (data.trim_start_matches("0x").len() / 2) as u64);
+
+        if self.config.trustless {
+            simulation_result.verification = self.verify_trustlessly(&touched_addresses).await;
+        }
+
         // Cache the result
         if self.config.cache_enabled {
             self.cache_result(&cache_key, &simulation_result).await;
@@ -95,6 +284,255 @@ impl HyperEVMClient {
         Ok(simulation_result)
     }
 
+    /// Simulate a transaction with an opcode-level execution trace, the
+    /// `debug_traceCall`-style counterpart to [`simulate`](Self::simulate).
+    /// `config` controls whether the trace captures flat opcode steps (with
+    /// optional stack/memory/storage snapshots) or just the top-level call
+    /// tree already carried by `SimulationResult::trace::calls`.
+    ///
+    /// This bypasses the cache and EIP-2929 access journal replay that
+    /// `simulate` applies, since a requested trace is already the
+    /// ground-truth execution record.
+    pub async fn simulate_with_trace(
+        &self,
+        transaction: TransactionRequest,
+        config: TraceConfig,
+    ) -> Result<SimulationResult> {
+        debug!("Simulating transaction with trace from {}", transaction.from);
+
+        let mut simulation_request = self.build_simulation_request(transaction)?;
+        if let serde_json::Value::Object(ref mut map) = simulation_request {
+            map.insert("trace".to_string(), trace_options(&config));
+        }
+
+        let response = self.send_rpc_request("hyperevm_simulate", simulation_request).await?;
+
+        let mut simulation_result = self.parse_simulation_response(response.clone())?;
+        simulation_result.trace = parse_execution_trace(&response, &config);
+
+        Ok(simulation_result)
+    }
+
+    /// Simulate many transactions in a single round trip. Transactions already
+    /// in the cache are served locally and never bundled into the batch;
+    /// every cache miss is assigned a monotonically increasing id, posted as
+    /// one JSON-RPC batch array, and correlated back to its input by id since
+    /// batch replies may arrive in a different order than they were sent.
+    ///
+    /// Unlike [`simulate`](Self::simulate), a single transaction failing
+    /// (a malformed response, an RPC error) doesn't fail the whole batch: it
+    /// surfaces as an `Err` at that transaction's position in the returned
+    /// `Vec`, alongside `Ok` results for the rest.
+    pub async fn simulate_batch(
+        &self,
+        transactions: Vec<TransactionRequest>,
+    ) -> Result<Vec<Result<SimulationResult>>> {
+        debug!("Simulating batch of {} transactions", transactions.len());
+
+        let mut results: Vec<Option<Result<SimulationResult>>> = Vec::with_capacity(transactions.len());
+        let mut pending: Vec<(usize, u64, TransactionRequest)> = Vec::new();
+
+        for (index, transaction) in transactions.into_iter().enumerate() {
+            let cache_key = self.generate_cache_key(&transaction);
+            if let Some(cached_result) = self.get_cached_result(&cache_key).await {
+                let mut metrics = self.metrics.write().await;
+                metrics.batch_cache_hits += 1;
+                results.push(Some(Ok(cached_result)));
+                continue;
+            }
+
+            let request_id = self.next_request_id();
+            results.push(None);
+            pending.push((index, request_id, transaction));
+        }
+
+        if !pending.is_empty() {
+            let mut metrics = self.metrics.write().await;
+            metrics.batch_cache_misses += pending.len() as u64;
+        }
+
+        if pending.is_empty() {
+            return Ok(results.into_iter().map(|r| r.expect("every index was filled")).collect());
+        }
+
+        let mut cache_keys: HashMap<u64, String> = HashMap::new();
+        let mut batch_requests = Vec::with_capacity(pending.len());
+        for (_, request_id, transaction) in &pending {
+            cache_keys.insert(*request_id, self.generate_cache_key(transaction));
+            let params = self.build_simulation_request(transaction.clone())?;
+            batch_requests.push(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "hyperevm_simulate",
+                "params": params,
+                "id": request_id,
+            }));
+        }
+
+        let responses = self.send_rpc_batch(batch_requests).await?;
+        let mut responses_by_id: HashMap<u64, serde_json::Value> = responses
+            .into_iter()
+            .filter_map(|response| {
+                let id = response.get("id")?.as_u64()?;
+                Some((id, response))
+            })
+            .collect();
+
+        for (index, request_id, _) in pending {
+            let outcome = match responses_by_id.remove(&request_id) {
+                Some(response) => {
+                    if let Some(error) = response.get("error") {
+                        Err(HyperSimError::simulation(format!("RPC error: {}", error)))
+                    } else {
+                        self.parse_simulation_response(response)
+                    }
+                }
+                None => Err(HyperSimError::simulation(format!(
+                    "No response for batched request id {}", request_id
+                ))),
+            };
+
+            if let (Ok(ref simulation_result), true) = (&outcome, self.config.cache_enabled) {
+                if let Some(cache_key) = cache_keys.get(&request_id) {
+                    self.cache_result(cache_key, simulation_result).await;
+                }
+            }
+
+            results[index] = Some(outcome);
+        }
+
+        Ok(results.into_iter().map(|r| r.expect("every index was filled")).collect())
+    }
+
+    /// Estimate gas for a transaction, crediting the EIP-2930 warm-access discount
+    /// for any address or storage slot already declared in `access_list`.
+    ///
+    /// Each declared address/slot costs `ACCESS_LIST_*_COST` up front, but turns
+    /// what would otherwise be a cold access (`COLD_SLOAD_COST`) into a warm one
+    /// (`WARM_STORAGE_READ_COST`) the first time it's touched during execution,
+    /// so the net effect is only beneficial when the slot is actually accessed.
+    pub async fn estimate_gas(&self, transaction: &TransactionRequest) -> Result<HyperEVMGasEstimate> {
+        let mut gas_limit: u64 = if transaction.to.is_some() {
+            21_000
+        } else {
+            crate::utils::constants::gas::CONTRACT_DEPLOYMENT_GAS_LIMIT
+        };
+
+        if let Some(ref data) = transaction.data {
+            let bytes = data.trim_start_matches("0x").len() / 2;
+            gas_limit += bytes as u64 * 16;
+        }
+
+        let mut factors = vec!["base_intrinsic_gas".to_string()];
+
+        if let Some(ref access_list) = transaction.access_list {
+            let addresses = access_list.len() as u64;
+            let storage_keys: u64 = access_list.iter().map(|e| e.storage_keys.len() as u64).sum();
+
+            let access_list_cost = addresses * gas_constants::ACCESS_LIST_ADDRESS_COST
+                + storage_keys * gas_constants::ACCESS_LIST_STORAGE_KEY_COST;
+            let warm_discount = (addresses + storage_keys)
+                * (gas_constants::COLD_SLOAD_COST - gas_constants::WARM_STORAGE_READ_COST);
+
+            gas_limit += access_list_cost.saturating_sub(warm_discount);
+            factors.push("eip2930_access_list_warm_discount".to_string());
+        }
+
+        let (gas_price, predicted_base_fee) = if let Some(ref max_fee) = transaction.max_fee_per_gas {
+            let parent_block = self.get_latest_block().await?;
+            let parent_base_fee: u128 = parent_block
+                .base_fee_per_gas
+                .as_ref()
+                .and_then(|w| w.as_str().parse().ok())
+                .unwrap_or(0);
+            let parent_gas_limit: u64 = parent_block.gas_limit.parse().unwrap_or(30_000_000);
+            let parent_gas_used: u64 = parent_block.gas_used.parse().unwrap_or(0);
+
+            let next_base_fee =
+                crate::types::compute_next_base_fee(parent_base_fee, parent_gas_limit, parent_gas_used);
+
+            let max_fee_val: u128 = max_fee.as_str().parse().unwrap_or(0);
+            let priority_fee_val: u128 = transaction
+                .max_priority_fee_per_gas
+                .as_ref()
+                .and_then(|w| w.as_str().parse().ok())
+                .unwrap_or(0);
+
+            let effective = crate::types::effective_gas_price(next_base_fee, max_fee_val, priority_fee_val);
+            factors.push("eip1559_base_fee_prediction".to_string());
+
+            (Wei::new(effective.to_string()), Some(Wei::new(next_base_fee.to_string())))
+        } else {
+            let gas_price = transaction
+                .gas_price
+                .clone()
+                .unwrap_or_else(|| Wei::new("20000000000"));
+            (gas_price, None)
+        };
+
+        let total_cost = gas_price
+            .checked_mul(&Wei::new(gas_limit.to_string()))
+            .unwrap_or_else(|_| Wei::new("0"));
+
+        Ok(HyperEVMGasEstimate {
+            gas_limit: gas_limit.to_string(),
+            gas_price,
+            total_cost,
+            confidence: 0.8,
+            factors,
+            predicted_base_fee,
+        })
+    }
+
+    /// Auto-generate an EIP-2930 access list by running a trial simulation and
+    /// collecting every account and storage slot the execution touches — the
+    /// equivalent of `eth_createAccessList`.
+    pub async fn create_access_list(&self, transaction: TransactionRequest) -> Result<Vec<AccessListEntry>> {
+        let from = transaction.from.clone();
+        let to = transaction.to.clone();
+
+        let trial = self.simulate(transaction).await?;
+
+        let access_list = accessed_accounts_and_slots(&from, to.as_ref(), trial.trace.as_ref());
+
+        let encoded_size = serde_json::to_vec(&access_list)
+            .map_err(|e| HyperSimError::serialization(format!("Failed to encode access list: {}", e)))?
+            .len();
+
+        if encoded_size > crate::utils::constants::limits::MAX_TRANSACTION_SIZE {
+            return Err(HyperSimError::validation(
+                "Generated access list exceeds MAX_TRANSACTION_SIZE"
+            ));
+        }
+
+        Ok(access_list)
+    }
+
+    /// Estimate gas with an auto-generated access list attached, and compare
+    /// it against the same transaction estimated without one, so callers can
+    /// see whether attaching the list is actually worth the up-front cost.
+    pub async fn estimate_gas_with_access_list(
+        &self,
+        transaction: TransactionRequest,
+    ) -> Result<AccessListGasComparison> {
+        let mut without_list = transaction.clone();
+        without_list.access_list = None;
+        let without_access_list = self.estimate_gas(&without_list).await?;
+
+        let access_list = self.create_access_list(transaction.clone()).await?;
+        let mut with_list = transaction;
+        with_list.access_list = Some(access_list);
+        let with_access_list = self.estimate_gas(&with_list).await?;
+
+        let with_cost: i128 = with_access_list.total_cost.as_str().parse().unwrap_or(0);
+        let without_cost: i128 = without_access_list.total_cost.as_str().parse().unwrap_or(0);
+
+        Ok(AccessListGasComparison {
+            with_access_list,
+            without_access_list,
+            total_cost_delta: with_cost - without_cost,
+        })
+    }
+
     /// Get network status
     pub async fn get_network_status(&self) -> Result<NetworkStatus> {
         let response = self.send_rpc_request("eth_getNetworkStatus", serde_json::Value::Null).await?;
@@ -129,6 +567,113 @@ impl HyperEVMClient {
         self.parse_block_response(response)
     }
 
+    /// Verify the state of `addresses` trustlessly against a Merkle proof of
+    /// the latest block's state root, advancing the tracked header checkpoint
+    /// via a verified parent-hash link the same way a light client does.
+    async fn verify_trustlessly(&self, addresses: &[Address]) -> VerificationStatus {
+        if addresses.is_empty() {
+            return VerificationStatus::Unverified;
+        }
+
+        let block = match self.get_latest_block().await {
+            Ok(block) => block,
+            Err(error) => {
+                debug!("Trustless verification skipped: failed to fetch latest block: {}", error);
+                return VerificationStatus::Unverified;
+            }
+        };
+
+        {
+            let mut tracker = self.header_tracker.write().await;
+            if tracker.verify_and_advance(block.clone()).is_err() {
+                return VerificationStatus::ProofFailed;
+            }
+        }
+
+        let state_root = block.state_root.to_string();
+
+        for address in addresses {
+            let proof = match self.fetch_account_proof(address, block.number).await {
+                Ok(proof) => proof,
+                Err(error) => {
+                    debug!("Trustless verification skipped: failed to fetch proof for {}: {}", address, error);
+                    return VerificationStatus::Unverified;
+                }
+            };
+
+            if !verify_account_proof(&state_root, &proof) {
+                return VerificationStatus::ProofFailed;
+            }
+        }
+
+        VerificationStatus::Verified
+    }
+
+    async fn fetch_account_proof(&self, address: &Address, block_number: u64) -> Result<AccountProof> {
+        self.fetch_account_proof_with_slots(address, block_number, &[]).await
+    }
+
+    async fn fetch_account_proof_with_slots(
+        &self,
+        address: &Address,
+        block_number: u64,
+        storage_keys: &[String],
+    ) -> Result<AccountProof> {
+        let response = self.send_rpc_request(
+            "eth_getProof",
+            serde_json::json!([address.to_string(), storage_keys, format!("0x{:x}", block_number)]),
+        ).await?;
+
+        let result = response.get("result")
+            .ok_or_else(|| HyperSimError::simulation("No result in eth_getProof response"))?;
+
+        serde_json::from_value(result.clone())
+            .map_err(|e| HyperSimError::serialization(format!("Invalid eth_getProof response: {}", e)))
+    }
+
+    /// Cross-check every address (and, for storage changes, slot) touched by
+    /// `result.state_changes` against the latest block's `state_root` via
+    /// `eth_getProof`, hard-failing with [`HyperSimError::verification`] the
+    /// first time a proof doesn't reconstruct the root. The strict,
+    /// error-propagating counterpart to the soft [`VerificationStatus`]
+    /// [`simulate`](Self::simulate) records automatically when
+    /// [`HyperEVMConfig::trustless`] is enabled.
+    ///
+    /// This verifies the *input* state the simulation ran against, not the
+    /// speculative `state_changes` themselves — a simulated result hasn't
+    /// been included in any block, so there's no on-chain proof of it to
+    /// check against. What this catches is a malicious or buggy endpoint
+    /// serving a forged parent state for the simulation to build on.
+    pub async fn verify_state_changes(&self, result: &SimulationResult) -> Result<()> {
+        if result.state_changes.is_empty() {
+            return Ok(());
+        }
+
+        let block = self.get_latest_block().await?;
+        let state_root = block.state_root.to_string();
+
+        let mut touched: HashMap<Address, Vec<String>> = HashMap::new();
+        for change in &result.state_changes {
+            let slots = touched.entry(change.address.clone()).or_default();
+            if matches!(change.change_type, crate::types::StateChangeType::StorageChange) {
+                for map in [&change.before, &change.after].into_iter().flatten() {
+                    for key in map.keys() {
+                        if !slots.contains(key) {
+                            slots.push(key.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        for (address, slots) in &touched {
+            let proof = self.fetch_account_proof_with_slots(address, block.number, slots).await?;
+            crate::verification::verify_account_proof_or_err(&state_root, &proof)?;
+        }
+
+        Ok(())
+    }
+
     /// Health check
     pub async fn health_check(&self) -> Result<()> {
         let response = self.send_rpc_request("eth_blockNumber", serde_json::Value::Null).await?;
@@ -142,6 +687,12 @@ impl HyperEVMClient {
 
     // Private implementation methods
 
+    /// Next monotonically increasing id for a batched JSON-RPC request, used
+    /// to correlate out-of-order replies in [`simulate_batch`](Self::simulate_batch)
+    fn next_request_id(&self) -> u64 {
+        self.next_batch_id.fetch_add(1, Ordering::Relaxed)
+    }
+
     async fn send_rpc_request(&self, method: &str, params: serde_json::Value) -> Result<serde_json::Value> {
         let request_body = serde_json::json!({
             "jsonrpc": "2.0",
@@ -150,8 +701,217 @@ impl HyperEVMClient {
             "id": 1
         });
 
+        // Dispatch across the endpoint pool: a flaky or rate-limited provider
+        // fails over to the next endpoint rather than failing the query outright.
+        // The whole dispatch (every endpoint in the pool) is retried with
+        // backoff, since a transient failure can affect all of them at once
+        // (e.g. a shared upstream blip) and may clear up by the next attempt.
+        let outcome = self
+            .retry_dispatch(|| {
+                self.dispatcher
+                    .dispatch(|endpoint| self.request_endpoint_with_breaker(endpoint, request_body.clone()))
+            })
+            .await;
+
+        if outcome.is_err() {
+            let mut metrics = self.metrics.write().await;
+            metrics.errors += 1;
+        }
+
+        Ok(outcome?.value)
+    }
+
+    /// Post a batch of JSON-RPC request objects as a single array, failing
+    /// over across the endpoint pool the same way [`send_rpc_request`](Self::send_rpc_request) does.
+    async fn send_rpc_batch(&self, requests: Vec<serde_json::Value>) -> Result<Vec<serde_json::Value>> {
+        let batch_body = serde_json::Value::Array(requests);
+
+        let outcome = self
+            .retry_dispatch(|| {
+                self.dispatcher
+                    .dispatch(|endpoint| self.request_endpoint_batch_with_breaker(endpoint, batch_body.clone()))
+            })
+            .await;
+
+        if outcome.is_err() {
+            let mut metrics = self.metrics.write().await;
+            metrics.errors += 1;
+        }
+
+        match outcome?.value.get("result") {
+            Some(serde_json::Value::Array(responses)) => Ok(responses.clone()),
+            other => Err(HyperSimError::serialization(format!(
+                "Expected a JSON-RPC batch array, got: {:?}", other
+            ))),
+        }
+    }
+
+    /// Retry a dispatch future with exponential backoff per
+    /// [`HyperEVMConfig::resilience`], counting every retried attempt into
+    /// [`ClientMetrics::retries`]. Only [`HyperSimError::is_retryable`] failures
+    /// (network/timeout/rate-limit/connection-pool) are retried; anything else
+    /// (e.g. a malformed RPC response) is returned immediately.
+    async fn retry_dispatch<F, Fut>(&self, op: F) -> Result<crate::clients::dispatcher::DispatchOutcome>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<crate::clients::dispatcher::DispatchOutcome>>,
+    {
+        let resilience = &self.config.resilience;
+        let policy = RetryPolicy::new(self.config.max_retries + 1)
+            .base_delay(std::time::Duration::from_millis(resilience.initial_backoff_ms))
+            .max_delay(std::time::Duration::from_millis(resilience.max_backoff_ms))
+            .multiplier(resilience.backoff_multiplier);
+
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        let result = retry_with_policy(policy, || {
+            attempts.fetch_add(1, Ordering::Relaxed);
+            op()
+        })
+        .await;
+
+        let retries = attempts.load(Ordering::Relaxed).saturating_sub(1);
+        if retries > 0 {
+            let mut metrics = self.metrics.write().await;
+            metrics.retries += retries as u64;
+        }
+
+        result
+    }
+
+    /// Whether `endpoint`'s circuit breaker currently allows a request through.
+    /// A breaker opens after [`ResilienceConfig::circuit_breaker_threshold`]
+    /// consecutive failures and lets requests through again once
+    /// [`ResilienceConfig::circuit_breaker_cooldown_ms`] has elapsed since it
+    /// opened; the failure count is kept until a success, so a failure after
+    /// the cooldown re-opens it for another cooldown.
+    async fn circuit_allows(&self, endpoint: &str) -> bool {
+        let state = self.circuit_state.read().await;
+        match state.get(endpoint).and_then(|s| s.opened_at) {
+            Some(opened_at) => {
+                let cooldown = std::time::Duration::from_millis(
+                    self.config.resilience.circuit_breaker_cooldown_ms,
+                );
+                opened_at.elapsed() >= cooldown
+            }
+            None => true,
+        }
+    }
+
+    /// Record the outcome of a request to `endpoint` against its circuit
+    /// breaker, tripping or resetting it as needed.
+    async fn record_circuit_outcome(&self, endpoint: &str, success: bool) {
+        let mut state = self.circuit_state.write().await;
+        let entry = state.entry(endpoint.to_string()).or_default();
+
+        if success {
+            *entry = EndpointCircuitState::default();
+            return;
+        }
+
+        entry.consecutive_failures += 1;
+        if entry.consecutive_failures >= self.config.resilience.circuit_breaker_threshold {
+            entry.opened_at = Some(std::time::Instant::now());
+            drop(state);
+            let mut metrics = self.metrics.write().await;
+            metrics.circuit_trips += 1;
+        }
+    }
+
+    /// [`request_endpoint`](Self::request_endpoint) guarded by `endpoint`'s
+    /// circuit breaker: short-circuits with a network error while the
+    /// breaker is open instead of issuing the request, so a consistently
+    /// failing endpoint stops being retried until its cooldown elapses.
+    async fn request_endpoint_with_breaker(&self, endpoint: &str, request_body: serde_json::Value) -> Result<serde_json::Value> {
+        if !self.circuit_allows(endpoint).await {
+            return Err(HyperSimError::network(format!("circuit open for endpoint {}", endpoint)));
+        }
+
+        let result = self.request_endpoint(endpoint, request_body).await;
+        self.record_circuit_outcome(endpoint, result.is_ok()).await;
+        result
+    }
+
+    /// [`request_endpoint_batch`](Self::request_endpoint_batch) guarded by
+    /// `endpoint`'s circuit breaker; see
+    /// [`request_endpoint_with_breaker`](Self::request_endpoint_with_breaker).
+    async fn request_endpoint_batch_with_breaker(&self, endpoint: &str, batch_body: serde_json::Value) -> Result<serde_json::Value> {
+        if !self.circuit_allows(endpoint).await {
+            return Err(HyperSimError::network(format!("circuit open for endpoint {}", endpoint)));
+        }
+
+        let result = self.request_endpoint_batch(endpoint, batch_body).await;
+        self.record_circuit_outcome(endpoint, result.is_ok()).await;
+        result
+    }
+
+    /// Send a batch of JSON-RPC requests directly to `endpoint`, with no
+    /// failover — mirrors [`request_endpoint`](Self::request_endpoint), but
+    /// expects a raw JSON array reply and wraps it as `{"result": [...]}` so
+    /// [`EndpointDispatcher`](crate::clients::EndpointDispatcher)'s
+    /// empty-reply detection (which looks for a top-level `result`) doesn't
+    /// mistake a populated batch reply for an empty one.
+    async fn request_endpoint_batch(
+        &self,
+        endpoint: &str,
+        batch_body: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let response_json = if crate::clients::EndpointAddress::is_unix_scheme(endpoint) {
+            let connection = crate::clients::connection_for(endpoint, self.http_client.clone());
+            connection.send_rpc(batch_body).await?
+        } else {
+            let response = self.http_client
+                .post(endpoint)
+                .json(&batch_body)
+                .send()
+                .await
+                .map_err(|e| HyperSimError::network(format!("HTTP request failed: {}", e)))?;
+
+            if !response.status().is_success() {
+                return Err(HyperSimError::network(format!(
+                    "HTTP error: {}", response.status()
+                )));
+            }
+
+            if let Some(security) = self.security.as_deref() {
+                verify_response_certificate(security, &response)?;
+            }
+
+            response
+                .json()
+                .await
+                .map_err(|e| HyperSimError::serialization(format!("Invalid JSON response: {}", e)))?
+        };
+
+        if !response_json.is_array() {
+            return Err(HyperSimError::serialization(
+                "Expected a JSON-RPC batch array response"
+            ));
+        }
+
+        Ok(serde_json::json!({ "result": response_json }))
+    }
+
+    /// Send a single JSON-RPC request directly to `endpoint`, with no
+    /// failover — the caller (e.g. [`send_rpc_request`](Self::send_rpc_request)
+    /// via [`EndpointDispatcher::dispatch`], or
+    /// [`endpoint_block_heights`](Self::endpoint_block_heights) via
+    /// [`EndpointDispatcher::query_all`]) decides how to route across the pool.
+    async fn request_endpoint(&self, endpoint: &str, request_body: serde_json::Value) -> Result<serde_json::Value> {
+        // A local node reachable over a Unix domain socket skips the TCP
+        // stack (and certificate pinning, which doesn't apply to IPC) entirely.
+        if crate::clients::EndpointAddress::is_unix_scheme(endpoint) {
+            let connection = crate::clients::connection_for(endpoint, self.http_client.clone());
+            let response_json = connection.send_rpc(request_body).await?;
+
+            if let Some(error) = response_json.get("error") {
+                return Err(HyperSimError::simulation(format!("RPC error: {}", error)));
+            }
+
+            return Ok(response_json);
+        }
+
         let response = self.http_client
-            .post(self.config.rpc_endpoint())
+            .post(endpoint)
             .json(&request_body)
             .send()
             .await
@@ -163,6 +923,10 @@ impl HyperEVMClient {
             )));
         }
 
+        if let Some(security) = self.security.as_deref() {
+            verify_response_certificate(security, &response)?;
+        }
+
         let response_json: serde_json::Value = response
             .json()
             .await
@@ -195,14 +959,33 @@ impl HyperEVMClient {
         }
         
         if let Some(gas_limit) = transaction.gas_limit {
-            tx_data.insert("gas".to_string(), serde_json::Value::String(format!("0x{:x}", 
-                gas_limit.parse::<u64>().unwrap_or(21000))));
+            let hex_gas_limit = crate::types::U256::parse(&gas_limit)
+                .map(|value| value.to_hex_string())
+                .unwrap_or_else(|_| "0x5208".to_string()); // 21000
+            tx_data.insert("gas".to_string(), serde_json::Value::String(hex_gas_limit));
         }
         
         if let Some(gas_price) = transaction.gas_price {
             tx_data.insert("gasPrice".to_string(), serde_json::Value::String(gas_price.to_string()));
         }
-        
+
+        if let Some(tx_type) = transaction.tx_type {
+            tx_data.insert("type".to_string(), serde_json::Value::String(format!("0x{:x}", tx_type)));
+        }
+
+        if let Some(access_list) = transaction.access_list {
+            let encoded: Vec<serde_json::Value> = access_list
+                .into_iter()
+                .map(|entry| {
+                    serde_json::json!({
+                        "address": entry.address.to_string(),
+                        "storageKeys": entry.storage_keys.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+                    })
+                })
+                .collect();
+            tx_data.insert("accessList".to_string(), serde_json::Value::Array(encoded));
+        }
+
         Ok(serde_json::Value::Object(tx_data))
     }
 
@@ -244,6 +1027,17 @@ impl HyperEVMClient {
             state_changes: Vec::new(),
             events: Vec::new(),
             tx_hash: None,
+            verification: crate::verification::VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
         })
     }
 
@@ -269,6 +1063,7 @@ impl HyperEVMClient {
             logs_bloom: "0x0".repeat(512),
             transaction_hashes: Vec::new(),
             uncles: Vec::new(),
+            base_fee_per_gas: Some(Wei::new("18000000000")),
         })
     }
 
@@ -326,6 +1121,294 @@ impl HyperEVMClient {
     }
 }
 
+/// Collect every address and storage slot touched by a trial simulation
+/// (the transaction's own `from`/`to`, plus every call target and storage
+/// access recorded in its execution trace) into EIP-2930 access list entries.
+fn accessed_accounts_and_slots(
+    from: &Address,
+    to: Option<&Address>,
+    trace: Option<&ExecutionTrace>,
+) -> Vec<AccessListEntry> {
+    let mut touched: HashMap<Address, Vec<Hash>> = HashMap::new();
+    touched.entry(from.clone()).or_default();
+    if let Some(to) = to {
+        touched.entry(to.clone()).or_default();
+    }
+
+    if let Some(trace) = trace {
+        for call in &trace.calls {
+            touched.entry(call.from.clone()).or_default();
+            touched.entry(call.to.clone()).or_default();
+        }
+
+        for access in &trace.storage_accesses {
+            let slots = touched.entry(access.address.clone()).or_default();
+            if let Ok(slot) = Hash::new(access.slot.clone()) {
+                if !slots.contains(&slot) {
+                    slots.push(slot);
+                }
+            }
+        }
+    }
+
+    touched
+        .into_iter()
+        .map(|(address, storage_keys)| AccessListEntry { address, storage_keys })
+        .collect()
+}
+
+/// Running record of which addresses and storage slots have already been
+/// paid for during a simulated execution (EIP-2929). Pre-warmed from the
+/// transaction's own `access_list`, `from`/`to`, and the precompiles
+/// (0x01-0x09), all of which are warm from the very first instruction.
+struct AccessJournal {
+    addresses: std::collections::HashSet<Address>,
+    storage: std::collections::HashSet<(Address, String)>,
+}
+
+impl AccessJournal {
+    fn seeded(transaction: &TransactionRequest) -> Self {
+        let mut addresses = std::collections::HashSet::new();
+        addresses.insert(transaction.from.clone());
+        if let Some(ref to) = transaction.to {
+            addresses.insert(to.clone());
+        }
+        for byte in 1u8..=9 {
+            addresses.insert(Address(format!("0x{:040x}", byte)));
+        }
+
+        let mut storage = std::collections::HashSet::new();
+        if let Some(ref access_list) = transaction.access_list {
+            for entry in access_list {
+                addresses.insert(entry.address.clone());
+                for key in &entry.storage_keys {
+                    storage.insert((entry.address.clone(), key.as_str().to_string()));
+                }
+            }
+        }
+
+        Self { addresses, storage }
+    }
+
+    /// Touch `address`, returning `(was_cold, gas_cost)`.
+    fn touch_address(&mut self, address: &Address) -> (bool, u64) {
+        if self.addresses.insert(address.clone()) {
+            (true, gas_constants::COLD_ACCOUNT_ACCESS_COST)
+        } else {
+            (false, gas_constants::WARM_ACCESS_COST)
+        }
+    }
+
+    /// Touch `(address, slot)`, returning `(was_cold, gas_cost)`. SLOAD and
+    /// SSTORE share the same cold surcharge on first access.
+    fn touch_storage(&mut self, address: &Address, slot: &str) -> (bool, u64) {
+        if self.storage.insert((address.clone(), slot.to_string())) {
+            (true, gas_constants::COLD_SLOAD_COST)
+        } else {
+            (false, gas_constants::WARM_STORAGE_READ_COST)
+        }
+    }
+}
+
+/// Walk one call frame: touch its target address, claim the storage accesses
+/// that happened against it (consecutive entries in `accesses` addressed to
+/// `call.to`, starting at `*cursor`), then recurse into sub-calls. If the
+/// frame reverted, every journal entry added during its execution —
+/// including its sub-calls' — is rolled back afterward, so a later frame
+/// retouching the same slot or sub-call target pays the cold price again;
+/// the frame's own target stays warm.
+fn touch_call_frame(
+    call: &TraceCall,
+    accesses: &mut [StorageAccess],
+    cursor: &mut usize,
+    journal: &mut AccessJournal,
+    cold_total: &mut u64,
+    warm_total: &mut u64,
+) {
+    let reverted = call.error.is_some();
+
+    // The CALL opcode warms `call.to` as part of the *caller's* gas
+    // accounting, before the sub-call's own state snapshot exists, so a
+    // revert inside the sub-call must not un-warm its target address — only
+    // accesses made *during* the reverted execution roll back.
+    let (cold, cost) = journal.touch_address(&call.to);
+    if cold { *cold_total += cost } else { *warm_total += cost }
+
+    let snapshot = reverted.then(|| (journal.addresses.clone(), journal.storage.clone()));
+
+    while *cursor < accesses.len() && accesses[*cursor].address == call.to {
+        let access = &mut accesses[*cursor];
+        let (cold, cost) = journal.touch_storage(&access.address, &access.slot);
+        if cold { *cold_total += cost } else { *warm_total += cost }
+        access.cold = cold;
+        access.gas_cost = cost.to_string();
+        *cursor += 1;
+    }
+
+    for sub_call in &call.calls {
+        touch_call_frame(sub_call, accesses, cursor, journal, cold_total, warm_total);
+    }
+
+    if let Some((addresses, storage)) = snapshot {
+        journal.addresses = addresses;
+        journal.storage = storage;
+    }
+}
+
+/// Annotate `trace`'s storage accesses with EIP-2929 warm/cold status and
+/// per-access gas cost, and fold the totals into `gas_breakdown.cold_access`
+/// / `gas_breakdown.warm_access`.
+fn apply_access_journal(trace: &mut ExecutionTrace, transaction: &TransactionRequest) {
+    let mut journal = AccessJournal::seeded(transaction);
+    let mut cold_total: u64 = 0;
+    let mut warm_total: u64 = 0;
+    let mut cursor = 0usize;
+
+    let calls = std::mem::take(&mut trace.calls);
+    for call in &calls {
+        touch_call_frame(call, &mut trace.storage_accesses, &mut cursor, &mut journal, &mut cold_total, &mut warm_total);
+    }
+    trace.calls = calls;
+
+    // Any accesses the call tree didn't claim (e.g. accesses against the
+    // transaction's own `to` with no matching call frame) are still billed.
+    for access in &mut trace.storage_accesses[cursor..] {
+        let (cold, cost) = journal.touch_storage(&access.address, &access.slot);
+        if cold { cold_total += cost } else { warm_total += cost }
+        access.cold = cold;
+        access.gas_cost = cost.to_string();
+    }
+
+    trace.gas_breakdown.cold_access = cold_total.to_string();
+    trace.gas_breakdown.warm_access = warm_total.to_string();
+}
+
+/// Build the `"trace"` request parameters `debug_traceCall`-style tracers
+/// expect: which of stack/memory/storage to capture per opcode step, and
+/// whether to skip flat steps entirely in favor of just the call tree.
+fn trace_options(config: &TraceConfig) -> serde_json::Value {
+    serde_json::json!({
+        "enableStack": config.capture_stack,
+        "enableMemory": config.capture_memory,
+        "enableStorage": config.capture_storage,
+        "callTreeOnly": config.call_tree_only,
+    })
+}
+
+/// Parse the `"trace"` object out of a `hyperevm_simulate` response into an
+/// [`ExecutionTrace`], honoring `config.call_tree_only` by skipping the
+/// (possibly large) flat opcode steps entirely when only the call tree was requested.
+fn parse_execution_trace(response: &serde_json::Value, config: &TraceConfig) -> Option<ExecutionTrace> {
+    let trace = response.get("result")?.get("trace")?;
+
+    let calls = trace
+        .get("calls")
+        .and_then(|v| v.as_array())
+        .map(|calls| calls.iter().filter_map(parse_trace_call).collect())
+        .unwrap_or_default();
+
+    let opcode_steps = if config.call_tree_only {
+        Vec::new()
+    } else {
+        trace
+            .get("steps")
+            .and_then(|v| v.as_array())
+            .map(|steps| steps.iter().filter_map(parse_opcode_step).collect())
+            .unwrap_or_default()
+    };
+
+    let storage_accesses = trace
+        .get("storageAccesses")
+        .and_then(|v| v.as_array())
+        .map(|accesses| accesses.iter().filter_map(parse_storage_access).collect())
+        .unwrap_or_default();
+
+    let gas_breakdown = trace
+        .get("gasBreakdown")
+        .map(parse_gas_breakdown)
+        .unwrap_or_else(zero_gas_breakdown);
+
+    Some(ExecutionTrace { calls, gas_breakdown, storage_accesses, opcode_steps })
+}
+
+fn parse_trace_call(value: &serde_json::Value) -> Option<TraceCall> {
+    Some(TraceCall {
+        call_type: value.get("type").and_then(|v| v.as_str()).unwrap_or("CALL").to_string(),
+        from: Address::new(value.get("from")?.as_str()?).ok()?,
+        to: Address::new(value.get("to")?.as_str()?).ok()?,
+        value: Wei::new(value.get("value").and_then(|v| v.as_str()).unwrap_or("0")),
+        input: value.get("input").and_then(|v| v.as_str()).unwrap_or("0x").to_string(),
+        output: value.get("output").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        gas_used: value.get("gasUsed").and_then(|v| v.as_str()).unwrap_or("0").to_string(),
+        error: value.get("error").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        calls: value
+            .get("calls")
+            .and_then(|v| v.as_array())
+            .map(|calls| calls.iter().filter_map(parse_trace_call).collect())
+            .unwrap_or_default(),
+    })
+}
+
+fn parse_opcode_step(value: &serde_json::Value) -> Option<OpcodeStep> {
+    Some(OpcodeStep {
+        pc: value.get("pc")?.as_u64()?,
+        op: value.get("op")?.as_str()?.to_string(),
+        gas: value.get("gas").and_then(|v| v.as_str()).unwrap_or("0").to_string(),
+        gas_cost: value.get("gasCost").and_then(|v| v.as_str()).unwrap_or("0").to_string(),
+        depth: value.get("depth").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+        stack: value.get("stack").and_then(|v| v.as_array()).map(|stack| {
+            stack.iter().filter_map(|s| s.as_str().map(|s| s.to_string())).collect()
+        }),
+        memory: value.get("memory").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        storage: value.get("storage").and_then(|v| v.as_object()).map(|storage| {
+            storage
+                .iter()
+                .filter_map(|(slot, val)| val.as_str().map(|val| (slot.clone(), val.to_string())))
+                .collect()
+        }),
+    })
+}
+
+fn parse_storage_access(value: &serde_json::Value) -> Option<StorageAccess> {
+    let access_type = match value.get("accessType").and_then(|v| v.as_str()) {
+        Some("write") => StorageAccessType::Write,
+        _ => StorageAccessType::Read,
+    };
+
+    Some(StorageAccess {
+        address: Address::new(value.get("address")?.as_str()?).ok()?,
+        slot: value.get("slot")?.as_str()?.to_string(),
+        access_type,
+        original_value: value.get("originalValue").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        new_value: value.get("newValue").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        cold: value.get("cold").and_then(|v| v.as_bool()).unwrap_or(false),
+        gas_cost: value.get("gasCost").and_then(|v| v.as_str()).unwrap_or("0").to_string(),
+    })
+}
+
+fn parse_gas_breakdown(value: &serde_json::Value) -> GasBreakdown {
+    let field = |key: &str| value.get(key).and_then(|v| v.as_str()).unwrap_or("0").to_string();
+    GasBreakdown {
+        intrinsic: field("intrinsic"),
+        execution: field("execution"),
+        cold_access: field("coldAccess"),
+        warm_access: field("warmAccess"),
+        refund: field("refund"),
+        total: field("total"),
+    }
+}
+
+fn zero_gas_breakdown() -> GasBreakdown {
+    GasBreakdown {
+        intrinsic: "0".to_string(),
+        execution: "0".to_string(),
+        cold_access: "0".to_string(),
+        warm_access: "0".to_string(),
+        refund: "0".to_string(),
+        total: "0".to_string(),
+    }
+}
+
 impl std::fmt::Debug for HyperEVMClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("HyperEVMClient")
@@ -348,6 +1431,67 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_client_dispatches_across_endpoint_pool() {
+        let mut config = HyperEVMConfig::new(Network::Local);
+        config.rpc_endpoints = vec![
+            "https://one.example.com".to_string(),
+            "https://two.example.com".to_string(),
+        ];
+
+        let client = HyperEVMClient::new(config).await.unwrap();
+        assert_eq!(client.dispatcher.endpoints().len(), 2);
+        assert!(client.endpoint_metrics().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_verify_trustlessly_skips_with_no_touched_addresses() {
+        let config = HyperEVMConfig::new(Network::Local);
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        let status = client.verify_trustlessly(&[]).await;
+        assert_eq!(status, VerificationStatus::Unverified);
+    }
+
+    #[tokio::test]
+    async fn test_verify_state_changes_skips_with_no_state_changes() {
+        let config = HyperEVMConfig::new(Network::Local);
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        let mut result = minimal_simulation_result();
+        result.state_changes = Vec::new();
+
+        assert!(client.verify_state_changes(&result).await.is_ok());
+    }
+
+    fn minimal_simulation_result() -> SimulationResult {
+        SimulationResult {
+            success: true,
+            gas_used: "21000".to_string(),
+            return_data: None,
+            error: None,
+            revert_reason: None,
+            block_type: BlockType::Fast,
+            estimated_block: 1,
+            trace: None,
+            hypercore_data: None,
+            state_changes: Vec::new(),
+            events: Vec::new(),
+            tx_hash: None,
+            verification: VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
+        }
+    }
+
     #[test]
     fn test_cache_key_generation() {
         let config = HyperEVMConfig::new(Network::Local);
@@ -361,4 +1505,478 @@ mod tests {
         assert!(key.starts_with("tx_"));
         assert!(key.len() > 10);
     }
+
+    #[tokio::test]
+    async fn test_estimate_gas_access_list_discount() {
+        let config = HyperEVMConfig::new(Network::Local);
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        let base_tx = TransactionRequest::builder()
+            .from("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap()
+            .to("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap()
+            .build().unwrap();
+
+        let with_access_list = TransactionRequest::builder()
+            .from("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap()
+            .to("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap()
+            .tx_type(1)
+            .access_list(vec![crate::types::AccessListEntry {
+                address: Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap(),
+                storage_keys: vec![Hash::new(
+                    "0x0000000000000000000000000000000000000000000000000000000000000001"
+                ).unwrap()],
+            }])
+            .build().unwrap();
+
+        let base_estimate = client.estimate_gas(&base_tx).await.unwrap();
+        let access_list_estimate = client.estimate_gas(&with_access_list).await.unwrap();
+
+        assert!(access_list_estimate.factors.contains(&"eip2930_access_list_warm_discount".to_string()));
+        assert_ne!(base_estimate.gas_limit, access_list_estimate.gas_limit);
+    }
+
+    #[test]
+    fn test_accessed_accounts_and_slots_collects_trace_data() {
+        let from = Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap();
+        let to = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let other = Address::new("0x0000000000000000000000000000000000000001").unwrap();
+        let slot = Hash::new("0x0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+
+        let trace = ExecutionTrace {
+            calls: vec![crate::types::TraceCall {
+                call_type: "CALL".to_string(),
+                from: from.clone(),
+                to: other.clone(),
+                value: Wei::new("0"),
+                input: "0x".to_string(),
+                output: None,
+                gas_used: "0".to_string(),
+                error: None,
+                calls: Vec::new(),
+            }],
+            gas_breakdown: crate::types::GasBreakdown {
+                intrinsic: "0".to_string(),
+                execution: "0".to_string(),
+                cold_access: "0".to_string(),
+                warm_access: "0".to_string(),
+                refund: "0".to_string(),
+                total: "0".to_string(),
+            },
+            storage_accesses: vec![crate::types::StorageAccess {
+                address: to.clone(),
+                slot: slot.as_str().to_string(),
+                access_type: crate::types::StorageAccessType::Read,
+                original_value: None,
+                new_value: None,
+                cold: false,
+                gas_cost: "0".to_string(),
+            }],
+            opcode_steps: Vec::new(),
+        };
+
+        let access_list = accessed_accounts_and_slots(&from, Some(&to), Some(&trace));
+
+        let addresses: Vec<&Address> = access_list.iter().map(|e| &e.address).collect();
+        assert!(addresses.contains(&&from));
+        assert!(addresses.contains(&&to));
+        assert!(addresses.contains(&&other));
+
+        let to_entry = access_list.iter().find(|e| e.address == to).unwrap();
+        assert_eq!(to_entry.storage_keys, vec![slot]);
+    }
+
+    #[test]
+    fn test_accessed_accounts_and_slots_without_trace_keeps_from_and_to() {
+        let from = Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap();
+        let to = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+
+        let access_list = accessed_accounts_and_slots(&from, Some(&to), None);
+
+        assert_eq!(access_list.len(), 2);
+        assert!(access_list.iter().all(|e| e.storage_keys.is_empty()));
+    }
+
+    fn empty_gas_breakdown() -> crate::types::GasBreakdown {
+        crate::types::GasBreakdown {
+            intrinsic: "0".to_string(),
+            execution: "0".to_string(),
+            cold_access: "0".to_string(),
+            warm_access: "0".to_string(),
+            refund: "0".to_string(),
+            total: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_access_journal_charges_cold_then_warm() {
+        let from = Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap();
+        let to = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001".to_string();
+
+        let tx = TransactionRequest::builder()
+            .from(from.as_str()).unwrap()
+            .to(to.as_str()).unwrap()
+            .build().unwrap();
+
+        let mut trace = ExecutionTrace {
+            calls: Vec::new(),
+            gas_breakdown: empty_gas_breakdown(),
+            storage_accesses: vec![
+                crate::types::StorageAccess {
+                    address: to.clone(),
+                    slot: slot.clone(),
+                    access_type: crate::types::StorageAccessType::Read,
+                    original_value: None,
+                    new_value: None,
+                    cold: false,
+                    gas_cost: "0".to_string(),
+                },
+                crate::types::StorageAccess {
+                    address: to.clone(),
+                    slot: slot.clone(),
+                    access_type: crate::types::StorageAccessType::Read,
+                    original_value: None,
+                    new_value: None,
+                    cold: false,
+                    gas_cost: "0".to_string(),
+                },
+            ],
+            opcode_steps: Vec::new(),
+        };
+
+        apply_access_journal(&mut trace, &tx);
+
+        assert!(trace.storage_accesses[0].cold);
+        assert_eq!(trace.storage_accesses[0].gas_cost, gas_constants::COLD_SLOAD_COST.to_string());
+        assert!(!trace.storage_accesses[1].cold);
+        assert_eq!(trace.storage_accesses[1].gas_cost, gas_constants::WARM_STORAGE_READ_COST.to_string());
+        assert_eq!(trace.gas_breakdown.cold_access, gas_constants::COLD_SLOAD_COST.to_string());
+        assert_eq!(trace.gas_breakdown.warm_access, gas_constants::WARM_STORAGE_READ_COST.to_string());
+    }
+
+    #[test]
+    fn test_apply_access_journal_rolls_back_reverted_frame() {
+        let from = Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap();
+        let to = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        // Not a precompile: precompiles are warm from the start.
+        let other = Address::new("0x00000000000000000000000000000000000000aa").unwrap();
+
+        let tx = TransactionRequest::builder()
+            .from(from.as_str()).unwrap()
+            .to(to.as_str()).unwrap()
+            .build().unwrap();
+
+        let mut trace = ExecutionTrace {
+            calls: vec![
+                crate::types::TraceCall {
+                    call_type: "CALL".to_string(),
+                    from: to.clone(),
+                    to: other.clone(),
+                    value: Wei::new("0"),
+                    input: "0x".to_string(),
+                    output: None,
+                    gas_used: "0".to_string(),
+                    error: Some("reverted".to_string()),
+                    calls: Vec::new(),
+                },
+                crate::types::TraceCall {
+                    call_type: "CALL".to_string(),
+                    from: to.clone(),
+                    to: other.clone(),
+                    value: Wei::new("0"),
+                    input: "0x".to_string(),
+                    output: None,
+                    gas_used: "0".to_string(),
+                    error: None,
+                    calls: Vec::new(),
+                },
+            ],
+            gas_breakdown: empty_gas_breakdown(),
+            storage_accesses: Vec::new(),
+            opcode_steps: Vec::new(),
+        };
+
+        apply_access_journal(&mut trace, &tx);
+
+        // `other` is touched by both calls. The first call reverted, but the
+        // CALL opcode itself warms its target in the *caller's* accounting
+        // before the sub-call's snapshot exists, so that warming survives the
+        // rollback and the second call to `other` pays the warm price.
+        assert_eq!(
+            trace.gas_breakdown.cold_access,
+            gas_constants::COLD_ACCOUNT_ACCESS_COST.to_string()
+        );
+        assert_eq!(
+            trace.gas_breakdown.warm_access,
+            gas_constants::WARM_ACCESS_COST.to_string()
+        );
+    }
+
+    #[test]
+    fn test_apply_access_journal_rolls_back_storage_touched_during_revert() {
+        let from = Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap();
+        let to = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let other = Address::new("0x0000000000000000000000000000000000000001").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001".to_string();
+
+        let tx = TransactionRequest::builder()
+            .from(from.as_str()).unwrap()
+            .to(to.as_str()).unwrap()
+            .build().unwrap();
+
+        let mut trace = ExecutionTrace {
+            calls: vec![
+                crate::types::TraceCall {
+                    call_type: "CALL".to_string(),
+                    from: to.clone(),
+                    to: other.clone(),
+                    value: Wei::new("0"),
+                    input: "0x".to_string(),
+                    output: None,
+                    gas_used: "0".to_string(),
+                    error: Some("reverted".to_string()),
+                    calls: vec![crate::types::TraceCall {
+                        call_type: "CALL".to_string(),
+                        from: other.clone(),
+                        to: to.clone(),
+                        value: Wei::new("0"),
+                        input: "0x".to_string(),
+                        output: None,
+                        gas_used: "0".to_string(),
+                        error: None,
+                        calls: Vec::new(),
+                    }],
+                },
+                crate::types::TraceCall {
+                    call_type: "CALL".to_string(),
+                    from: to.clone(),
+                    to: other.clone(),
+                    value: Wei::new("0"),
+                    input: "0x".to_string(),
+                    output: None,
+                    gas_used: "0".to_string(),
+                    error: None,
+                    calls: Vec::new(),
+                },
+            ],
+            gas_breakdown: empty_gas_breakdown(),
+            storage_accesses: vec![
+                crate::types::StorageAccess {
+                    address: other.clone(),
+                    slot: slot.clone(),
+                    access_type: crate::types::StorageAccessType::Read,
+                    original_value: None,
+                    new_value: None,
+                    cold: false,
+                    gas_cost: "0".to_string(),
+                },
+                crate::types::StorageAccess {
+                    address: to.clone(),
+                    slot: slot.clone(),
+                    access_type: crate::types::StorageAccessType::Read,
+                    original_value: None,
+                    new_value: None,
+                    cold: false,
+                    gas_cost: "0".to_string(),
+                },
+                crate::types::StorageAccess {
+                    address: other.clone(),
+                    slot: slot.clone(),
+                    access_type: crate::types::StorageAccessType::Read,
+                    original_value: None,
+                    new_value: None,
+                    cold: false,
+                    gas_cost: "0".to_string(),
+                },
+            ],
+            opcode_steps: Vec::new(),
+        };
+
+        apply_access_journal(&mut trace, &tx);
+
+        // The slot is touched inside the first (reverted) call and again in
+        // the second call; the access between them belongs to the first
+        // call's sub-call, so each call claims only its own access. Unlike
+        // the call target itself, slots touched during the reverted execution
+        // are not warmed by caller accounting, so the rollback applies and
+        // the second touch still pays cold.
+        assert!(trace.storage_accesses[0].cold);
+        assert!(trace.storage_accesses[2].cold);
+    }
+
+    #[test]
+    fn test_trace_options_reflects_config() {
+        let config = TraceConfig {
+            capture_stack: false,
+            capture_memory: false,
+            capture_storage: true,
+            call_tree_only: true,
+        };
+
+        let options = trace_options(&config);
+        assert_eq!(options["enableStack"], false);
+        assert_eq!(options["enableMemory"], false);
+        assert_eq!(options["enableStorage"], true);
+        assert_eq!(options["callTreeOnly"], true);
+    }
+
+    #[test]
+    fn test_parse_execution_trace_flat_steps() {
+        let response = serde_json::json!({
+            "result": {
+                "trace": {
+                    "calls": [],
+                    "steps": [
+                        {
+                            "pc": 0,
+                            "op": "PUSH1",
+                            "gas": "100000",
+                            "gasCost": "3",
+                            "depth": 1,
+                            "stack": ["0x0"],
+                        },
+                        {
+                            "pc": 2,
+                            "op": "SLOAD",
+                            "gas": "99997",
+                            "gasCost": "2100",
+                            "depth": 1,
+                        },
+                    ],
+                    "storageAccesses": [],
+                    "gasBreakdown": { "intrinsic": "21000" },
+                }
+            }
+        });
+
+        let trace = parse_execution_trace(&response, &TraceConfig::default()).unwrap();
+        assert_eq!(trace.opcode_steps.len(), 2);
+        assert_eq!(trace.opcode_steps[0].op, "PUSH1");
+        assert_eq!(trace.opcode_steps[0].stack, Some(vec!["0x0".to_string()]));
+        assert_eq!(trace.opcode_steps[1].op, "SLOAD");
+        assert_eq!(trace.gas_breakdown.intrinsic, "21000");
+    }
+
+    #[test]
+    fn test_parse_execution_trace_call_tree_only_skips_steps() {
+        let response = serde_json::json!({
+            "result": {
+                "trace": {
+                    "calls": [{
+                        "type": "CALL",
+                        "from": "0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1",
+                        "to": "0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123",
+                        "gasUsed": "21000",
+                    }],
+                    "steps": [{ "pc": 0, "op": "PUSH1", "gas": "100000", "gasCost": "3", "depth": 1 }],
+                }
+            }
+        });
+
+        let config = TraceConfig { call_tree_only: true, ..TraceConfig::default() };
+        let trace = parse_execution_trace(&response, &config).unwrap();
+        assert!(trace.opcode_steps.is_empty());
+        assert_eq!(trace.calls.len(), 1);
+        assert_eq!(trace.calls[0].gas_used, "21000");
+    }
+
+    #[tokio::test]
+    async fn test_simulate_batch_empty_input_returns_empty() {
+        let config = HyperEVMConfig::new(Network::Local);
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        let results = client.simulate_batch(Vec::new()).await.unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_simulate_batch_serves_cache_hits_without_rpc() {
+        let config = HyperEVMConfig::new(Network::Local);
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        let tx = TransactionRequest::builder()
+            .from("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap()
+            .build().unwrap();
+
+        let cache_key = client.generate_cache_key(&tx);
+        let mut cached = minimal_simulation_result();
+        cached.gas_used = "9001".to_string();
+        client.cache_result(&cache_key, &cached).await;
+
+        let results = client.simulate_batch(vec![tx]).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap().gas_used, "9001");
+
+        let metrics = client.metrics.read().await;
+        assert_eq!(metrics.batch_cache_hits, 1);
+        assert_eq!(metrics.batch_cache_misses, 0);
+    }
+
+    #[test]
+    fn test_next_request_id_increments_monotonically() {
+        let config = HyperEVMConfig::new(Network::Local);
+        let client = tokio_test::block_on(HyperEVMClient::new(config)).unwrap();
+
+        let first = client.next_request_id();
+        let second = client.next_request_id();
+        assert!(second > first);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_allows_before_any_failures() {
+        let config = HyperEVMConfig::new(Network::Local);
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        assert!(client.circuit_allows("https://one.example.com").await);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_trips_after_threshold_failures() {
+        let mut config = HyperEVMConfig::new(Network::Local);
+        config.resilience.circuit_breaker_threshold = 2;
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        client.record_circuit_outcome("https://one.example.com", false).await;
+        assert!(client.circuit_allows("https://one.example.com").await);
+
+        client.record_circuit_outcome("https://one.example.com", false).await;
+        assert!(!client.circuit_allows("https://one.example.com").await);
+
+        let metrics = client.metrics.read().await;
+        assert_eq!(metrics.circuit_trips, 1);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_resets_on_success() {
+        let mut config = HyperEVMConfig::new(Network::Local);
+        config.resilience.circuit_breaker_threshold = 1;
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        client.record_circuit_outcome("https://one.example.com", false).await;
+        assert!(!client.circuit_allows("https://one.example.com").await);
+
+        client.record_circuit_outcome("https://one.example.com", true).await;
+        assert!(client.circuit_allows("https://one.example.com").await);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_reopens_when_post_cooldown_probe_fails() {
+        let mut config = HyperEVMConfig::new(Network::Local);
+        config.resilience.circuit_breaker_threshold = 1;
+        config.resilience.circuit_breaker_cooldown_ms = 50;
+        let client = HyperEVMClient::new(config).await.unwrap();
+
+        client.record_circuit_outcome("https://one.example.com", false).await;
+        tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+        assert!(client.circuit_allows("https://one.example.com").await);
+
+        client.record_circuit_outcome("https://one.example.com", false).await;
+        assert!(
+            !client.circuit_allows("https://one.example.com").await,
+            "a failing probe after cooldown must re-open the breaker instead of leaving it permanently closed"
+        );
+
+        let metrics = client.metrics.read().await;
+        assert_eq!(metrics.circuit_trips, 2);
+    }
 }
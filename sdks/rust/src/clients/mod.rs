@@ -1,9 +1,20 @@
 //! Network clients for HyperEVM, HyperCore, and WebSocket connections
 
+pub mod discovery;
+pub mod dispatcher;
+mod fee_history;
+pub mod gas_estimator;
 pub mod hyperevm;
 pub mod hypercore;
+pub(crate) mod hypercore_cache;
+pub mod transport;
 pub mod websocket;
+mod ws_backend;
 
+pub use discovery::{discover_fallback_endpoints, DiscoveredEndpoint, FallbackDiscoveryConfig};
+pub use dispatcher::{DispatchOutcome, EndpointDispatcher, EndpointMetrics};
+pub use gas_estimator::{GasEstimator, GasPriceTiers};
 pub use hyperevm::HyperEVMClient;
 pub use hypercore::HyperCoreClient;
-pub use websocket::WebSocketClient;
+pub use transport::{connection_for, Connection, EndpointAddress, TcpConnection, UnixConnection};
+pub use websocket::{FromWSEvent, Subscription, SubscriptionStream, WebSocketClient};
@@ -0,0 +1,184 @@
+//! Transport abstraction so RPC dispatch works transparently over TCP or a
+//! Unix domain socket, letting `Network::Local` development connect to a
+//! node over IPC instead of paying TCP overhead on the same machine.
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::error::{HyperSimError, Result};
+
+/// Where an endpoint URL resolves to dial
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EndpointAddress {
+    /// Standard HTTP(S)/WS(S) endpoint
+    Tcp(String),
+    /// Unix domain socket, parsed from an `ipc://` or `unix:` endpoint
+    Unix(std::path::PathBuf),
+}
+
+impl EndpointAddress {
+    /// Parse `endpoint`, recognizing the `ipc://` and `unix:` schemes
+    pub fn parse(endpoint: &str) -> Self {
+        if let Some(path) = endpoint.strip_prefix("ipc://") {
+            EndpointAddress::Unix(std::path::PathBuf::from(path))
+        } else if let Some(path) = endpoint.strip_prefix("unix:") {
+            EndpointAddress::Unix(std::path::PathBuf::from(path))
+        } else {
+            EndpointAddress::Tcp(endpoint.to_string())
+        }
+    }
+
+    /// Whether `endpoint` uses the `ipc://`/`unix:` scheme
+    pub fn is_unix_scheme(endpoint: &str) -> bool {
+        endpoint.starts_with("ipc://") || endpoint.starts_with("unix:")
+    }
+}
+
+/// A JSON-RPC transport that can dial either TCP or a Unix domain socket
+#[async_trait]
+pub trait Connection: Send + Sync {
+    /// Send a JSON-RPC request body and return the parsed JSON response
+    async fn send_rpc(&self, body: Value) -> Result<Value>;
+}
+
+/// Dials a standard HTTP(S) JSON-RPC endpoint
+pub struct TcpConnection {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl TcpConnection {
+    pub fn new(client: reqwest::Client, url: String) -> Self {
+        Self { client, url }
+    }
+}
+
+#[async_trait]
+impl Connection for TcpConnection {
+    async fn send_rpc(&self, body: Value) -> Result<Value> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| HyperSimError::network(format!("RPC request failed: {}", e)))?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| HyperSimError::network(format!("Failed to parse RPC response: {}", e)))
+    }
+}
+
+/// Dials a JSON-RPC endpoint exposed over a Unix domain socket, writing a
+/// minimal HTTP/1.1 request directly onto the stream
+pub struct UnixConnection {
+    socket_path: std::path::PathBuf,
+}
+
+impl UnixConnection {
+    pub fn new(socket_path: std::path::PathBuf) -> Self {
+        Self { socket_path }
+    }
+}
+
+#[async_trait]
+impl Connection for UnixConnection {
+    async fn send_rpc(&self, body: Value) -> Result<Value> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::UnixStream;
+
+        let mut stream = UnixStream::connect(&self.socket_path).await.map_err(|e| {
+            HyperSimError::network(format!(
+                "Failed to connect to Unix socket '{}': {}",
+                self.socket_path.display(),
+                e
+            ))
+        })?;
+
+        let payload = serde_json::to_vec(&body)
+            .map_err(|e| HyperSimError::serialization(format!("Failed to serialize RPC request: {}", e)))?;
+
+        let request = format!(
+            "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            payload.len()
+        );
+
+        stream
+            .write_all(request.as_bytes())
+            .await
+            .map_err(|e| HyperSimError::network(format!("Failed to write to Unix socket: {}", e)))?;
+        stream
+            .write_all(&payload)
+            .await
+            .map_err(|e| HyperSimError::network(format!("Failed to write to Unix socket: {}", e)))?;
+
+        let mut response = Vec::new();
+        stream
+            .read_to_end(&mut response)
+            .await
+            .map_err(|e| HyperSimError::network(format!("Failed to read from Unix socket: {}", e)))?;
+
+        let body_start = find_http_body_start(&response)
+            .ok_or_else(|| HyperSimError::network("Malformed HTTP response over Unix socket"))?;
+
+        serde_json::from_slice(&response[body_start..])
+            .map_err(|e| HyperSimError::network(format!("Failed to parse Unix socket RPC response: {}", e)))
+    }
+}
+
+fn find_http_body_start(response: &[u8]) -> Option<usize> {
+    response.windows(4).position(|w| w == b"\r\n\r\n").map(|i| i + 4)
+}
+
+/// Build the [`Connection`] appropriate for `endpoint`, dialing TCP or a Unix
+/// domain socket depending on its scheme
+pub fn connection_for(endpoint: &str, client: reqwest::Client) -> Box<dyn Connection> {
+    match EndpointAddress::parse(endpoint) {
+        EndpointAddress::Tcp(url) => Box::new(TcpConnection::new(client, url)),
+        EndpointAddress::Unix(path) => Box::new(UnixConnection::new(path)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recognizes_ipc_scheme() {
+        assert_eq!(
+            EndpointAddress::parse("ipc:///tmp/hyperevm.sock"),
+            EndpointAddress::Unix(std::path::PathBuf::from("/tmp/hyperevm.sock"))
+        );
+    }
+
+    #[test]
+    fn test_parse_recognizes_unix_scheme() {
+        assert_eq!(
+            EndpointAddress::parse("unix:/tmp/hyperevm.sock"),
+            EndpointAddress::Unix(std::path::PathBuf::from("/tmp/hyperevm.sock"))
+        );
+    }
+
+    #[test]
+    fn test_parse_falls_back_to_tcp() {
+        assert_eq!(
+            EndpointAddress::parse("http://localhost:8545"),
+            EndpointAddress::Tcp("http://localhost:8545".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_unix_scheme() {
+        assert!(EndpointAddress::is_unix_scheme("ipc:///tmp/a.sock"));
+        assert!(EndpointAddress::is_unix_scheme("unix:/tmp/a.sock"));
+        assert!(!EndpointAddress::is_unix_scheme("http://localhost:8545"));
+    }
+
+    #[test]
+    fn test_find_http_body_start() {
+        let response = b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}";
+        assert_eq!(find_http_body_start(response), Some(response.len() - 2));
+    }
+}
@@ -1,22 +1,88 @@
 //! WebSocket client implementation for real-time streaming
 
+use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
 use std::collections::HashMap;
-use tokio::sync::{RwLock, mpsc};
-use tracing::{debug, error, info, warn};
+use futures_util::stream::Stream;
+use tokio::sync::{watch, RwLock, mpsc, oneshot};
+use tracing::{debug, info, warn};
 
+use crate::clients::fee_history::{FeeHistoryTracker, DEFAULT_TREND_THRESHOLD, DEFAULT_WINDOW_BLOCKS};
+use crate::clients::gas_estimator::GasEstimator;
+use crate::clients::ws_backend::{self, Instruction, WsBackend};
+use crate::plugins::PluginSystem;
 use crate::types::{
-    WebSocketClientConfig, ConnectionState, WSSubscription, WSMessage,
-    SubscriptionType, SubscriptionParams, WSEvent, WSError,
+    WebSocketClientConfig, ConnectionState, WSSubscription, NewBlockHeader, NewTransaction,
+    LogNotification, SimulationNotification, GasPriceNotification, NetworkStatusNotification,
+    FeeHistoryNotification, SubscriptionType, SubscriptionParams, SubscriptionQuery, WSEvent,
+    SubscriptionQueueMetrics, HyperEVMBlock, Hash,
 };
 use crate::error::{HyperSimError, Result};
 
 /// High-performance WebSocket client for real-time data streaming
+#[derive(Clone)]
 pub struct WebSocketClient {
     config: WebSocketClientConfig,
     state: Arc<RwLock<ClientState>>,
     subscriptions: Arc<RwLock<HashMap<String, WSSubscription>>>,
+    /// Maps the stable local subscription handle returned to callers to the
+    /// subscription ID the server most recently assigned it
+    subscription_server_ids: Arc<RwLock<HashMap<String, String>>>,
+    /// Client-side [`SubscriptionQuery`] for subscriptions created via
+    /// [`WebSocketClient::subscribe_logs_query`], re-checked against every
+    /// notification so the query is enforced correctly even on a mock/test
+    /// transport that ignores the server-side filter argument
+    queries: Arc<RwLock<HashMap<String, SubscriptionQuery>>>,
+    /// Per-subscription event sink for subscriptions created via
+    /// [`WebSocketClient::subscribe_stream`], so a reconnect re-attaches
+    /// notifications to that [`Subscription`]'s own channel instead of the
+    /// shared `event_sender` fan-out. Bounded by
+    /// [`WebSocketClientConfig::queue_capacity_items`] so a slow consumer
+    /// can't grow the SDK's memory without bound; notifications that arrive
+    /// while full are dropped and counted in `stream_queue_drops`
+    stream_targets: Arc<RwLock<HashMap<String, mpsc::Sender<WSEvent>>>>,
+    /// Count of notifications dropped, per subscription, because
+    /// `stream_targets`'s queue was full when the notification arrived
+    stream_queue_drops: Arc<RwLock<HashMap<String, Arc<AtomicU64>>>>,
     event_sender: Option<mpsc::UnboundedSender<WSEvent>>,
+    /// Handle into the live [`WsBackend`] task, or `None` while disconnected
+    command_tx: Arc<RwLock<Option<mpsc::UnboundedSender<Instruction>>>>,
+    /// Fires once when the current [`WsBackend`] task exits, for any
+    /// reason. Taken by the connection supervisor each time it starts
+    /// watching a fresh connection generation
+    disconnect_signal: Arc<RwLock<Option<oneshot::Receiver<()>>>>,
+    /// Every JSON-RPC request still awaiting a response, independent of
+    /// connection generation: a request is only removed once it gets a
+    /// real answer (success or JSON-RPC error). A connection drop leaves
+    /// its entry in place so the connection supervisor can redispatch it
+    /// onto the next connection instead of failing the caller outright
+    pending_requests: Arc<RwLock<HashMap<u64, PendingRequest>>>,
+    /// Rolling-window gas price estimate fed by every decoded
+    /// `NewTransaction` notification, regardless of which subscription
+    /// produced it
+    gas_estimator: Arc<GasEstimator>,
+    /// Broadcasts every [`ConnectionState`] transition so callers (e.g. the
+    /// SDK's reconnection coordinator) can watch for disconnect/connect
+    /// edges without polling [`WebSocketClient::get_connection_state`]
+    connection_state_tx: watch::Sender<ConnectionState>,
+    /// Set once by [`HyperSimSDK`](crate::core::HyperSimSDK) after both the
+    /// client and the plugin system exist (the client is constructed first;
+    /// see [`WebSocketClient::set_plugin_system`]). `None` means no plugin
+    /// hooks fire, which is the case for a bare `WebSocketClient` used
+    /// outside the SDK or in tests.
+    plugin_system: Arc<RwLock<Option<Arc<PluginSystem>>>>,
+}
+
+/// A JSON-RPC request registered in [`WebSocketClient::pending_requests`]
+struct PendingRequest {
+    /// The full `{"jsonrpc", "id", "method", "params"}` envelope, kept
+    /// around so it can be resent verbatim after a reconnect
+    payload: serde_json::Value,
+    /// Resolved once, with the request's real outcome — never with a
+    /// connection-drop error; see [`WebSocketClient::dispatch_pending_request`]
+    resp: oneshot::Sender<Result<serde_json::Value>>,
 }
 
 #[derive(Debug)]
@@ -25,6 +91,10 @@ struct ClientState {
     last_ping: Option<std::time::Instant>,
     reconnect_attempts: u32,
     message_id_counter: u64,
+    /// Incremented on every `establish_connection`; tags the [`WsBackend`]
+    /// (and its [`SubscriptionRouter`](crate::clients::ws_backend::SubscriptionRouter))
+    /// spawned for that connection so routing is never confused across reconnects
+    connection_generation: u64,
 }
 
 impl WebSocketClient {
@@ -35,55 +105,112 @@ impl WebSocketClient {
             last_ping: None,
             reconnect_attempts: 0,
             message_id_counter: 0,
+            connection_generation: 0,
         }));
+        let (connection_state_tx, _) = watch::channel(ConnectionState::Disconnected);
 
         Ok(Self {
             config,
             state,
             subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            subscription_server_ids: Arc::new(RwLock::new(HashMap::new())),
+            queries: Arc::new(RwLock::new(HashMap::new())),
+            stream_targets: Arc::new(RwLock::new(HashMap::new())),
+            stream_queue_drops: Arc::new(RwLock::new(HashMap::new())),
             event_sender: None,
+            command_tx: Arc::new(RwLock::new(None)),
+            disconnect_signal: Arc::new(RwLock::new(None)),
+            pending_requests: Arc::new(RwLock::new(HashMap::new())),
+            gas_estimator: Arc::new(GasEstimator::default()),
+            connection_state_tx,
+            plugin_system: Arc::new(RwLock::new(None)),
         })
     }
 
+    /// Wire up the plugin system so subscription lifecycle and notification
+    /// hooks fire from here on. Called by [`HyperSimSDK`](crate::core::HyperSimSDK)
+    /// once construction completes, since the client exists before the
+    /// plugin system does.
+    pub async fn set_plugin_system(&self, plugin_system: Arc<PluginSystem>) {
+        *self.plugin_system.write().await = Some(plugin_system);
+    }
+
+    /// Update the connection state and notify anything watching
+    /// [`WebSocketClient::watch_connection_state`]
+    async fn set_connection_state(&self, new_state: ConnectionState) {
+        self.state.write().await.connection_state = new_state;
+        let _ = self.connection_state_tx.send(new_state);
+    }
+
     /// Connect to the WebSocket endpoint
     pub async fn connect(&mut self) -> Result<()> {
         info!("Connecting to WebSocket endpoint: {}", self.config.ws_endpoint());
-        
-        {
-            let mut state = self.state.write().await;
-            state.connection_state = ConnectionState::Connecting;
-        }
 
-        // In a real implementation, this would establish actual WebSocket connection
-        // For now, simulate successful connection
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        self.set_connection_state(ConnectionState::Connecting).await;
+
+        self.establish_connection().await?;
 
+        self.set_connection_state(ConnectionState::Connected).await;
         {
             let mut state = self.state.write().await;
-            state.connection_state = ConnectionState::Connected;
             state.reconnect_attempts = 0;
             state.last_ping = Some(std::time::Instant::now());
         }
 
         info!("WebSocket connected successfully");
-        
+
         // Start background tasks
         self.start_background_tasks().await?;
 
         Ok(())
     }
 
+    /// Dial the endpoint and spawn the [`WsBackend`] task that owns the
+    /// connection from here on; replaces any previously-spawned backend.
+    async fn establish_connection(&self) -> Result<()> {
+        let transport = ws_backend::dial(&self.config.ws_endpoint()).await?;
+
+        let generation = {
+            let mut state = self.state.write().await;
+            state.connection_generation += 1;
+            state.connection_generation
+        };
+
+        let (instruction_tx, instruction_rx) = mpsc::unbounded_channel();
+        let (disconnected_tx, disconnected_rx) = oneshot::channel();
+        let backend = WsBackend::new(transport, instruction_rx, generation);
+        tokio::spawn(backend.run(disconnected_tx));
+
+        *self.command_tx.write().await = Some(instruction_tx);
+        *self.disconnect_signal.write().await = Some(disconnected_rx);
+        Ok(())
+    }
+
     /// Disconnect from WebSocket
     pub async fn disconnect(&self) -> Result<()> {
         info!("Disconnecting from WebSocket");
-        
-        let mut state = self.state.write().await;
-        state.connection_state = ConnectionState::Disconnected;
-        
+
+        self.set_connection_state(ConnectionState::Disconnected).await;
+
+        // Dropping the backend's instruction sender lets its task exit
+        *self.command_tx.write().await = None;
+
+        // There is no connection left to redispatch onto; fail outstanding
+        // requests rather than leaving their callers waiting forever
+        self.fail_all_pending_requests("WebSocket disconnected").await;
+
         // Clear all subscriptions
         let mut subscriptions = self.subscriptions.write().await;
         subscriptions.clear();
-        
+        let mut subscription_server_ids = self.subscription_server_ids.write().await;
+        subscription_server_ids.clear();
+        let mut queries = self.queries.write().await;
+        queries.clear();
+        let mut stream_targets = self.stream_targets.write().await;
+        stream_targets.clear();
+        let mut stream_queue_drops = self.stream_queue_drops.write().await;
+        stream_queue_drops.clear();
+
         Ok(())
     }
 
@@ -98,66 +225,402 @@ impl WebSocketClient {
             return Err(HyperSimError::websocket("Not connected to WebSocket"));
         }
 
-        let subscription_id = self.generate_subscription_id().await;
-        
+        let local_id = self.generate_subscription_id().await;
+
+        let subscribe_params = serde_json::json!([
+            self.subscription_type_to_string(&subscription_type),
+            params,
+        ]);
+        let response = self.send_request("eth_subscribe", subscribe_params).await?;
+        let server_id = response
+            .as_str()
+            .ok_or_else(|| HyperSimError::websocket("eth_subscribe response did not contain a subscription id"))?
+            .to_string();
+
+        let limit = params.limit;
         let subscription = WSSubscription {
-            id: subscription_id.clone(),
-            subscription_type,
+            id: local_id.clone(),
+            subscription_type: subscription_type.clone(),
             params,
             active: true,
             created_at: chrono::Utc::now().timestamp_millis() as u64,
         };
 
-        // Send subscription message
-        let subscribe_msg = WSMessage {
-            id: Some(subscription_id.clone()),
-            method: "eth_subscribe".to_string(),
-            params: serde_json::json!([
-                self.subscription_type_to_string(&subscription.subscription_type),
-                subscription.params
-            ]),
-            result: None,
-            error: None,
-            timestamp: chrono::Utc::now().timestamp_millis() as u64,
+        self.attach_subscription_channel(server_id.clone(), subscription_type, None, limit).await?;
+
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.insert(local_id.clone(), subscription.clone());
+        let mut subscription_server_ids = self.subscription_server_ids.write().await;
+        subscription_server_ids.insert(local_id.clone(), server_id);
+
+        debug!("Subscribed to {:?} with ID: {}", subscription.subscription_type, local_id);
+
+        if let Some(ref plugin_system) = *self.plugin_system.read().await {
+            let _ = plugin_system.execute_on_subscribe(&subscription).await;
+        }
+
+        Ok(subscription)
+    }
+
+    /// Subscribe to logs matching a composable [`SubscriptionQuery`]
+    /// (inspired by tendermint-rs's `Query`/`Condition` model) instead of an
+    /// opaque [`SubscriptionParams`] filter. The query's `address`/`topics`
+    /// conditions are translated into the server-side `eth_subscribe("logs",
+    /// ..)` filter object, and the full query is also cached so every
+    /// condition (including ones the server can't filter on) is re-checked
+    /// client-side against each notification — this keeps the query correct
+    /// even against a mock/test transport that ignores the filter argument.
+    pub async fn subscribe_logs_query(&self, query: SubscriptionQuery) -> Result<WSSubscription> {
+        let connection_state = self.state.read().await.connection_state;
+        if !connection_state.is_connected() {
+            return Err(HyperSimError::websocket("Not connected to WebSocket"));
+        }
+
+        let local_id = self.generate_subscription_id().await;
+
+        let subscribe_params = serde_json::json!(["logs", query.to_logs_filter()]);
+        let response = self.send_request("eth_subscribe", subscribe_params).await?;
+        let server_id = response
+            .as_str()
+            .ok_or_else(|| HyperSimError::websocket("eth_subscribe response did not contain a subscription id"))?
+            .to_string();
+
+        let subscription = WSSubscription {
+            id: local_id.clone(),
+            subscription_type: SubscriptionType::Logs,
+            params: SubscriptionParams { filter: None, include_details: false, limit: None },
+            active: true,
+            created_at: chrono::Utc::now().timestamp_millis() as u64,
         };
 
-        // In a real implementation, this would send over WebSocket
-        self.send_message(subscribe_msg).await?;
+        self.attach_subscription_channel(server_id.clone(), SubscriptionType::Logs, Some(query.clone()), None).await?;
 
-        // Store subscription
         let mut subscriptions = self.subscriptions.write().await;
-        subscriptions.insert(subscription_id.clone(), subscription.clone());
+        subscriptions.insert(local_id.clone(), subscription.clone());
+        let mut subscription_server_ids = self.subscription_server_ids.write().await;
+        subscription_server_ids.insert(local_id.clone(), server_id);
+        let mut queries = self.queries.write().await;
+        queries.insert(local_id.clone(), query);
+
+        debug!("Subscribed to logs query with ID: {}", local_id);
+
+        if let Some(ref plugin_system) = *self.plugin_system.read().await {
+            let _ = plugin_system.execute_on_subscribe(&subscription).await;
+        }
 
-        debug!("Subscribed to {:?} with ID: {}", subscription.subscription_type, subscription_id);
-        
         Ok(subscription)
     }
 
+    /// Subscribe and get back a [`Subscription`] handle that is itself a
+    /// `Stream<Item = WSEvent>` over a dedicated channel, following
+    /// jsonrpsee's explicit-unsubscribe design: a caller can `.next().await`
+    /// only its own events without competing with every other subscriber on
+    /// the shared [`WebSocketClient::set_event_handler`] fan-out. Call
+    /// [`Subscription::unsubscribe`] to tear the subscription down
+    /// explicitly, or simply drop the handle — `Drop` best-effort enqueues
+    /// the `eth_unsubscribe` so the server-side subscription doesn't linger.
+    pub async fn subscribe_stream(
+        &self,
+        subscription_type: SubscriptionType,
+        params: SubscriptionParams,
+    ) -> Result<Subscription> {
+        let connection_state = self.state.read().await.connection_state;
+        if !connection_state.is_connected() {
+            return Err(HyperSimError::websocket("Not connected to WebSocket"));
+        }
+
+        let local_id = self.generate_subscription_id().await;
+
+        let subscribe_params = serde_json::json!([
+            self.subscription_type_to_string(&subscription_type),
+            params,
+        ]);
+        let response = self.send_request("eth_subscribe", subscribe_params).await?;
+        let server_id = response
+            .as_str()
+            .ok_or_else(|| HyperSimError::websocket("eth_subscribe response did not contain a subscription id"))?
+            .to_string();
+
+        let limit = params.limit;
+        let info = WSSubscription {
+            id: local_id.clone(),
+            subscription_type: subscription_type.clone(),
+            params,
+            active: true,
+            created_at: chrono::Utc::now().timestamp_millis() as u64,
+        };
+
+        let (event_tx, event_rx) = mpsc::channel(self.config.queue_capacity_items);
+        let dropped = Arc::new(AtomicU64::new(0));
+        self.attach_subscription_channel_to(server_id.clone(), subscription_type, None, event_tx.clone(), Arc::clone(&dropped), limit).await?;
+
+        let mut subscriptions = self.subscriptions.write().await;
+        subscriptions.insert(local_id.clone(), info.clone());
+        let mut subscription_server_ids = self.subscription_server_ids.write().await;
+        subscription_server_ids.insert(local_id.clone(), server_id);
+        let mut stream_targets = self.stream_targets.write().await;
+        stream_targets.insert(local_id.clone(), event_tx);
+        let mut stream_queue_drops = self.stream_queue_drops.write().await;
+        stream_queue_drops.insert(local_id.clone(), dropped);
+
+        debug!("Subscribed (stream) to {:?} with ID: {}", info.subscription_type, local_id);
+
+        if let Some(ref plugin_system) = *self.plugin_system.read().await {
+            let _ = plugin_system.execute_on_subscribe(&info).await;
+        }
+
+        Ok(Subscription { id: local_id, info, receiver: event_rx, client: self.clone(), torn_down: false })
+    }
+
+    /// Like [`subscribe_stream`](Self::subscribe_stream), but narrows the
+    /// returned stream down to `T`'s decoded payload directly instead of the
+    /// enclosing [`WSEvent`] — e.g.
+    /// `subscribe_typed::<NewBlockHeader>(SubscriptionType::NewHeads, ..)`
+    /// yields [`NewBlockHeader`] so callers can `while let Some(header) =
+    /// blocks.next().await` without matching on `WSEvent` themselves. `T`
+    /// must be the payload type that matches `subscription_type` (see
+    /// [`FromWSEvent`])); events of another shape (e.g. `Resubscribed`) are
+    /// skipped rather than ending the stream.
+    pub async fn subscribe_typed<T: FromWSEvent>(
+        &self,
+        subscription_type: SubscriptionType,
+        params: SubscriptionParams,
+    ) -> Result<SubscriptionStream<T>> {
+        let inner = self.subscribe_stream(subscription_type, params).await?;
+        Ok(SubscriptionStream { inner, _marker: std::marker::PhantomData })
+    }
+
+    /// Subscribe to new block headers, decoded as [`HyperEVMBlock`]s — a
+    /// [`subscribe_typed`](Self::subscribe_typed) convenience wrapper for the
+    /// common simulate-on-new-block workflow, so callers can drive it off
+    /// `while let Some(block) = stream.next().await` instead of polling
+    /// [`HyperEVMClient::get_latest_block`](crate::clients::HyperEVMClient::get_latest_block).
+    pub async fn subscribe_new_heads(&self) -> Result<SubscriptionStream<HyperEVMBlock>> {
+        self.subscribe_typed(
+            SubscriptionType::NewHeads,
+            SubscriptionParams { filter: None, include_details: false, limit: None },
+        )
+        .await
+    }
+
+    /// Subscribe to pending transaction hashes as they enter the mempool —
+    /// a [`subscribe_typed`](Self::subscribe_typed) convenience wrapper that
+    /// narrows each notification down to just the hash, for callers that
+    /// only need to know a transaction exists (e.g. to `simulate()` it)
+    /// rather than its full decoded fields.
+    pub async fn subscribe_pending_transactions(&self) -> Result<SubscriptionStream<Hash>> {
+        self.subscribe_typed(
+            SubscriptionType::PendingTransactions,
+            SubscriptionParams { filter: None, include_details: false, limit: None },
+        )
+        .await
+    }
+
+    /// Per-subscription queue depth and drop counts for every subscription
+    /// opened via [`subscribe_stream`](Self::subscribe_stream), so callers
+    /// running many streams can surface (via
+    /// [`HyperSimSDK::get_metrics`](crate::core::HyperSimSDK::get_metrics))
+    /// which ones have a consumer falling behind
+    pub async fn subscription_queue_metrics(&self) -> Vec<SubscriptionQueueMetrics> {
+        let stream_targets = self.stream_targets.read().await;
+        let stream_queue_drops = self.stream_queue_drops.read().await;
+        let capacity = self.config.queue_capacity_items;
+
+        stream_targets
+            .iter()
+            .map(|(id, sender)| SubscriptionQueueMetrics {
+                subscription_id: id.clone(),
+                queue_depth: capacity.saturating_sub(sender.capacity()),
+                queue_capacity: capacity,
+                dropped_notifications: stream_queue_drops
+                    .get(id)
+                    .map(|counter| counter.load(Ordering::Relaxed))
+                    .unwrap_or(0),
+            })
+            .collect()
+    }
+
+    /// Re-establish every currently-stored subscription after a reconnect:
+    /// re-send `eth_subscribe` for each, remap the new server-assigned
+    /// subscription ID back to the caller's stable local handle, and emit
+    /// [`WSEvent::Resubscribed`]. Best-effort — a subscription that fails to
+    /// re-establish is logged and skipped rather than aborting the rest.
+    async fn resubscribe_all(&self) {
+        let subscriptions: Vec<WSSubscription> = self.subscriptions.read().await.values().cloned().collect();
+
+        for subscription in subscriptions {
+            if let Err(error) = self.resubscribe_one(&subscription).await {
+                warn!("Failed to re-establish subscription {} after reconnect: {}", subscription.id, error);
+            }
+        }
+    }
+
+    async fn resubscribe_one(&self, subscription: &WSSubscription) -> Result<()> {
+        let query = self.queries.read().await.get(&subscription.id).cloned();
+        let stream_target = self.stream_targets.read().await.get(&subscription.id).cloned();
+        let stream_dropped = self.stream_queue_drops.read().await.get(&subscription.id).cloned();
+
+        let subscribe_params = match &query {
+            Some(query) => serde_json::json!(["logs", query.to_logs_filter()]),
+            None => serde_json::json!([
+                self.subscription_type_to_string(&subscription.subscription_type),
+                subscription.params,
+            ]),
+        };
+        let response = self.send_request("eth_subscribe", subscribe_params).await?;
+        let server_id = response
+            .as_str()
+            .ok_or_else(|| HyperSimError::websocket("eth_subscribe response did not contain a subscription id"))?
+            .to_string();
+
+        let limit = subscription.params.limit;
+        match &stream_target {
+            Some(sink) => {
+                self.attach_subscription_channel_to(
+                    server_id.clone(),
+                    subscription.subscription_type.clone(),
+                    query,
+                    sink.clone(),
+                    stream_dropped.clone().unwrap_or_else(|| Arc::new(AtomicU64::new(0))),
+                    limit,
+                )
+                .await?;
+            }
+            None => {
+                self.attach_subscription_channel(server_id.clone(), subscription.subscription_type.clone(), query, limit)
+                    .await?;
+            }
+        }
+        self.subscription_server_ids.write().await.insert(subscription.id.clone(), server_id);
+
+        let resubscribed = WSEvent::Resubscribed { id: subscription.id.clone() };
+        match &stream_target {
+            Some(sink) => {
+                if sink.try_send(resubscribed).is_err() {
+                    if let Some(ref dropped) = stream_dropped {
+                        dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+            None => {
+                if let Some(ref event_sender) = self.event_sender {
+                    let _ = event_sender.send(resubscribed);
+                }
+            }
+        }
+
+        debug!("Re-established subscription {} after reconnect", subscription.id);
+        Ok(())
+    }
+
+    /// Tell the backend to route `eth_subscription` notifications for
+    /// `server_id` to a freshly-spawned forwarder task that feeds the
+    /// client's shared `event_sender`. `query`, if present, is re-checked
+    /// against every notification before it's forwarded.
+    async fn attach_subscription_channel(
+        &self,
+        server_id: String,
+        subscription_type: SubscriptionType,
+        query: Option<SubscriptionQuery>,
+        fee_history_window: Option<u32>,
+    ) -> Result<()> {
+        let command_tx = {
+            let guard = self.command_tx.read().await;
+            guard.clone().ok_or_else(|| HyperSimError::websocket("Not connected to WebSocket"))?
+        };
+
+        let (notification_tx, notification_rx) = mpsc::unbounded_channel();
+        command_tx
+            .send(Instruction::Subscribe { id: server_id, sink: notification_tx })
+            .map_err(|_| HyperSimError::websocket("WebSocket backend is not running"))?;
+
+        self.spawn_notification_forwarder(subscription_type, query, notification_rx, fee_history_window).await;
+        Ok(())
+    }
+
+    /// Like [`attach_subscription_channel`], but forwards decoded events
+    /// directly to `event_sender` instead of the client's shared sender —
+    /// used by [`subscribe_stream`] so each [`Subscription`] gets its own
+    /// private channel.
+    async fn attach_subscription_channel_to(
+        &self,
+        server_id: String,
+        subscription_type: SubscriptionType,
+        query: Option<SubscriptionQuery>,
+        event_sender: mpsc::Sender<WSEvent>,
+        dropped: Arc<AtomicU64>,
+        fee_history_window: Option<u32>,
+    ) -> Result<()> {
+        let command_tx = {
+            let guard = self.command_tx.read().await;
+            guard.clone().ok_or_else(|| HyperSimError::websocket("Not connected to WebSocket"))?
+        };
+
+        let (notification_tx, notification_rx) = mpsc::unbounded_channel();
+        command_tx
+            .send(Instruction::Subscribe { id: server_id, sink: notification_tx })
+            .map_err(|_| HyperSimError::websocket("WebSocket backend is not running"))?;
+
+        let plugin_system = self.plugin_system.read().await.clone();
+        spawn_bounded_forwarder(
+            subscription_type,
+            query,
+            notification_rx,
+            event_sender,
+            self.gas_estimator.clone(),
+            dropped,
+            plugin_system,
+            fee_history_window,
+        );
+        Ok(())
+    }
+
+    /// Decode notifications forwarded from the backend for this subscription
+    /// and re-emit them as [`WSEvent`]s on the client's shared `event_sender`.
+    async fn spawn_notification_forwarder(
+        &self,
+        subscription_type: SubscriptionType,
+        query: Option<SubscriptionQuery>,
+        notifications: mpsc::UnboundedReceiver<serde_json::Value>,
+        fee_history_window: Option<u32>,
+    ) {
+        let Some(event_sender) = self.event_sender.clone() else { return };
+        let plugin_system = self.plugin_system.read().await.clone();
+        spawn_forwarder(subscription_type, query, notifications, event_sender, self.gas_estimator.clone(), plugin_system, fee_history_window);
+    }
+
     /// Unsubscribe from WebSocket events
     pub async fn unsubscribe(&self, subscription_id: &str) -> Result<()> {
+        let server_id = {
+            let mut subscription_server_ids = self.subscription_server_ids.write().await;
+            subscription_server_ids
+                .remove(subscription_id)
+                .ok_or_else(|| HyperSimError::websocket(format!("Subscription not found: {}", subscription_id)))?
+        };
+
+        self.send_request("eth_unsubscribe", serde_json::json!([server_id])).await?;
+
+        let command_tx = self.command_tx.read().await.clone();
+        if let Some(command_tx) = command_tx {
+            let _ = command_tx.send(Instruction::Unsubscribe { id: server_id });
+        }
+
         let mut subscriptions = self.subscriptions.write().await;
-        
-        if let Some(mut subscription) = subscriptions.get_mut(subscription_id) {
-            subscription.active = false;
-            
-            // Send unsubscribe message
-            let unsubscribe_msg = WSMessage {
-                id: Some(subscription_id.to_string()),
-                method: "eth_unsubscribe".to_string(),
-                params: serde_json::json!([subscription_id]),
-                result: None,
-                error: None,
-                timestamp: chrono::Utc::now().timestamp_millis() as u64,
-            };
-
-            self.send_message(unsubscribe_msg).await?;
-            subscriptions.remove(subscription_id);
-            
-            debug!("Unsubscribed from subscription: {}", subscription_id);
-            Ok(())
-        } else {
-            Err(HyperSimError::websocket(format!("Subscription not found: {}", subscription_id)))
+        subscriptions.remove(subscription_id);
+        let mut queries = self.queries.write().await;
+        queries.remove(subscription_id);
+        let mut stream_targets = self.stream_targets.write().await;
+        stream_targets.remove(subscription_id);
+        let mut stream_queue_drops = self.stream_queue_drops.write().await;
+        stream_queue_drops.remove(subscription_id);
+
+        debug!("Unsubscribed from subscription: {}", subscription_id);
+
+        if let Some(ref plugin_system) = *self.plugin_system.read().await {
+            let _ = plugin_system.execute_on_unsubscribe(subscription_id).await;
         }
+
+        Ok(())
     }
 
     /// Get current connection state
@@ -165,6 +628,14 @@ impl WebSocketClient {
         self.state.read().await.connection_state
     }
 
+    /// Subscribe to every [`ConnectionState`] transition this client makes,
+    /// starting from the state at the moment of the call. Used by the SDK's
+    /// reconnection coordinator to detect the disconnected-to-connected edge
+    /// without polling [`get_connection_state`](Self::get_connection_state).
+    pub fn watch_connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.connection_state_tx.subscribe()
+    }
+
     /// Get active subscriptions
     pub async fn get_subscriptions(&self) -> Vec<WSSubscription> {
         self.subscriptions.read().await.values().cloned().collect()
@@ -175,83 +646,216 @@ impl WebSocketClient {
         self.event_sender = Some(sender);
     }
 
+    /// Current gas price estimate (in wei) at `percentile`, from the rolling
+    /// window of gas prices observed on pending-transaction notifications.
+    /// Returns `None` until at least one sample has been observed.
+    pub async fn gas_estimate(&self, percentile: f64) -> Option<u128> {
+        self.gas_estimator.estimate(percentile).await
+    }
+
     // Private implementation methods
 
-    async fn send_message(&self, message: WSMessage) -> Result<()> {
-        // In a real implementation, this would send over actual WebSocket connection
-        debug!("Sending WebSocket message: {}", message.method);
-        
-        // Simulate network delay
-        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
-        
-        Ok(())
+    /// Send a JSON-RPC request and await its real outcome: registers it in
+    /// `pending_requests` first, so if the connection drops mid-flight the
+    /// connection supervisor redispatches it onto the next connection
+    /// instead of this call ever seeing a connection-drop error.
+    async fn send_request(&self, method: &str, params: serde_json::Value) -> Result<serde_json::Value> {
+        if self.command_tx.read().await.is_none() {
+            return Err(HyperSimError::websocket("Not connected to WebSocket"));
+        }
+
+        let id = self.next_message_id().await;
+        let payload = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+
+        debug!("Sending WebSocket request: {}", method);
+
+        let (resp, resp_rx) = oneshot::channel();
+        self.pending_requests.write().await.insert(id, PendingRequest { payload: payload.clone(), resp });
+
+        self.dispatch_pending_request(id, payload).await;
+
+        resp_rx.await.map_err(|_| HyperSimError::websocket("WebSocket client dropped the request"))?
+    }
+
+    /// Send `payload` (already registered in `pending_requests` under `id`)
+    /// to the currently-live backend and forward its real outcome back onto
+    /// the matching `pending_requests` entry. If the backend is gone or
+    /// dies before answering, the entry is left in place rather than
+    /// resolved — the connection supervisor calls this again for every
+    /// still-pending entry once reconnected.
+    async fn dispatch_pending_request(&self, id: u64, payload: serde_json::Value) {
+        let Some(command_tx) = self.command_tx.read().await.clone() else {
+            debug!("WebSocket request {} queued; no live connection to dispatch onto yet", id);
+            return;
+        };
+
+        let (inner_resp, inner_rx) = oneshot::channel();
+        if command_tx.send(Instruction::Request { id, payload, resp: inner_resp }).is_err() {
+            return;
+        }
+
+        let pending_requests = Arc::clone(&self.pending_requests);
+        tokio::spawn(async move {
+            match inner_rx.await {
+                Ok(result) => {
+                    if let Some(entry) = pending_requests.write().await.remove(&id) {
+                        let _ = entry.resp.send(result);
+                    }
+                }
+                Err(_) => {
+                    debug!("WebSocket request {} interrupted by disconnect; will redispatch on reconnect", id);
+                }
+            }
+        });
+    }
+
+    /// Re-send every request still awaiting a response onto the fresh
+    /// connection after a reconnect
+    async fn redispatch_pending_requests(&self) {
+        let entries: Vec<(u64, serde_json::Value)> = self
+            .pending_requests
+            .read()
+            .await
+            .iter()
+            .map(|(id, entry)| (*id, entry.payload.clone()))
+            .collect();
+
+        if entries.is_empty() {
+            return;
+        }
+
+        info!("Redispatching {} pending request(s) after reconnect", entries.len());
+        for (id, payload) in entries {
+            self.dispatch_pending_request(id, payload).await;
+        }
+    }
+
+    /// Resolve every still-pending request with a terminal error — used
+    /// when there is no connection left to redispatch onto (an explicit
+    /// `disconnect()`) or reconnection has given up, so callers waiting on
+    /// [`send_request`](Self::send_request) don't hang forever.
+    async fn fail_all_pending_requests(&self, message: &str) {
+        let mut pending_requests = self.pending_requests.write().await;
+        for (_, entry) in pending_requests.drain() {
+            let _ = entry.resp.send(Err(HyperSimError::websocket(message.to_string())));
+        }
     }
 
     async fn start_background_tasks(&self) -> Result<()> {
         // Start ping task
         let state_clone = Arc::clone(&self.state);
+        let command_tx_handle = Arc::clone(&self.command_tx);
         let ping_interval = self.config.ping_interval;
-        
+
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(ping_interval));
-            
+
             loop {
                 interval.tick().await;
-                
-                let mut state = state_clone.write().await;
-                if state.connection_state == ConnectionState::Connected {
-                    state.last_ping = Some(std::time::Instant::now());
-                    // In real implementation, send ping frame
+
+                let connected = state_clone.read().await.connection_state == ConnectionState::Connected;
+                if !connected {
+                    continue;
+                }
+
+                let command_tx = command_tx_handle.read().await.clone();
+                let Some(command_tx) = command_tx else { continue };
+
+                if command_tx.send(Instruction::Ping).is_ok() {
+                    state_clone.write().await.last_ping = Some(std::time::Instant::now());
                     debug!("Sending WebSocket ping");
+                } else {
+                    warn!("WebSocket ping failed: backend is not running");
                 }
             }
         });
 
-        // Start message handling task (mock)
-        self.start_message_handler().await;
+        // Start the connection supervisor that detects an unexpected
+        // disconnect and drives reconnection
+        self.start_connection_supervisor().await;
 
         Ok(())
     }
 
-    async fn start_message_handler(&self) {
-        let event_sender = self.event_sender.clone();
-        
+    /// Watch for the current [`WsBackend`] exiting unexpectedly (as opposed
+    /// to a caller-initiated [`disconnect`](Self::disconnect), which already
+    /// set [`ConnectionState::Disconnected`] before tearing the backend
+    /// down) and drive recovery: exponential backoff up to
+    /// `max_reconnect_attempts`, then surface [`ConnectionState::Error`] and
+    /// fail every request still waiting for an answer. Re-arms itself
+    /// against the next connection generation after every successful
+    /// reconnect, so it keeps supervising for as long as the client lives.
+    async fn start_connection_supervisor(&self) {
+        let client = self.clone();
+
         tokio::spawn(async move {
-            // Mock incoming messages
-            let mut interval = tokio::time::interval(std::time::Duration::from_secs(5));
-            
             loop {
-                interval.tick().await;
-                
-                if let Some(ref sender) = event_sender {
-                    // Send mock new block event
-                    let mock_event = WSEvent::NewBlock {
-                        header: crate::types::NewBlockHeader {
-                            hash: crate::types::Hash("0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string()),
-                            parent_hash: crate::types::Hash("0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890".to_string()),
-                            number: rand::random::<u64>() % 1000000 + 18000000,
-                            timestamp: chrono::Utc::now().timestamp() as u64,
-                            gas_limit: "30000000".to_string(),
-                            gas_used: "15000000".to_string(),
-                            difficulty: "58750003716598352816469".to_string(),
-                            miner: crate::types::Address("0x0000000000000000000000000000000000000000".to_string()),
-                            extra_data: "0x".to_string(),
-                            transaction_count: rand::random::<u32>() % 200,
-                        },
-                    };
-                    
-                    if sender.send(mock_event).is_err() {
-                        warn!("Failed to send WebSocket event");
+                let Some(disconnected) = client.disconnect_signal.write().await.take() else {
+                    return;
+                };
+
+                let _ = disconnected.await;
+
+                if client.get_connection_state().await == ConnectionState::Disconnected {
+                    debug!("WebSocket disconnected intentionally; connection supervisor exiting");
+                    return;
+                }
+
+                warn!("WebSocket connection lost unexpectedly; attempting to reconnect");
+                client.set_connection_state(ConnectionState::Reconnecting).await;
+
+                if !client.config.auto_reconnect {
+                    client.fail_all_pending_requests("WebSocket disconnected and auto_reconnect is disabled").await;
+                    client.set_connection_state(ConnectionState::Error).await;
+                    return;
+                }
+
+                let mut reconnected = false;
+                let mut gave_up_early = false;
+                while client.state.read().await.reconnect_attempts < client.config.max_reconnect_attempts {
+                    match client.handle_reconnection().await {
+                        Ok(()) => {
+                            reconnected = true;
+                            break;
+                        }
+                        Err(error) if !error.is_retryable() => {
+                            // The peer rejected the handshake or violated the protocol;
+                            // retrying the exact same connection attempt won't fix that.
+                            warn!("Reconnection attempt failed with a non-retryable error, giving up: {}", error);
+                            gave_up_early = true;
+                            break;
+                        }
+                        Err(error) => warn!("Reconnection attempt failed: {}", error),
                     }
                 }
+
+                if !reconnected {
+                    let reason = if gave_up_early {
+                        "WebSocket reconnection failed: non-retryable error"
+                    } else {
+                        "WebSocket reconnection failed: max attempts exceeded"
+                    };
+                    client.fail_all_pending_requests(reason).await;
+                    client.set_connection_state(ConnectionState::Error).await;
+                    return;
+                }
             }
         });
     }
 
-    async fn generate_subscription_id(&self) -> String {
+    async fn next_message_id(&self) -> u64 {
         let mut state = self.state.write().await;
         state.message_id_counter += 1;
-        format!("sub_{:08x}", state.message_id_counter)
+        state.message_id_counter
+    }
+
+    async fn generate_subscription_id(&self) -> String {
+        format!("sub_{:08x}", self.next_message_id().await)
     }
 
     fn subscription_type_to_string(&self, sub_type: &SubscriptionType) -> &'static str {
@@ -264,45 +868,473 @@ impl WebSocketClient {
             SubscriptionType::SimulationResults => "simulationResults",
             SubscriptionType::GasPrices => "gasPrices",
             SubscriptionType::NetworkStatus => "networkStatus",
+            SubscriptionType::FeeHistory => "feeHistory",
         }
     }
 
     async fn handle_reconnection(&self) -> Result<()> {
         let mut state = self.state.write().await;
-        
+
         if state.reconnect_attempts >= self.config.max_reconnect_attempts {
             return Err(HyperSimError::websocket("Max reconnection attempts exceeded"));
         }
 
-        state.connection_state = ConnectionState::Reconnecting;
         state.reconnect_attempts += 1;
-        
-        let delay = (self.config.reconnect_backoff.powi(state.reconnect_attempts as i32) * 1000.0) as u64;
-        
-        info!("Attempting reconnection in {}ms (attempt {})", 
-            delay, state.reconnect_attempts);
-        
-        drop(state); // Release lock before sleep
-        
+        let attempts = state.reconnect_attempts;
+        drop(state);
+
+        self.set_connection_state(ConnectionState::Reconnecting).await;
+
+        let delay = (self.config.reconnect_backoff.powi(attempts as i32) * 1000.0) as u64;
+
+        info!("Attempting reconnection in {}ms (attempt {})", delay, attempts);
+
         tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
-        
+
         // Attempt reconnection
         self.attempt_connect().await
     }
 
     async fn attempt_connect(&self) -> Result<()> {
-        // In real implementation, establish WebSocket connection
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-        
-        let mut state = self.state.write().await;
-        state.connection_state = ConnectionState::Connected;
-        state.last_ping = Some(std::time::Instant::now());
-        
+        self.establish_connection().await?;
+
+        self.set_connection_state(ConnectionState::Connected).await;
+        {
+            let mut state = self.state.write().await;
+            state.last_ping = Some(std::time::Instant::now());
+            // A fresh connection earns a fresh reconnection budget; otherwise
+            // a second, later disconnect would inherit an already-exhausted
+            // attempt count from this one.
+            state.reconnect_attempts = 0;
+        }
+
         info!("WebSocket reconnected successfully");
+
+        self.resubscribe_all().await;
+        self.redispatch_pending_requests().await;
+
         Ok(())
     }
 }
 
+/// Decode a raw `eth_subscription` notification payload into the [`WSEvent`]
+/// matching the subscription type that produced it
+fn decode_subscription_event(subscription_type: &SubscriptionType, payload: serde_json::Value) -> Result<WSEvent> {
+    let decode_err = |e: serde_json::Error| {
+        HyperSimError::serialization(format!("Failed to decode subscription payload: {}", e))
+    };
+
+    match subscription_type {
+        SubscriptionType::NewHeads | SubscriptionType::NewBlocks => {
+            let header: NewBlockHeader = serde_json::from_value(payload).map_err(decode_err)?;
+            Ok(WSEvent::NewBlock { header })
+        }
+        SubscriptionType::NewTransactions | SubscriptionType::PendingTransactions => {
+            let transaction: NewTransaction = serde_json::from_value(payload).map_err(decode_err)?;
+            Ok(WSEvent::NewTransaction { transaction })
+        }
+        SubscriptionType::Logs => {
+            let log: LogNotification = serde_json::from_value(payload).map_err(decode_err)?;
+            Ok(WSEvent::Log { log })
+        }
+        SubscriptionType::SimulationResults => {
+            let notification: SimulationNotification = serde_json::from_value(payload).map_err(decode_err)?;
+            Ok(WSEvent::SimulationResult { notification })
+        }
+        SubscriptionType::GasPrices => {
+            let notification: GasPriceNotification = serde_json::from_value(payload).map_err(decode_err)?;
+            Ok(WSEvent::GasPriceUpdate { notification })
+        }
+        SubscriptionType::NetworkStatus => {
+            let notification: NetworkStatusNotification = serde_json::from_value(payload).map_err(decode_err)?;
+            Ok(WSEvent::NetworkStatus { notification })
+        }
+        SubscriptionType::FeeHistory => {
+            // The wire payload describes a single new block; the forwarder
+            // expands this into the windowed history and computes `trend`
+            // via a per-subscription `FeeHistoryTracker` before the caller
+            // sees it — see `spawn_forwarder`/`spawn_bounded_forwarder`.
+            let notification: FeeHistoryNotification = serde_json::from_value(payload).map_err(decode_err)?;
+            Ok(WSEvent::FeeHistory { notification })
+        }
+    }
+}
+
+/// Expand a freshly decoded `WSEvent::FeeHistory` sample (the wire payload
+/// describes only the single new block) into the full windowed history by
+/// feeding `tracker` and recomputing priority-fee percentiles from
+/// `gas_estimator`'s rolling window. Every other event variant passes
+/// through unchanged.
+async fn expand_fee_history_event(
+    event: WSEvent,
+    tracker: &FeeHistoryTracker,
+    gas_estimator: &Arc<GasEstimator>,
+) -> WSEvent {
+    let WSEvent::FeeHistory { notification } = event else {
+        return event;
+    };
+
+    let base_fee_wei = notification
+        .base_fee_per_gas
+        .last()
+        .and_then(|fee| fee.parse::<u128>().ok())
+        .unwrap_or(0);
+    let gas_used_ratio = notification.gas_used_ratio.last().copied().unwrap_or(0.0);
+
+    let trend = tracker.observe_block(base_fee_wei, gas_used_ratio).await;
+
+    let mut priority_fee_percentiles = HashMap::new();
+    for percentile in [10.0, 50.0, 90.0] {
+        if let Some(price) = gas_estimator.estimate(percentile).await {
+            let priority_fee = price.saturating_sub(base_fee_wei);
+            priority_fee_percentiles.insert((percentile as u32).to_string(), priority_fee.to_string());
+        }
+    }
+
+    WSEvent::FeeHistory {
+        notification: FeeHistoryNotification {
+            base_fee_per_gas: tracker.base_fee_history().await,
+            gas_used_ratio: tracker.gas_used_ratio_history().await,
+            priority_fee_percentiles,
+            trend,
+            ..notification
+        },
+    }
+}
+
+/// Spawn the task that decodes raw `eth_subscription` payloads forwarded
+/// from the backend and re-emits them as [`WSEvent`]s on `event_sender`.
+/// Notifications that don't satisfy `query` (when present) are dropped
+/// before decoding. Every decoded `NewTransaction` feeds `gas_estimator`'s
+/// rolling window regardless of subscription type, and every `NewBlock`
+/// triggers a fresh `WSEvent::GasPrices` estimate on the same channel —
+/// this is how the estimator stays fed purely by piggybacking on whatever
+/// subscriptions are already active, without a dedicated background task.
+/// `plugin_system`, if present, gets every decoded event via `on_notification`
+/// before it's forwarded. Every decoded `FeeHistory` sample (one new block)
+/// is expanded into the full windowed history and its `trend` is computed by
+/// a `FeeHistoryTracker` scoped to this forwarder, sized from
+/// `fee_history_window` (`SubscriptionParams::limit`, or
+/// [`DEFAULT_WINDOW_BLOCKS`] if unset).
+fn spawn_forwarder(
+    subscription_type: SubscriptionType,
+    query: Option<SubscriptionQuery>,
+    mut notifications: mpsc::UnboundedReceiver<serde_json::Value>,
+    event_sender: mpsc::UnboundedSender<WSEvent>,
+    gas_estimator: Arc<GasEstimator>,
+    plugin_system: Option<Arc<PluginSystem>>,
+    fee_history_window: Option<u32>,
+) {
+    tokio::spawn(async move {
+        let fee_history_tracker = FeeHistoryTracker::new(
+            fee_history_window.map(|w| w as usize).unwrap_or(DEFAULT_WINDOW_BLOCKS),
+            DEFAULT_TREND_THRESHOLD,
+        );
+
+        while let Some(payload) = notifications.recv().await {
+            if let Some(ref query) = query {
+                if !query.matches(&payload) {
+                    continue;
+                }
+            }
+
+            match decode_subscription_event(&subscription_type, payload) {
+                Ok(event) => {
+                    let event = expand_fee_history_event(event, &fee_history_tracker, &gas_estimator).await;
+
+                    if let Some(ref plugin_system) = plugin_system {
+                        let _ = plugin_system.execute_on_notification(&event).await;
+                    }
+
+                    match &event {
+                        WSEvent::NewTransaction { transaction } => {
+                            gas_estimator.observe(transaction).await;
+                        }
+                        WSEvent::NewBlock { .. } => {
+                            if let Some(tiers) = gas_estimator.tiered_estimate().await {
+                                let _ = event_sender.send(WSEvent::GasPrices {
+                                    slow: tiers.slow.to_string(),
+                                    standard: tiers.standard.to_string(),
+                                    fast: tiers.fast.to_string(),
+                                });
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    if event_sender.send(event).is_err() {
+                        break;
+                    }
+                }
+                Err(error) => warn!("Failed to decode WebSocket subscription payload: {}", error),
+            }
+        }
+    });
+}
+
+/// Like [`spawn_forwarder`], but feeds a [`subscribe_stream`](WebSocketClient::subscribe_stream)
+/// subscription's own bounded channel instead of the shared fan-out. A
+/// consumer that can't keep up causes the channel to report full; rather
+/// than block the forwarder (and every other subscription's delivery along
+/// with it) or grow without bound, the event that didn't fit is dropped and
+/// counted in `dropped`, which [`WebSocketClient::subscription_queue_metrics`]
+/// surfaces per subscription.
+fn spawn_bounded_forwarder(
+    subscription_type: SubscriptionType,
+    query: Option<SubscriptionQuery>,
+    mut notifications: mpsc::UnboundedReceiver<serde_json::Value>,
+    event_sender: mpsc::Sender<WSEvent>,
+    gas_estimator: Arc<GasEstimator>,
+    dropped: Arc<AtomicU64>,
+    plugin_system: Option<Arc<PluginSystem>>,
+    fee_history_window: Option<u32>,
+) {
+    tokio::spawn(async move {
+        let fee_history_tracker = FeeHistoryTracker::new(
+            fee_history_window.map(|w| w as usize).unwrap_or(DEFAULT_WINDOW_BLOCKS),
+            DEFAULT_TREND_THRESHOLD,
+        );
+
+        while let Some(payload) = notifications.recv().await {
+            if let Some(ref query) = query {
+                if !query.matches(&payload) {
+                    continue;
+                }
+            }
+
+            match decode_subscription_event(&subscription_type, payload) {
+                Ok(event) => {
+                    let event = expand_fee_history_event(event, &fee_history_tracker, &gas_estimator).await;
+
+                    if let Some(ref plugin_system) = plugin_system {
+                        let _ = plugin_system.execute_on_notification(&event).await;
+                    }
+
+                    match &event {
+                        WSEvent::NewTransaction { transaction } => {
+                            gas_estimator.observe(transaction).await;
+                        }
+                        WSEvent::NewBlock { .. } => {
+                            if let Some(tiers) = gas_estimator.tiered_estimate().await {
+                                if event_sender.try_send(WSEvent::GasPrices {
+                                    slow: tiers.slow.to_string(),
+                                    standard: tiers.standard.to_string(),
+                                    fast: tiers.fast.to_string(),
+                                }).is_err() {
+                                    dropped.fetch_add(1, Ordering::Relaxed);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    match event_sender.try_send(event) {
+                        Ok(()) => {}
+                        Err(mpsc::error::TrySendError::Full(_)) => {
+                            dropped.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(mpsc::error::TrySendError::Closed(_)) => break,
+                    }
+                }
+                Err(error) => warn!("Failed to decode WebSocket subscription payload: {}", error),
+            }
+        }
+    });
+}
+
+/// A live subscription handle returned by [`WebSocketClient::subscribe_stream`].
+///
+/// Implements `Stream<Item = WSEvent>` over a dedicated channel, following
+/// jsonrpsee's explicit-unsubscribe design: each handle only ever yields
+/// events for its own subscription. Call [`Subscription::unsubscribe`] to
+/// tear it down explicitly; otherwise `Drop` best-effort enqueues the
+/// `eth_unsubscribe` so the server-side subscription doesn't linger.
+pub struct Subscription {
+    id: String,
+    info: WSSubscription,
+    receiver: mpsc::Receiver<WSEvent>,
+    client: WebSocketClient,
+    torn_down: bool,
+}
+
+impl Subscription {
+    /// The caller-stable local handle for this subscription
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Metadata describing this subscription
+    pub fn info(&self) -> &WSSubscription {
+        &self.info
+    }
+
+    /// Send `eth_unsubscribe` and consume this handle. Unlike letting the
+    /// handle simply drop, this awaits the server round-trip and surfaces
+    /// any failure.
+    pub async fn unsubscribe(mut self) -> Result<()> {
+        self.torn_down = true;
+        self.client.unsubscribe(&self.id).await
+    }
+}
+
+impl Stream for Subscription {
+    type Item = WSEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if self.torn_down {
+            return;
+        }
+
+        let client = self.client.clone();
+        let id = self.id.clone();
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                if let Err(error) = client.unsubscribe(&id).await {
+                    warn!("Best-effort unsubscribe on drop failed for {}: {}", id, error);
+                }
+            });
+        }
+    }
+}
+
+/// Narrows a [`WSEvent`] down to the single payload type a
+/// [`SubscriptionStream<T>`] yields. Implemented for each notification type
+/// `decode_subscription_event` can produce except [`FeeHistoryNotification`],
+/// matching the same [`SubscriptionType`] -> payload mapping, plus
+/// [`HyperEVMBlock`] (from new heads) and [`Hash`] (from transactions).
+pub trait FromWSEvent: Sized {
+    /// Extract `Self` from `event`, or `None` if `event` carries a different
+    /// payload (e.g. the `Resubscribed`/`Unsubscribed` bookkeeping events
+    /// every subscription kind can emit).
+    fn from_ws_event(event: WSEvent) -> Option<Self>;
+}
+
+impl FromWSEvent for NewBlockHeader {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::NewBlock { header } => Some(header),
+            _ => None,
+        }
+    }
+}
+
+impl FromWSEvent for NewTransaction {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::NewTransaction { transaction } => Some(transaction),
+            _ => None,
+        }
+    }
+}
+
+impl FromWSEvent for LogNotification {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::Log { log } => Some(log),
+            _ => None,
+        }
+    }
+}
+
+impl FromWSEvent for SimulationNotification {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::SimulationResult { notification } => Some(notification),
+            _ => None,
+        }
+    }
+}
+
+impl FromWSEvent for GasPriceNotification {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::GasPriceUpdate { notification } => Some(notification),
+            _ => None,
+        }
+    }
+}
+
+impl FromWSEvent for NetworkStatusNotification {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::NetworkStatus { notification } => Some(notification),
+            _ => None,
+        }
+    }
+}
+
+impl FromWSEvent for HyperEVMBlock {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::NewBlock { header } => Some(header.into()),
+            _ => None,
+        }
+    }
+}
+
+impl FromWSEvent for Hash {
+    fn from_ws_event(event: WSEvent) -> Option<Self> {
+        match event {
+            WSEvent::NewTransaction { transaction } => Some(transaction.hash),
+            _ => None,
+        }
+    }
+}
+
+/// A [`Subscription`] narrowed to yield `T` directly, via
+/// [`WebSocketClient::subscribe_typed`]. Backed by the same per-subscription
+/// bounded channel as [`Subscription`], so it inherits its
+/// drop-on-full queue accounting and explicit-unsubscribe-on-drop behavior —
+/// this type only adds the `WSEvent` -> `T` narrowing on top.
+pub struct SubscriptionStream<T> {
+    inner: Subscription,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> SubscriptionStream<T> {
+    /// The caller-stable local handle for this subscription
+    pub fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    /// Metadata describing this subscription
+    pub fn info(&self) -> &WSSubscription {
+        self.inner.info()
+    }
+
+    /// Send `eth_unsubscribe` and consume this handle; see
+    /// [`Subscription::unsubscribe`]
+    pub async fn unsubscribe(self) -> Result<()> {
+        self.inner.unsubscribe().await
+    }
+}
+
+impl<T: FromWSEvent> Stream for SubscriptionStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(value) = T::from_ws_event(event) {
+                        return Poll::Ready(Some(value));
+                    }
+                    // Not this stream's payload type (e.g. `Resubscribed`) — keep polling.
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
 impl std::fmt::Debug for WebSocketClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("WebSocketClient")
@@ -328,22 +1360,388 @@ mod tests {
     async fn test_subscription_id_generation() {
         let config = WebSocketClientConfig::new(Network::Local);
         let client = WebSocketClient::new(config).await.unwrap();
-        
+
         let id1 = client.generate_subscription_id().await;
         let id2 = client.generate_subscription_id().await;
-        
+
         assert!(id1.starts_with("sub_"));
         assert!(id2.starts_with("sub_"));
         assert_ne!(id1, id2);
     }
 
-    #[tokio::test] 
+    #[tokio::test]
     async fn test_subscription_type_conversion() {
         let config = WebSocketClientConfig::new(Network::Local);
         let client = WebSocketClient::new(config).await.unwrap();
-        
+
         assert_eq!(client.subscription_type_to_string(&SubscriptionType::NewHeads), "newHeads");
         assert_eq!(client.subscription_type_to_string(&SubscriptionType::Logs), "logs");
         assert_eq!(client.subscription_type_to_string(&SubscriptionType::PendingTransactions), "pendingTransactions");
     }
+
+    #[tokio::test]
+    async fn test_subscribe_before_connect_fails() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let result = client.subscribe(SubscriptionType::NewHeads, SubscriptionParams {
+            filter: None,
+            include_details: false,
+            limit: None,
+        }).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resubscribe_all_is_best_effort_without_connection() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let subscription = WSSubscription {
+            id: "sub_00000001".to_string(),
+            subscription_type: SubscriptionType::NewHeads,
+            params: SubscriptionParams { filter: None, include_details: false, limit: None },
+            active: true,
+            created_at: 0,
+        };
+        client.subscriptions.write().await.insert(subscription.id.clone(), subscription);
+
+        // No live backend to resubscribe through; should log and move on rather than panic
+        client.resubscribe_all().await;
+
+        assert!(client.subscription_server_ids.read().await.is_empty());
+    }
+
+    #[test]
+    fn test_decode_subscription_event_routes_new_heads() {
+        let payload = serde_json::json!({
+            "hash": "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef",
+            "parent_hash": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "number": 100,
+            "timestamp": 1700000000,
+            "gas_limit": "30000000",
+            "gas_used": "15000000",
+            "difficulty": "0",
+            "miner": "0x0000000000000000000000000000000000000000",
+            "extra_data": "0x",
+            "transaction_count": 5,
+        });
+
+        let event = decode_subscription_event(&SubscriptionType::NewHeads, payload).unwrap();
+        assert!(matches!(event, WSEvent::NewBlock { .. }));
+    }
+
+    #[test]
+    fn test_decode_subscription_event_routes_fee_history() {
+        let payload = serde_json::json!({
+            "network": "local",
+            "base_fee_per_gas": ["1000000000"],
+            "gas_used_ratio": [0.5],
+            "priority_fee_percentiles": {},
+            "trend": "stable",
+            "timestamp": 1700000000,
+        });
+
+        let event = decode_subscription_event(&SubscriptionType::FeeHistory, payload).unwrap();
+        assert!(matches!(event, WSEvent::FeeHistory { .. }));
+    }
+
+    #[test]
+    fn test_decode_subscription_event_rejects_malformed_payload() {
+        let payload = serde_json::json!({ "unexpected": "shape" });
+        assert!(decode_subscription_event(&SubscriptionType::NewHeads, payload).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_logs_query_before_connect_fails() {
+        use crate::types::{Condition, ConditionOp, SubscriptionQuery};
+
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let query = SubscriptionQuery::new().and(Condition::new("address", ConditionOp::Eq, "0xabc".to_string()));
+        let result = client.subscribe_logs_query(query).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resubscribe_one_uses_cached_query_for_logs_filter() {
+        use crate::types::{Condition, ConditionOp, SubscriptionQuery};
+
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let query = SubscriptionQuery::new().and(Condition::new("address", ConditionOp::Eq, "0xabc".to_string()));
+        let subscription = WSSubscription {
+            id: "sub_00000001".to_string(),
+            subscription_type: SubscriptionType::Logs,
+            params: SubscriptionParams { filter: None, include_details: false, limit: None },
+            active: true,
+            created_at: 0,
+        };
+        client.subscriptions.write().await.insert(subscription.id.clone(), subscription.clone());
+        client.queries.write().await.insert(subscription.id.clone(), query);
+
+        // No live backend to resubscribe through; should fail on send_request
+        // rather than panic, and must not drop the cached query as a side effect.
+        let result = client.resubscribe_one(&subscription).await;
+        assert!(result.is_err());
+        assert!(client.queries.read().await.contains_key(&subscription.id));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_stream_before_connect_fails() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let result = client.subscribe_stream(SubscriptionType::NewHeads, SubscriptionParams {
+            filter: None,
+            include_details: false,
+            limit: None,
+        }).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_typed_before_connect_fails() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let result = client.subscribe_typed::<NewBlockHeader>(SubscriptionType::NewHeads, SubscriptionParams {
+            filter: None,
+            include_details: false,
+            limit: None,
+        }).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_ws_event_narrows_to_matching_payload_only() {
+        let payload = serde_json::json!({
+            "hash": "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef",
+            "parent_hash": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "number": 100,
+            "timestamp": 1700000000,
+            "gas_limit": "30000000",
+            "gas_used": "15000000",
+            "difficulty": "0",
+            "miner": "0x0000000000000000000000000000000000000000",
+            "extra_data": "0x",
+            "transaction_count": 5,
+        });
+        let event = decode_subscription_event(&SubscriptionType::NewHeads, payload).unwrap();
+
+        assert!(matches!(event, WSEvent::NewBlock { .. }));
+        let WSEvent::NewBlock { header } = event else { unreachable!() };
+        assert!(NewBlockHeader::from_ws_event(WSEvent::NewBlock { header }).is_some());
+        assert!(NewBlockHeader::from_ws_event(WSEvent::Resubscribed { id: "sub_1".to_string() }).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscription_stream_is_a_stream() {
+        use futures_util::StreamExt;
+
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+        let (event_tx, event_rx) = mpsc::channel(8);
+
+        let mut subscription = Subscription {
+            id: "sub_00000001".to_string(),
+            info: WSSubscription {
+                id: "sub_00000001".to_string(),
+                subscription_type: SubscriptionType::NewHeads,
+                params: SubscriptionParams { filter: None, include_details: false, limit: None },
+                active: true,
+                created_at: 0,
+            },
+            receiver: event_rx,
+            client,
+            torn_down: true, // avoid spawning a best-effort unsubscribe on drop in this test
+        };
+
+        event_tx.try_send(WSEvent::Connected).unwrap();
+        drop(event_tx);
+
+        assert!(matches!(subscription.next().await, Some(WSEvent::Connected)));
+        assert!(subscription.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscription_unsubscribe_consumes_handle_without_backend() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+        let (_event_tx, event_rx) = mpsc::channel(8);
+
+        let subscription = Subscription {
+            id: "sub_00000001".to_string(),
+            info: WSSubscription {
+                id: "sub_00000001".to_string(),
+                subscription_type: SubscriptionType::NewHeads,
+                params: SubscriptionParams { filter: None, include_details: false, limit: None },
+                active: true,
+                created_at: 0,
+            },
+            receiver: event_rx,
+            client,
+            torn_down: false,
+        };
+
+        // No real subscription was ever registered, so the underlying
+        // unsubscribe errors — but it must be a clean `Result`, not a panic.
+        assert!(subscription.unsubscribe().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_gas_estimate_reflects_observed_transactions() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        assert_eq!(client.gas_estimate(50.0).await, None);
+
+        for price in [10u128, 20, 30] {
+            client.gas_estimator.observe_price(price).await;
+        }
+
+        assert_eq!(client.gas_estimate(50.0).await, Some(20));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_request_without_connection_leaves_entry_pending() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let (resp, _resp_rx) = oneshot::channel();
+        let payload = serde_json::json!({"id": 1});
+        client.pending_requests.write().await.insert(1, PendingRequest { payload: payload.clone(), resp });
+
+        client.dispatch_pending_request(1, payload).await;
+
+        // No live backend to dispatch onto; the entry must survive so a
+        // later reconnect can redispatch it rather than losing the request.
+        assert!(client.pending_requests.read().await.contains_key(&1));
+    }
+
+    #[tokio::test]
+    async fn test_redispatch_pending_requests_without_connection_is_a_noop() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let (resp, _resp_rx) = oneshot::channel();
+        client.pending_requests.write().await.insert(1, PendingRequest {
+            payload: serde_json::json!({"id": 1}),
+            resp,
+        });
+
+        client.redispatch_pending_requests().await;
+
+        assert!(client.pending_requests.read().await.contains_key(&1));
+    }
+
+    #[tokio::test]
+    async fn test_fail_all_pending_requests_resolves_with_error() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let (resp, resp_rx) = oneshot::channel();
+        client.pending_requests.write().await.insert(1, PendingRequest {
+            payload: serde_json::json!({"id": 1}),
+            resp,
+        });
+
+        client.fail_all_pending_requests("test failure").await;
+
+        assert!(client.pending_requests.read().await.is_empty());
+        assert!(resp_rx.await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_fails_pending_requests_instead_of_hanging() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        let (resp, resp_rx) = oneshot::channel();
+        client.pending_requests.write().await.insert(1, PendingRequest {
+            payload: serde_json::json!({"id": 1}),
+            resp,
+        });
+
+        client.disconnect().await.unwrap();
+
+        assert!(resp_rx.await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_set_plugin_system_is_visible_to_subsequent_calls() {
+        use crate::plugins::PluginSystem;
+
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+        assert!(client.plugin_system.read().await.is_none());
+
+        let plugin_system = Arc::new(PluginSystem::new().await.unwrap());
+        client.set_plugin_system(Arc::clone(&plugin_system)).await;
+
+        assert!(client.plugin_system.read().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_new_heads_before_connect_fails() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        assert!(client.subscribe_new_heads().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_pending_transactions_before_connect_fails() {
+        let config = WebSocketClientConfig::new(Network::Local);
+        let client = WebSocketClient::new(config).await.unwrap();
+
+        assert!(client.subscribe_pending_transactions().await.is_err());
+    }
+
+    #[test]
+    fn test_hyperevm_block_from_ws_event_narrows_new_block_only() {
+        let header = NewBlockHeader {
+            hash: crate::types::Hash("0xaaaa".to_string()),
+            parent_hash: crate::types::Hash("0xbbbb".to_string()),
+            number: 7,
+            timestamp: 1700000000,
+            gas_limit: "30000000".to_string(),
+            gas_used: "1000".to_string(),
+            difficulty: "0".to_string(),
+            miner: crate::types::Address("0xminer".to_string()),
+            extra_data: "0x".to_string(),
+            transaction_count: 0,
+        };
+
+        let block = crate::types::HyperEVMBlock::from_ws_event(WSEvent::NewBlock { header });
+        assert_eq!(block.unwrap().number, 7);
+        assert!(crate::types::HyperEVMBlock::from_ws_event(WSEvent::Resubscribed { id: "sub_1".to_string() }).is_none());
+    }
+
+    #[test]
+    fn test_hash_from_ws_event_narrows_new_transaction_only() {
+        let transaction = NewTransaction {
+            hash: crate::types::Hash("0xdeadbeef".to_string()),
+            from: crate::types::Address("0xfrom".to_string()),
+            to: None,
+            value: "0".to_string(),
+            gas_price: "1".to_string(),
+            gas_limit: "21000".to_string(),
+            nonce: 0,
+            input: "0x".to_string(),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+        };
+
+        let hash = crate::types::Hash::from_ws_event(WSEvent::NewTransaction { transaction });
+        assert_eq!(hash.unwrap().0, "0xdeadbeef");
+        assert!(crate::types::Hash::from_ws_event(WSEvent::Resubscribed { id: "sub_1".to_string() }).is_none());
+    }
 }
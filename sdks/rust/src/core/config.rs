@@ -1,9 +1,13 @@
 //! Configuration types and builders for HyperSim SDK
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
-use crate::types::{Network, SDKOptions};
+use crate::types::{Network, SDKOptions, StalenessGuard, TransportConfig};
 use crate::plugins::PluginConfig;
 use crate::error::{HyperSimError, Result};
+use crate::security::SecurityConfig;
+use crate::cache::CacheBackend;
 
 /// Main configuration for HyperSim SDK
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +20,9 @@ pub struct HyperSimConfig {
     openai_api_key: Option<String>,
     /// Custom RPC endpoint (overrides default)
     rpc_endpoint: Option<String>,
+    /// Pool of RPC endpoints to dispatch queries across with failover.
+    /// Takes priority over `rpc_endpoint` when non-empty.
+    rpc_endpoints: Vec<String>,
     /// Request timeout in milliseconds
     timeout_ms: u64,
     /// Enable cross-layer HyperCore integration
@@ -34,12 +41,62 @@ pub struct HyperSimConfig {
     max_connections: u32,
     /// Connection pool configuration
     connection_pool_enabled: bool,
-    /// Enable request caching
-    cache_enabled: bool,
+    /// Where cached simulation/RPC results are stored
+    cache_backend: CacheBackend,
     /// Cache TTL in seconds
     cache_ttl_secs: u64,
     /// Enable metrics collection
     metrics_enabled: bool,
+    /// Verify simulation state trustlessly via Merkle proofs and a
+    /// consensus-verified header chain instead of trusting RPC responses
+    trustless: bool,
+    /// Base64 SHA-256 SPKI pins enforced for every RPC host, unless
+    /// overridden per-host via `certificate_pins_by_host`
+    certificate_pins: Vec<String>,
+    /// Per-host certificate pin overrides, e.g. distinct pins for the
+    /// HyperCore and HyperEVM endpoints
+    certificate_pins_by_host: HashMap<String, Vec<String>>,
+    /// Track per-endpoint EWMA latency and failure rate so the RPC selector
+    /// can steer traffic to the fastest healthy node
+    latency_tracking_enabled: bool,
+    /// Socket-level transport tuning for outbound connections
+    transport: TransportConfig,
+    /// Operator-supplied static list of candidate endpoints, probed and
+    /// merged into the RPC pool once every configured endpoint is unhealthy
+    fallback_endpoints: Vec<String>,
+    /// Also fetch and probe the published candidate list at `fallback_url`
+    load_external_fallback: bool,
+    /// URL serving a JSON document of fallback candidate endpoints, e.g.
+    /// `{"endpoints": ["https://...", "https://..."]}`
+    fallback_url: Option<String>,
+    /// Exclude RPC endpoints whose reported chain state is too stale to
+    /// trust, the same way an open circuit is excluded
+    staleness_guard_enabled: bool,
+    /// Maximum tolerated gap between an endpoint's known block height and
+    /// the highest height known across the pool
+    max_block_lag: u64,
+    /// Maximum tolerated age, in seconds, of an endpoint's last known block
+    max_block_age_secs: u64,
+    /// Maximum number of retries for a `simulate()` call that fails with
+    /// what looks like a transient rate-limit error from the RPC endpoint
+    rate_limit_max_retries: u32,
+    /// Exponential backoff multiplier applied between rate-limit retries
+    /// when only one RPC endpoint is configured (with more than one, a
+    /// retry routes to a different endpoint immediately instead of waiting)
+    rate_limit_backoff_multiplier: f64,
+    /// Case-insensitive substrings that mark an RPC error message as a
+    /// transient rate limit rather than a real failure
+    rate_limit_markers: Vec<String>,
+    /// Maximum tolerated block-height lag behind the quorum-agreed consensus
+    /// head before the consensus finder marks an endpoint unhealthy
+    consensus_lag_threshold: u64,
+    /// Maximum number of simultaneously active WebSocket subscriptions;
+    /// `subscribe()` is rejected once this many are outstanding
+    max_active_subscriptions: usize,
+    /// Capacity of each subscription's event queue, in buffered
+    /// notifications, before incoming notifications are dropped rather than
+    /// buffered further
+    subscription_queue_capacity: usize,
 }
 
 impl HyperSimConfig {
@@ -70,6 +127,12 @@ impl HyperSimConfig {
             .unwrap_or_else(|| self.network.default_rpc_endpoint())
     }
 
+    /// Get the configured pool of RPC endpoints for failover dispatch.
+    /// Empty unless [`HyperSimConfigBuilder::rpc_endpoints`] was used.
+    pub fn rpc_endpoints(&self) -> &[String] {
+        &self.rpc_endpoints
+    }
+
     /// Get the request timeout in milliseconds
     pub fn timeout_ms(&self) -> u64 {
         self.timeout_ms
@@ -117,9 +180,14 @@ impl HyperSimConfig {
         self.connection_pool_enabled
     }
 
-    /// Check if caching is enabled
+    /// Get the configured cache backend
+    pub fn cache_backend(&self) -> &CacheBackend {
+        &self.cache_backend
+    }
+
+    /// Check if caching is enabled (i.e. the backend is not [`CacheBackend::Disabled`])
     pub fn cache_enabled(&self) -> bool {
-        self.cache_enabled
+        self.cache_backend.is_enabled()
     }
 
     /// Get cache TTL in seconds
@@ -132,6 +200,123 @@ impl HyperSimConfig {
         self.metrics_enabled
     }
 
+    /// Check if trustless verification of simulation state is enabled
+    pub fn trustless(&self) -> bool {
+        self.trustless
+    }
+
+    /// Get the global certificate pins applied to any host without a
+    /// per-host override
+    pub fn certificate_pins(&self) -> &[String] {
+        &self.certificate_pins
+    }
+
+    /// Get the per-host certificate pin overrides
+    pub fn certificate_pins_by_host(&self) -> &HashMap<String, Vec<String>> {
+        &self.certificate_pins_by_host
+    }
+
+    /// Check if per-endpoint EWMA latency tracking is enabled
+    pub fn latency_tracking_enabled(&self) -> bool {
+        self.latency_tracking_enabled
+    }
+
+    /// Get the socket-level transport tuning for outbound connections
+    pub fn transport(&self) -> &TransportConfig {
+        &self.transport
+    }
+
+    /// Get the operator-supplied static fallback endpoint list
+    pub fn fallback_endpoints(&self) -> &[String] {
+        &self.fallback_endpoints
+    }
+
+    /// Check whether the externally-published fallback endpoint list is loaded
+    pub fn load_external_fallback(&self) -> bool {
+        self.load_external_fallback
+    }
+
+    /// Get the URL serving the externally-published fallback endpoint list
+    pub fn fallback_url(&self) -> Option<&str> {
+        self.fallback_url.as_deref()
+    }
+
+    /// Check whether the block-sync staleness guard is enabled
+    pub fn staleness_guard_enabled(&self) -> bool {
+        self.staleness_guard_enabled
+    }
+
+    /// Get the maximum tolerated block-height lag behind the pool's highest
+    /// known height
+    pub fn max_block_lag(&self) -> u64 {
+        self.max_block_lag
+    }
+
+    /// Get the maximum tolerated age, in seconds, of an endpoint's last known
+    /// block
+    pub fn max_block_age_secs(&self) -> u64 {
+        self.max_block_age_secs
+    }
+
+    /// Get the maximum number of rate-limit retries for a `simulate()` call
+    pub fn rate_limit_max_retries(&self) -> u32 {
+        self.rate_limit_max_retries
+    }
+
+    /// Get the backoff multiplier applied between single-endpoint rate-limit retries
+    pub fn rate_limit_backoff_multiplier(&self) -> f64 {
+        self.rate_limit_backoff_multiplier
+    }
+
+    /// Get the case-insensitive substrings that mark an RPC error as a
+    /// transient rate limit
+    pub fn rate_limit_markers(&self) -> &[String] {
+        &self.rate_limit_markers
+    }
+
+    /// Get the maximum tolerated block-height lag behind the consensus head
+    /// before the consensus finder marks an endpoint unhealthy
+    pub fn consensus_lag_threshold(&self) -> u64 {
+        self.consensus_lag_threshold
+    }
+
+    /// Get the maximum number of simultaneously active WebSocket subscriptions
+    pub fn max_active_subscriptions(&self) -> usize {
+        self.max_active_subscriptions
+    }
+
+    /// Get the per-subscription event queue capacity, in buffered notifications
+    pub fn subscription_queue_capacity(&self) -> usize {
+        self.subscription_queue_capacity
+    }
+
+    /// Build the [`StalenessGuard`] the RPC selector should enforce, or
+    /// `None` when the guard is disabled
+    pub fn staleness_guard(&self) -> Option<StalenessGuard> {
+        if !self.staleness_guard_enabled {
+            return None;
+        }
+
+        Some(StalenessGuard {
+            max_block_lag: self.max_block_lag,
+            max_block_age_secs: self.max_block_age_secs,
+        })
+    }
+
+    /// Build the [`SecurityConfig`] clients should enforce, or `None` when no
+    /// pins are configured at all (pinning is opt-in)
+    pub fn security_config(&self) -> Option<SecurityConfig> {
+        if self.certificate_pins.is_empty() && self.certificate_pins_by_host.is_empty() {
+            return None;
+        }
+
+        Some(SecurityConfig {
+            certificate_pins: self.certificate_pins.clone(),
+            certificate_pins_by_host: self.certificate_pins_by_host.clone(),
+            ..SecurityConfig::default()
+        })
+    }
+
     /// Get the HyperCore endpoint
     pub fn hypercore_endpoint(&self) -> &str {
         self.network.hypercore_endpoint()
@@ -169,28 +354,101 @@ impl HyperSimConfig {
             return Err(HyperSimError::configuration("Max connections cannot exceed 1000"));
         }
 
-        // Validate cache TTL
-        if self.cache_enabled && self.cache_ttl_secs == 0 {
+        // Validate cache configuration
+        self.cache_backend.validate()?;
+        if self.cache_backend.is_enabled() && self.cache_ttl_secs == 0 {
             return Err(HyperSimError::configuration("Cache TTL must be greater than 0 when caching is enabled"));
         }
 
-        // Validate custom endpoints if provided
+        // Validate custom endpoints if provided. `ipc://`/`unix:` (Unix domain
+        // socket) endpoints are only accepted for `Network::Local`, where
+        // same-machine IPC makes sense.
+        let allow_uds = self.network == Network::Local;
+
         if let Some(ref rpc_endpoint) = self.rpc_endpoint {
-            if !rpc_endpoint.starts_with("http://") && !rpc_endpoint.starts_with("https://") {
-                return Err(HyperSimError::configuration("RPC endpoint must use HTTP or HTTPS"));
+            if !rpc_endpoint.starts_with("http://") && !rpc_endpoint.starts_with("https://")
+                && !(allow_uds && crate::clients::EndpointAddress::is_unix_scheme(rpc_endpoint))
+            {
+                return Err(HyperSimError::configuration(
+                    "RPC endpoint must use HTTP or HTTPS (or ipc:// or unix: on Network::Local)"
+                ));
+            }
+        }
+
+        for rpc_endpoint in &self.rpc_endpoints {
+            if !rpc_endpoint.starts_with("http://") && !rpc_endpoint.starts_with("https://")
+                && !(allow_uds && crate::clients::EndpointAddress::is_unix_scheme(rpc_endpoint))
+            {
+                return Err(HyperSimError::configuration(
+                    "Every pooled RPC endpoint must use HTTP or HTTPS (or ipc:// or unix: on Network::Local)"
+                ));
             }
         }
 
         if let Some(ref ws_endpoint) = self.ws_endpoint {
-            if !ws_endpoint.starts_with("ws://") && !ws_endpoint.starts_with("wss://") {
-                return Err(HyperSimError::configuration("WebSocket endpoint must use WS or WSS"));
+            if !ws_endpoint.starts_with("ws://") && !ws_endpoint.starts_with("wss://")
+                && !(allow_uds && crate::clients::EndpointAddress::is_unix_scheme(ws_endpoint))
+            {
+                return Err(HyperSimError::configuration(
+                    "WebSocket endpoint must use WS or WSS (or ipc:// or unix: on Network::Local)"
+                ));
+            }
+        }
+
+        self.transport.validate()?;
+
+        if self.load_external_fallback && self.fallback_url.is_none() {
+            return Err(HyperSimError::configuration(
+                "fallback_url is required when load_external_fallback is enabled"
+            ));
+        }
+
+        if let Some(ref fallback_url) = self.fallback_url {
+            if !fallback_url.starts_with("http://") && !fallback_url.starts_with("https://") {
+                return Err(HyperSimError::configuration(
+                    "fallback_url must use HTTP or HTTPS"
+                ));
+            }
+        }
+
+        for fallback_endpoint in &self.fallback_endpoints {
+            if !fallback_endpoint.starts_with("http://") && !fallback_endpoint.starts_with("https://") {
+                return Err(HyperSimError::configuration(
+                    "Every fallback endpoint must use HTTP or HTTPS"
+                ));
             }
         }
 
+        if self.staleness_guard_enabled && self.max_block_age_secs == 0 {
+            return Err(HyperSimError::configuration(
+                "max_block_age_secs must be greater than 0 when the staleness guard is enabled"
+            ));
+        }
+
+        if self.rate_limit_backoff_multiplier < 1.0 {
+            return Err(HyperSimError::configuration(
+                "rate_limit_backoff_multiplier must be at least 1.0"
+            ));
+        }
+
         Ok(())
     }
 }
 
+/// Default case-insensitive substrings used to recognize a rate-limit error.
+/// Deliberately avoids bare words like "limit" or "exceeded" that show up in
+/// unrelated errors (e.g. "result length exceeding limit" is a response-size
+/// error, not a rate limit).
+fn default_rate_limit_markers() -> Vec<String> {
+    vec![
+        "rate limit".to_string(),
+        "too many requests".to_string(),
+        "quota usage".to_string(),
+        "quota exceeded".to_string(),
+        "429".to_string(),
+    ]
+}
+
 /// Builder for HyperSimConfig with fluent API
 #[derive(Debug)]
 pub struct HyperSimConfigBuilder {
@@ -198,6 +456,7 @@ pub struct HyperSimConfigBuilder {
     ai_enabled: bool,
     openai_api_key: Option<String>,
     rpc_endpoint: Option<String>,
+    rpc_endpoints: Vec<String>,
     timeout_ms: u64,
     cross_layer_enabled: bool,
     streaming_enabled: bool,
@@ -207,9 +466,26 @@ pub struct HyperSimConfigBuilder {
     sdk_options: SDKOptions,
     max_connections: u32,
     connection_pool_enabled: bool,
-    cache_enabled: bool,
+    cache_backend: CacheBackend,
     cache_ttl_secs: u64,
     metrics_enabled: bool,
+    trustless: bool,
+    certificate_pins: Vec<String>,
+    certificate_pins_by_host: HashMap<String, Vec<String>>,
+    latency_tracking_enabled: bool,
+    transport: TransportConfig,
+    fallback_endpoints: Vec<String>,
+    load_external_fallback: bool,
+    fallback_url: Option<String>,
+    staleness_guard_enabled: bool,
+    max_block_lag: u64,
+    max_block_age_secs: u64,
+    rate_limit_max_retries: u32,
+    rate_limit_backoff_multiplier: f64,
+    rate_limit_markers: Vec<String>,
+    consensus_lag_threshold: u64,
+    max_active_subscriptions: usize,
+    subscription_queue_capacity: usize,
 }
 
 impl HyperSimConfigBuilder {
@@ -220,6 +496,7 @@ impl HyperSimConfigBuilder {
             ai_enabled: false,
             openai_api_key: None,
             rpc_endpoint: None,
+            rpc_endpoints: Vec::new(),
             timeout_ms: 30000,
             cross_layer_enabled: true,
             streaming_enabled: false,
@@ -229,9 +506,26 @@ impl HyperSimConfigBuilder {
             sdk_options: SDKOptions::default(),
             max_connections: 10,
             connection_pool_enabled: true,
-            cache_enabled: true,
+            cache_backend: CacheBackend::default(),
             cache_ttl_secs: 300,
             metrics_enabled: true,
+            trustless: false,
+            certificate_pins: Vec::new(),
+            certificate_pins_by_host: HashMap::new(),
+            latency_tracking_enabled: false,
+            transport: TransportConfig::default(),
+            fallback_endpoints: Vec::new(),
+            load_external_fallback: false,
+            fallback_url: None,
+            staleness_guard_enabled: false,
+            max_block_lag: 10,
+            max_block_age_secs: 120,
+            rate_limit_max_retries: 3,
+            rate_limit_backoff_multiplier: 2.0,
+            rate_limit_markers: default_rate_limit_markers(),
+            consensus_lag_threshold: 3,
+            max_active_subscriptions: 256,
+            subscription_queue_capacity: 1024,
         }
     }
 
@@ -259,6 +553,13 @@ impl HyperSimConfigBuilder {
         self
     }
 
+    /// Configure a pool of RPC endpoints to dispatch queries across with
+    /// failover, instead of a single `rpc_endpoint`
+    pub fn rpc_endpoints(mut self, endpoints: Vec<String>) -> Self {
+        self.rpc_endpoints = endpoints;
+        self
+    }
+
     /// Set the request timeout in milliseconds
     pub fn timeout_ms(mut self, timeout: u64) -> Self {
         self.timeout_ms = timeout;
@@ -319,9 +620,16 @@ impl HyperSimConfigBuilder {
         self
     }
 
-    /// Enable or disable caching
+    /// Set the cache backend (in-memory, Redis, or disabled)
+    pub fn cache_backend(mut self, backend: CacheBackend) -> Self {
+        self.cache_backend = backend;
+        self
+    }
+
+    /// Enable or disable caching: `false` selects [`CacheBackend::Disabled`],
+    /// `true` replaces the configured backend with the default in-memory one
     pub fn cache_enabled(mut self, enabled: bool) -> Self {
-        self.cache_enabled = enabled;
+        self.cache_backend = if enabled { CacheBackend::default() } else { CacheBackend::Disabled };
         self
     }
 
@@ -337,6 +645,122 @@ impl HyperSimConfigBuilder {
         self
     }
 
+    /// Enable trustless verification of simulation state via Merkle proofs
+    /// and a consensus-verified header chain, instead of trusting RPC
+    /// responses outright
+    pub fn trustless(mut self, enabled: bool) -> Self {
+        self.trustless = enabled;
+        self
+    }
+
+    /// Set the base64 SHA-256 SPKI pins enforced for every RPC host that has
+    /// no per-host override
+    pub fn certificate_pins(mut self, pins: Vec<String>) -> Self {
+        self.certificate_pins = pins;
+        self
+    }
+
+    /// Pin a specific host to its own set of base64 SHA-256 SPKI pins,
+    /// overriding the global `certificate_pins` for that host
+    pub fn certificate_pins_for_host(mut self, host: impl Into<String>, pins: Vec<String>) -> Self {
+        self.certificate_pins_by_host.insert(host.into(), pins);
+        self
+    }
+
+    /// Enable per-endpoint EWMA latency tracking so the RPC selector steers
+    /// traffic toward the fastest healthy node instead of a static default
+    pub fn latency_tracking_enabled(mut self, enabled: bool) -> Self {
+        self.latency_tracking_enabled = enabled;
+        self
+    }
+
+    /// Set socket-level transport tuning for outbound connections
+    pub fn transport(mut self, transport: TransportConfig) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Set a static list of candidate endpoints to probe and merge into the
+    /// RPC pool once every configured endpoint is unhealthy
+    pub fn fallback_endpoints(mut self, endpoints: Vec<String>) -> Self {
+        self.fallback_endpoints = endpoints;
+        self
+    }
+
+    /// Enable or disable fetching the externally-published fallback endpoint
+    /// list at `fallback_url`
+    pub fn load_external_fallback(mut self, enabled: bool) -> Self {
+        self.load_external_fallback = enabled;
+        self
+    }
+
+    /// Set the URL serving a JSON document of fallback candidate endpoints
+    pub fn fallback_url(mut self, url: impl Into<String>) -> Self {
+        self.fallback_url = Some(url.into());
+        self
+    }
+
+    /// Enable or disable the block-sync staleness guard
+    pub fn staleness_guard_enabled(mut self, enabled: bool) -> Self {
+        self.staleness_guard_enabled = enabled;
+        self
+    }
+
+    /// Set the maximum tolerated block-height lag behind the pool's highest
+    /// known height
+    pub fn max_block_lag(mut self, max_block_lag: u64) -> Self {
+        self.max_block_lag = max_block_lag;
+        self
+    }
+
+    /// Set the maximum tolerated age, in seconds, of an endpoint's last
+    /// known block
+    pub fn max_block_age_secs(mut self, max_block_age_secs: u64) -> Self {
+        self.max_block_age_secs = max_block_age_secs;
+        self
+    }
+
+    /// Set the maximum number of retries for a `simulate()` call that fails
+    /// with what looks like a transient rate-limit error
+    pub fn rate_limit_max_retries(mut self, max_retries: u32) -> Self {
+        self.rate_limit_max_retries = max_retries;
+        self
+    }
+
+    /// Set the backoff multiplier applied between rate-limit retries when
+    /// only one RPC endpoint is configured
+    pub fn rate_limit_backoff_multiplier(mut self, multiplier: f64) -> Self {
+        self.rate_limit_backoff_multiplier = multiplier;
+        self
+    }
+
+    /// Set the case-insensitive substrings that mark an RPC error message as
+    /// a transient rate limit rather than a real failure, replacing the
+    /// built-in defaults
+    pub fn rate_limit_markers(mut self, markers: Vec<String>) -> Self {
+        self.rate_limit_markers = markers;
+        self
+    }
+
+    /// Set the maximum tolerated block-height lag behind the consensus head
+    /// before the consensus finder marks an endpoint unhealthy
+    pub fn consensus_lag_threshold(mut self, threshold: u64) -> Self {
+        self.consensus_lag_threshold = threshold;
+        self
+    }
+
+    /// Set the maximum number of simultaneously active WebSocket subscriptions
+    pub fn max_active_subscriptions(mut self, max: usize) -> Self {
+        self.max_active_subscriptions = max;
+        self
+    }
+
+    /// Set the per-subscription event queue capacity, in buffered notifications
+    pub fn subscription_queue_capacity(mut self, capacity: usize) -> Self {
+        self.subscription_queue_capacity = capacity;
+        self
+    }
+
     /// Build the configuration
     pub fn build(self) -> Result<HyperSimConfig> {
         let network = self.network
@@ -347,6 +771,7 @@ impl HyperSimConfigBuilder {
             ai_enabled: self.ai_enabled,
             openai_api_key: self.openai_api_key,
             rpc_endpoint: self.rpc_endpoint,
+            rpc_endpoints: self.rpc_endpoints,
             timeout_ms: self.timeout_ms,
             cross_layer_enabled: self.cross_layer_enabled,
             streaming_enabled: self.streaming_enabled,
@@ -356,9 +781,26 @@ impl HyperSimConfigBuilder {
             sdk_options: self.sdk_options,
             max_connections: self.max_connections,
             connection_pool_enabled: self.connection_pool_enabled,
-            cache_enabled: self.cache_enabled,
+            cache_backend: self.cache_backend,
             cache_ttl_secs: self.cache_ttl_secs,
             metrics_enabled: self.metrics_enabled,
+            trustless: self.trustless,
+            certificate_pins: self.certificate_pins,
+            certificate_pins_by_host: self.certificate_pins_by_host,
+            latency_tracking_enabled: self.latency_tracking_enabled,
+            transport: self.transport,
+            fallback_endpoints: self.fallback_endpoints,
+            load_external_fallback: self.load_external_fallback,
+            fallback_url: self.fallback_url,
+            staleness_guard_enabled: self.staleness_guard_enabled,
+            max_block_lag: self.max_block_lag,
+            max_block_age_secs: self.max_block_age_secs,
+            rate_limit_max_retries: self.rate_limit_max_retries,
+            rate_limit_backoff_multiplier: self.rate_limit_backoff_multiplier,
+            rate_limit_markers: self.rate_limit_markers,
+            consensus_lag_threshold: self.consensus_lag_threshold,
+            max_active_subscriptions: self.max_active_subscriptions,
+            subscription_queue_capacity: self.subscription_queue_capacity,
         };
 
         // Validate the configuration
@@ -429,6 +871,153 @@ mod tests {
         assert_eq!(config.hypercore_endpoint(), "https://hypercore-mainnet.hyperevm.com");
     }
 
+    #[test]
+    fn test_rpc_endpoint_pool_validation() {
+        let result = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .rpc_endpoints(vec!["not-a-url".to_string()])
+            .build();
+        assert!(result.is_err());
+
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .rpc_endpoints(vec![
+                "https://one.example.com".to_string(),
+                "https://two.example.com".to_string(),
+            ])
+            .build()
+            .expect("Should build valid config");
+
+        assert_eq!(config.rpc_endpoints().len(), 2);
+    }
+
+    #[test]
+    fn test_trustless_defaults_to_disabled() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(!config.trustless());
+
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .trustless(true)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(config.trustless());
+    }
+
+    #[test]
+    fn test_certificate_pins_default_to_unset() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(config.certificate_pins().is_empty());
+        assert!(config.certificate_pins_by_host().is_empty());
+        assert!(config.security_config().is_none());
+    }
+
+    #[test]
+    fn test_security_config_built_from_pins() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .certificate_pins(vec!["global-pin".to_string()])
+            .certificate_pins_for_host("hypercore.example.com", vec!["hypercore-pin".to_string()])
+            .build()
+            .expect("Should build valid config");
+
+        let security = config.security_config().expect("Pins configured, so a SecurityConfig should be built");
+        assert_eq!(security.certificate_pins, vec!["global-pin".to_string()]);
+        assert_eq!(
+            security.certificate_pins_by_host.get("hypercore.example.com"),
+            Some(&vec!["hypercore-pin".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_latency_tracking_defaults_to_disabled() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(!config.latency_tracking_enabled());
+
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .latency_tracking_enabled(true)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(config.latency_tracking_enabled());
+    }
+
+    #[test]
+    fn test_transport_defaults_to_keep_alive_and_nodelay() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(config.transport().keep_alive_enabled);
+        assert!(config.transport().tcp_nodelay);
+    }
+
+    #[test]
+    fn test_transport_rejects_zero_keep_alive_interval_when_enabled() {
+        let result = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .transport(crate::types::TransportConfig {
+                keep_alive_enabled: true,
+                keep_alive_interval_secs: 0,
+                ..Default::default()
+            })
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cache_backend_defaults_to_bounded_in_memory() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(config.cache_enabled());
+        assert_eq!(config.cache_backend(), &crate::cache::CacheBackend::InMemory { max_entries: 1000 });
+    }
+
+    #[test]
+    fn test_cache_enabled_false_disables_backend() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .cache_enabled(false)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(!config.cache_enabled());
+        assert_eq!(config.cache_backend(), &crate::cache::CacheBackend::Disabled);
+    }
+
+    #[test]
+    fn test_cache_backend_rejects_invalid_redis_url() {
+        let result = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .cache_backend(crate::cache::CacheBackend::Redis {
+                url: "not-a-redis-url".to_string(),
+                pool_size: 4,
+                key_prefix: "hypersim".to_string(),
+            })
+            .build();
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_custom_endpoints() {
         let config = HyperSimConfig::builder()
@@ -441,4 +1030,152 @@ mod tests {
         assert_eq!(config.rpc_endpoint(), "http://localhost:8545");
         assert_eq!(config.ws_endpoint(), "ws://localhost:8546");
     }
+
+    #[test]
+    fn test_uds_endpoint_accepted_on_local_network() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Local)
+            .rpc_endpoint("ipc:///tmp/hyperevm.sock")
+            .build();
+
+        assert!(config.is_ok());
+    }
+
+    #[test]
+    fn test_uds_endpoint_rejected_off_local_network() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .rpc_endpoint("ipc:///tmp/hyperevm.sock")
+            .build();
+
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn test_fallback_discovery_defaults_to_disabled() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(config.fallback_endpoints().is_empty());
+        assert!(!config.load_external_fallback());
+        assert!(config.fallback_url().is_none());
+    }
+
+    #[test]
+    fn test_fallback_endpoints_configured() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .fallback_endpoints(vec!["https://fallback.example.com".to_string()])
+            .build()
+            .expect("Should build valid config");
+
+        assert_eq!(config.fallback_endpoints(), &["https://fallback.example.com".to_string()]);
+    }
+
+    #[test]
+    fn test_fallback_endpoints_reject_non_http_scheme() {
+        let result = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .fallback_endpoints(vec!["not-a-url".to_string()])
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_external_fallback_requires_url() {
+        let result = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .load_external_fallback(true)
+            .build();
+
+        assert!(result.is_err());
+
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .load_external_fallback(true)
+            .fallback_url("https://example.com/fallback-endpoints.json")
+            .build()
+            .expect("Should build valid config");
+
+        assert!(config.load_external_fallback());
+        assert_eq!(config.fallback_url(), Some("https://example.com/fallback-endpoints.json"));
+    }
+
+    #[test]
+    fn test_staleness_guard_defaults_to_disabled() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert!(!config.staleness_guard_enabled());
+        assert!(config.staleness_guard().is_none());
+    }
+
+    #[test]
+    fn test_staleness_guard_enabled_builds_policy() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .staleness_guard_enabled(true)
+            .max_block_lag(5)
+            .max_block_age_secs(30)
+            .build()
+            .expect("Should build valid config");
+
+        let guard = config.staleness_guard().expect("Staleness guard should be configured");
+        assert_eq!(guard.max_block_lag, 5);
+        assert_eq!(guard.max_block_age_secs, 30);
+    }
+
+    #[test]
+    fn test_staleness_guard_rejects_zero_max_block_age() {
+        let result = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .staleness_guard_enabled(true)
+            .max_block_age_secs(0)
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_defaults_avoid_bare_limit_and_exceeded() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .build()
+            .expect("Should build valid config");
+
+        assert_eq!(config.rate_limit_max_retries(), 3);
+        assert_eq!(config.rate_limit_backoff_multiplier(), 2.0);
+        assert!(!config.rate_limit_markers().iter().any(|m| m == "limit"));
+        assert!(!config.rate_limit_markers().iter().any(|m| m == "exceeded"));
+    }
+
+    #[test]
+    fn test_rate_limit_settings_are_configurable() {
+        let config = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .rate_limit_max_retries(5)
+            .rate_limit_backoff_multiplier(1.5)
+            .rate_limit_markers(vec!["slow down".to_string()])
+            .build()
+            .expect("Should build valid config");
+
+        assert_eq!(config.rate_limit_max_retries(), 5);
+        assert_eq!(config.rate_limit_backoff_multiplier(), 1.5);
+        assert_eq!(config.rate_limit_markers(), &["slow down".to_string()]);
+    }
+
+    #[test]
+    fn test_rate_limit_backoff_multiplier_must_be_at_least_one() {
+        let result = HyperSimConfig::builder()
+            .network(Network::Testnet)
+            .rate_limit_backoff_multiplier(0.5)
+            .build();
+
+        assert!(result.is_err());
+    }
 }
@@ -1,21 +1,46 @@
 //! Main HyperSim SDK implementation
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
-use tokio::sync::RwLock;
+use hdrhistogram::Histogram;
+use tokio::sync::{watch, RwLock};
 use tracing::{debug, error, info, warn};
 
 use crate::core::HyperSimConfig;
 use crate::clients::{HyperEVMClient, HyperCoreClient, WebSocketClient};
-use crate::plugins::{PluginSystem, Plugin};
+use crate::plugins::{PluginSystem, Plugin, PluginConfig};
 use crate::ai::AIAnalyzer;
 use crate::types::{
     TransactionRequest, SimulationResult, BundleOptimization,
     AIInsights, PerformanceMetrics, NetworkStatus, ConnectionHealth,
     WSSubscription, SubscriptionType, SubscriptionParams,
+    BlockSyncStatus,
 };
 use crate::error::{HyperSimError, Result};
 
+/// Initial backoff before the first single-endpoint rate-limit retry; scaled
+/// by `HyperSimConfig::rate_limit_backoff_multiplier` on each subsequent retry
+const RATE_LIMIT_INITIAL_BACKOFF_MS: u64 = 200;
+
+/// Lower bound tracked by the response-time histogram, in milliseconds
+const RESPONSE_TIME_HISTOGRAM_MIN_MS: u64 = 1;
+/// Upper bound tracked by the response-time histogram, in milliseconds.
+/// Samples are clamped into `[RESPONSE_TIME_HISTOGRAM_MIN_MS, RESPONSE_TIME_HISTOGRAM_MAX_MS]`
+/// before recording, so a request slower than this is recorded as exactly this value.
+const RESPONSE_TIME_HISTOGRAM_MAX_MS: u64 = 1000;
+/// Number of significant decimal digits the response-time histogram
+/// preserves at every magnitude
+const RESPONSE_TIME_HISTOGRAM_SIGFIGS: u8 = 3;
+
+/// A fresh HDR histogram over `request.simulate()`'s observed response
+/// times, reset every 60-second metrics interval so `PerformanceMetrics`
+/// reflects a recent window rather than an all-time aggregate
+fn new_response_time_histogram() -> Histogram<u32> {
+    Histogram::new_with_bounds(RESPONSE_TIME_HISTOGRAM_MIN_MS, RESPONSE_TIME_HISTOGRAM_MAX_MS, RESPONSE_TIME_HISTOGRAM_SIGFIGS)
+        .expect("static histogram bounds are always valid")
+}
+
 /// Main SDK for HyperEVM transaction simulation
 /// 
 /// Provides comprehensive transaction simulation capabilities with:
@@ -50,9 +75,19 @@ pub struct HyperSimSDK {
     
     /// Performance metrics
     metrics: Arc<RwLock<PerformanceMetrics>>,
+
+    /// HDR histogram of response times for the current 60-second metrics
+    /// window; rolled into `metrics.response_time_percentiles` and reset by
+    /// `start_metrics_task`
+    response_time_histogram: Arc<RwLock<Histogram<u32>>>,
     
     /// SDK state
     state: Arc<RwLock<SDKState>>,
+
+    /// Publishes every update the consensus finder makes to the pool's
+    /// agreed-on block-sync status, so callers can `await` the next head
+    /// change instead of polling [`HyperSimSDK::get_connection_health`]
+    block_sync_tx: watch::Sender<BlockSyncStatus>,
 }
 
 /// Internal SDK state
@@ -105,12 +140,20 @@ impl HyperSimSDK {
             crate::types::HyperEVMConfig {
                 network: config.network(),
                 rpc_endpoint: Some(config.rpc_endpoint().to_string()),
+                rpc_endpoints: config.rpc_endpoints().to_vec(),
                 timeout: config.timeout_ms(),
                 max_retries: 3,
                 cache_enabled: config.cache_enabled(),
                 cache_ttl: config.cache_ttl_secs(),
                 api_key: None,
                 debug: config.debug_enabled(),
+                trustless: config.trustless(),
+                security: config.security_config(),
+                transport: config.transport().clone(),
+                fallback_endpoints: config.fallback_endpoints().to_vec(),
+                load_external_fallback: config.load_external_fallback(),
+                fallback_url: config.fallback_url().map(|url| url.to_string()),
+                resilience: crate::types::ResilienceConfig::default(),
             }
         ).await?);
 
@@ -127,6 +170,8 @@ impl HyperSimSDK {
                     max_batch_size: 100,
                     compression: true,
                     debug: config.debug_enabled(),
+                    security: config.security_config(),
+                    transport: config.transport().clone(),
                 }
             ).await?))
         } else {
@@ -147,6 +192,7 @@ impl HyperSimSDK {
                     buffer_size: 1024 * 1024,
                     compression: true,
                     headers: std::collections::HashMap::new(),
+                    queue_capacity_items: config.subscription_queue_capacity(),
                 }
             ).await?))
         } else {
@@ -161,10 +207,31 @@ impl HyperSimSDK {
             plugin_system.load_plugin(plugin_config.clone()).await?;
         }
 
-        // Initialize AI analyzer if enabled
+        // The WebSocket client is constructed before the plugin system
+        // above, so it learns about it here instead of at construction time
+        if let Some(ref ws_client) = websocket_client {
+            ws_client.set_plugin_system(Arc::clone(&plugin_system)).await;
+        }
+
+        // Initialize AI analyzer if enabled. The analysis engine itself is
+        // pluggable: we register an "openai-analysis" provider ahead of the
+        // always-available "heuristic-analysis" one, and the analyzer falls
+        // back down that priority order if the higher-priority provider
+        // errors (e.g. a transient API failure).
         let ai_analyzer = if config.ai_enabled() {
             if let Some(api_key) = config.openai_api_key() {
-                Some(Arc::new(AIAnalyzer::new(api_key.to_string()).await?))
+                plugin_system.load_plugin(
+                    PluginConfig::new("openai-analysis")
+                        .priority(30)
+                        .config_value("api_key", api_key.to_string())
+                ).await?;
+                plugin_system.load_plugin(
+                    PluginConfig::new("heuristic-analysis").priority(90)
+                ).await?;
+
+                let analyzer = Arc::new(AIAnalyzer::new().await?);
+                analyzer.set_plugin_system(Arc::clone(&plugin_system)).await;
+                Some(analyzer)
             } else {
                 warn!("AI features enabled but no API key provided");
                 None
@@ -186,8 +253,14 @@ impl HyperSimSDK {
             },
             cache_hit_ratio: 0.0,
             uptime: 0,
+            endpoint_pool: crate::types::EndpointPoolMetrics::default(),
+            rate_limited_retries: 0,
+            response_time_percentiles: crate::types::ResponseTimePercentiles::default(),
+            subscription_queues: Vec::new(),
         }));
 
+        let response_time_histogram = Arc::new(RwLock::new(new_response_time_histogram()));
+
         // Initialize SDK state
         let state = Arc::new(RwLock::new(SDKState {
             initialized: true,
@@ -200,14 +273,25 @@ impl HyperSimSDK {
                     highest_block: 0,
                     starting_block: 0,
                     syncing: false,
+                    current_block_timestamp_ms: None,
                 },
                 last_success: Some(chrono::Utc::now().timestamp_millis() as u64),
                 uptime_ms: 0,
+                tcp_info: None,
+                reconnect_resubscriptions: 0,
             },
             last_error: None,
             uptime_start: std::time::Instant::now(),
         }));
 
+        let (block_sync_tx, _) = watch::channel(BlockSyncStatus {
+            current_block: 0,
+            highest_block: 0,
+            starting_block: 0,
+            syncing: false,
+            current_block_timestamp_ms: None,
+        });
+
         let sdk = Arc::new(Self {
             config,
             hyperevm_client,
@@ -217,7 +301,9 @@ impl HyperSimSDK {
             ai_analyzer,
             request_counter: AtomicU64::new(0),
             metrics,
+            response_time_histogram,
             state,
+            block_sync_tx,
         });
 
         // Start background tasks
@@ -257,13 +343,15 @@ impl HyperSimSDK {
     pub async fn simulate(&self, transaction: TransactionRequest) -> Result<SimulationResult> {
         let start_time = std::time::Instant::now();
         let request_id = self.generate_request_id();
-        
+
         debug!("Starting simulation for request {}", request_id);
 
         // Execute pre-simulation hooks
-        self.plugin_system.execute_before_simulation(&transaction).await?;
+        for plugin_error in self.plugin_system.execute_before_simulation(&transaction).await? {
+            warn!("{}", plugin_error);
+        }
 
-        let mut result = match self.hyperevm_client.simulate(transaction.clone()).await {
+        let mut result = match self.simulate_with_rate_limit_retry(&transaction).await {
             Ok(mut sim_result) => {
                 // Fetch cross-layer data if enabled
                 if let Some(ref hypercore_client) = self.hypercore_client {
@@ -287,7 +375,9 @@ impl HyperSimSDK {
         };
 
         // Execute post-simulation hooks
-        self.plugin_system.execute_after_simulation(&mut result).await?;
+        for plugin_error in self.plugin_system.execute_after_simulation(&mut result).await? {
+            warn!("{}", plugin_error);
+        }
 
         let duration = start_time.elapsed();
         self.update_success_metrics(duration.as_millis() as u64).await;
@@ -369,8 +459,20 @@ impl HyperSimSDK {
             .as_ref()
             .ok_or_else(|| HyperSimError::configuration("WebSocket streaming not enabled"))?;
 
+        let max_active_subscriptions = self.config.max_active_subscriptions();
+        {
+            let state = self.state.read().await;
+            if state.active_subscriptions.len() >= max_active_subscriptions {
+                return Err(HyperSimError::websocket(format!(
+                    "Maximum active subscriptions ({}) reached; unsubscribe from an existing \
+                     stream before opening a new one",
+                    max_active_subscriptions
+                )));
+            }
+        }
+
         let subscription = ws_client.subscribe(subscription_type, params).await?;
-        
+
         // Add to active subscriptions
         let mut state = self.state.write().await;
         state.active_subscriptions.push(subscription.clone());
@@ -399,11 +501,19 @@ impl HyperSimSDK {
     /// Get current performance metrics
     pub async fn get_metrics(&self) -> PerformanceMetrics {
         let mut metrics = self.metrics.read().await.clone();
-        
+
         // Update uptime
         let state = self.state.read().await;
         metrics.uptime = state.uptime_start.elapsed().as_millis() as u64;
-        
+
+        // Refresh the RPC endpoint pool's latency ranking
+        metrics.endpoint_pool = self.hyperevm_client.endpoint_pool_metrics().await;
+
+        // Refresh per-subscription queue depth/drop counts
+        if let Some(ref ws_client) = self.websocket_client {
+            metrics.subscription_queues = ws_client.subscription_queue_metrics().await;
+        }
+
         metrics
     }
 
@@ -420,6 +530,13 @@ impl HyperSimSDK {
         health
     }
 
+    /// Watch the RPC endpoint pool's consensus-agreed [`BlockSyncStatus`],
+    /// starting from the state at the moment of the call. Callers can
+    /// `.changed().await` this instead of polling [`get_connection_health`](Self::get_connection_health).
+    pub fn watch_block_sync_status(&self) -> watch::Receiver<BlockSyncStatus> {
+        self.block_sync_tx.subscribe()
+    }
+
     /// Shutdown the SDK and cleanup resources
     pub async fn shutdown(&self) -> Result<()> {
         info!("Shutting down HyperSim SDK");
@@ -455,20 +572,43 @@ impl HyperSimSDK {
             self.start_metrics_task().await;
         }
 
-        // Start health check task  
+        // Start health check task
         self.start_health_check_task().await;
 
+        // Start the consensus-based head-block tracker
+        self.start_consensus_finder_task().await;
+
         Ok(())
     }
 
+    /// Every 60 seconds, roll `response_time_histogram`'s p50/p90/p99/max
+    /// into `metrics.response_time_percentiles` and reset it, so the
+    /// reported percentiles reflect the most recent window rather than an
+    /// all-time aggregate.
     async fn start_metrics_task(&self) {
         let metrics = Arc::clone(&self.metrics);
+        let response_time_histogram = Arc::clone(&self.response_time_histogram);
         let _handle = tokio::spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
             loop {
                 interval.tick().await;
-                // Update metrics periodically
-                // Implementation would collect various metrics
+
+                let mut histogram = response_time_histogram.write().await;
+                let percentiles = crate::types::ResponseTimePercentiles {
+                    p50: histogram.value_at_quantile(0.50),
+                    p90: histogram.value_at_quantile(0.90),
+                    p99: histogram.value_at_quantile(0.99),
+                    max: histogram.max(),
+                };
+                let mean = histogram.mean();
+                histogram.reset();
+                drop(histogram);
+
+                let mut metrics_guard = metrics.write().await;
+                metrics_guard.response_time_percentiles = percentiles;
+                if mean > 0.0 {
+                    metrics_guard.average_response_time = mean;
+                }
             }
         });
     }
@@ -496,6 +636,86 @@ impl HyperSimSDK {
         });
     }
 
+    /// Extends [`start_health_check_task`](Self::start_health_check_task)
+    /// with consensus-based head-block tracking: polls every pooled
+    /// HyperEVM endpoint's `eth_blockNumber`, groups the replies by reported
+    /// head, and treats the head seen by a quorum (a strict majority) of
+    /// responding endpoints as the canonical `highest_block`; `current_block`
+    /// is the highest consensus head the SDK has confidently observed so
+    /// far, so it never regresses if a later poll briefly loses quorum.
+    /// Endpoints lagging the consensus head by more than
+    /// `HyperSimConfig::consensus_lag_threshold` are marked unhealthy so the
+    /// latency-ranked pool stops routing to them. Every update is published
+    /// on `block_sync_tx` in addition to being stored on
+    /// `connection_health`, so [`watch_block_sync_status`](Self::watch_block_sync_status)
+    /// callers see it without polling [`get_connection_health`](Self::get_connection_health).
+    async fn start_consensus_finder_task(&self) {
+        let state = Arc::clone(&self.state);
+        let hyperevm_client = Arc::clone(&self.hyperevm_client);
+        let config = Arc::clone(&self.config);
+        let block_sync_tx = self.block_sync_tx.clone();
+
+        let _handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+            loop {
+                interval.tick().await;
+
+                let heights = hyperevm_client.endpoint_block_heights().await;
+                if heights.is_empty() {
+                    continue;
+                }
+
+                let mut votes: HashMap<u64, u32> = HashMap::new();
+                for &height in heights.values() {
+                    *votes.entry(height).or_insert(0) += 1;
+                }
+
+                let total = heights.len() as u32;
+                // Ties broken toward the higher height: an equally-voted but
+                // higher block is always at least as trustworthy as a lower one.
+                let (consensus_head, quorum_votes) = votes
+                    .into_iter()
+                    .max_by_key(|&(height, count)| (count, height))
+                    .unwrap_or((0, 0));
+
+                if quorum_votes * 2 <= total {
+                    warn!(
+                        "No quorum among {} endpoint(s) for head block: most common height {} only had {}/{} votes",
+                        total, consensus_head, quorum_votes, total
+                    );
+                    continue;
+                }
+
+                let lag_threshold = config.consensus_lag_threshold();
+                for (endpoint, height) in &heights {
+                    if consensus_head.saturating_sub(*height) > lag_threshold {
+                        warn!(
+                            "Endpoint {} lags consensus head {} by {} block(s) (threshold {}); marking unhealthy",
+                            endpoint, consensus_head, consensus_head - *height, lag_threshold
+                        );
+                        hyperevm_client.mark_endpoint_unhealthy(endpoint).await;
+                    }
+                }
+
+                let status = {
+                    let mut state_guard = state.write().await;
+                    let previous_current = state_guard.connection_health.block_sync_status.current_block;
+                    let status = BlockSyncStatus {
+                        current_block: previous_current.max(consensus_head),
+                        highest_block: consensus_head,
+                        starting_block: state_guard.connection_health.block_sync_status.starting_block,
+                        syncing: false,
+                        current_block_timestamp_ms: Some(chrono::Utc::now().timestamp_millis() as u64),
+                    };
+                    state_guard.connection_health.block_sync_status = status.clone();
+                    status
+                };
+
+                let _ = block_sync_tx.send(status);
+            }
+        });
+    }
+
     fn generate_request_id(&self) -> u64 {
         self.request_counter.fetch_add(1, Ordering::SeqCst)
     }
@@ -504,11 +724,19 @@ impl HyperSimSDK {
         let mut metrics = self.metrics.write().await;
         metrics.total_requests += 1;
         metrics.successful_requests += 1;
-        
+
         // Update average response time (simple moving average)
         let total = metrics.successful_requests as f64;
-        metrics.average_response_time = 
+        metrics.average_response_time =
             (metrics.average_response_time * (total - 1.0) + response_time as f64) / total;
+        drop(metrics);
+
+        let clamped = response_time
+            .max(RESPONSE_TIME_HISTOGRAM_MIN_MS)
+            .min(RESPONSE_TIME_HISTOGRAM_MAX_MS);
+        if let Err(e) = self.response_time_histogram.write().await.record(clamped) {
+            warn!("Failed to record response time sample in histogram: {}", e);
+        }
     }
 
     async fn update_error_metrics(&self) {
@@ -517,6 +745,59 @@ impl HyperSimSDK {
         metrics.failed_requests += 1;
     }
 
+    async fn record_rate_limit_retry(&self) {
+        let mut metrics = self.metrics.write().await;
+        metrics.rate_limited_retries += 1;
+    }
+
+    /// Check whether `error`'s message matches one of `HyperSimConfig::rate_limit_markers`
+    fn is_rate_limit_error(&self, error: &HyperSimError) -> bool {
+        let message = error.to_string().to_lowercase();
+        self.config
+            .rate_limit_markers()
+            .iter()
+            .any(|marker| message.contains(&marker.to_lowercase()))
+    }
+
+    /// Call `hyperevm_client.simulate()`, retrying when the failure looks
+    /// like a transient rate limit (per `HyperSimConfig::rate_limit_markers`)
+    /// up to `rate_limit_max_retries` times. With more than one endpoint in
+    /// the RPC pool the retry is immediate, since the dispatcher's EWMA
+    /// ranking already marks the endpoint that just rate-limited us as
+    /// unhealthy and steers the next attempt elsewhere; with a single
+    /// endpoint each retry instead backs off by `rate_limit_backoff_multiplier`.
+    async fn simulate_with_rate_limit_retry(
+        &self,
+        transaction: &TransactionRequest,
+    ) -> Result<SimulationResult> {
+        let max_retries = self.config.rate_limit_max_retries();
+        let mut delay_ms = RATE_LIMIT_INITIAL_BACKOFF_MS;
+
+        for attempt in 0..=max_retries {
+            match self.hyperevm_client.simulate(transaction.clone()).await {
+                Ok(result) => return Ok(result),
+                Err(error) if attempt < max_retries && self.is_rate_limit_error(&error) => {
+                    self.record_rate_limit_retry().await;
+                    warn!(
+                        "Rate limited by RPC endpoint (attempt {}/{}): {}",
+                        attempt + 1,
+                        max_retries,
+                        error
+                    );
+
+                    let pool_size = self.hyperevm_client.endpoint_pool_metrics().await.endpoints.len();
+                    if pool_size <= 1 {
+                        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+                        delay_ms = (delay_ms as f64 * self.config.rate_limit_backoff_multiplier()) as u64;
+                    }
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        unreachable!("the loop above always returns on its final iteration")
+    }
+
     async fn basic_bundle_optimization(&self, simulations: Vec<SimulationResult>) -> Result<BundleOptimization> {
         // Basic optimization without AI
         let original_order: Vec<usize> = (0..simulations.len()).collect();
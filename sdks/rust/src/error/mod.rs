@@ -0,0 +1,659 @@
+//! Error handling for the HyperSim SDK
+//!
+//! This module provides comprehensive error handling with context-rich error types
+//! that maintain performance while providing detailed debugging information.
+//!
+//! The core [`HyperSimError`] enum, its constructors, and [`Result`] compile under
+//! `no_std + alloc` so embedded/WASM consumers can report errors through the same
+//! type the full SDK uses without pulling in `std`. Everything that genuinely
+//! needs an OS or a specific async runtime — the `reqwest`/`tokio`/`tungstenite`
+//! `From` impls and [`ErrorContext`] — lives in [`std_ext`] behind the default-on
+//! `std` feature.
+//!
+//! Reporting the full cause chain is behind the [`Tracer`] trait rather than a
+//! single hard-coded implementation, so the rendering backend is swappable:
+//! [`DefaultTracer`] formats with only `alloc`, while the optional `eyre_tracer`
+//! feature (see [`std_ext::EyreTracer`]) wraps causes in an `eyre::Report` for
+//! richer native-side diagnostics.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use thiserror::Error;
+
+#[cfg(feature = "std")]
+mod std_ext;
+
+#[cfg(feature = "std")]
+pub use std_ext::ErrorContext;
+#[cfg(feature = "eyre_tracer")]
+pub use std_ext::EyreTracer;
+
+/// Result type alias for HyperSim SDK operations
+pub type Result<T> = core::result::Result<T, HyperSimError>;
+
+/// A captured stack trace, when one is available.
+///
+/// Under the `std` feature this is [`std::backtrace::Backtrace`]. Without
+/// `std` there is no portable way to walk frames, so this is an inert
+/// placeholder that still lets every variant carry an `Option<Backtrace>`
+/// field unconditionally — callers on `no_std` just never get `Some`.
+#[cfg(feature = "std")]
+pub use std::backtrace::Backtrace;
+
+/// See the `std` version of this type for details; this is the `no_std` stand-in.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub struct Backtrace;
+
+#[cfg(not(feature = "std"))]
+impl core::fmt::Display for Backtrace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("<unavailable: built without the `std` feature>")
+    }
+}
+
+/// What kind of failure a [`HyperSimError::WebSocket`] represents, so callers
+/// (in particular the reconnection supervisor) can tell a transient drop from
+/// a violation the peer isn't going to stop making on the next attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketErrorKind {
+    /// The connection was closed; `code`/`reason` are populated when the
+    /// close frame carried them. Usually transient (idle timeout, server
+    /// restart, load balancer reset) — worth reconnecting.
+    ConnectionClosed { code: Option<u16>, reason: Option<String> },
+    /// A WebSocket protocol violation (bad frame, masking, reserved bits,
+    /// attack attempt). The peer is misbehaving or incompatible; retrying
+    /// the same handshake won't help.
+    Protocol,
+    /// A message exceeded the configured read/write buffer capacity.
+    MessageTooLarge,
+    /// The opening HTTP upgrade handshake failed (non-101 response,
+    /// malformed request/response, TLS failure).
+    Handshake,
+    /// An underlying I/O error (connection reset, broken pipe, etc.) —
+    /// almost always transient.
+    Io,
+    /// Not one of the more specific buckets above; treated as transient for
+    /// backward compatibility with call sites that predate this enum.
+    Other,
+}
+
+impl WebSocketErrorKind {
+    /// Whether reconnecting is worth attempting for this kind of failure
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, WebSocketErrorKind::Protocol | WebSocketErrorKind::Handshake | WebSocketErrorKind::MessageTooLarge)
+    }
+}
+
+/// Main error type for the HyperSim SDK
+#[derive(Error, Debug)]
+pub enum HyperSimError {
+    /// Configuration errors
+    #[error("Configuration error: {message}")]
+    Configuration { message: String, backtrace: Option<Backtrace> },
+
+    /// Network connection errors
+    #[error("Network error: {message}")]
+    Network {
+        message: String,
+        source: Option<Box<dyn core::error::Error + Send + Sync>>,
+        backtrace: Option<Backtrace>,
+    },
+
+    /// Transaction simulation errors
+    #[error("Simulation error: {message}")]
+    Simulation {
+        message: String,
+        tx_hash: Option<String>,
+        source: Option<Box<dyn core::error::Error + Send + Sync>>,
+        backtrace: Option<Backtrace>,
+    },
+
+    /// WebSocket connection errors
+    #[error("WebSocket error: {message}")]
+    WebSocket {
+        message: String,
+        url: Option<String>,
+        kind: WebSocketErrorKind,
+        source: Option<Box<dyn core::error::Error + Send + Sync>>,
+        backtrace: Option<Backtrace>,
+    },
+
+    /// AI analysis errors
+    #[error("AI analysis error: {message}")]
+    AIAnalysis { message: String, backtrace: Option<Backtrace> },
+
+    /// Plugin system errors
+    #[error("Plugin error: {message}")]
+    Plugin {
+        message: String,
+        plugin_name: Option<String>,
+        source: Option<Box<dyn core::error::Error + Send + Sync>>,
+        backtrace: Option<Backtrace>,
+    },
+
+    /// Validation errors for input data
+    #[error("Validation error: {message}")]
+    Validation { message: String, field: Option<String>, backtrace: Option<Backtrace> },
+
+    /// Serialization/deserialization errors
+    #[error("Serialization error: {message}")]
+    Serialization { message: String, backtrace: Option<Backtrace> },
+
+    /// Authentication and authorization errors
+    #[error("Authentication error: {message}")]
+    Authentication { message: String, backtrace: Option<Backtrace> },
+
+    /// Rate limiting errors
+    #[error("Rate limit exceeded: {message}")]
+    RateLimit { message: String, retry_after: Option<u64>, backtrace: Option<Backtrace> },
+
+    /// Timeout errors
+    #[error("Timeout error: operation timed out after {duration_ms}ms")]
+    Timeout { duration_ms: u64, backtrace: Option<Backtrace> },
+
+    /// ABI encoding/decoding errors
+    #[error("ABI error: {message}")]
+    ABI {
+        message: String,
+        source: Option<Box<dyn core::error::Error + Send + Sync>>,
+        backtrace: Option<Backtrace>,
+    },
+
+    /// Connection pool errors
+    #[error("Connection pool error: {message}")]
+    ConnectionPool {
+        message: String,
+        source: Option<Box<dyn core::error::Error + Send + Sync>>,
+        backtrace: Option<Backtrace>,
+    },
+
+    /// Security policy violations (e.g. certificate pin mismatches)
+    #[error("Security error: {message}")]
+    Security { message: String, backtrace: Option<Backtrace> },
+
+    /// Internal SDK errors
+    #[error("Internal error: {message}")]
+    Internal {
+        message: String,
+        source: Option<Box<dyn core::error::Error + Send + Sync>>,
+        backtrace: Option<Backtrace>,
+    },
+
+    /// Unknown or unexpected errors
+    #[error("Unknown error: {message}")]
+    Unknown { message: String, backtrace: Option<Backtrace> },
+
+    /// Trustless verification errors: a Merkle-Patricia proof didn't
+    /// reconstruct the claimed state root
+    #[error("Verification error: {message}")]
+    Verification { message: String, backtrace: Option<Backtrace> },
+}
+
+/// Capture a backtrace at error-construction time when the `backtrace`
+/// feature is enabled; a no-op `None` otherwise so the common case doesn't
+/// pay for frame unwinding it won't use. Capturing requires `std`, so this
+/// is also a no-op on `no_std` builds regardless of the `backtrace` feature.
+#[cfg(all(feature = "backtrace", feature = "std"))]
+fn capture_backtrace() -> Option<Backtrace> {
+    Some(Backtrace::capture())
+}
+
+#[cfg(not(all(feature = "backtrace", feature = "std")))]
+fn capture_backtrace() -> Option<Backtrace> {
+    None
+}
+
+impl HyperSimError {
+    /// Create a new configuration error
+    pub fn configuration(message: impl Into<String>) -> Self {
+        Self::Configuration { message: message.into(), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new network error with optional source
+    pub fn network(message: impl Into<String>) -> Self {
+        Self::Network { message: message.into(), source: None, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new network error with source error
+    pub fn network_with_source(
+        message: impl Into<String>,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    ) -> Self {
+        Self::Network { message: message.into(), source: Some(source), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new simulation error
+    pub fn simulation(message: impl Into<String>) -> Self {
+        Self::Simulation {
+            message: message.into(),
+            tx_hash: None,
+            source: None,
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new simulation error with transaction hash
+    pub fn simulation_with_hash(message: impl Into<String>, tx_hash: impl Into<String>) -> Self {
+        Self::Simulation {
+            message: message.into(),
+            tx_hash: Some(tx_hash.into()),
+            source: None,
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new simulation error with source error
+    pub fn simulation_with_source(
+        message: impl Into<String>,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    ) -> Self {
+        Self::Simulation {
+            message: message.into(),
+            tx_hash: None,
+            source: Some(source),
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new WebSocket error
+    pub fn websocket(message: impl Into<String>) -> Self {
+        Self::WebSocket {
+            message: message.into(),
+            url: None,
+            kind: WebSocketErrorKind::Other,
+            source: None,
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new WebSocket error with URL
+    pub fn websocket_with_url(message: impl Into<String>, url: impl Into<String>) -> Self {
+        Self::WebSocket {
+            message: message.into(),
+            url: Some(url.into()),
+            kind: WebSocketErrorKind::Other,
+            source: None,
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new WebSocket error with source error
+    pub fn websocket_with_source(
+        message: impl Into<String>,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    ) -> Self {
+        Self::WebSocket {
+            message: message.into(),
+            url: None,
+            kind: WebSocketErrorKind::Other,
+            source: Some(source),
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new WebSocket error with an explicit [`WebSocketErrorKind`] and source error
+    pub fn websocket_with_kind(
+        message: impl Into<String>,
+        kind: WebSocketErrorKind,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    ) -> Self {
+        Self::WebSocket {
+            message: message.into(),
+            url: None,
+            kind,
+            source: Some(source),
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new AI analysis error
+    pub fn ai_analysis(message: impl Into<String>) -> Self {
+        Self::AIAnalysis { message: message.into(), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new plugin error
+    pub fn plugin(message: impl Into<String>) -> Self {
+        Self::Plugin { message: message.into(), plugin_name: None, source: None, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new plugin error with plugin name
+    pub fn plugin_with_name(message: impl Into<String>, plugin_name: impl Into<String>) -> Self {
+        Self::Plugin {
+            message: message.into(),
+            plugin_name: Some(plugin_name.into()),
+            source: None,
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new plugin error with source error
+    pub fn plugin_with_source(
+        message: impl Into<String>,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    ) -> Self {
+        Self::Plugin {
+            message: message.into(),
+            plugin_name: None,
+            source: Some(source),
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new validation error
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::Validation { message: message.into(), field: None, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new validation error with field name
+    pub fn validation_with_field(message: impl Into<String>, field: impl Into<String>) -> Self {
+        Self::Validation {
+            message: message.into(),
+            field: Some(field.into()),
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new serialization error
+    pub fn serialization(message: impl Into<String>) -> Self {
+        Self::Serialization { message: message.into(), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new authentication error
+    pub fn authentication(message: impl Into<String>) -> Self {
+        Self::Authentication { message: message.into(), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new rate limit error
+    pub fn rate_limit(message: impl Into<String>) -> Self {
+        Self::RateLimit { message: message.into(), retry_after: None, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new rate limit error with retry after
+    pub fn rate_limit_with_retry(message: impl Into<String>, retry_after: u64) -> Self {
+        Self::RateLimit {
+            message: message.into(),
+            retry_after: Some(retry_after),
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new timeout error
+    pub fn timeout(duration_ms: u64) -> Self {
+        Self::Timeout { duration_ms, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new ABI error
+    pub fn abi(message: impl Into<String>) -> Self {
+        Self::ABI { message: message.into(), source: None, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new ABI error with source error
+    pub fn abi_with_source(message: impl Into<String>, source: Box<dyn core::error::Error + Send + Sync>) -> Self {
+        Self::ABI { message: message.into(), source: Some(source), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new connection pool error
+    pub fn connection_pool(message: impl Into<String>) -> Self {
+        Self::ConnectionPool { message: message.into(), source: None, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new connection pool error with source error
+    pub fn connection_pool_with_source(
+        message: impl Into<String>,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    ) -> Self {
+        Self::ConnectionPool { message: message.into(), source: Some(source), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new security error
+    pub fn security(message: impl Into<String>) -> Self {
+        Self::Security { message: message.into(), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new internal error
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::Internal { message: message.into(), source: None, backtrace: capture_backtrace() }
+    }
+
+    /// Create a new internal error with source error
+    pub fn internal_with_source(
+        message: impl Into<String>,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    ) -> Self {
+        Self::Internal { message: message.into(), source: Some(source), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new unknown error
+    pub fn unknown(message: impl Into<String>) -> Self {
+        Self::Unknown { message: message.into(), backtrace: capture_backtrace() }
+    }
+
+    /// Create a new verification error
+    pub fn verification(message: impl Into<String>) -> Self {
+        Self::Verification { message: message.into(), backtrace: capture_backtrace() }
+    }
+
+    /// Check if error is retryable
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            HyperSimError::WebSocket { kind, .. } => kind.is_retryable(),
+            HyperSimError::Network { .. }
+            | HyperSimError::Timeout { .. }
+            | HyperSimError::RateLimit { .. }
+            | HyperSimError::ConnectionPool { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Get error category for metrics
+    pub fn category(&self) -> &'static str {
+        match self {
+            HyperSimError::Configuration { .. } => "configuration",
+            HyperSimError::Network { .. } => "network",
+            HyperSimError::Simulation { .. } => "simulation",
+            HyperSimError::WebSocket { .. } => "websocket",
+            HyperSimError::AIAnalysis { .. } => "ai_analysis",
+            HyperSimError::Plugin { .. } => "plugin",
+            HyperSimError::Validation { .. } => "validation",
+            HyperSimError::Serialization { .. } => "serialization",
+            HyperSimError::Authentication { .. } => "authentication",
+            HyperSimError::RateLimit { .. } => "rate_limit",
+            HyperSimError::Timeout { .. } => "timeout",
+            HyperSimError::ABI { .. } => "abi",
+            HyperSimError::ConnectionPool { .. } => "connection_pool",
+            HyperSimError::Security { .. } => "security",
+            HyperSimError::Internal { .. } => "internal",
+            HyperSimError::Unknown { .. } => "unknown",
+            HyperSimError::Verification { .. } => "verification",
+        }
+    }
+
+    /// This error's own captured backtrace, when the `backtrace` feature is
+    /// enabled and one was recorded at construction time
+    fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            HyperSimError::Configuration { backtrace, .. }
+            | HyperSimError::Network { backtrace, .. }
+            | HyperSimError::Simulation { backtrace, .. }
+            | HyperSimError::WebSocket { backtrace, .. }
+            | HyperSimError::AIAnalysis { backtrace, .. }
+            | HyperSimError::Plugin { backtrace, .. }
+            | HyperSimError::Validation { backtrace, .. }
+            | HyperSimError::Serialization { backtrace, .. }
+            | HyperSimError::Authentication { backtrace, .. }
+            | HyperSimError::RateLimit { backtrace, .. }
+            | HyperSimError::Timeout { backtrace, .. }
+            | HyperSimError::ABI { backtrace, .. }
+            | HyperSimError::ConnectionPool { backtrace, .. }
+            | HyperSimError::Security { backtrace, .. }
+            | HyperSimError::Internal { backtrace, .. }
+            | HyperSimError::Unknown { backtrace, .. }
+            | HyperSimError::Verification { backtrace, .. } => backtrace.as_ref(),
+        }
+    }
+
+    /// Render the full error chain via the default, `alloc`-only [`Tracer`].
+    /// Intended for logs and bug reports; `Display`/`to_string()` stay
+    /// single-line. Use [`HyperSimError::report_with`] to plug in a richer
+    /// tracer such as [`std_ext::EyreTracer`] (behind the `eyre_tracer` feature).
+    pub fn report(&self) -> String {
+        self.report_with(&DefaultTracer)
+    }
+
+    /// Render the full error chain using the given [`Tracer`] backend.
+    pub fn report_with(&self, tracer: &dyn Tracer) -> String {
+        tracer.trace(self)
+    }
+}
+
+/// Pluggable backend for rendering an error's cause chain (and, when available,
+/// its backtrace) into a human-readable report.
+///
+/// This indirection — rather than a single hard-coded `report()` — lets
+/// `no_std`/WASM consumers keep reporting down to `alloc`-only formatting
+/// ([`DefaultTracer`]) while native users opt into richer rendering, e.g.
+/// [`std_ext::EyreTracer`] under the `eyre_tracer` feature.
+pub trait Tracer {
+    /// Render `error`'s full chain (and backtrace, if captured) as a report string.
+    fn trace(&self, error: &HyperSimError) -> String;
+}
+
+/// The default [`Tracer`]: walks `source()` and appends the captured
+/// backtrace (when one was recorded) as plain text. Works under `no_std + alloc`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultTracer;
+
+impl Tracer for DefaultTracer {
+    fn trace(&self, error: &HyperSimError) -> String {
+        let mut report = error.to_string();
+
+        let mut source = core::error::Error::source(error);
+        while let Some(err) = source {
+            report.push_str("\ncaused by: ");
+            report.push_str(&err.to_string());
+            source = err.source();
+        }
+
+        if let Some(backtrace) = error.backtrace() {
+            report.push_str("\n\nbacktrace:\n");
+            report.push_str(&backtrace.to_string());
+        }
+
+        report
+    }
+}
+
+// Conversions from common error types that are themselves no_std/alloc-friendly.
+impl From<serde_json::Error> for HyperSimError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::serialization(alloc::format!("JSON error: {}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+
+    #[test]
+    fn test_error_creation() {
+        let err = HyperSimError::validation("Invalid address");
+        assert!(matches!(err, HyperSimError::Validation { .. }));
+        assert_eq!(err.category(), "validation");
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        let network_err = HyperSimError::network("Connection failed");
+        assert!(network_err.is_retryable());
+
+        let validation_err = HyperSimError::validation("Invalid input");
+        assert!(!validation_err.is_retryable());
+    }
+
+    #[test]
+    fn test_websocket_error_kind_drives_retryability() {
+        let closed = HyperSimError::WebSocket {
+            message: "closed".into(),
+            url: None,
+            kind: WebSocketErrorKind::ConnectionClosed { code: None, reason: None },
+            source: None,
+            backtrace: None,
+        };
+        assert!(closed.is_retryable());
+
+        let io = HyperSimError::WebSocket {
+            message: "io".into(),
+            url: None,
+            kind: WebSocketErrorKind::Io,
+            source: None,
+            backtrace: None,
+        };
+        assert!(io.is_retryable());
+
+        let handshake = HyperSimError::WebSocket {
+            message: "handshake".into(),
+            url: None,
+            kind: WebSocketErrorKind::Handshake,
+            source: None,
+            backtrace: None,
+        };
+        assert!(!handshake.is_retryable());
+
+        let protocol = HyperSimError::WebSocket {
+            message: "protocol".into(),
+            url: None,
+            kind: WebSocketErrorKind::Protocol,
+            source: None,
+            backtrace: None,
+        };
+        assert!(!protocol.is_retryable());
+
+        // Generic constructors predate `WebSocketErrorKind` and keep their
+        // prior (retryable) behavior via `WebSocketErrorKind::Other`.
+        assert!(HyperSimError::websocket("generic").is_retryable());
+    }
+
+    /// Minimal `core::error::Error` implementor so these tests can construct a
+    /// `source` without depending on `std::io::Error`, keeping this module's
+    /// tests buildable under `no_std + alloc` too.
+    #[derive(Debug)]
+    struct ConstructedError(&'static str);
+
+    impl core::fmt::Display for ConstructedError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl core::error::Error for ConstructedError {}
+
+    #[test]
+    fn test_source_chain_is_walkable() {
+        let err = HyperSimError::internal_with_source(
+            "Internal operation failed",
+            Box::new(ConstructedError("underlying io failure")),
+        );
+
+        let source = core::error::Error::source(&err).expect("source should be attached");
+        assert!(source.to_string().contains("underlying io failure"));
+    }
+
+    #[test]
+    fn test_report_includes_chained_causes() {
+        let err = HyperSimError::plugin_with_source(
+            "Plugin write failed",
+            Box::new(ConstructedError("disk full")),
+        );
+
+        let report = err.report();
+        assert!(report.contains("Plugin write failed"));
+        assert!(report.contains("caused by: disk full"));
+    }
+}
@@ -0,0 +1,147 @@
+//! `std`-only extensions to the [`super::HyperSimError`] core: conversions from
+//! error types belonging to crates that are themselves `std`-only (`reqwest`,
+//! `tokio`, `tungstenite`, `ethers`), plus the [`ErrorContext`] convenience trait
+//! and the optional `eyre_tracer` [`Tracer`](super::Tracer) backend.
+
+use super::{HyperSimError, Result, Tracer, WebSocketErrorKind};
+
+impl From<reqwest::Error> for HyperSimError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::timeout(30000) // Default timeout
+        } else if err.is_connect() {
+            Self::network_with_source("Connection error", Box::new(err))
+        } else {
+            Self::network_with_source("HTTP error", Box::new(err))
+        }
+    }
+}
+
+impl From<tokio::time::error::Elapsed> for HyperSimError {
+    fn from(_: tokio::time::error::Elapsed) -> Self {
+        Self::timeout(0)
+    }
+}
+
+impl From<tungstenite::Error> for HyperSimError {
+    fn from(err: tungstenite::Error) -> Self {
+        let kind = websocket_error_kind(&err);
+        let message = format!("WebSocket error: {}", err);
+        Self::websocket_with_kind(message, kind, Box::new(err))
+    }
+}
+
+/// Classify a `tungstenite::Error` into the [`WebSocketErrorKind`] bucket the
+/// reconnection supervisor cares about. These error variants carry no close
+/// frame, so every `ConnectionClosed` produced here (including for
+/// `ProtocolError::ResetWithoutClosingHandshake`) has `None`/`None` for its
+/// code/reason and callers treat any such close as transient.
+fn websocket_error_kind(err: &tungstenite::Error) -> WebSocketErrorKind {
+    use tungstenite::error::ProtocolError;
+    use tungstenite::Error;
+
+    match err {
+        Error::ConnectionClosed | Error::AlreadyClosed => {
+            WebSocketErrorKind::ConnectionClosed { code: None, reason: None }
+        }
+        Error::Io(_) => WebSocketErrorKind::Io,
+        Error::Capacity(_) | Error::WriteBufferFull(_) => WebSocketErrorKind::MessageTooLarge,
+        Error::Http(_) | Error::HttpFormat(_) | Error::Url(_) | Error::Tls(_) => WebSocketErrorKind::Handshake,
+        Error::Protocol(protocol_error) => match protocol_error {
+            ProtocolError::ResetWithoutClosingHandshake => {
+                WebSocketErrorKind::ConnectionClosed { code: None, reason: None }
+            }
+            _ => WebSocketErrorKind::Protocol,
+        },
+        Error::Utf8 | Error::AttackAttempt => WebSocketErrorKind::Protocol,
+        #[allow(unreachable_patterns)]
+        _ => WebSocketErrorKind::Other,
+    }
+}
+
+impl From<ethers::types::ParseError> for HyperSimError {
+    fn from(err: ethers::types::ParseError) -> Self {
+        Self::validation(format!("Parse error: {}", err))
+    }
+}
+
+#[cfg(feature = "cross-layer")]
+impl From<ethereum_abi::Error> for HyperSimError {
+    fn from(err: ethereum_abi::Error) -> Self {
+        Self::abi(format!("ABI error: {}", err))
+    }
+}
+
+/// Error context trait for adding context to errors
+pub trait ErrorContext<T> {
+    fn with_context<F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> String;
+
+    fn context(self, message: &str) -> Result<T>;
+}
+
+impl<T, E> ErrorContext<T> for std::result::Result<T, E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn with_context<F>(self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> String,
+    {
+        self.map_err(|err| {
+            HyperSimError::unknown(format!("{}: {}", f(), err))
+        })
+    }
+
+    fn context(self, message: &str) -> Result<T> {
+        self.map_err(|err| {
+            HyperSimError::unknown(format!("{}: {}", message, err))
+        })
+    }
+}
+
+/// An optional [`Tracer`] that wraps the cause chain in an [`eyre::Report`]
+/// before rendering it, picking up `eyre`'s source-location and
+/// pretty-printing support for native, non-embedded consumers.
+#[cfg(feature = "eyre_tracer")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EyreTracer;
+
+#[cfg(feature = "eyre_tracer")]
+impl Tracer for EyreTracer {
+    fn trace(&self, error: &HyperSimError) -> String {
+        let mut report = eyre::Report::msg(error.to_string());
+
+        let mut source = std::error::Error::source(error);
+        while let Some(err) = source {
+            report = report.wrap_err(err.to_string());
+            source = err.source();
+        }
+
+        // `{:?}` is eyre's pretty, multi-line "Error: ...\n\nCaused by:\n 0: ..."
+        // rendering — the whole point of opting into this tracer over `DefaultTracer`.
+        format!("{:?}", report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::HyperSimError;
+
+    #[test]
+    fn test_error_context() {
+        let result: std::result::Result<(), std::io::Error> =
+            Err(std::io::Error::new(std::io::ErrorKind::NotFound, "file not found"));
+
+        let contextualized = result.context("Failed to read config file");
+        assert!(contextualized.is_err());
+    }
+
+    #[test]
+    fn test_timeout_error_reports_timeout_category() {
+        let err = HyperSimError::timeout(0);
+        assert_eq!(err.category(), "timeout");
+    }
+}
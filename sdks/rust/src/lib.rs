@@ -53,21 +53,59 @@
 //! - [`plugins`] - Plugin system implementation
 //! - [`ai`] - AI-powered analysis and optimization
 //! - [`error`] - Error types and handling
+//! - [`verification`] - Trustless verification of simulation results via Merkle proofs
+//! - [`bridge`] - Active fraud-proof challenge tracking for optimistic bridge operations
+//! - [`security`] - Certificate pinning and other transport security policy
+//! - [`cache`] - Pluggable cache backends (in-memory, Redis) for simulation/RPC results
+//! - [`retry`] - Generic retry-with-backoff for operations that return a [`HyperSimError`]
+//!
+//! ## `no_std` support
+//!
+//! With default features disabled (`--no-default-features`), the crate builds under
+//! `no_std + alloc`. Every module except [`error`] depends on `tokio`/`reqwest`/the
+//! network clients and is only compiled with the default-on `std` feature, so a
+//! `no_std` build exposes just [`HyperSimError`] and friends — enough for an
+//! embedded or WASM component to report errors through the same type the full SDK
+//! uses, without pulling in an async runtime. See `error`'s module docs for the
+//! `std`/`eyre_tracer` feature split.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
+#[cfg(feature = "std")]
 pub mod ai;
+#[cfg(feature = "std")]
+pub mod bridge;
+#[cfg(feature = "std")]
+pub mod cache;
+#[cfg(feature = "std")]
 pub mod clients;
+#[cfg(feature = "std")]
 pub mod core;
 pub mod error;
+#[cfg(feature = "std")]
 pub mod plugins;
+#[cfg(feature = "std")]
+pub mod retry;
+#[cfg(feature = "std")]
+pub mod security;
+#[cfg(feature = "std")]
 pub mod types;
+#[cfg(feature = "std")]
 pub mod utils;
+#[cfg(feature = "std")]
+pub mod verification;
 
 // Re-export main components
+#[cfg(feature = "std")]
 pub use core::{HyperSimConfig, HyperSimSDK, HyperSimSDKBuilder};
 pub use error::{HyperSimError, Result};
+#[cfg(feature = "std")]
 pub use types::*;
 
 // Prelude module for common imports
+#[cfg(feature = "std")]
 pub mod prelude {
     pub use crate::core::{HyperSimConfig, HyperSimSDK, HyperSimSDKBuilder};
     pub use crate::error::{HyperSimError, Result};
@@ -78,6 +116,9 @@ pub mod prelude {
     pub use crate::clients::{HyperEVMClient, HyperCoreClient, WebSocketClient};
     pub use crate::plugins::{Plugin, PluginConfig, PluginSystem};
     pub use crate::ai::AIAnalyzer;
+    pub use crate::verification::VerificationStatus;
+    pub use crate::security::{SecurityConfig, SecurityManager};
+    pub use crate::cache::{CacheBackend, CacheStore};
 }
 
 // SDK metadata
@@ -85,7 +126,7 @@ pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const SDK_NAME: &str = env!("CARGO_PKG_NAME");
 pub const SDK_DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use tokio_test;
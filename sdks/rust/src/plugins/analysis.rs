@@ -0,0 +1,1209 @@
+//! Pluggable analysis engine
+//!
+//! [`AnalysisProvider`] lets a [`Plugin`] supply transaction insights and
+//! bundle optimization instead of [`AIAnalyzer`](crate::ai::AIAnalyzer)
+//! hardcoding one implementation. Providers are loaded and ordered exactly
+//! like any other plugin, via [`PluginSystem`](crate::plugins::PluginSystem)
+//! and `PluginConfig.priority`; `AIAnalyzer` delegates to the
+//! highest-priority enabled provider and falls back down the chain on
+//! error, so a user can swap in an offline ONNX/ML model or a custom risk
+//! engine without forking the crate.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::error::{HyperSimError, Result};
+use crate::plugins::Plugin;
+use crate::types::{
+    AIInsights, AccessListEntry, BundleOptimization, GasOptimization, Hash, MarketAnalysis,
+    Pattern, PerformanceInsights, Recommendation, RiskLevel, SecurityAnalysis, SimulationResult,
+    TimeWindow, TransactionEnvelope, TransactionOptimization, VenueConstraints, Wei,
+};
+
+/// Supplies transaction insights and bundle optimization for
+/// [`AIAnalyzer`](crate::ai::AIAnalyzer) to delegate to. Implemented by a
+/// [`Plugin`] via [`Plugin::as_analysis_provider`], so providers are
+/// registered, priority-ordered, and health-checked through the same
+/// [`PluginSystem`](crate::plugins::PluginSystem) as any other plugin.
+#[async_trait]
+pub trait AnalysisProvider: Send + Sync {
+    /// Analyze a single simulation result
+    async fn analyze(&self, simulation_result: &SimulationResult) -> Result<AIInsights>;
+
+    /// Suggest a reordering and per-transaction optimizations for a bundle
+    async fn optimize(&self, simulations: &[SimulationResult]) -> Result<BundleOptimization>;
+}
+
+/// Deterministic, dependency-free analysis provider: gas/fee/blob math
+/// derived entirely from a simulation's own trace and EIP-1559/4844 fields,
+/// with no external model or network call involved. Always available as
+/// the last entry in the priority chain.
+pub struct HeuristicAnalysisProvider {
+    name: String,
+}
+
+impl HeuristicAnalysisProvider {
+    pub fn new() -> Self {
+        Self { name: "heuristic-analysis".to_string() }
+    }
+}
+
+impl Default for HeuristicAnalysisProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AnalysisProvider for HeuristicAnalysisProvider {
+    async fn analyze(&self, simulation_result: &SimulationResult) -> Result<AIInsights> {
+        let risk_level = if simulation_result.success {
+            if simulation_result.gas_used.parse::<u64>().unwrap_or(0) > 500000 {
+                RiskLevel::Medium
+            } else {
+                RiskLevel::Low
+            }
+        } else {
+            RiskLevel::High
+        };
+
+        let gas_used = simulation_result.gas_used.parse::<u64>().unwrap_or(0);
+        let optimized_gas = gas_used.saturating_sub(gas_used / 10); // 10% reduction
+
+        let (access_list, access_list_savings) = synthesize_access_list(simulation_result);
+        let total_gas_savings = (gas_used - optimized_gas) + access_list_savings;
+        let total_cost_savings = 1_000_000_000_000_000u128 + access_list_savings as u128 * 20_000_000_000;
+
+        let fee_breakdown = compute_fee_breakdown(simulation_result);
+        let blob_accounting = compute_blob_accounting(simulation_result);
+        let base_fee_forecast = forecast_base_fee(simulation_result);
+        let priority_tip_per_gas = observed_priority_tip_per_gas(simulation_result)
+            .unwrap_or(DEFAULT_PRIORITY_TIP_PER_GAS);
+        let suggested_max_fee_per_gas = base_fee_forecast
+            .as_ref()
+            .and_then(|forecast| forecast.expected.last())
+            .map(|&expected| Wei::new(expected.saturating_mul(2).saturating_add(priority_tip_per_gas).to_string()))
+            .unwrap_or_else(|| Wei::new("25000000000"));
+        let optimal_timing = match (&base_fee_forecast, simulation_result.base_fee_per_gas.as_ref()) {
+            (Some(forecast), Some(base_fee)) => {
+                let current_base_fee: u128 = base_fee.as_str().parse().unwrap_or(0);
+                optimal_timing_windows(current_base_fee, forecast, simulation_result.block_type)
+            }
+            _ => Vec::new(),
+        };
+        let venue = crate::ai::dex::default_venue();
+        let (optimal_timing, timing_bottlenecks) = venue_budget_windows(optimal_timing, &venue);
+        let (liquidity_assessment, price_impact, market_bottlenecks) =
+            crate::ai::dex::assess_market(&crate::ai::dex::default_pools(), 0.01, Some(&venue));
+        let bottlenecks: Vec<String> = timing_bottlenecks.into_iter().chain(market_bottlenecks).collect();
+
+        let mut recommendations = vec![
+            Recommendation {
+                recommendation_type: crate::types::RecommendationType::GasOptimization,
+                description: "Consider reducing gas limit by 10%".to_string(),
+                priority: crate::types::Priority::Medium,
+                expected_impact: "Reduce transaction cost by ~10%".to_string(),
+                difficulty: crate::types::Difficulty::Easy,
+                confidence: 0.8,
+            },
+        ];
+        if let Some(blob_count) = simulation_result.blob_count {
+            let needed = blobs_needed_for_calldata(simulation_result);
+            if blob_count > needed {
+                recommendations.push(Recommendation {
+                    recommendation_type: crate::types::RecommendationType::ParameterAdjustment,
+                    description: format!(
+                        "Transaction carries {} blob(s) but its calldata only needs {}",
+                        blob_count, needed
+                    ),
+                    priority: crate::types::Priority::Medium,
+                    expected_impact: "Reduce blob fee by dropping unused blobs".to_string(),
+                    difficulty: crate::types::Difficulty::Easy,
+                    confidence: 0.7,
+                });
+            }
+        }
+        if !access_list.is_empty() && access_list_savings > 0 {
+            recommendations.push(Recommendation {
+                recommendation_type: crate::types::RecommendationType::AccessListAddition,
+                description: format!(
+                    "Attach an EIP-2930 access list declaring {} address(es) from the trace's repeated touches",
+                    access_list.len()
+                ),
+                priority: crate::types::Priority::Medium,
+                expected_impact: format!(
+                    "Save ~{} gas by pre-warming storage slots and accounts ahead of execution",
+                    access_list_savings
+                ),
+                difficulty: crate::types::Difficulty::Easy,
+                confidence: 0.9,
+            });
+        }
+        let transaction_envelope = simulation_result.tx_type.and_then(TransactionEnvelope::from_tx_type);
+        recommendations.extend(typed_transaction_recommendations(simulation_result, transaction_envelope));
+
+        Ok(AIInsights {
+            risk_level,
+            risk_score: risk_level.as_score(),
+            success_probability: if simulation_result.success { 0.95 } else { 0.15 },
+            gas_optimization: GasOptimization {
+                current_gas_estimate: simulation_result.gas_used.clone(),
+                optimized_gas_estimate: optimized_gas.to_string(),
+                gas_savings: total_gas_savings.to_string(),
+                cost_savings: Wei::new(total_cost_savings.to_string()),
+                suggested_gas_price: Some(Wei::new("20000000000")),
+                suggested_max_fee_per_gas: Some(suggested_max_fee_per_gas),
+                suggested_max_priority_fee_per_gas: Some(Wei::new(priority_tip_per_gas.to_string())),
+                optimization_techniques: if access_list.is_empty() {
+                    vec![
+                        "Use more efficient opcodes".to_string(),
+                        "Optimize storage operations".to_string(),
+                    ]
+                } else {
+                    vec![
+                        "Use more efficient opcodes".to_string(),
+                        "Optimize storage operations".to_string(),
+                        "Attach an EIP-2930 access list".to_string(),
+                    ]
+                },
+                access_list,
+                effective_gas_price: fee_breakdown.as_ref().map(|f| Wei::new(f.effective_gas_price.to_string())),
+                base_fee_burn: fee_breakdown.as_ref().map(|f| Wei::new(f.base_fee_burn.to_string())),
+                priority_tip: fee_breakdown.as_ref().map(|f| Wei::new(f.priority_tip.to_string())),
+                over_estimation_burn: fee_breakdown.as_ref().map(|f| Wei::new(f.over_estimation_burn.to_string())),
+                refund: fee_breakdown.as_ref().map(|f| Wei::new(f.refund.to_string())),
+                blob_gas_used: blob_accounting.as_ref().map(|(gas, _)| gas.to_string()),
+                blob_fee: blob_accounting.as_ref().map(|(_, fee)| fee.clone()),
+                transaction_envelope,
+            },
+            security_analysis: SecurityAnalysis {
+                security_score: 0.85,
+                vulnerabilities: Vec::new(),
+                contract_analysis: Vec::new(),
+                transaction_patterns: Vec::new(),
+                anomalies: Vec::new(),
+            },
+            performance_insights: PerformanceInsights {
+                expected_execution_time: 200.0,
+                congestion_factor: 1.2,
+                optimal_timing,
+                bottlenecks,
+                scalability_concerns: Vec::new(),
+            },
+            market_analysis: MarketAnalysis {
+                volatility: 0.25,
+                liquidity_assessment,
+                price_impact,
+                sentiment_score: 0.1,
+                market_events: Vec::new(),
+            },
+            recommendations,
+            patterns: vec![
+                Pattern {
+                    id: "standard_transfer".to_string(),
+                    name: "Standard Token Transfer".to_string(),
+                    description: "Basic ERC-20 token transfer pattern".to_string(),
+                    category: "Token Operations".to_string(),
+                    confidence: 0.95,
+                    success_rate: 0.99,
+                    insights: vec![
+                        "Low risk operation".to_string(),
+                        "Predictable gas usage".to_string(),
+                    ],
+                },
+            ],
+            confidence_score: 0.85,
+        })
+    }
+
+    async fn optimize(&self, simulations: &[SimulationResult]) -> Result<BundleOptimization> {
+        let original_order: Vec<usize> = (0..simulations.len()).collect();
+
+        let access_sets: Vec<TxAccessSets> = simulations.iter().map(TxAccessSets::from_simulation).collect();
+        let optimized_order = conflict_aware_order(&access_sets);
+
+        let gas_savings = bundle_cold_access_gas(&original_order, &access_sets)
+            .saturating_sub(bundle_cold_access_gas(&optimized_order, &access_sets));
+
+        let blob_warnings = blob_limit_warnings(&optimized_order, simulations);
+
+        let mut transaction_optimizations = Vec::new();
+        for (i, simulation) in simulations.iter().enumerate() {
+            let current_gas = simulation.gas_used.parse::<u64>().unwrap_or(0);
+            let optimized_gas = current_gas.saturating_sub(current_gas / 10);
+
+            let mut warnings = if simulation.success {
+                Vec::new()
+            } else {
+                vec!["Transaction may fail".to_string()]
+            };
+            for j in 0..access_sets.len() {
+                if j != i && access_sets[i].has_write_write_conflict(&access_sets[j]) {
+                    warnings.push(format!(
+                        "Unavoidable write-write conflict with transaction {} on overlapping storage",
+                        j
+                    ));
+                }
+            }
+            if let Some(blob_warning) = blob_warnings.get(&i) {
+                warnings.push(blob_warning.clone());
+            }
+
+            transaction_optimizations.push(TransactionOptimization {
+                index: i,
+                suggested_gas_limit: Some(optimized_gas.to_string()),
+                suggested_gas_price: Some(Wei::new("20000000000")),
+                suggested_max_fee_per_gas: Some(Wei::new("25000000000")),
+                suggested_max_priority_fee_per_gas: Some(Wei::new("2000000000")),
+                recommendations: vec![
+                    "Optimize gas limit".to_string(),
+                    "Consider timing optimization".to_string(),
+                ],
+                warnings,
+            });
+        }
+
+        Ok(BundleOptimization {
+            original_order,
+            optimized_order,
+            gas_savings: gas_savings.to_string(),
+            time_savings: 2.5,
+            success_probability: 0.92,
+            transaction_optimizations,
+            recommendations: vec![
+                "Execute successful transactions first".to_string(),
+                "Consider adjusting gas prices based on network congestion".to_string(),
+                "Monitor for MEV opportunities".to_string(),
+            ],
+        })
+    }
+}
+
+#[async_trait]
+impl Plugin for HeuristicAnalysisProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "Deterministic gas/fee/blob heuristic analysis provider"
+    }
+
+    async fn initialize(&mut self, _config: &serde_json::Value) -> Result<()> {
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn as_analysis_provider(&self) -> Option<&dyn AnalysisProvider> {
+        Some(self)
+    }
+}
+
+/// Analysis provider backed by the OpenAI API. For now it delegates its
+/// math to a [`HeuristicAnalysisProvider`] — wiring a real OpenAI request
+/// through `client`/`api_key` is future work; this gives the provider
+/// somewhere to carry those once it lands, the same way
+/// [`AIAnalyzer::get_market_analysis`](crate::ai::AIAnalyzer::get_market_analysis)
+/// documents a mocked-for-now external call.
+pub struct OpenAiAnalysisProvider {
+    name: String,
+    api_key: String,
+    client: reqwest::Client,
+    fallback: HeuristicAnalysisProvider,
+}
+
+impl OpenAiAnalysisProvider {
+    pub fn new() -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(|e| HyperSimError::ai_analysis(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self {
+            name: "openai-analysis".to_string(),
+            api_key: String::new(),
+            client,
+            fallback: HeuristicAnalysisProvider::new(),
+        })
+    }
+}
+
+#[async_trait]
+impl AnalysisProvider for OpenAiAnalysisProvider {
+    async fn analyze(&self, simulation_result: &SimulationResult) -> Result<AIInsights> {
+        // In a real implementation, this would call the OpenAI API via
+        // `self.client`, authenticated with `self.api_key`.
+        let _ = (&self.client, &self.api_key);
+        self.fallback.analyze(simulation_result).await
+    }
+
+    async fn optimize(&self, simulations: &[SimulationResult]) -> Result<BundleOptimization> {
+        self.fallback.optimize(simulations).await
+    }
+}
+
+#[async_trait]
+impl Plugin for OpenAiAnalysisProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "OpenAI-backed analysis provider"
+    }
+
+    async fn initialize(&mut self, config: &serde_json::Value) -> Result<()> {
+        if let Some(api_key) = config.get("api_key").and_then(|v| v.as_str()) {
+            self.api_key = api_key.to_string();
+        }
+        if self.api_key.is_empty() {
+            return Err(HyperSimError::plugin("openai-analysis plugin requires an \"api_key\" config value"));
+        }
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn as_analysis_provider(&self) -> Option<&dyn AnalysisProvider> {
+        Some(self)
+    }
+}
+
+/// A transaction's read/write sets of `(address, slot)` pairs, gathered from
+/// its simulated execution trace, plus the set of addresses it touched at
+/// all (for grouping transactions that can share EIP-2929 warm state).
+struct TxAccessSets {
+    addresses: std::collections::HashSet<crate::types::Address>,
+    reads: std::collections::HashSet<(crate::types::Address, String)>,
+    writes: std::collections::HashSet<(crate::types::Address, String)>,
+}
+
+impl TxAccessSets {
+    fn from_simulation(simulation: &SimulationResult) -> Self {
+        let mut addresses = std::collections::HashSet::new();
+        let mut reads = std::collections::HashSet::new();
+        let mut writes = std::collections::HashSet::new();
+
+        if let Some(ref trace) = simulation.trace {
+            for access in &trace.storage_accesses {
+                addresses.insert(access.address.clone());
+                let key = (access.address.clone(), access.slot.clone());
+                match access.access_type {
+                    crate::types::StorageAccessType::Read => { reads.insert(key); }
+                    crate::types::StorageAccessType::Write => { writes.insert(key); }
+                }
+            }
+        }
+
+        Self { addresses, reads, writes }
+    }
+
+    /// Whether `self`, executed before `other`, creates a RAW/WAR/WAW
+    /// dependency: `self` writes something `other` reads or writes, or
+    /// `other` writes something `self` read.
+    fn conflicts_with(&self, other: &Self) -> bool {
+        !self.writes.is_disjoint(&other.reads)
+            || !self.writes.is_disjoint(&other.writes)
+            || !self.reads.is_disjoint(&other.writes)
+    }
+
+    fn has_write_write_conflict(&self, other: &Self) -> bool {
+        !self.writes.is_disjoint(&other.writes)
+    }
+}
+
+/// Find a valid execution order for `sets` that respects every RAW/WAR/WAW
+/// dependency (tx *j* must follow tx *i* for any `i < j` in `conflicts_with`),
+/// greedily grouping transactions that touch overlapping addresses adjacently
+/// so EIP-2929 warm-access discounts carry over across the bundle. Falls back
+/// to the identity order whenever the dependency graph leaves no independent
+/// components to reorder (every step has exactly one eligible transaction).
+fn conflict_aware_order(sets: &[TxAccessSets]) -> Vec<usize> {
+    let n = sets.len();
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut in_degree = vec![0usize; n];
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if sets[i].conflicts_with(&sets[j]) {
+                dependents[i].push(j);
+                in_degree[j] += 1;
+            }
+        }
+    }
+
+    let mut available: Vec<usize> = (0..n).filter(|&i| in_degree[i] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    let mut last_addresses: Option<&std::collections::HashSet<crate::types::Address>> = None;
+
+    while let Some(pos) = available
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, &idx)| {
+            let overlap = last_addresses
+                .map_or(0, |addrs| sets[idx].addresses.intersection(addrs).count());
+            (overlap, std::cmp::Reverse(idx))
+        })
+        .map(|(pos, _)| pos)
+    {
+        let chosen = available.remove(pos);
+        last_addresses = Some(&sets[chosen].addresses);
+        order.push(chosen);
+
+        for &dependent in &dependents[chosen] {
+            in_degree[dependent] -= 1;
+            if in_degree[dependent] == 0 {
+                available.push(dependent);
+            }
+        }
+    }
+
+    order
+}
+
+/// Total EIP-2929 account-access gas `order` would charge, crediting the warm
+/// discount only when an address was also touched by the immediately
+/// preceding transaction (warm state doesn't carry further than that) —
+/// this is exactly what rewards grouping same-address transactions adjacently.
+fn bundle_cold_access_gas(order: &[usize], sets: &[TxAccessSets]) -> u64 {
+    use crate::utils::constants::gas as gas_constants;
+
+    let mut total = 0u64;
+    let mut previous: Option<&std::collections::HashSet<crate::types::Address>> = None;
+
+    for &idx in order {
+        for address in &sets[idx].addresses {
+            let warm = previous.map_or(false, |addrs| addrs.contains(address));
+            total += if warm {
+                gas_constants::WARM_ACCESS_COST
+            } else {
+                gas_constants::COLD_ACCOUNT_ACCESS_COST
+            };
+        }
+        previous = Some(&sets[idx].addresses);
+    }
+
+    total
+}
+
+/// Walk the bundle in `order`, tracking cumulative EIP-4844 blob gas per
+/// `BlockType` (each block type lands in its own block, so the limit is
+/// tracked separately per type), and warn on whichever transaction's blobs
+/// first push that block's cumulative count over the per-block target (3)
+/// or max (6).
+fn blob_limit_warnings(
+    order: &[usize],
+    simulations: &[SimulationResult],
+) -> HashMap<usize, String> {
+    use crate::utils::constants::gas::{BLOB_MAX_PER_BLOCK, BLOB_TARGET_PER_BLOCK};
+
+    let mut cumulative: HashMap<crate::types::BlockType, u32> = HashMap::new();
+    let mut warnings = HashMap::new();
+
+    for &idx in order {
+        let blob_count = simulations[idx].blob_count.unwrap_or(0);
+        if blob_count == 0 {
+            continue;
+        }
+
+        let block_type = simulations[idx].block_type;
+        let total = cumulative.entry(block_type).or_insert(0);
+        *total += blob_count;
+
+        if *total > BLOB_MAX_PER_BLOCK {
+            warnings.insert(idx, format!(
+                "Reordering pushes cumulative blobs for {:?} blocks to {}, over the per-block max ({})",
+                block_type, total, BLOB_MAX_PER_BLOCK
+            ));
+        } else if *total > BLOB_TARGET_PER_BLOCK {
+            warnings.insert(idx, format!(
+                "Reordering pushes cumulative blobs for {:?} blocks to {}, over the per-block target ({})",
+                block_type, total, BLOB_TARGET_PER_BLOCK
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Synthesize an EIP-2930 access list from a simulation's storage trace,
+/// and the total gas it nets back by turning repeated cold accesses warm.
+///
+/// Each address and storage slot is only included when declaring it up
+/// front actually pays for itself: a slot touched `n` times saves
+/// `(n - 1) * (COLD_SLOAD_COST - WARM_STORAGE_READ_COST)` by skipping the
+/// cold surcharge on every touch after the first, which must exceed
+/// `ACCESS_LIST_STORAGE_KEY_COST` to be worth declaring. An address touched
+/// `n` times is credited `(n - 1)` address warm discounts and charged
+/// `ACCESS_LIST_ADDRESS_COST`; it is listed once it carries any declared
+/// slot (the list format requires it), or on its own if those repeated
+/// touches alone clear `ACCESS_LIST_ADDRESS_COST`.
+fn synthesize_access_list(simulation: &SimulationResult) -> (Vec<AccessListEntry>, u64) {
+    use crate::utils::constants::gas as gas_constants;
+
+    let Some(ref trace) = simulation.trace else {
+        return (Vec::new(), 0);
+    };
+
+    let mut slot_counts: HashMap<(crate::types::Address, String), u64> = HashMap::new();
+    for access in &trace.storage_accesses {
+        *slot_counts.entry((access.address.clone(), access.slot.clone())).or_insert(0) += 1;
+    }
+
+    let mut address_counts: HashMap<crate::types::Address, u64> = HashMap::new();
+    for ((address, _), count) in &slot_counts {
+        *address_counts.entry(address.clone()).or_insert(0) += count;
+    }
+
+    let mut net_savings: u64 = 0;
+    let mut declared_slots: HashMap<crate::types::Address, Vec<Hash>> = HashMap::new();
+
+    for ((address, slot), count) in &slot_counts {
+        let savings = (count - 1) * (gas_constants::COLD_SLOAD_COST - gas_constants::WARM_STORAGE_READ_COST);
+        if gas_constants::ACCESS_LIST_STORAGE_KEY_COST < savings {
+            if let Ok(hash) = Hash::new(slot.clone()) {
+                declared_slots.entry(address.clone()).or_default().push(hash);
+                net_savings += savings - gas_constants::ACCESS_LIST_STORAGE_KEY_COST;
+            }
+        }
+    }
+
+    let mut access_list = Vec::new();
+    for (address, count) in &address_counts {
+        let has_declared_slots = declared_slots.contains_key(address);
+        let savings = (count - 1) * (gas_constants::COLD_ACCOUNT_ACCESS_COST - gas_constants::WARM_ACCESS_COST);
+        if has_declared_slots {
+            net_savings = (net_savings + savings).saturating_sub(gas_constants::ACCESS_LIST_ADDRESS_COST);
+        } else {
+            if gas_constants::ACCESS_LIST_ADDRESS_COST >= savings {
+                continue;
+            }
+            net_savings += savings - gas_constants::ACCESS_LIST_ADDRESS_COST;
+        }
+
+        access_list.push(AccessListEntry {
+            address: address.clone(),
+            storage_keys: declared_slots.get(address).cloned().unwrap_or_default(),
+        });
+    }
+
+    (access_list, net_savings)
+}
+
+/// A declared tip more than this multiple of [`DEFAULT_PRIORITY_TIP_PER_GAS`]
+/// is considered wastefully high relative to what recent blocks have needed
+/// to clear.
+const WASTEFUL_TIP_MULTIPLIER: u128 = 5;
+
+/// EIP-2718-envelope-aware recommendations: nudge legacy/access-list
+/// transactions toward EIP-1559 pricing with a quantified savings estimate,
+/// and flag EIP-1559 transactions whose fee parameters are either invalid
+/// (`max_fee_per_gas` below `base_fee + max_priority_fee_per_gas`) or
+/// wastefully generous.
+fn typed_transaction_recommendations(
+    simulation: &SimulationResult,
+    envelope: Option<TransactionEnvelope>,
+) -> Vec<Recommendation> {
+    let mut recommendations = Vec::new();
+
+    let base_fee: Option<u128> =
+        simulation.base_fee_per_gas.as_ref().and_then(|w| w.as_str().parse().ok());
+
+    match envelope {
+        Some(TransactionEnvelope::Legacy) | Some(TransactionEnvelope::AccessList) => {
+            let paid: Option<u128> =
+                simulation.effective_gas_price.as_ref().and_then(|w| w.as_str().parse().ok());
+            if let (Some(base_fee), Some(paid)) = (base_fee, paid) {
+                let type2_price = base_fee.saturating_add(DEFAULT_PRIORITY_TIP_PER_GAS);
+                if paid > type2_price {
+                    let gas_used = simulation.gas_used.parse::<u64>().unwrap_or(0) as u128;
+                    let savings = paid.saturating_sub(type2_price).saturating_mul(gas_used);
+                    recommendations.push(Recommendation {
+                        recommendation_type: crate::types::RecommendationType::ParameterAdjustment,
+                        description: "Migrate from a legacy/access-list transaction to EIP-1559 (type 2) pricing".to_string(),
+                        priority: crate::types::Priority::Medium,
+                        expected_impact: format!(
+                            "Before: {} wei/gas flat gas price. After: ~{} wei/gas (base fee {} + {} wei/gas tip). Saves ~{} wei total",
+                            paid, type2_price, base_fee, DEFAULT_PRIORITY_TIP_PER_GAS, savings
+                        ),
+                        difficulty: crate::types::Difficulty::Easy,
+                        confidence: 0.75,
+                    });
+                }
+            }
+        }
+        Some(TransactionEnvelope::DynamicFee) | Some(TransactionEnvelope::Blob) => {
+            let max_fee: Option<u128> =
+                simulation.max_fee_per_gas.as_ref().and_then(|w| w.as_str().parse().ok());
+            let tip: Option<u128> =
+                simulation.max_priority_fee_per_gas.as_ref().and_then(|w| w.as_str().parse().ok());
+
+            if let (Some(base_fee), Some(max_fee), Some(tip)) = (base_fee, max_fee, tip) {
+                let required = base_fee.saturating_add(tip);
+                if max_fee < required {
+                    recommendations.push(Recommendation {
+                        recommendation_type: crate::types::RecommendationType::ParameterAdjustment,
+                        description: "max_fee_per_gas is below base_fee + max_priority_fee_per_gas".to_string(),
+                        priority: crate::types::Priority::High,
+                        expected_impact: format!(
+                            "Before: max_fee_per_gas {} wei/gas (needs {}). After: raise max_fee_per_gas to at least {} wei/gas so the transaction can be included",
+                            max_fee, required, required
+                        ),
+                        difficulty: crate::types::Difficulty::Easy,
+                        confidence: 0.95,
+                    });
+                }
+            }
+
+            if let Some(tip) = tip {
+                if tip > DEFAULT_PRIORITY_TIP_PER_GAS.saturating_mul(WASTEFUL_TIP_MULTIPLIER) {
+                    recommendations.push(Recommendation {
+                        recommendation_type: crate::types::RecommendationType::ParameterAdjustment,
+                        description: "max_priority_fee_per_gas is set far above what recent blocks have required".to_string(),
+                        priority: crate::types::Priority::Medium,
+                        expected_impact: format!(
+                            "Before: {} wei/gas tip. After: ~{} wei/gas, in line with recent blocks",
+                            tip, DEFAULT_PRIORITY_TIP_PER_GAS
+                        ),
+                        difficulty: crate::types::Difficulty::Easy,
+                        confidence: 0.6,
+                    });
+                }
+            }
+        }
+        None => {}
+    }
+
+    recommendations
+}
+
+/// Post-execution fee accounting for [`GasOptimization`], modeled on an
+/// FVM-style `GasOutputs::compute`: every wei of `gas_limit * effective_gas_price`
+/// is either burned, tipped to the block builder, or refunded to the sender.
+struct FeeBreakdown {
+    effective_gas_price: u128,
+    base_fee_burn: u128,
+    priority_tip: u128,
+    over_estimation_burn: u128,
+    refund: u128,
+}
+
+/// Compute the fee breakdown from a simulation's EIP-1559 context, or
+/// `None` if the simulation didn't carry enough of it (`base_fee_per_gas`,
+/// `effective_gas_price`, `gas_limit`, `max_fee_per_gas`) to do so.
+fn compute_fee_breakdown(simulation: &SimulationResult) -> Option<FeeBreakdown> {
+    use crate::utils::constants::gas::OVER_ESTIMATION_PENALTY_PERCENT;
+
+    let base_fee: u128 = simulation.base_fee_per_gas.as_ref()?.as_str().parse().ok()?;
+    let effective_gas_price: u128 = simulation.effective_gas_price.as_ref()?.as_str().parse().ok()?;
+    let max_fee_per_gas: u128 = simulation.max_fee_per_gas.as_ref()?.as_str().parse().ok()?;
+    let gas_used: u128 = simulation.gas_used.parse().ok()?;
+    let gas_limit: u128 = simulation.gas_limit.as_ref()?.parse().ok()?;
+
+    let base_fee_burn = gas_used * base_fee;
+    let priority_tip = gas_used * effective_gas_price.saturating_sub(base_fee);
+
+    let headroom = gas_limit.saturating_sub(gas_used);
+    let gross_refund = headroom * max_fee_per_gas;
+    let over_estimation_burn = gross_refund * OVER_ESTIMATION_PENALTY_PERCENT as u128 / 100;
+    let refund = gross_refund - over_estimation_burn;
+
+    Some(FeeBreakdown {
+        effective_gas_price,
+        base_fee_burn,
+        priority_tip,
+        over_estimation_burn,
+        refund,
+    })
+}
+
+/// EIP-4844 `(blob_gas_used, blob_fee)` for a blob-carrying simulation, or
+/// `None` if it didn't declare any blobs.
+fn compute_blob_accounting(simulation: &SimulationResult) -> Option<(u64, Wei)> {
+    use crate::utils::constants::gas::GAS_PER_BLOB;
+
+    let blob_count = simulation.blob_count?;
+    let blob_base_fee: u128 = simulation.blob_base_fee.as_ref()?.as_str().parse().ok()?;
+
+    let blob_gas_used = blob_count as u64 * GAS_PER_BLOB;
+    let blob_fee = Wei::new((blob_gas_used as u128 * blob_base_fee).to_string());
+
+    Some((blob_gas_used, blob_fee))
+}
+
+/// How many blobs `simulation`'s calldata would actually need, were it
+/// carried as blob data instead (`ceil(calldata_size / GAS_PER_BLOB)`).
+fn blobs_needed_for_calldata(simulation: &SimulationResult) -> u32 {
+    use crate::utils::constants::gas::GAS_PER_BLOB;
+
+    let calldata_size = simulation.calldata_size.unwrap_or(0);
+    ((calldata_size + GAS_PER_BLOB - 1) / GAS_PER_BLOB) as u32
+}
+
+/// Default per-gas priority tip assumed when a simulation didn't carry
+/// enough EIP-1559 context to observe one, matching the existing
+/// `suggested_max_priority_fee_per_gas` heuristic default (2 gwei)
+const DEFAULT_PRIORITY_TIP_PER_GAS: u128 = 2_000_000_000;
+
+/// How many blocks ahead [`forecast_base_fee`] looks
+const BASE_FEE_FORECAST_BLOCKS: u64 = 5;
+
+/// A base-fee forecast for the next [`BASE_FEE_FORECAST_BLOCKS`] blocks.
+/// `expected` assumes the parent block's own fill ratio repeats every
+/// block; `min`/`max` assume the emptiest/fullest possible block each
+/// step — the fastest the base fee could fall or rise — bounding the band
+/// a sender should actually expect to pay within.
+struct BaseFeeForecast {
+    expected: Vec<u128>,
+    min: Vec<u128>,
+    max: Vec<u128>,
+}
+
+/// Iterate the EIP-1559 recurrence ([`crate::types::compute_next_base_fee`])
+/// forward [`BASE_FEE_FORECAST_BLOCKS`] blocks from a simulation's EIP-1559
+/// context, or `None` if it didn't carry enough of it (`base_fee_per_gas`,
+/// `gas_limit`). Every step is floored at 1 wei: the recurrence can fall by
+/// at most 12.5% per block but must never reach (or cross below) zero.
+fn forecast_base_fee(simulation: &SimulationResult) -> Option<BaseFeeForecast> {
+    let base_fee: u128 = simulation.base_fee_per_gas.as_ref()?.as_str().parse().ok()?;
+    let gas_limit: u64 = simulation.gas_limit.as_ref()?.parse().ok()?;
+    let gas_used: u64 = simulation.gas_used.parse().ok()?;
+
+    if gas_limit == 0 {
+        return None;
+    }
+
+    let mut forecast = BaseFeeForecast { expected: Vec::new(), min: Vec::new(), max: Vec::new() };
+    let (mut expected_fee, mut min_fee, mut max_fee) = (base_fee, base_fee, base_fee);
+
+    for _ in 0..BASE_FEE_FORECAST_BLOCKS {
+        expected_fee = crate::types::compute_next_base_fee(expected_fee, gas_limit, gas_used).max(1);
+        min_fee = crate::types::compute_next_base_fee(min_fee, gas_limit, 0).max(1);
+        max_fee = crate::types::compute_next_base_fee(max_fee, gas_limit, gas_limit).max(1);
+
+        forecast.expected.push(expected_fee);
+        forecast.min.push(min_fee);
+        forecast.max.push(max_fee);
+    }
+
+    Some(forecast)
+}
+
+/// The per-gas priority tip this simulation actually paid
+/// (`effective_gas_price - base_fee_per_gas`), or `None` if it didn't carry
+/// enough EIP-1559 context to observe one
+fn observed_priority_tip_per_gas(simulation: &SimulationResult) -> Option<u128> {
+    let base_fee: u128 = simulation.base_fee_per_gas.as_ref()?.as_str().parse().ok()?;
+    let effective_gas_price: u128 = simulation.effective_gas_price.as_ref()?.as_str().parse().ok()?;
+    Some(effective_gas_price.saturating_sub(base_fee))
+}
+
+/// `TimeWindow`s for blocks in `forecast.expected` cheaper than
+/// `current_base_fee`, so a sender willing to wait can see exactly which
+/// upcoming block(s) are worth it. Windows are expressed as Unix
+/// timestamps, spaced out using `block_type`'s typical confirmation time.
+fn optimal_timing_windows(
+    current_base_fee: u128,
+    forecast: &BaseFeeForecast,
+    block_type: crate::types::BlockType,
+) -> Vec<TimeWindow> {
+    let block_time = block_type.confirmation_time_secs();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    forecast
+        .expected
+        .iter()
+        .zip(forecast.min.iter())
+        .zip(forecast.max.iter())
+        .enumerate()
+        .filter_map(|(i, ((&expected, &min), &max))| {
+            let improvement_factor = current_base_fee as f64 / expected as f64;
+            if improvement_factor <= 1.0 {
+                return None;
+            }
+
+            let start = now + (i as u64) * block_time;
+            Some(TimeWindow {
+                start,
+                end: start + block_time,
+                improvement_factor,
+                reason: format!(
+                    "Base fee forecast to fall to ~{} wei (range {}-{}) in ~{} block(s)",
+                    expected,
+                    min,
+                    max,
+                    i + 1
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Drop `windows` that would push a venue over `venue.rate_limit` submissions
+/// within any trailing `venue.rate_limit_interval_secs` window, keeping them
+/// in the order `optimal_timing_windows` produced (earliest, i.e. cheapest
+/// improvement, first). A window is kept only if fewer than `rate_limit`
+/// already-kept windows start within `rate_limit_interval_secs` before it.
+/// Dropped windows are reported as bottleneck strings so the caller can fold
+/// them into `PerformanceInsights::bottlenecks`.
+fn venue_budget_windows(
+    windows: Vec<TimeWindow>,
+    venue: &VenueConstraints,
+) -> (Vec<TimeWindow>, Vec<String>) {
+    let mut kept: Vec<TimeWindow> = Vec::new();
+    let mut bottlenecks = Vec::new();
+
+    for window in windows {
+        let submissions_in_window = kept
+            .iter()
+            .filter(|kept_window| {
+                window.start.saturating_sub(kept_window.start) < venue.rate_limit_interval_secs
+            })
+            .count();
+
+        if submissions_in_window >= venue.rate_limit as usize {
+            bottlenecks.push(format!(
+                "{}: dropped timing window at t={} — rate limit of {} submission(s) per {}s already reached",
+                venue.name, window.start, venue.rate_limit, venue.rate_limit_interval_secs
+            ));
+            continue;
+        }
+
+        kept.push(window);
+    }
+
+    (kept, bottlenecks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Address, BlockType};
+
+    fn simulation_with_accesses(
+        accesses: Vec<crate::types::StorageAccess>,
+    ) -> SimulationResult {
+        SimulationResult {
+            success: true,
+            gas_used: "21000".to_string(),
+            return_data: None,
+            error: None,
+            revert_reason: None,
+            block_type: BlockType::Fast,
+            estimated_block: 12345,
+            trace: Some(crate::types::ExecutionTrace {
+                calls: Vec::new(),
+                gas_breakdown: crate::types::GasBreakdown {
+                    intrinsic: "0".to_string(),
+                    execution: "0".to_string(),
+                    cold_access: "0".to_string(),
+                    warm_access: "0".to_string(),
+                    refund: "0".to_string(),
+                    total: "0".to_string(),
+                },
+                storage_accesses: accesses,
+                opcode_steps: Vec::new(),
+            }),
+            hypercore_data: None,
+            state_changes: Vec::new(),
+            events: Vec::new(),
+            tx_hash: None,
+            verification: crate::verification::VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
+        }
+    }
+
+    fn storage_access(
+        address: &Address,
+        slot: &str,
+        access_type: crate::types::StorageAccessType,
+    ) -> crate::types::StorageAccess {
+        crate::types::StorageAccess {
+            address: address.clone(),
+            slot: slot.to_string(),
+            access_type,
+            original_value: None,
+            new_value: None,
+            cold: false,
+            gas_cost: "0".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_analyzes_low_risk_transfer() {
+        let provider = HeuristicAnalysisProvider::new();
+        let insights = provider.analyze(&simulation_with_accesses(Vec::new())).await.unwrap();
+        assert_eq!(insights.risk_level, RiskLevel::Low);
+        assert!(insights.success_probability > 0.9);
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_includes_repeatedly_touched_slot_in_access_list() {
+        use crate::types::StorageAccessType;
+
+        let provider = HeuristicAnalysisProvider::new();
+        let contract = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        let simulation = simulation_with_accesses(vec![
+            storage_access(&contract, slot, StorageAccessType::Read),
+            storage_access(&contract, slot, StorageAccessType::Write),
+            storage_access(&contract, slot, StorageAccessType::Read),
+        ]);
+
+        let insights = provider.analyze(&simulation).await.unwrap();
+        assert_eq!(insights.gas_optimization.access_list.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_recommends_access_list_addition_when_net_savings_positive() {
+        use crate::types::{RecommendationType, StorageAccessType};
+
+        let provider = HeuristicAnalysisProvider::new();
+        let contract = Address::new("0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123").unwrap();
+        let slot = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+        let simulation = simulation_with_accesses(vec![
+            storage_access(&contract, slot, StorageAccessType::Read),
+            storage_access(&contract, slot, StorageAccessType::Write),
+            storage_access(&contract, slot, StorageAccessType::Read),
+        ]);
+
+        let insights = provider.analyze(&simulation).await.unwrap();
+        assert!(insights
+            .recommendations
+            .iter()
+            .any(|r| matches!(r.recommendation_type, RecommendationType::AccessListAddition)));
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_omits_access_list_recommendation_without_repeated_touches() {
+        use crate::types::RecommendationType;
+
+        let provider = HeuristicAnalysisProvider::new();
+        let insights = provider.analyze(&simulation_with_accesses(Vec::new())).await.unwrap();
+        assert!(insights.gas_optimization.access_list.is_empty());
+        assert!(!insights
+            .recommendations
+            .iter()
+            .any(|r| matches!(r.recommendation_type, RecommendationType::AccessListAddition)));
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_forecasts_suggested_max_fee_from_rising_base_fee() {
+        let provider = HeuristicAnalysisProvider::new();
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.gas_used = "30000000".to_string();
+        simulation.gas_limit = Some("30000000".to_string()); // fully-used block: base fee should rise
+        simulation.base_fee_per_gas = Some(Wei::new("1000000000"));
+
+        let insights = provider.analyze(&simulation).await.unwrap();
+        let suggested: u128 = insights
+            .gas_optimization
+            .suggested_max_fee_per_gas
+            .unwrap()
+            .as_str()
+            .parse()
+            .unwrap();
+
+        // A fully-used block raises the base fee by 12.5% every step, so
+        // 5 blocks out it's grown by ~1.125^5 ≈ 1.8x; doubled plus the
+        // default 2 gwei tip comfortably clears 3 gwei.
+        assert!(suggested > 3_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_emits_optimal_timing_window_for_falling_base_fee() {
+        let provider = HeuristicAnalysisProvider::new();
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.gas_used = "0".to_string(); // empty block: base fee only falls
+        simulation.gas_limit = Some("30000000".to_string());
+        simulation.base_fee_per_gas = Some(Wei::new("1000000000"));
+
+        let insights = provider.analyze(&simulation).await.unwrap();
+        let windows = insights.performance_insights.optimal_timing;
+        assert!(!windows.is_empty());
+        assert!(windows.iter().all(|w| w.improvement_factor > 1.0));
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_omits_timing_windows_without_eip1559_context() {
+        let provider = HeuristicAnalysisProvider::new();
+        let insights = provider.analyze(&simulation_with_accesses(Vec::new())).await.unwrap();
+        assert!(insights.performance_insights.optimal_timing.is_empty());
+    }
+
+    #[test]
+    fn test_forecast_base_fee_floors_at_one_wei_under_sustained_empty_blocks() {
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.gas_used = "0".to_string();
+        simulation.gas_limit = Some("30000000".to_string());
+        simulation.base_fee_per_gas = Some(Wei::new("1"));
+
+        let forecast = forecast_base_fee(&simulation).unwrap();
+        assert!(forecast.expected.iter().all(|&fee| fee >= 1));
+        assert!(forecast.min.iter().all(|&fee| fee >= 1));
+    }
+
+    #[test]
+    fn test_forecast_base_fee_none_without_eip1559_context() {
+        let simulation = simulation_with_accesses(Vec::new());
+        assert!(forecast_base_fee(&simulation).is_none());
+    }
+
+    #[test]
+    fn test_observed_priority_tip_per_gas_subtracts_base_fee() {
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.base_fee_per_gas = Some(Wei::new("1000000000"));
+        simulation.effective_gas_price = Some(Wei::new("1500000000"));
+        assert_eq!(observed_priority_tip_per_gas(&simulation), Some(500_000_000));
+    }
+
+    fn test_venue(rate_limit: u32, rate_limit_interval_secs: u64) -> VenueConstraints {
+        VenueConstraints {
+            name: "Test Venue".to_string(),
+            rate_limit,
+            rate_limit_interval_secs,
+            min_lot: 0.0,
+            tick_size: 1.0,
+            min_notional: 0.0,
+        }
+    }
+
+    fn window_at(start: u64) -> TimeWindow {
+        TimeWindow {
+            start,
+            end: start + 12,
+            improvement_factor: 1.1,
+            reason: "test window".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_venue_budget_windows_keeps_windows_within_rate_limit() {
+        let venue = test_venue(2, 60);
+        let windows = vec![window_at(0), window_at(10)];
+        let (kept, bottlenecks) = venue_budget_windows(windows, &venue);
+        assert_eq!(kept.len(), 2);
+        assert!(bottlenecks.is_empty());
+    }
+
+    #[test]
+    fn test_venue_budget_windows_drops_windows_past_rate_limit() {
+        let venue = test_venue(1, 60);
+        let windows = vec![window_at(0), window_at(10), window_at(90)];
+        let (kept, bottlenecks) = venue_budget_windows(windows, &venue);
+        assert_eq!(kept.iter().map(|w| w.start).collect::<Vec<_>>(), vec![0, 90]);
+        assert_eq!(bottlenecks.len(), 1);
+        assert!(bottlenecks[0].contains("dropped timing window"));
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_recommends_migrating_legacy_tx_to_type_two() {
+        use crate::types::RecommendationType;
+
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.tx_type = Some(0);
+        simulation.base_fee_per_gas = Some(Wei::new("1000000000"));
+        simulation.effective_gas_price = Some(Wei::new("20000000000"));
+
+        let provider = HeuristicAnalysisProvider::new();
+        let insights = provider.analyze(&simulation).await.unwrap();
+        assert_eq!(insights.gas_optimization.transaction_envelope, Some(crate::types::TransactionEnvelope::Legacy));
+        assert!(insights
+            .recommendations
+            .iter()
+            .any(|r| matches!(r.recommendation_type, RecommendationType::ParameterAdjustment)
+                && r.description.contains("Migrate")));
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_flags_underpriced_type_two_max_fee() {
+        use crate::types::RecommendationType;
+
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.tx_type = Some(2);
+        simulation.base_fee_per_gas = Some(Wei::new("1000000000"));
+        simulation.max_priority_fee_per_gas = Some(Wei::new("1000000000"));
+        simulation.max_fee_per_gas = Some(Wei::new("1500000000"));
+
+        let provider = HeuristicAnalysisProvider::new();
+        let insights = provider.analyze(&simulation).await.unwrap();
+        assert!(insights.recommendations.iter().any(|r| {
+            matches!(r.recommendation_type, RecommendationType::ParameterAdjustment)
+                && r.description.contains("below base_fee")
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_flags_wastefully_high_priority_tip() {
+        use crate::types::RecommendationType;
+
+        let mut simulation = simulation_with_accesses(Vec::new());
+        simulation.tx_type = Some(2);
+        simulation.base_fee_per_gas = Some(Wei::new("1000000000"));
+        simulation.max_priority_fee_per_gas = Some(Wei::new("50000000000"));
+        simulation.max_fee_per_gas = Some(Wei::new("60000000000"));
+
+        let provider = HeuristicAnalysisProvider::new();
+        let insights = provider.analyze(&simulation).await.unwrap();
+        assert!(insights.recommendations.iter().any(|r| {
+            matches!(r.recommendation_type, RecommendationType::ParameterAdjustment)
+                && r.description.contains("far above")
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_heuristic_provider_optimize_keeps_identity_order_without_conflicts() {
+        let provider = HeuristicAnalysisProvider::new();
+        let simulations = vec![
+            simulation_with_accesses(Vec::new()),
+            simulation_with_accesses(Vec::new()),
+        ];
+
+        let optimization = provider.optimize(&simulations).await.unwrap();
+        assert_eq!(optimization.optimized_order, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_openai_provider_requires_api_key() {
+        let mut provider = OpenAiAnalysisProvider::new().unwrap();
+        let err = provider.initialize(&serde_json::json!({})).await.unwrap_err();
+        assert!(err.to_string().contains("api_key"));
+    }
+
+    #[tokio::test]
+    async fn test_openai_provider_delegates_to_heuristic_once_initialized() {
+        let mut provider = OpenAiAnalysisProvider::new().unwrap();
+        provider.initialize(&serde_json::json!({ "api_key": "test-key" })).await.unwrap();
+
+        let insights = AnalysisProvider::analyze(&provider, &simulation_with_accesses(Vec::new())).await.unwrap();
+        assert_eq!(insights.risk_level, RiskLevel::Low);
+    }
+}
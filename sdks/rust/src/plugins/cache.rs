@@ -0,0 +1,231 @@
+//! Pluggable backends for the analysis cache
+//!
+//! [`AnalysisCacheStore`] lets [`AIAnalyzer`](crate::ai::AIAnalyzer) persist
+//! cached [`AIInsights`](crate::types::AIInsights) somewhere other than an
+//! in-process map — a shared Redis instance, an embedded KV store, whatever
+//! a [`Plugin`] chooses to wrap — loaded and priority-ordered exactly like
+//! an [`AnalysisProvider`](crate::plugins::analysis::AnalysisProvider) via
+//! [`PluginSystem`](crate::plugins::PluginSystem) and `PluginConfig`.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::error::{HyperSimError, Result};
+use crate::plugins::Plugin;
+
+/// A keyed store for serialized cached analyses. Implement this (and
+/// [`Plugin::as_cache_store`]) to back the analysis cache with something
+/// other than the in-process default — Redis, RocksDB, sled, or any other
+/// keyed store.
+#[async_trait]
+pub trait AnalysisCacheStore: Send + Sync {
+    /// Fetch a cached entry by key, or `None` if missing
+    async fn read(&self, key: &str) -> Result<Option<String>>;
+
+    /// Store the serialized entry under `key`
+    async fn write(&self, key: &str, value: String) -> Result<()>;
+
+    /// Remove a cached entry
+    async fn evict(&self, key: &str) -> Result<()>;
+}
+
+/// Process-local in-memory cache store, used when no plugin registers one
+pub struct InMemoryAnalysisCacheStore {
+    name: String,
+    entries: RwLock<HashMap<String, String>>,
+}
+
+impl InMemoryAnalysisCacheStore {
+    pub fn new() -> Self {
+        Self { name: "in-memory-cache".to_string(), entries: RwLock::new(HashMap::new()) }
+    }
+}
+
+impl Default for InMemoryAnalysisCacheStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AnalysisCacheStore for InMemoryAnalysisCacheStore {
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.entries.read().await.get(key).cloned())
+    }
+
+    async fn write(&self, key: &str, value: String) -> Result<()> {
+        self.entries.write().await.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn evict(&self, key: &str) -> Result<()> {
+        self.entries.write().await.remove(key);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Plugin for InMemoryAnalysisCacheStore {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "In-process, non-persistent analysis cache store"
+    }
+
+    async fn initialize(&mut self, _config: &serde_json::Value) -> Result<()> {
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn as_cache_store(&self) -> Option<&dyn AnalysisCacheStore> {
+        Some(self)
+    }
+}
+
+/// Redis-backed analysis cache store, so cached insights survive a restart
+/// and can be shared across SDK instances
+pub struct RedisAnalysisCacheStore {
+    name: String,
+    url: String,
+    key_prefix: String,
+    client: Option<redis::Client>,
+}
+
+impl RedisAnalysisCacheStore {
+    pub fn new() -> Self {
+        Self {
+            name: "redis-cache".to_string(),
+            url: String::new(),
+            key_prefix: "hypersim:analysis".to_string(),
+            client: None,
+        }
+    }
+
+    fn namespaced(&self, key: &str) -> String {
+        format!("{}:{}", self.key_prefix, key)
+    }
+
+    fn connected_client(&self) -> Result<&redis::Client> {
+        self.client.as_ref().ok_or_else(|| HyperSimError::plugin("redis-cache plugin is not initialized"))
+    }
+}
+
+impl Default for RedisAnalysisCacheStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl AnalysisCacheStore for RedisAnalysisCacheStore {
+    async fn read(&self, key: &str) -> Result<Option<String>> {
+        use redis::AsyncCommands;
+        let mut conn = self.connected_client()?.get_multiplexed_async_connection().await
+            .map_err(|e| HyperSimError::network(format!("Redis connection failed: {}", e)))?;
+        conn.get(self.namespaced(key)).await
+            .map_err(|e| HyperSimError::network(format!("Redis GET failed: {}", e)))
+    }
+
+    async fn write(&self, key: &str, value: String) -> Result<()> {
+        use redis::AsyncCommands;
+        let mut conn = self.connected_client()?.get_multiplexed_async_connection().await
+            .map_err(|e| HyperSimError::network(format!("Redis connection failed: {}", e)))?;
+        conn.set(self.namespaced(key), value).await
+            .map_err(|e| HyperSimError::network(format!("Redis SET failed: {}", e)))
+    }
+
+    async fn evict(&self, key: &str) -> Result<()> {
+        use redis::AsyncCommands;
+        let mut conn = self.connected_client()?.get_multiplexed_async_connection().await
+            .map_err(|e| HyperSimError::network(format!("Redis connection failed: {}", e)))?;
+        conn.del(self.namespaced(key)).await
+            .map_err(|e| HyperSimError::network(format!("Redis DEL failed: {}", e)))
+    }
+}
+
+#[async_trait]
+impl Plugin for RedisAnalysisCacheStore {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "Redis-backed, cross-process analysis cache store"
+    }
+
+    async fn initialize(&mut self, config: &serde_json::Value) -> Result<()> {
+        if let Some(url) = config.get("url").and_then(|v| v.as_str()) {
+            self.url = url.to_string();
+        }
+        if self.url.is_empty() {
+            return Err(HyperSimError::plugin("redis-cache plugin requires a \"url\" config value"));
+        }
+        if let Some(key_prefix) = config.get("key_prefix").and_then(|v| v.as_str()) {
+            self.key_prefix = key_prefix.to_string();
+        }
+
+        self.client = Some(
+            redis::Client::open(self.url.as_str())
+                .map_err(|e| HyperSimError::plugin(format!("Invalid Redis URL: {}", e)))?,
+        );
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn as_cache_store(&self) -> Option<&dyn AnalysisCacheStore> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_store_round_trips_value() {
+        let store = InMemoryAnalysisCacheStore::new();
+        store.write("key", "value".to_string()).await.unwrap();
+        assert_eq!(store.read("key").await.unwrap(), Some("value".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_evict_removes_entry() {
+        let store = InMemoryAnalysisCacheStore::new();
+        store.write("key", "value".to_string()).await.unwrap();
+        store.evict("key").await.unwrap();
+        assert_eq!(store.read("key").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_redis_cache_store_requires_url() {
+        let mut plugin = RedisAnalysisCacheStore::new();
+        let config = serde_json::json!({});
+        assert!(plugin.initialize(&config).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_redis_cache_store_initializes_with_url() {
+        let mut plugin = RedisAnalysisCacheStore::new();
+        let config = serde_json::json!({ "url": "redis://localhost:6379", "key_prefix": "test" });
+        assert!(plugin.initialize(&config).await.is_ok());
+        assert!(plugin.as_cache_store().is_some());
+    }
+}
@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Configuration for a plugin
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +17,15 @@ pub struct PluginConfig {
     pub config: HashMap<String, serde_json::Value>,
     /// Plugin priority (lower numbers = higher priority)
     pub priority: u32,
+    /// Path to a `wasm32-wasi` module implementing this plugin. When set,
+    /// `PluginSystem::load_plugin` loads a [`WasmPlugin`](crate::plugins::wasm::WasmPlugin)
+    /// instead of looking the name up among the built-ins
+    pub wasm_path: Option<PathBuf>,
+    /// Expected content hash of `wasm_path`, checked before instantiating
+    pub wasm_hash: Option<String>,
+    /// Names of plugins that must be placed earlier in the execution order
+    /// than this one
+    pub depends_on: Vec<String>,
 }
 
 impl PluginConfig {
@@ -26,6 +36,9 @@ impl PluginConfig {
             enabled: true,
             config: HashMap::new(),
             priority: 100,
+            wasm_path: None,
+            wasm_hash: None,
+            depends_on: Vec::new(),
         }
     }
 
@@ -48,4 +61,23 @@ impl PluginConfig {
         self.priority = priority;
         self
     }
+
+    /// Load this plugin from a compiled `wasm32-wasi` module at `path`
+    /// instead of one of the built-ins
+    pub fn wasm_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.wasm_path = Some(path.into());
+        self
+    }
+
+    /// Expected content hash of `wasm_path`, verified before instantiating
+    pub fn wasm_hash(mut self, hash: impl Into<String>) -> Self {
+        self.wasm_hash = Some(hash.into());
+        self
+    }
+
+    /// Require `plugin_name` to be placed earlier in the execution order
+    pub fn depends_on(mut self, plugin_name: impl Into<String>) -> Self {
+        self.depends_on.push(plugin_name.into());
+        self
+    }
 }
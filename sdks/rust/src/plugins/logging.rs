@@ -0,0 +1,183 @@
+//! Per-operation plugin execution logging
+//!
+//! When [`PluginSystem`](crate::plugins::PluginSystem) is configured with a
+//! log directory, every plugin hook invocation inside
+//! `execute_before_simulation`/`execute_after_simulation`/`execute_on_error`
+//! is recorded to a dedicated log file: a structured record per plugin
+//! (name, hook, start/end timestamps, normalized outcome) plus whatever
+//! `tracing` output the plugin emitted during the call. A failing hook no
+//! longer just gets `error!`-logged and swallowed — it's collected into a
+//! [`PluginExecutionError`] carrying the log file path, so callers can
+//! surface "plugin X failed, see &lt;logfile&gt;" to users.
+
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+
+use crate::error::Result;
+
+/// A plugin hook invocation that failed, with a pointer to the log file
+/// recording it (when plugin execution logging is enabled)
+#[derive(Debug, Clone)]
+pub struct PluginExecutionError {
+    pub plugin_name: String,
+    pub hook: String,
+    pub message: String,
+    pub log_path: Option<PathBuf>,
+}
+
+impl fmt::Display for PluginExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.log_path {
+            Some(path) => write!(
+                f,
+                "plugin '{}' failed during {}: {} (see {})",
+                self.plugin_name,
+                self.hook,
+                self.message,
+                path.display()
+            ),
+            None => write!(f, "plugin '{}' failed during {}: {}", self.plugin_name, self.hook, self.message),
+        }
+    }
+}
+
+/// Outcome of a single plugin hook invocation, normalized so log output
+/// renders identically across platforms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HookOutcome {
+    Success,
+    Failure,
+}
+
+impl fmt::Display for HookOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HookOutcome::Success => "success",
+            HookOutcome::Failure => "failure",
+        })
+    }
+}
+
+/// Writes structured per-plugin-hook records, and any `tracing` output
+/// captured while the hook ran, to a file under a configured log directory.
+/// A `HookLogger` with no log directory is a no-op passthrough.
+pub(crate) struct HookLogger {
+    log_dir: Option<PathBuf>,
+}
+
+impl HookLogger {
+    pub fn new(log_dir: Option<PathBuf>) -> Self {
+        Self { log_dir }
+    }
+
+    /// Run `call`, capturing any `tracing` events it emits, and (when a log
+    /// directory is configured) append a structured record plus the
+    /// captured output to this invocation's log file. Returns whatever
+    /// `call` returned, alongside the log file path if one was written.
+    pub async fn record<Fut, T>(&self, plugin_name: &str, hook: &str, call: Fut) -> (Result<T>, Option<PathBuf>)
+    where
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let Some(log_dir) = &self.log_dir else {
+            return (call.await, None);
+        };
+
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let writer = CapturingWriter { buffer: Arc::clone(&captured) };
+        let subscriber = tracing_subscriber::fmt().with_writer(writer).with_ansi(false).finish();
+
+        let start = Utc::now();
+        let result = {
+            let _guard = tracing::subscriber::set_default(subscriber);
+            call.await
+        };
+        let end = Utc::now();
+
+        let outcome = if result.is_ok() { HookOutcome::Success } else { HookOutcome::Failure };
+        let error_message = result.as_ref().err().map(|e| e.to_string());
+        let log_path = self.write_record(log_dir, plugin_name, hook, start, end, outcome, error_message.as_deref(), &captured);
+
+        (result, log_path)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_record(
+        &self,
+        log_dir: &Path,
+        plugin_name: &str,
+        hook: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        outcome: HookOutcome,
+        error_message: Option<&str>,
+        captured: &Arc<Mutex<Vec<u8>>>,
+    ) -> Option<PathBuf> {
+        if let Err(e) = std::fs::create_dir_all(log_dir) {
+            tracing::warn!("Failed to create plugin log directory {}: {}", log_dir.display(), e);
+            return None;
+        }
+
+        let path = log_dir.join(format!("{}-{}-{}.log", start.timestamp_millis(), plugin_name, hook));
+
+        let mut contents = String::new();
+        contents.push_str(&format!("plugin: {}\n", plugin_name));
+        contents.push_str(&format!("hook: {}\n", hook));
+        contents.push_str(&format!("start: {}\n", start.to_rfc3339()));
+        contents.push_str(&format!("end: {}\n", end.to_rfc3339()));
+        contents.push_str(&format!("outcome: {}\n", outcome));
+        if let Some(message) = error_message {
+            contents.push_str(&format!("error: {}\n", message));
+        }
+        contents.push_str("--- captured output ---\n");
+        if let Ok(buffer) = captured.lock() {
+            contents.push_str(&String::from_utf8_lossy(&buffer));
+        }
+
+        match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(mut file) => match file.write_all(contents.as_bytes()) {
+                Ok(()) => Some(path),
+                Err(e) => {
+                    tracing::warn!("Failed to write plugin log {}: {}", path.display(), e);
+                    None
+                }
+            },
+            Err(e) => {
+                tracing::warn!("Failed to open plugin log {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct CapturingWriter {
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for CapturingWriter {
+    type Writer = CapturingHandle;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        CapturingHandle { buffer: Arc::clone(&self.buffer) }
+    }
+}
+
+struct CapturingHandle {
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Write for CapturingHandle {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
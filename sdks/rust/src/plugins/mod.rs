@@ -1,9 +1,17 @@
 //! Plugin system for extending SDK functionality
 
+pub mod analysis;
+pub mod cache;
 pub mod system;
 pub mod traits;
 pub mod config;
+pub mod logging;
+pub mod wasm;
 
+pub use analysis::{AnalysisProvider, HeuristicAnalysisProvider, OpenAiAnalysisProvider};
+pub use cache::{AnalysisCacheStore, InMemoryAnalysisCacheStore, RedisAnalysisCacheStore};
 pub use system::PluginSystem;
 pub use traits::Plugin;
 pub use config::PluginConfig;
+pub use logging::PluginExecutionError;
+pub use wasm::WasmPlugin;
@@ -1,32 +1,56 @@
 //! Plugin system implementation
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
-use crate::plugins::{Plugin, PluginConfig};
+use crate::plugins::{Plugin, PluginConfig, PluginExecutionError, WasmPlugin};
+use crate::plugins::analysis::{HeuristicAnalysisProvider, OpenAiAnalysisProvider};
+use crate::plugins::cache::{InMemoryAnalysisCacheStore, RedisAnalysisCacheStore};
+use crate::plugins::logging::HookLogger;
 use crate::plugins::traits::builtin::{LoggingPlugin, MetricsPlugin};
-use crate::types::{TransactionRequest, SimulationResult};
+use crate::types::{AIInsights, BundleOptimization, TransactionRequest, SimulationResult, WSSubscription, WSEvent};
 use crate::error::{HyperSimError, Result};
 
 /// Plugin system for managing and executing plugins
 pub struct PluginSystem {
     /// Loaded plugins
     plugins: Arc<RwLock<HashMap<String, Box<dyn Plugin>>>>,
-    /// Plugin execution order (by priority)
+    /// Plugin execution order, recomputed from `plugin_meta` via topological sort
     execution_order: Arc<RwLock<Vec<String>>>,
+    /// Priority and dependency metadata for each loaded plugin, used to
+    /// recompute `execution_order`
+    plugin_meta: Arc<RwLock<HashMap<String, PluginMeta>>>,
     /// Whether system is initialized
     initialized: bool,
+    /// Records structured per-plugin-hook logs when a log directory is configured
+    hook_logger: HookLogger,
+}
+
+/// Priority and dependency metadata for a loaded plugin, used by
+/// `PluginSystem::recompute_execution_order` to order hook execution
+struct PluginMeta {
+    priority: u32,
+    depends_on: Vec<String>,
 }
 
 impl PluginSystem {
-    /// Create a new plugin system
+    /// Create a new plugin system with plugin execution logging disabled
     pub async fn new() -> Result<Self> {
+        Self::with_log_dir(None).await
+    }
+
+    /// Create a new plugin system, recording a structured log per plugin
+    /// hook invocation under `log_dir` when set
+    pub async fn with_log_dir(log_dir: Option<PathBuf>) -> Result<Self> {
         let mut system = Self {
             plugins: Arc::new(RwLock::new(HashMap::new())),
             execution_order: Arc::new(RwLock::new(Vec::new())),
+            plugin_meta: Arc::new(RwLock::new(HashMap::new())),
             initialized: true,
+            hook_logger: HookLogger::new(log_dir),
         };
 
         // Load built-in plugins
@@ -35,17 +59,23 @@ impl PluginSystem {
         Ok(system)
     }
 
-    /// Load a plugin from configuration
+    /// Load a plugin from configuration. If `config.wasm_path` is set, the
+    /// plugin is loaded as a compiled `wasm32-wasi` module via [`WasmPlugin`]
+    /// instead of being looked up among the built-ins.
     pub async fn load_plugin(&self, config: PluginConfig) -> Result<()> {
         if !config.enabled {
             debug!("Plugin {} is disabled, skipping", config.name);
-            return Ok();
+            return Ok(());
         }
 
         info!("Loading plugin: {}", config.name);
 
         // Create plugin instance
-        let mut plugin = self.create_plugin(&config.name)?;
+        let mut plugin: Box<dyn Plugin> = if let Some(wasm_path) = config.wasm_path.clone() {
+            Box::new(WasmPlugin::load(config.name.clone(), wasm_path, config.wasm_hash.as_deref()).await?)
+        } else {
+            self.create_plugin(&config.name)?
+        };
 
         // Initialize plugin with configuration
         let config_value = serde_json::to_value(&config.config)
@@ -56,23 +86,105 @@ impl PluginSystem {
         // Store plugin
         let mut plugins = self.plugins.write().await;
         plugins.insert(config.name.clone(), plugin);
+        drop(plugins);
 
         // Update execution order
-        self.update_execution_order(config.name, config.priority).await;
+        self.update_execution_order(config.name.clone(), config.priority, config.depends_on).await?;
 
         info!("Plugin {} loaded successfully", config.name);
         Ok(())
     }
 
-    /// Execute before simulation hooks
-    pub async fn execute_before_simulation(&self, request: &TransactionRequest) -> Result<()> {
+    /// Execute before simulation hooks. A failing plugin doesn't stop the
+    /// others from running; every failure is collected (with its log file
+    /// path, if plugin execution logging is enabled) instead of being
+    /// silently swallowed.
+    pub async fn execute_before_simulation(&self, request: &TransactionRequest) -> Result<Vec<PluginExecutionError>> {
         let execution_order = self.execution_order.read().await;
         let plugins = self.plugins.read().await;
+        let mut errors = Vec::new();
 
         for plugin_name in execution_order.iter() {
             if let Some(plugin) = plugins.get(plugin_name) {
-                if let Err(e) = plugin.before_simulation(request).await {
+                let (outcome, log_path) =
+                    self.hook_logger.record(plugin_name, "before_simulation", plugin.before_simulation(request)).await;
+                if let Err(e) = outcome {
                     error!("Plugin {} before_simulation failed: {}", plugin_name, e);
+                    errors.push(PluginExecutionError {
+                        plugin_name: plugin_name.clone(),
+                        hook: "before_simulation".to_string(),
+                        message: e.to_string(),
+                        log_path,
+                    });
+                }
+            }
+        }
+
+        Ok(errors)
+    }
+
+    /// Execute after simulation hooks. A failing plugin doesn't stop the
+    /// others from running; every failure is collected the same way as in
+    /// [`Self::execute_before_simulation`].
+    pub async fn execute_after_simulation(&self, result: &mut SimulationResult) -> Result<Vec<PluginExecutionError>> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+        let mut errors = Vec::new();
+
+        for plugin_name in execution_order.iter() {
+            if let Some(plugin) = plugins.get(plugin_name) {
+                let (outcome, log_path) =
+                    self.hook_logger.record(plugin_name, "after_simulation", plugin.after_simulation(result)).await;
+                if let Err(e) = outcome {
+                    error!("Plugin {} after_simulation failed: {}", plugin_name, e);
+                    errors.push(PluginExecutionError {
+                        plugin_name: plugin_name.clone(),
+                        hook: "after_simulation".to_string(),
+                        message: e.to_string(),
+                        log_path,
+                    });
+                }
+            }
+        }
+
+        Ok(errors)
+    }
+
+    /// Execute error hooks. A failing plugin doesn't stop the others from
+    /// running; every failure is collected the same way as in
+    /// [`Self::execute_before_simulation`].
+    pub async fn execute_on_error(&self, error: &HyperSimError) -> Result<Vec<PluginExecutionError>> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+        let mut errors = Vec::new();
+
+        for plugin_name in execution_order.iter() {
+            if let Some(plugin) = plugins.get(plugin_name) {
+                let (outcome, log_path) = self.hook_logger.record(plugin_name, "on_error", plugin.on_error(error)).await;
+                if let Err(e) = outcome {
+                    warn!("Plugin {} on_error failed: {}", plugin_name, e);
+                    errors.push(PluginExecutionError {
+                        plugin_name: plugin_name.clone(),
+                        hook: "on_error".to_string(),
+                        message: e.to_string(),
+                        log_path,
+                    });
+                }
+            }
+        }
+
+        Ok(errors)
+    }
+
+    /// Execute subscription-established hooks
+    pub async fn execute_on_subscribe(&self, subscription: &WSSubscription) -> Result<()> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+
+        for plugin_name in execution_order.iter() {
+            if let Some(plugin) = plugins.get(plugin_name) {
+                if let Err(e) = plugin.on_subscribe(subscription).await {
+                    error!("Plugin {} on_subscribe failed: {}", plugin_name, e);
                     // Continue with other plugins even if one fails
                 }
             }
@@ -81,15 +193,15 @@ impl PluginSystem {
         Ok(())
     }
 
-    /// Execute after simulation hooks
-    pub async fn execute_after_simulation(&self, result: &mut SimulationResult) -> Result<()> {
+    /// Execute subscription-torn-down hooks
+    pub async fn execute_on_unsubscribe(&self, subscription_id: &str) -> Result<()> {
         let execution_order = self.execution_order.read().await;
         let plugins = self.plugins.read().await;
 
         for plugin_name in execution_order.iter() {
             if let Some(plugin) = plugins.get(plugin_name) {
-                if let Err(e) = plugin.after_simulation(result).await {
-                    error!("Plugin {} after_simulation failed: {}", plugin_name, e);
+                if let Err(e) = plugin.on_unsubscribe(subscription_id).await {
+                    error!("Plugin {} on_unsubscribe failed: {}", plugin_name, e);
                     // Continue with other plugins even if one fails
                 }
             }
@@ -98,15 +210,15 @@ impl PluginSystem {
         Ok(())
     }
 
-    /// Execute error hooks
-    pub async fn execute_on_error(&self, error: &HyperSimError) -> Result<()> {
+    /// Execute hooks for a decoded WebSocket notification
+    pub async fn execute_on_notification(&self, event: &WSEvent) -> Result<()> {
         let execution_order = self.execution_order.read().await;
         let plugins = self.plugins.read().await;
 
         for plugin_name in execution_order.iter() {
             if let Some(plugin) = plugins.get(plugin_name) {
-                if let Err(e) = plugin.on_error(error).await {
-                    warn!("Plugin {} on_error failed: {}", plugin_name, e);
+                if let Err(e) = plugin.on_notification(event).await {
+                    warn!("Plugin {} on_notification failed: {}", plugin_name, e);
                     // Continue with other plugins even if one fails
                 }
             }
@@ -115,6 +227,99 @@ impl PluginSystem {
         Ok(())
     }
 
+    /// Analyze `simulation_result` via the highest-priority enabled analysis
+    /// provider among loaded plugins (see [`Plugin::as_analysis_provider`]),
+    /// falling back to the next provider down the priority chain if it
+    /// errors. Errors if no loaded plugin offers an analysis provider, or
+    /// every one of them failed.
+    pub async fn analyze_with_providers(&self, simulation_result: &SimulationResult) -> Result<AIInsights> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+
+        let mut last_error = None;
+        for plugin_name in execution_order.iter() {
+            let Some(plugin) = plugins.get(plugin_name) else { continue };
+            let Some(provider) = plugin.as_analysis_provider() else { continue };
+            match provider.analyze(simulation_result).await {
+                Ok(insights) => return Ok(insights),
+                Err(e) => {
+                    warn!("Analysis provider {} failed, trying next: {}", plugin_name, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| HyperSimError::ai_analysis("no analysis provider is loaded")))
+    }
+
+    /// Same priority-ordered fallback-chain behavior as
+    /// [`Self::analyze_with_providers`], for bundle optimization.
+    pub async fn optimize_with_providers(&self, simulations: &[SimulationResult]) -> Result<BundleOptimization> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+
+        let mut last_error = None;
+        for plugin_name in execution_order.iter() {
+            let Some(plugin) = plugins.get(plugin_name) else { continue };
+            let Some(provider) = plugin.as_analysis_provider() else { continue };
+            match provider.optimize(simulations).await {
+                Ok(optimization) => return Ok(optimization),
+                Err(e) => {
+                    warn!("Analysis provider {} failed, trying next: {}", plugin_name, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| HyperSimError::ai_analysis("no analysis provider is loaded")))
+    }
+
+    /// Read a cached analysis entry from the highest-priority loaded
+    /// plugin that offers an [`AnalysisCacheStore`](crate::plugins::cache::AnalysisCacheStore)
+    /// (see [`Plugin::as_cache_store`]), or `Ok(None)` if no such plugin is loaded.
+    pub async fn read_cached_insight(&self, key: &str) -> Result<Option<String>> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+
+        for plugin_name in execution_order.iter() {
+            let Some(plugin) = plugins.get(plugin_name) else { continue };
+            let Some(store) = plugin.as_cache_store() else { continue };
+            return store.read(key).await;
+        }
+
+        Ok(None)
+    }
+
+    /// Write a cached analysis entry to the highest-priority loaded plugin
+    /// offering an analysis cache store, a no-op if none is loaded.
+    pub async fn write_cached_insight(&self, key: &str, value: String) -> Result<()> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+
+        for plugin_name in execution_order.iter() {
+            let Some(plugin) = plugins.get(plugin_name) else { continue };
+            let Some(store) = plugin.as_cache_store() else { continue };
+            return store.write(key, value).await;
+        }
+
+        Ok(())
+    }
+
+    /// Evict a cached analysis entry from the highest-priority loaded plugin
+    /// offering an analysis cache store, a no-op if none is loaded.
+    pub async fn evict_cached_insight(&self, key: &str) -> Result<()> {
+        let execution_order = self.execution_order.read().await;
+        let plugins = self.plugins.read().await;
+
+        for plugin_name in execution_order.iter() {
+            let Some(plugin) = plugins.get(plugin_name) else { continue };
+            let Some(store) = plugin.as_cache_store() else { continue };
+            return store.evict(key).await;
+        }
+
+        Ok(())
+    }
+
     /// Get loaded plugin names
     pub async fn get_loaded_plugins(&self) -> Vec<String> {
         let plugins = self.plugins.read().await;
@@ -140,8 +345,11 @@ impl PluginSystem {
         }
 
         // Update execution order
-        let mut execution_order = self.execution_order.write().await;
-        execution_order.retain(|n| n != name);
+        {
+            let mut meta = self.plugin_meta.write().await;
+            meta.remove(name);
+        }
+        self.recompute_execution_order().await?;
 
         info!("Plugin {} unloaded", name);
         Ok(())
@@ -177,6 +385,10 @@ impl PluginSystem {
 
         let mut execution_order = self.execution_order.write().await;
         execution_order.clear();
+        drop(execution_order);
+
+        let mut meta = self.plugin_meta.write().await;
+        meta.clear();
 
         info!("Plugin system shutdown completed");
         Ok(())
@@ -204,27 +416,57 @@ impl PluginSystem {
         match name {
             "logging" => Ok(Box::new(LoggingPlugin::new())),
             "metrics" => Ok(Box::new(MetricsPlugin::new())),
+            "openai-analysis" => Ok(Box::new(OpenAiAnalysisProvider::new()?)),
+            "heuristic-analysis" => Ok(Box::new(HeuristicAnalysisProvider::new())),
+            "in-memory-cache" => Ok(Box::new(InMemoryAnalysisCacheStore::new())),
+            "redis-cache" => Ok(Box::new(RedisAnalysisCacheStore::new())),
             _ => Err(HyperSimError::plugin(format!("Unknown plugin: {}", name))),
         }
     }
 
-    async fn update_execution_order(&self, plugin_name: String, priority: u32) {
+    /// Record `plugin_name`'s priority and dependencies, then recompute
+    /// `execution_order` for every loaded plugin via topological sort
+    async fn update_execution_order(&self, plugin_name: String, priority: u32, depends_on: Vec<String>) -> Result<()> {
+        {
+            let mut meta = self.plugin_meta.write().await;
+            meta.insert(plugin_name, PluginMeta { priority, depends_on });
+        }
+        self.recompute_execution_order().await
+    }
+
+    /// Topologically sort loaded plugins by `depends_on`, breaking ties
+    /// between simultaneously-placeable plugins by ascending `priority` then
+    /// by name. Errors if a dependency cycle leaves nodes unplaceable.
+    async fn recompute_execution_order(&self) -> Result<()> {
+        let meta = self.plugin_meta.read().await;
+        let mut placed: Vec<String> = Vec::with_capacity(meta.len());
+        let mut remaining: Vec<&String> = meta.keys().collect();
+
+        while !remaining.is_empty() {
+            let next = remaining
+                .iter()
+                .filter(|name| {
+                    meta[**name]
+                        .depends_on
+                        .iter()
+                        .all(|dep| !meta.contains_key(dep) || placed.iter().any(|p| p == dep))
+                })
+                .min_by(|a, b| meta[**a].priority.cmp(&meta[**b].priority).then_with(|| a.cmp(b)))
+                .copied();
+
+            let Some(next) = next else {
+                return Err(HyperSimError::plugin(
+                    "Cycle detected in plugin dependency graph; cannot compute execution order",
+                ));
+            };
+
+            placed.push(next.clone());
+            remaining.retain(|name| *name != next);
+        }
+
         let mut execution_order = self.execution_order.write().await;
-        
-        // Remove if already exists
-        execution_order.retain(|name| name != &plugin_name);
-        
-        // Find insertion position based on priority
-        let position = execution_order
-            .iter()
-            .position(|name| {
-                // Get priority of existing plugin (default to 100)
-                // In a real implementation, we'd store priorities separately
-                false // For now, just append
-            })
-            .unwrap_or(execution_order.len());
-            
-        execution_order.insert(position, plugin_name);
+        *execution_order = placed;
+        Ok(())
     }
 }
 
@@ -272,4 +514,162 @@ mod tests {
         assert_eq!(health.get("logging"), Some(&true));
         assert_eq!(health.get("metrics"), Some(&true));
     }
+
+    #[tokio::test]
+    async fn test_subscription_lifecycle_hooks_run_without_error() {
+        let system = PluginSystem::new().await.unwrap();
+        let subscription = WSSubscription {
+            id: "sub-test".to_string(),
+            subscription_type: crate::types::SubscriptionType::NewHeads,
+            params: crate::types::SubscriptionParams {
+                filter: None,
+                include_details: false,
+                limit: None,
+            },
+            active: true,
+            created_at: 0,
+        };
+
+        assert!(system.execute_on_subscribe(&subscription).await.is_ok());
+        assert!(system.execute_on_notification(&WSEvent::Connected).await.is_ok());
+        assert!(system.execute_on_unsubscribe(&subscription.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_execute_before_simulation_writes_hook_log_per_plugin() {
+        let log_dir = std::env::temp_dir().join(format!("hypersim-plugin-log-test-{}", std::process::id()));
+        let system = PluginSystem::with_log_dir(Some(log_dir.clone())).await.unwrap();
+
+        let tx = TransactionRequest::builder()
+            .from("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap()
+            .build().unwrap();
+
+        let errors = system.execute_before_simulation(&tx).await.unwrap();
+        assert!(errors.is_empty(), "Built-in plugins should not fail before_simulation");
+
+        let log_files: Vec<_> = std::fs::read_dir(&log_dir).unwrap().collect();
+        assert!(!log_files.is_empty(), "Expected a log file per plugin hook invocation");
+
+        let _ = std::fs::remove_dir_all(&log_dir);
+    }
+
+    #[tokio::test]
+    async fn test_execution_order_respects_priority_and_dependencies() {
+        let system = PluginSystem::new().await.unwrap();
+
+        // Give metrics a lower (higher-priority) number than logging, but make
+        // it depend on logging. The dependency must win over priority.
+        let metrics_config = PluginConfig::new("metrics").priority(1).depends_on("logging");
+        system.load_plugin(metrics_config).await.unwrap();
+
+        let order = system.execution_order.read().await.clone();
+        assert_eq!(order, vec!["logging".to_string(), "metrics".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_update_execution_order_detects_cycle() {
+        let system = PluginSystem::new().await.unwrap();
+
+        system
+            .load_plugin(PluginConfig::new("metrics").priority(20).depends_on("logging"))
+            .await
+            .unwrap();
+
+        let err = system
+            .load_plugin(PluginConfig::new("logging").priority(10).depends_on("metrics"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Cycle"));
+    }
+
+    fn minimal_simulation_result() -> SimulationResult {
+        SimulationResult {
+            success: true,
+            gas_used: "21000".to_string(),
+            return_data: None,
+            error: None,
+            revert_reason: None,
+            block_type: crate::types::BlockType::Fast,
+            estimated_block: 12345,
+            trace: None,
+            hypercore_data: None,
+            state_changes: Vec::new(),
+            events: Vec::new(),
+            tx_hash: None,
+            verification: crate::verification::VerificationStatus::Unverified,
+            base_fee_per_gas: None,
+            effective_gas_price: None,
+            burned_fee: None,
+            gas_limit: None,
+            max_fee_per_gas: None,
+            blob_count: None,
+            blob_base_fee: None,
+            max_priority_fee_per_gas: None,
+            calldata_size: None,
+            tx_type: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_analyze_with_providers_errors_without_a_registered_provider() {
+        let system = PluginSystem::new().await.unwrap();
+        let err = system.analyze_with_providers(&minimal_simulation_result()).await.unwrap_err();
+        assert!(err.to_string().contains("no analysis provider"));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_with_providers_uses_highest_priority_provider() {
+        let system = PluginSystem::new().await.unwrap();
+        system
+            .load_plugin(PluginConfig::new("heuristic-analysis").priority(90))
+            .await
+            .unwrap();
+        system
+            .load_plugin(
+                PluginConfig::new("openai-analysis")
+                    .priority(30)
+                    .config_value("api_key", "test-key"),
+            )
+            .await
+            .unwrap();
+
+        let order = system.execution_order.read().await.clone();
+        assert_eq!(
+            order,
+            vec![
+                "logging".to_string(),
+                "metrics".to_string(),
+                "openai-analysis".to_string(),
+                "heuristic-analysis".to_string(),
+            ]
+        );
+
+        let insights = system.analyze_with_providers(&minimal_simulation_result()).await.unwrap();
+        assert_eq!(insights.risk_level, crate::types::RiskLevel::Low);
+    }
+
+    #[tokio::test]
+    async fn test_read_cached_insight_is_none_without_a_registered_store() {
+        let system = PluginSystem::new().await.unwrap();
+        assert_eq!(system.read_cached_insight("sim_1").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_write_then_read_cached_insight_round_trips_through_registered_store() {
+        let system = PluginSystem::new().await.unwrap();
+        system.load_plugin(PluginConfig::new("in-memory-cache").priority(50)).await.unwrap();
+
+        system.write_cached_insight("sim_1", "payload".to_string()).await.unwrap();
+        assert_eq!(system.read_cached_insight("sim_1").await.unwrap(), Some("payload".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_evict_cached_insight_removes_entry_from_registered_store() {
+        let system = PluginSystem::new().await.unwrap();
+        system.load_plugin(PluginConfig::new("in-memory-cache").priority(50)).await.unwrap();
+
+        system.write_cached_insight("sim_1", "payload".to_string()).await.unwrap();
+        system.evict_cached_insight("sim_1").await.unwrap();
+        assert_eq!(system.read_cached_insight("sim_1").await.unwrap(), None);
+    }
 }
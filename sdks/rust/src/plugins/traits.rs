@@ -1,7 +1,7 @@
 //! Plugin trait definitions
 
 use async_trait::async_trait;
-use crate::types::{TransactionRequest, SimulationResult, RequestContext};
+use crate::types::{TransactionRequest, SimulationResult, RequestContext, WSSubscription, WSEvent};
 use crate::error::Result;
 
 /// Main plugin trait that all plugins must implement
@@ -41,11 +41,44 @@ pub trait Plugin: Send + Sync {
     async fn on_request(&self, _context: &RequestContext) -> Result<()> {
         Ok(())
     }
-    
+
+    /// Execute when a WebSocket subscription is established
+    async fn on_subscribe(&self, _subscription: &WSSubscription) -> Result<()> {
+        Ok(())
+    }
+
+    /// Execute when a WebSocket subscription is torn down
+    async fn on_unsubscribe(&self, _subscription_id: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Execute on every decoded WebSocket event, regardless of which
+    /// subscription produced it
+    async fn on_notification(&self, _event: &WSEvent) -> Result<()> {
+        Ok(())
+    }
+
     /// Health check for the plugin
     async fn health_check(&self) -> Result<()> {
         Ok(())
     }
+
+    /// Downcast to an [`AnalysisProvider`](crate::plugins::analysis::AnalysisProvider)
+    /// when this plugin offers one. `PluginSystem::analyze_with_providers`/
+    /// `optimize_with_providers` use this to find analysis-capable plugins
+    /// among the loaded set, walked in the same priority/dependency order
+    /// as the lifecycle hooks.
+    fn as_analysis_provider(&self) -> Option<&dyn crate::plugins::analysis::AnalysisProvider> {
+        None
+    }
+
+    /// Downcast to an [`AnalysisCacheStore`](crate::plugins::cache::AnalysisCacheStore)
+    /// when this plugin offers one. `PluginSystem::read_cached_insight`/
+    /// `write_cached_insight`/`evict_cached_insight` use this to find the
+    /// highest-priority loaded plugin backing the analysis cache.
+    fn as_cache_store(&self) -> Option<&dyn crate::plugins::cache::AnalysisCacheStore> {
+        None
+    }
 }
 
 /// Plugin factory trait for dynamic loading
@@ -127,57 +160,99 @@ pub mod builtin {
         name: String,
         request_count: std::sync::atomic::AtomicU64,
         error_count: std::sync::atomic::AtomicU64,
+        /// Notification counts keyed by `WSEvent` variant name (e.g.
+        /// `"NewBlock"`, `"Log"`), so a caller can see which subscription
+        /// kinds are producing traffic without the plugin needing to know
+        /// about `SubscriptionType` itself
+        notification_counts: std::sync::Mutex<std::collections::HashMap<String, u64>>,
     }
-    
+
     impl MetricsPlugin {
         pub fn new() -> Self {
             Self {
                 name: "metrics".to_string(),
                 request_count: std::sync::atomic::AtomicU64::new(0),
                 error_count: std::sync::atomic::AtomicU64::new(0),
+                notification_counts: std::sync::Mutex::new(std::collections::HashMap::new()),
             }
         }
-        
+
         pub fn get_request_count(&self) -> u64 {
             self.request_count.load(std::sync::atomic::Ordering::SeqCst)
         }
-        
+
         pub fn get_error_count(&self) -> u64 {
             self.error_count.load(std::sync::atomic::Ordering::SeqCst)
         }
+
+        /// Number of notifications observed for the `WSEvent` variant named
+        /// `kind` (e.g. `"NewBlock"`)
+        pub fn get_notification_count(&self, kind: &str) -> u64 {
+            self.notification_counts.lock().unwrap().get(kind).copied().unwrap_or(0)
+        }
+
+        /// Notification counts for every `WSEvent` variant observed so far
+        pub fn notification_counts(&self) -> std::collections::HashMap<String, u64> {
+            self.notification_counts.lock().unwrap().clone()
+        }
+
+        fn event_kind(event: &WSEvent) -> &'static str {
+            match event {
+                WSEvent::Connected => "Connected",
+                WSEvent::Disconnected { .. } => "Disconnected",
+                WSEvent::Error { .. } => "Error",
+                WSEvent::Subscribed { .. } => "Subscribed",
+                WSEvent::Unsubscribed { .. } => "Unsubscribed",
+                WSEvent::Resubscribed { .. } => "Resubscribed",
+                WSEvent::NewBlock { .. } => "NewBlock",
+                WSEvent::NewTransaction { .. } => "NewTransaction",
+                WSEvent::Log { .. } => "Log",
+                WSEvent::SimulationResult { .. } => "SimulationResult",
+                WSEvent::GasPriceUpdate { .. } => "GasPriceUpdate",
+                WSEvent::GasPrices { .. } => "GasPrices",
+                WSEvent::NetworkStatus { .. } => "NetworkStatus",
+                WSEvent::FeeHistory { .. } => "FeeHistory",
+            }
+        }
     }
-    
+
     #[async_trait]
     impl Plugin for MetricsPlugin {
         fn name(&self) -> &str {
             &self.name
         }
-        
+
         fn version(&self) -> &str {
             "1.0.0"
         }
-        
+
         fn description(&self) -> &str {
             "Built-in metrics collection plugin"
         }
-        
+
         async fn initialize(&mut self, _config: &serde_json::Value) -> Result<()> {
             Ok(())
         }
-        
+
         async fn shutdown(&mut self) -> Result<()> {
             Ok(())
         }
-        
+
         async fn before_simulation(&self, _request: &TransactionRequest) -> Result<()> {
             self.request_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
             Ok(())
         }
-        
+
         async fn on_error(&self, _error: &crate::error::HyperSimError) -> Result<()> {
             self.error_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
             Ok(())
         }
+
+        async fn on_notification(&self, event: &WSEvent) -> Result<()> {
+            let mut counts = self.notification_counts.lock().unwrap();
+            *counts.entry(Self::event_kind(event).to_string()).or_insert(0) += 1;
+            Ok(())
+        }
     }
 }
 
@@ -211,7 +286,20 @@ mod tests {
             .build().unwrap();
             
         plugin.before_simulation(&tx).await.unwrap();
-        
+
         assert_eq!(plugin.get_request_count(), initial_count + 1);
     }
+
+    #[tokio::test]
+    async fn test_metrics_plugin_counts_notifications_by_kind() {
+        let plugin = MetricsPlugin::new();
+
+        plugin.on_notification(&WSEvent::Connected).await.unwrap();
+        plugin.on_notification(&WSEvent::Unsubscribed { subscription_id: "sub-1".to_string() }).await.unwrap();
+        plugin.on_notification(&WSEvent::Unsubscribed { subscription_id: "sub-2".to_string() }).await.unwrap();
+
+        assert_eq!(plugin.get_notification_count("Connected"), 1);
+        assert_eq!(plugin.get_notification_count("Unsubscribed"), 2);
+        assert_eq!(plugin.get_notification_count("NewBlock"), 0);
+    }
 }
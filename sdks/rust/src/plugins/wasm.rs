@@ -0,0 +1,218 @@
+//! WASM-based plugin runtime
+//!
+//! Lets third parties ship a plugin as a compiled `wasm32-wasi` module
+//! instead of forking the crate and implementing [`Plugin`] in Rust
+//! directly. The guest exports `before_simulation`, `after_simulation`,
+//! `on_error`, `health_check`, and `shutdown`; host and guest exchange
+//! `TransactionRequest`/`SimulationResult`/error payloads as length-prefixed
+//! serde-serialized byte buffers written into guest linear memory (the
+//! guest allocates the buffer itself via an exported `alloc` and returns a
+//! packed `(ptr << 32 | len)` for the host to read back and deserialize).
+//! Guests are built with `cargo build --target=wasm32-wasi --release`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use tokio::sync::{Mutex, RwLock};
+use wasmtime::{Engine, Instance, Linker, Memory, Module, Store};
+use wasmtime_wasi::sync::WasiCtxBuilder;
+use wasmtime_wasi::WasiCtx;
+
+use crate::error::{HyperSimError, Result};
+use crate::plugins::traits::Plugin;
+use crate::types::{SimulationResult, TransactionRequest};
+
+/// Process-wide cache of compiled [`Module`]s, keyed by the `.wasm` path and
+/// its content hash, so repeated `load_plugin` calls for the same unchanged
+/// file reuse the (expensive) compiled module and only pay for a fresh
+/// `Instance`/`Store`, while a rebuilt file at the same path misses the
+/// cache and recompiles instead of silently reusing the stale `Module`.
+static PLUGIN_MODULE_CACHE: OnceLock<RwLock<HashMap<(PathBuf, String), Arc<Module>>>> = OnceLock::new();
+
+fn module_cache() -> &'static RwLock<HashMap<(PathBuf, String), Arc<Module>>> {
+    PLUGIN_MODULE_CACHE.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Plugin implementation backed by a `wasm32-wasi` guest module
+pub struct WasmPlugin {
+    name: String,
+    path: PathBuf,
+    store: Mutex<Store<WasiCtx>>,
+    instance: Instance,
+    memory: Memory,
+}
+
+impl WasmPlugin {
+    /// Compile (or reuse a cached compilation of) the module at `path` and
+    /// instantiate it. If `expected_hash` is set, the module's content hash
+    /// is verified before it is used.
+    pub async fn load(name: impl Into<String>, path: impl Into<PathBuf>, expected_hash: Option<&str>) -> Result<Self> {
+        let name = name.into();
+        let path = path.into();
+
+        let bytes = std::fs::read(&path).map_err(|e| {
+            HyperSimError::plugin_with_name(format!("Failed to read WASM module at {}: {}", path.display(), e), name.clone())
+        })?;
+
+        if let Some(expected) = expected_hash {
+            let actual = content_hash(&bytes);
+            if actual != expected {
+                return Err(HyperSimError::plugin_with_name(
+                    format!("WASM module hash mismatch for {}: expected {}, got {}", path.display(), expected, actual),
+                    name,
+                ));
+            }
+        }
+
+        // One process-wide engine: a cached `Module` can only be instantiated
+        // with the `Engine` that compiled it.
+        static ENGINE: OnceLock<Engine> = OnceLock::new();
+        let engine = ENGINE.get_or_init(Engine::default).clone();
+        let module = Self::cached_module(&engine, &path, &bytes).await?;
+
+        let mut linker: Linker<WasiCtx> = Linker::new(&engine);
+        wasmtime_wasi::sync::add_to_linker(&mut linker, |ctx| ctx).map_err(|e| {
+            HyperSimError::plugin_with_name(format!("Failed to wire WASI imports: {}", e), name.clone())
+        })?;
+
+        let wasi = WasiCtxBuilder::new().inherit_stdio().build();
+        let mut store = Store::new(&engine, wasi);
+
+        let instance = linker.instantiate(&mut store, &module).map_err(|e| {
+            HyperSimError::plugin_with_name(format!("Failed to instantiate WASM module: {}", e), name.clone())
+        })?;
+
+        let memory = instance.get_memory(&mut store, "memory").ok_or_else(|| {
+            HyperSimError::plugin_with_name("WASM module does not export linear memory \"memory\"", name.clone())
+        })?;
+
+        Ok(Self { name, path, store: Mutex::new(store), instance, memory })
+    }
+
+    async fn cached_module(engine: &Engine, path: &Path, bytes: &[u8]) -> Result<Arc<Module>> {
+        let key = (path.to_path_buf(), content_hash(bytes));
+
+        {
+            let cache = module_cache().read().await;
+            if let Some(module) = cache.get(&key) {
+                return Ok(Arc::clone(module));
+            }
+        }
+
+        let module = Arc::new(
+            Module::new(engine, bytes)
+                .map_err(|e| HyperSimError::plugin(format!("Failed to compile WASM module at {}: {}", path.display(), e)))?,
+        );
+
+        let mut cache = module_cache().write().await;
+        Ok(Arc::clone(cache.entry(key).or_insert(module)))
+    }
+
+    /// Serialize `value`, hand it to the guest's `alloc` export, write it
+    /// into linear memory, call `func_name(ptr, len)`, and deserialize the
+    /// packed `(ptr, len)` the guest returns back into `R`
+    async fn call_with_payload<T, R>(&self, func_name: &str, value: &T) -> Result<R>
+    where
+        T: serde::Serialize,
+        R: serde::de::DeserializeOwned,
+    {
+        let payload = serde_json::to_vec(value)
+            .map_err(|e| HyperSimError::serialization(format!("Failed to encode payload for {}: {}", func_name, e)))?;
+
+        let mut store = self.store.lock().await;
+
+        let alloc = self
+            .instance
+            .get_typed_func::<u32, u32>(&mut *store, "alloc")
+            .map_err(|e| self.export_error("alloc", e))?;
+        let func = self
+            .instance
+            .get_typed_func::<(u32, u32), u64>(&mut *store, func_name)
+            .map_err(|e| self.export_error(func_name, e))?;
+
+        let ptr = alloc.call(&mut *store, payload.len() as u32).map_err(|e| self.call_error("alloc", e))?;
+        self.memory
+            .write(&mut *store, ptr as usize, &payload)
+            .map_err(|e| HyperSimError::plugin_with_name(format!("Failed to write payload into guest memory: {}", e), self.name.clone()))?;
+
+        let packed = func.call(&mut *store, (ptr, payload.len() as u32)).map_err(|e| self.call_error(func_name, e))?;
+        let (result_ptr, result_len) = ((packed >> 32) as u32, packed as u32);
+
+        let mut buf = vec![0u8; result_len as usize];
+        self.memory
+            .read(&mut *store, result_ptr as usize, &mut buf)
+            .map_err(|e| HyperSimError::plugin_with_name(format!("Failed to read result from guest memory: {}", e), self.name.clone()))?;
+
+        serde_json::from_slice(&buf)
+            .map_err(|e| HyperSimError::serialization(format!("Failed to decode result of {}: {}", func_name, e)))
+    }
+
+    async fn call_void(&self, func_name: &str) -> Result<()> {
+        let mut store = self.store.lock().await;
+        let func = self
+            .instance
+            .get_typed_func::<(), ()>(&mut *store, func_name)
+            .map_err(|e| self.export_error(func_name, e))?;
+        func.call(&mut *store, ()).map_err(|e| self.call_error(func_name, e))
+    }
+
+    fn export_error(&self, func_name: &str, source: impl std::fmt::Display) -> HyperSimError {
+        HyperSimError::plugin_with_name(format!("WASM module does not export {}: {}", func_name, source), self.name.clone())
+    }
+
+    fn call_error(&self, func_name: &str, source: impl std::fmt::Display) -> HyperSimError {
+        HyperSimError::plugin_with_name(format!("Call to {} failed: {}", func_name, source), self.name.clone())
+    }
+}
+
+fn content_hash(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(crate::utils::abi::keccak256_hash(bytes)))
+}
+
+#[async_trait]
+impl Plugin for WasmPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        "wasm"
+    }
+
+    fn description(&self) -> &str {
+        "WASM-backed plugin"
+    }
+
+    async fn initialize(&mut self, _config: &serde_json::Value) -> Result<()> {
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        self.call_void("shutdown").await
+    }
+
+    async fn before_simulation(&self, request: &TransactionRequest) -> Result<()> {
+        self.call_with_payload("before_simulation", request).await
+    }
+
+    async fn after_simulation(&self, result: &mut SimulationResult) -> Result<()> {
+        *result = self.call_with_payload("after_simulation", result).await?;
+        Ok(())
+    }
+
+    async fn on_error(&self, error: &HyperSimError) -> Result<()> {
+        self.call_with_payload("on_error", &serde_json::json!({ "message": error.to_string() })).await
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.call_void("health_check").await
+    }
+}
+
+impl std::fmt::Debug for WasmPlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmPlugin").field("name", &self.name).field("path", &self.path).finish()
+    }
+}
@@ -0,0 +1,213 @@
+//! Generic retry-with-backoff for operations that return a [`HyperSimError`]
+//!
+//! [`HyperSimError::is_retryable`] and `RateLimit`'s `retry_after` already
+//! flag which failures are worth retrying and how long to wait, but nothing
+//! in the SDK actually acted on those signals outside of the one-off
+//! rate-limit retry in [`crate::core::HyperSimSDK::simulate`]. [`RetryPolicy`]
+//! and [`retry_with_policy`] generalize that loop to any fallible async
+//! operation.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::error::{HyperSimError, Result};
+
+/// Exponential backoff with full jitter: max attempts, the base/max delay
+/// bounding `base * multiplier^attempt`, and the multiplier itself
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that retries up to `max_attempts` times with the default
+    /// 100ms base delay, 10s cap, and 2x multiplier
+    pub fn new(max_attempts: u32) -> Self {
+        Self { max_attempts, ..Self::default() }
+    }
+
+    /// Override the base delay used for the first retry
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Override the ceiling the computed (pre-jitter) backoff is clamped to
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Override the per-attempt backoff multiplier
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// The backoff for `attempt` (0-indexed) before jitter: `min(max_delay, base * multiplier^attempt)`
+    fn backoff_for(&self, attempt: u32) -> Duration {
+        let scaled = self.base_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        Duration::from_secs_f64(scaled.min(self.max_delay.as_secs_f64()))
+    }
+}
+
+/// Retry `op` under `policy`, re-invoking it whenever it returns a
+/// [`HyperSimError`] where [`HyperSimError::is_retryable`] is true.
+///
+/// The delay before each retry is `min(max_delay, base * multiplier^attempt)`,
+/// randomized with full jitter (`uniform(0, computed)`), then floored by the
+/// failing error's `RateLimit::retry_after` (seconds, matching HTTP's
+/// `Retry-After` header) when present — a server telling us exactly how long
+/// to back off always wins over the computed jitter.
+///
+/// Non-retryable errors return immediately. Once `max_attempts` is exhausted,
+/// the last error is wrapped with the attempt count and total elapsed time so
+/// callers can tell a retry-exhaustion failure from a first-try one.
+pub async fn retry_with_policy<F, Fut, T>(policy: RetryPolicy, mut op: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let start = Instant::now();
+
+    for attempt in 0..policy.max_attempts {
+        let error = match op().await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+
+        if !error.is_retryable() {
+            return Err(error);
+        }
+        if attempt + 1 == policy.max_attempts {
+            return Err(exhausted_error(error, attempt + 1, start.elapsed()));
+        }
+
+        let computed = policy.backoff_for(attempt);
+        let jittered = if computed.is_zero() {
+            computed
+        } else {
+            Duration::from_secs_f64(rand::thread_rng().gen_range(0.0..=computed.as_secs_f64()))
+        };
+        let delay = match retry_after_floor(&error) {
+            Some(floor) => jittered.max(floor),
+            None => jittered,
+        };
+
+        tokio::time::sleep(delay).await;
+    }
+
+    unreachable!("the loop above always returns by its final iteration")
+}
+
+/// `RateLimit`'s `retry_after`, when present, as a hard floor on the next delay
+fn retry_after_floor(error: &HyperSimError) -> Option<Duration> {
+    match error {
+        HyperSimError::RateLimit { retry_after: Some(seconds), .. } => Some(Duration::from_secs(*seconds)),
+        _ => None,
+    }
+}
+
+fn exhausted_error(last_error: HyperSimError, attempts: u32, elapsed: Duration) -> HyperSimError {
+    HyperSimError::internal_with_source(
+        format!(
+            "operation did not succeed after {} attempt(s) ({:.2}s elapsed)",
+            attempts,
+            elapsed.as_secs_f64()
+        ),
+        Box::new(last_error),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_retry_succeeds_after_transient_failures() {
+        let attempts = AtomicU32::new(0);
+
+        let result = retry_with_policy(RetryPolicy::new(5).base_delay(Duration::from_millis(1)), || {
+            let attempts = &attempts;
+            async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(HyperSimError::network("transient"))
+                } else {
+                    Ok(42)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_error_short_circuits() {
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<()> = retry_with_policy(RetryPolicy::new(5), || {
+            let attempts = &attempts;
+            async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(HyperSimError::validation("bad input"))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_wraps_attempt_count() {
+        let result: Result<()> =
+            retry_with_policy(RetryPolicy::new(2).base_delay(Duration::from_millis(1)), || async {
+                Err(HyperSimError::network("still failing"))
+            })
+            .await;
+
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("2 attempt"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retry_after_floors_the_delay() {
+        let start = Instant::now();
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<()> = retry_with_policy(RetryPolicy::new(2).base_delay(Duration::from_millis(1)), || {
+            let attempts = &attempts;
+            async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(HyperSimError::rate_limit_with_retry("slow down", 1))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+}
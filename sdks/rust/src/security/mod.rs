@@ -3,24 +3,8 @@
 //! multi-signature support, request signing, and OWASP compliance.
 
 pub mod security_manager;
-pub mod api_key_manager;
-pub mod multi_signature;
-pub mod request_signer;
-pub mod rate_limiter;
-pub mod secure_storage;
-pub mod security_auditor;
-pub mod owasp_validator;
-pub mod input_sanitizer;
 
 pub use security_manager::*;
-pub use api_key_manager::*;
-pub use multi_signature::*;
-pub use request_signer::*;
-pub use rate_limiter::*;
-pub use secure_storage::*;
-pub use security_auditor::*;
-pub use owasp_validator::*;
-pub use input_sanitizer::*;
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
@@ -37,6 +21,10 @@ pub struct SecurityConfig {
     pub audit_logging: bool,
     pub owasp_compliance: bool,
     pub certificate_pins: Vec<String>,
+    /// Per-host certificate pin overrides, so distinct HyperCore/HyperEVM
+    /// hosts can carry their own pin sets. Falls back to `certificate_pins`
+    /// for any host not present here.
+    pub certificate_pins_by_host: HashMap<String, Vec<String>>,
     pub input_validation: String, // "strict", "moderate", "basic"
     pub debug: bool,
 }
@@ -143,6 +131,7 @@ impl Default for SecurityConfig {
             audit_logging: true,
             owasp_compliance: true,
             certificate_pins: vec![],
+            certificate_pins_by_host: HashMap::new(),
             input_validation: "strict".to_string(),
             debug: false,
         }
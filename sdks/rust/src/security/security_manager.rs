@@ -0,0 +1,334 @@
+//! Enforces `SecurityConfig.certificate_pins` against certificates presented
+//! by RPC hosts, instead of leaving the config field inert.
+//!
+//! SPKI pinning is the standard way to detect a compromised or substituted
+//! CA without trusting the whole public CA hierarchy: operators pin the
+//! base64 SHA-256 hash of a host's SubjectPublicKeyInfo (the same value
+//! produced by `openssl x509 -pubkey | openssl pkey -pubin -outform der |
+//! openssl dgst -sha256 -binary | base64`), and any certificate whose SPKI
+//! doesn't match one of the configured pins is rejected.
+
+use base64::Engine as _;
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+
+use super::{current_timestamp, SecurityConfig, SecurityEvent, SecurityEventType, SecurityMetrics, Severity};
+use crate::error::{HyperSimError, Result};
+
+/// Tracks pin configuration plus the metrics/events produced by enforcing it.
+///
+/// Enforcement only ever happens synchronously (it sits on the certificate
+/// verification path), so metrics/events use `std::sync::Mutex` rather than
+/// the crate's usual `tokio::sync::RwLock`.
+pub struct SecurityManager {
+    config: SecurityConfig,
+    metrics: Mutex<SecurityMetrics>,
+    events: Mutex<Vec<SecurityEvent>>,
+}
+
+impl SecurityManager {
+    pub fn new(config: SecurityConfig) -> Self {
+        Self {
+            config,
+            metrics: Mutex::new(SecurityMetrics::default()),
+            events: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn config(&self) -> &SecurityConfig {
+        &self.config
+    }
+
+    pub fn metrics(&self) -> SecurityMetrics {
+        self.metrics.lock().unwrap().clone()
+    }
+
+    pub fn events(&self) -> Vec<SecurityEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Pins configured for `host`, falling back to the global pin set when no
+    /// host-specific entry exists.
+    fn pins_for_host(&self, host: &str) -> &[String] {
+        self.config
+            .certificate_pins_by_host
+            .get(host)
+            .unwrap_or(&self.config.certificate_pins)
+    }
+
+    /// Whether pinning is actually enforced for `host` (a host carries no
+    /// pins at all is left unaffected, per the "hard failure only when pins
+    /// are configured" requirement).
+    pub fn has_pins_for(&self, host: &str) -> bool {
+        !self.pins_for_host(host).is_empty()
+    }
+
+    /// Verify a DER-encoded leaf certificate against the pins configured for
+    /// `host`. Returns `true` when no pins are configured for `host` (pinning
+    /// is opt-in), or when the certificate's SPKI hash matches one of them.
+    pub fn verify_certificate(&self, host: &str, der_certificate: &[u8]) -> bool {
+        let pins = self.pins_for_host(host);
+        if pins.is_empty() {
+            return true;
+        }
+
+        let presented = match spki_sha256_base64(der_certificate) {
+            Ok(hash) => hash,
+            Err(_) => {
+                self.record_mismatch(host);
+                return false;
+            }
+        };
+
+        if pins.iter().any(|pin| pin == &presented) {
+            true
+        } else {
+            self.record_mismatch(host);
+            false
+        }
+    }
+
+    fn record_mismatch(&self, host: &str) {
+        self.metrics.lock().unwrap().blocked_requests += 1;
+        self.events.lock().unwrap().push(SecurityEvent {
+            event_type: SecurityEventType::CertificateMismatch,
+            severity: Severity::High,
+            timestamp: current_timestamp(),
+            description: format!("Certificate pin mismatch for host '{}'", host),
+            metadata: None,
+        });
+    }
+}
+
+/// Enforce certificate pinning against the leaf certificate `reqwest`
+/// recorded for this response (via `ClientBuilder::tls_info`). A hard
+/// failure only when pins are actually configured for the responding host.
+pub fn verify_response_certificate(security: &SecurityManager, response: &reqwest::Response) -> Result<()> {
+    let host = response.url().host_str().unwrap_or_default().to_string();
+    let der_certificate = response
+        .extensions()
+        .get::<reqwest::tls::TlsInfo>()
+        .and_then(|info| info.peer_certificate());
+
+    match der_certificate {
+        Some(der) if security.verify_certificate(&host, der) => Ok(()),
+        Some(_) => Err(HyperSimError::security(format!(
+            "Certificate pin mismatch for host '{}'", host
+        ))),
+        None if security.has_pins_for(&host) => Err(HyperSimError::security(format!(
+            "No certificate available to verify pin for host '{}'", host
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// SHA-256 hash of a DER certificate's SubjectPublicKeyInfo, base64-encoded —
+/// the same value operators configure in `certificate_pins`.
+pub fn spki_sha256_base64(der_certificate: &[u8]) -> Result<String, String> {
+    let spki = extract_spki(der_certificate)?;
+    let digest = Sha256::digest(spki);
+    Ok(base64::engine::general_purpose::STANDARD.encode(digest))
+}
+
+/// Walk just enough of the X.509 `Certificate ::= SEQUENCE { tbsCertificate,
+/// signatureAlgorithm, signatureValue }` DER structure to slice out
+/// `tbsCertificate.subjectPublicKeyInfo`, without pulling in a full ASN.1
+/// dependency for one field.
+fn extract_spki(der_certificate: &[u8]) -> Result<&[u8], String> {
+    let cert_body = der_sequence_body(der_certificate)?;
+    let (tbs_element, _) = der_element(cert_body)?;
+    let tbs_fields = der_sequence_body(tbs_element.bytes)?;
+
+    // tbsCertificate fields, in order: [0] version (optional, explicit
+    // context tag), serialNumber, signature, issuer, validity, subject,
+    // subjectPublicKeyInfo, ...
+    let (first, mut rest) = der_element(tbs_fields)?;
+    if first.tag != 0xA0 {
+        rest = tbs_fields;
+    }
+
+    // Skip serialNumber, signature, issuer, validity, subject.
+    for _ in 0..5 {
+        let (_, remainder) = der_element(rest)?;
+        rest = remainder;
+    }
+
+    let (spki, _) = der_element(rest)?;
+    Ok(spki.bytes)
+}
+
+struct DerElement<'a> {
+    tag: u8,
+    bytes: &'a [u8],
+}
+
+/// Read one DER TLV element and return it along with whatever trails it.
+fn der_element(input: &[u8]) -> Result<(DerElement<'_>, &[u8]), String> {
+    if input.len() < 2 {
+        return Err("DER element truncated".to_string());
+    }
+    let tag = input[0];
+    let (content_len, len_size) = der_length(&input[1..])?;
+    let header_len = 1 + len_size;
+    let total_len = header_len + content_len;
+    if input.len() < total_len {
+        return Err("DER element length exceeds input".to_string());
+    }
+    Ok((DerElement { tag, bytes: &input[..total_len] }, &input[total_len..]))
+}
+
+/// Read a DER SEQUENCE (tag `0x30`) and return its content (header stripped).
+fn der_sequence_body(input: &[u8]) -> Result<&[u8], String> {
+    let (element, _) = der_element(input)?;
+    if element.tag != 0x30 {
+        return Err(format!("Expected DER SEQUENCE (0x30), found 0x{:x}", element.tag));
+    }
+    let (_, len_size) = der_length(&element.bytes[1..])?;
+    Ok(&element.bytes[1 + len_size..])
+}
+
+/// Parse a DER length octet (short or long form) and return `(length, bytes_consumed)`.
+fn der_length(input: &[u8]) -> Result<(usize, usize), String> {
+    if input.is_empty() {
+        return Err("DER length truncated".to_string());
+    }
+    let first = input[0];
+    if first & 0x80 == 0 {
+        Ok((first as usize, 1))
+    } else {
+        let num_bytes = (first & 0x7F) as usize;
+        if num_bytes == 0 || num_bytes > 4 || input.len() < 1 + num_bytes {
+            return Err("Unsupported DER length encoding".to_string());
+        }
+        let mut len = 0usize;
+        for &b in &input[1..1 + num_bytes] {
+            len = (len << 8) | b as usize;
+        }
+        Ok((len, 1 + num_bytes))
+    }
+}
+
+/// Encode a DER TLV element from a tag and raw content bytes (short-form
+/// length only - sufficient for the small synthetic fixtures under test).
+#[cfg(test)]
+fn encode_der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag, content.len() as u8];
+    out.extend_from_slice(content);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Builds a minimal synthetic DER certificate with a recognizable SPKI
+    /// payload, just deep enough to exercise `extract_spki`'s field walk.
+    fn synthetic_certificate(spki_content: &[u8]) -> Vec<u8> {
+        let serial_number = encode_der(0x02, &[0x01]);
+        let signature = encode_der(0x30, &[]);
+        let issuer = encode_der(0x30, &[]);
+        let validity = encode_der(0x30, &[]);
+        let subject = encode_der(0x30, &[]);
+        let spki = encode_der(0x30, spki_content);
+
+        let mut tbs_certificate = Vec::new();
+        tbs_certificate.extend(serial_number);
+        tbs_certificate.extend(signature);
+        tbs_certificate.extend(issuer);
+        tbs_certificate.extend(validity);
+        tbs_certificate.extend(subject);
+        tbs_certificate.extend(spki);
+
+        let tbs_certificate = encode_der(0x30, &tbs_certificate);
+        let signature_algorithm = encode_der(0x30, &[]);
+        let signature_value = encode_der(0x03, &[]);
+
+        let mut certificate = Vec::new();
+        certificate.extend(tbs_certificate);
+        certificate.extend(signature_algorithm);
+        certificate.extend(signature_value);
+
+        encode_der(0x30, &certificate)
+    }
+
+    #[test]
+    fn test_extract_spki_round_trips_payload() {
+        let spki_content = b"fake-public-key-bytes";
+        let certificate = synthetic_certificate(spki_content);
+
+        let spki = extract_spki(&certificate).expect("should extract SPKI");
+        // The extracted element is the full SEQUENCE TLV, so the content
+        // tail should still contain exactly what we embedded.
+        assert!(spki.ends_with(spki_content));
+    }
+
+    #[test]
+    fn test_spki_sha256_base64_is_deterministic() {
+        let certificate = synthetic_certificate(b"fake-public-key-bytes");
+        let first = spki_sha256_base64(&certificate).unwrap();
+        let second = spki_sha256_base64(&certificate).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 44); // base64 of a 32-byte SHA-256 digest
+    }
+
+    #[test]
+    fn test_verify_certificate_without_pins_is_unaffected() {
+        let manager = SecurityManager::new(SecurityConfig::default());
+        let certificate = synthetic_certificate(b"whatever");
+        assert!(manager.verify_certificate("api.example.com", &certificate));
+        assert!(!manager.has_pins_for("api.example.com"));
+        assert_eq!(manager.metrics().blocked_requests, 0);
+    }
+
+    #[test]
+    fn test_verify_certificate_matches_configured_pin() {
+        let certificate = synthetic_certificate(b"fake-public-key-bytes");
+        let pin = spki_sha256_base64(&certificate).unwrap();
+
+        let mut config = SecurityConfig::default();
+        config.certificate_pins = vec![pin];
+        let manager = SecurityManager::new(config);
+
+        assert!(manager.verify_certificate("api.example.com", &certificate));
+        assert_eq!(manager.metrics().blocked_requests, 0);
+    }
+
+    #[test]
+    fn test_verify_certificate_rejects_mismatched_pin() {
+        let certificate = synthetic_certificate(b"fake-public-key-bytes");
+
+        let mut config = SecurityConfig::default();
+        config.certificate_pins = vec!["wrong-pin-base64".to_string()];
+        let manager = SecurityManager::new(config);
+
+        assert!(!manager.verify_certificate("api.example.com", &certificate));
+        assert_eq!(manager.metrics().blocked_requests, 1);
+        let events = manager.events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0].event_type, SecurityEventType::CertificateMismatch));
+        assert!(matches!(events[0].severity, Severity::High));
+    }
+
+    #[test]
+    fn test_per_host_pins_override_global_pins() {
+        let pinned_host_cert = synthetic_certificate(b"pinned-host-key");
+        let other_host_cert = synthetic_certificate(b"other-host-key");
+        let pinned_host_pin = spki_sha256_base64(&pinned_host_cert).unwrap();
+        let other_host_pin = spki_sha256_base64(&other_host_cert).unwrap();
+
+        let mut certificate_pins_by_host = HashMap::new();
+        certificate_pins_by_host.insert("hypercore.example.com".to_string(), vec![pinned_host_pin]);
+
+        let config = SecurityConfig {
+            certificate_pins: vec![other_host_pin],
+            certificate_pins_by_host,
+            ..SecurityConfig::default()
+        };
+        let manager = SecurityManager::new(config);
+
+        assert!(manager.verify_certificate("hypercore.example.com", &pinned_host_cert));
+        assert!(manager.verify_certificate("hyperevm.example.com", &other_host_cert));
+        assert!(!manager.verify_certificate("hypercore.example.com", &other_host_cert));
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::types::{Address, Wei};
+use crate::types::{Address, AccessListEntry, Wei};
 
 /// Risk level assessment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -84,6 +84,62 @@ pub struct GasOptimization {
     pub suggested_max_priority_fee_per_gas: Option<Wei>,
     /// Gas optimization techniques identified
     pub optimization_techniques: Vec<String>,
+    /// EIP-2930 access list synthesized from the trace, limited to entries
+    /// whose declaration cost is more than offset by the warm-access
+    /// discount on their repeated touches. Empty when declaring an access
+    /// list would not net save gas. Callers can attach this directly to a
+    /// `TransactionRequest.access_list` for a typed transaction.
+    pub access_list: Vec<AccessListEntry>,
+    /// `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`, present
+    /// only when the simulation carried enough EIP-1559 context
+    /// (`base_fee_per_gas`, `effective_gas_price`, `gas_limit`, `max_fee_per_gas`)
+    /// for the fee breakdown
+    pub effective_gas_price: Option<Wei>,
+    /// `gas_used * base_fee`, burned regardless of who mines the block
+    pub base_fee_burn: Option<Wei>,
+    /// `gas_used * (effective_gas_price - base_fee)`, tipped to the block builder
+    pub priority_tip: Option<Wei>,
+    /// Penalty proportional to the unused `(gas_limit - gas_used)` headroom,
+    /// charged against an over-large gas limit rather than refunded
+    pub over_estimation_burn: Option<Wei>,
+    /// `(gas_limit - gas_used) * max_fee_per_gas` minus `over_estimation_burn`,
+    /// returned to the sender
+    pub refund: Option<Wei>,
+    /// EIP-4844 `blob_count * GAS_PER_BLOB`, present only for blob-carrying transactions
+    pub blob_gas_used: Option<String>,
+    /// EIP-4844 `blob_gas_used * blob_base_fee`
+    pub blob_fee: Option<Wei>,
+    /// EIP-2718 envelope of the simulated transaction, present only when
+    /// `SimulationResult::tx_type` carried a recognized type byte
+    pub transaction_envelope: Option<TransactionEnvelope>,
+}
+
+/// EIP-2718 typed-transaction envelope, decoded from `SimulationResult::tx_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionEnvelope {
+    /// Type `0x00`: pre-EIP-2718 transaction, priced by a single `gas_price`
+    Legacy,
+    /// Type `0x01` (EIP-2930): legacy pricing plus an optional access list
+    AccessList,
+    /// Type `0x02` (EIP-1559): priced by `max_fee_per_gas`/`max_priority_fee_per_gas`
+    DynamicFee,
+    /// Type `0x03` (EIP-4844): dynamic-fee pricing plus blob sidecar data
+    Blob,
+}
+
+impl TransactionEnvelope {
+    /// Decode an EIP-2718 envelope type byte, or `None` if it isn't one of
+    /// the types this SDK understands
+    pub fn from_tx_type(tx_type: u8) -> Option<Self> {
+        match tx_type {
+            0 => Some(Self::Legacy),
+            1 => Some(Self::AccessList),
+            2 => Some(Self::DynamicFee),
+            3 => Some(Self::Blob),
+            _ => None,
+        }
+    }
 }
 
 /// Security analysis results
@@ -288,6 +344,27 @@ pub struct PriceImpact {
     pub slippage: f64,
 }
 
+/// Per-venue execution constraints the timing engine and liquidity path
+/// fold into their scheduling decisions, so `optimal_timing` and
+/// `MarketAnalysis` reflect what a venue will actually accept rather than a
+/// theoretical optimum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueConstraints {
+    /// Human-readable venue name
+    pub name: String,
+    /// Maximum number of submissions the venue accepts per `rate_limit_interval_secs`
+    pub rate_limit: u32,
+    /// Length of the rate-limit window, in seconds
+    pub rate_limit_interval_secs: u64,
+    /// Minimum order size the venue will accept
+    pub min_lot: f64,
+    /// Smallest price increment the venue's order book supports; order sizes
+    /// are rounded down to a multiple of this
+    pub tick_size: f64,
+    /// Minimum notional (price * size) value the venue will accept
+    pub min_notional: f64,
+}
+
 /// Market event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketEvent {
@@ -330,6 +407,7 @@ pub enum RecommendationType {
     ParameterAdjustment,
     AlternativeApproach,
     RiskMitigation,
+    AccessListAddition,
     Other(String),
 }
 
@@ -87,6 +87,76 @@ pub struct PerformanceMetrics {
     pub cache_hit_ratio: f64,
     /// Uptime in milliseconds
     pub uptime: u64,
+    /// Per-endpoint latency ranking for the HyperEVM RPC endpoint pool, and
+    /// which endpoint is currently being routed to
+    pub endpoint_pool: EndpointPoolMetrics,
+    /// Number of `simulate()` attempts retried after a transient rate-limit
+    /// error, as opposed to `failed_requests` which only counts calls that
+    /// ultimately gave up
+    pub rate_limited_retries: u64,
+    /// Response-time percentiles over the current 60-second metrics window,
+    /// recorded by an HDR histogram rather than a cumulative moving average
+    /// so tail latency isn't hidden by early samples
+    pub response_time_percentiles: ResponseTimePercentiles,
+    /// Per-subscription event queue depth and drop counts, for subscriptions
+    /// opened with streaming event delivery, so callers running many streams
+    /// can detect a consumer that's falling behind
+    pub subscription_queues: Vec<SubscriptionQueueMetrics>,
+}
+
+/// Queue depth and drop count for a single WebSocket subscription's bounded
+/// event queue. `dropped_notifications` only grows once `queue_depth` has
+/// reached `queue_capacity` and an incoming notification had to be dropped
+/// because a slow consumer hadn't made room for it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionQueueMetrics {
+    /// The subscription's caller-stable local ID
+    pub subscription_id: String,
+    /// Number of notifications currently buffered, awaiting the consumer
+    pub queue_depth: usize,
+    /// Maximum number of notifications the queue will buffer
+    pub queue_capacity: usize,
+    /// Notifications dropped because the queue was full when they arrived
+    pub dropped_notifications: u64,
+}
+
+/// Response-time percentiles (in milliseconds) recorded by an HDR histogram
+/// over the current metrics window. All zero before the first request in a
+/// window has completed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResponseTimePercentiles {
+    /// Median response time
+    pub p50: u64,
+    /// 90th percentile response time
+    pub p90: u64,
+    /// 99th percentile response time
+    pub p99: u64,
+    /// Slowest response time recorded in the window
+    pub max: u64,
+}
+
+/// One endpoint's latency ranking within a multi-endpoint pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointLatency {
+    /// Endpoint URL
+    pub endpoint: String,
+    /// Exponentially-weighted moving average of round-trip latency, in
+    /// milliseconds (`0.0` if the endpoint has not been queried yet)
+    pub ewma_ms: f64,
+    /// Whether the endpoint's most recently completed request succeeded
+    /// (`true` if the endpoint has not been queried yet)
+    pub healthy: bool,
+}
+
+/// Per-endpoint latency ranking for a multi-endpoint connection pool, and
+/// which endpoint the pool is currently routing to
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EndpointPoolMetrics {
+    /// Latency/health snapshot for every endpoint in the pool
+    pub endpoints: Vec<EndpointLatency>,
+    /// The endpoint a dispatch would currently pick: the lowest-EWMA
+    /// endpoint among those whose last attempt succeeded
+    pub selected: Option<String>,
 }
 
 /// Connection pool statistics
@@ -335,9 +405,34 @@ impl Address {
         &self.0
     }
 
+    /// Render this address in its EIP-55 mixed-case checksummed form: lowercase
+    /// the 40 hex chars, then uppercase each one whose corresponding nibble of
+    /// `keccak256(lowercase_ascii_address)` is >= 8.
     pub fn checksum(&self) -> String {
-        // TODO: Implement EIP-55 checksum encoding
-        self.0.clone()
+        let hex_part = self.0.trim_start_matches("0x").to_lowercase();
+        let hash = crate::utils::abi::keccak256_hash(hex_part.as_bytes());
+
+        let checksummed: String = hex_part
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if !c.is_ascii_alphabetic() {
+                    return c;
+                }
+                let nibble = if i % 2 == 0 {
+                    hash[i / 2] >> 4
+                } else {
+                    hash[i / 2] & 0x0f
+                };
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+
+        format!("0x{}", checksummed)
     }
 }
 
@@ -389,8 +484,175 @@ impl std::str::FromStr for Hash {
     }
 }
 
-/// Wei amount wrapper for precise decimal handling
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// A 256-bit unsigned integer, stored as four big-endian `u64` limbs
+/// (`limbs[0]` most significant). Backs [`Wei`] so gas/value arithmetic
+/// doesn't silently truncate above `u128::MAX` the way a naive
+/// `str.parse::<u128>()` round-trip would.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct U256 {
+    limbs: [u64; 4],
+}
+
+impl U256 {
+    pub const ZERO: U256 = U256 { limbs: [0, 0, 0, 0] };
+
+    pub fn from_u128(value: u128) -> Self {
+        Self { limbs: [0, 0, (value >> 64) as u64, value as u64] }
+    }
+
+    /// Parse a `0x`-prefixed hex string (the canonical wire encoding) or a
+    /// plain decimal string (accepted for convenience/backwards compat with
+    /// the plain-decimal `Wei` strings this type replaces).
+    pub fn parse(value: &str) -> crate::error::Result<Self> {
+        if let Some(hex) = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+            Self::from_hex_str(hex)
+        } else {
+            Self::from_dec_str(value)
+        }
+    }
+
+    pub fn from_hex_str(hex: &str) -> crate::error::Result<Self> {
+        let hex = if hex.is_empty() { "0" } else { hex };
+        if hex.len() > 64 {
+            return Err(crate::error::HyperSimError::validation("U256 hex value overflows 256 bits"));
+        }
+
+        let padded = format!("{:0>64}", hex);
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let chunk = &padded[i * 16..(i + 1) * 16];
+            *limb = u64::from_str_radix(chunk, 16)
+                .map_err(|_| crate::error::HyperSimError::validation(format!("Invalid U256 hex value: 0x{}", hex)))?;
+        }
+        Ok(Self { limbs })
+    }
+
+    pub fn from_dec_str(dec: &str) -> crate::error::Result<Self> {
+        if dec.is_empty() || !dec.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(crate::error::HyperSimError::validation(format!("Invalid U256 decimal value: {}", dec)));
+        }
+
+        let mut value = U256::ZERO;
+        let ten = U256::from_u128(10);
+        for digit in dec.bytes() {
+            let digit = U256::from_u128((digit - b'0') as u128);
+            value = value
+                .checked_mul(&ten)
+                .and_then(|v| v.checked_add(&digit))
+                .ok_or_else(|| crate::error::HyperSimError::validation("U256 decimal value overflows 256 bits"))?;
+        }
+        Ok(value)
+    }
+
+    /// Canonical `0x`-prefixed hex encoding with no leading zeros (`0x0` for zero).
+    pub fn to_hex_string(&self) -> String {
+        let hex: String = self.limbs.iter().map(|limb| format!("{:016x}", limb)).collect();
+        let trimmed = hex.trim_start_matches('0');
+        if trimmed.is_empty() {
+            "0x0".to_string()
+        } else {
+            format!("0x{}", trimmed)
+        }
+    }
+
+    pub fn checked_add(&self, other: &U256) -> Option<U256> {
+        let mut result = [0u64; 4];
+        let mut carry = 0u128;
+        for i in (0..4).rev() {
+            let sum = self.limbs[i] as u128 + other.limbs[i] as u128 + carry;
+            result[i] = sum as u64;
+            carry = sum >> 64;
+        }
+        if carry != 0 {
+            None
+        } else {
+            Some(Self { limbs: result })
+        }
+    }
+
+    pub fn checked_sub(&self, other: &U256) -> Option<U256> {
+        if self < other {
+            return None;
+        }
+        let mut result = [0u64; 4];
+        let mut borrow = 0i128;
+        for i in (0..4).rev() {
+            let diff = self.limbs[i] as i128 - other.limbs[i] as i128 - borrow;
+            if diff < 0 {
+                result[i] = (diff + (1i128 << 64)) as u64;
+                borrow = 1;
+            } else {
+                result[i] = diff as u64;
+                borrow = 0;
+            }
+        }
+        Some(Self { limbs: result })
+    }
+
+    pub fn checked_mul(&self, other: &U256) -> Option<U256> {
+        // Schoolbook long multiplication over the 4 limbs, rejecting any
+        // product that would overflow the 256-bit result.
+        let mut wide = [0u128; 8];
+        for (i, &a) in self.limbs.iter().rev().enumerate() {
+            for (j, &b) in other.limbs.iter().rev().enumerate() {
+                let product = a as u128 * b as u128;
+                let low = product as u64;
+                let high = (product >> 64) as u64;
+
+                wide[i + j] += low as u128;
+                wide[i + j + 1] += high as u128;
+            }
+        }
+
+        // Propagate carries up through the wide accumulator.
+        for i in 0..wide.len() - 1 {
+            let carry = wide[i] >> 64;
+            wide[i] &= u64::MAX as u128;
+            wide[i + 1] += carry;
+        }
+
+        if wide[4..].iter().any(|&limb| limb != 0) {
+            return None;
+        }
+
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().rev().enumerate() {
+            *limb = wide[i] as u64;
+        }
+        Some(Self { limbs })
+    }
+}
+
+impl std::fmt::Display for U256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if *self == U256::ZERO {
+            return write!(f, "0");
+        }
+
+        // Repeated division by 10 over the limb array, emitting digits
+        // least-significant-first, then reversed.
+        let mut limbs = self.limbs;
+        let mut digits = Vec::new();
+        while limbs.iter().any(|&l| l != 0) {
+            let mut remainder: u128 = 0;
+            for limb in limbs.iter_mut() {
+                let acc = (remainder << 64) | *limb as u128;
+                *limb = (acc / 10) as u64;
+                remainder = acc % 10;
+            }
+            digits.push(b'0' + remainder as u8);
+        }
+        digits.reverse();
+        write!(f, "{}", String::from_utf8(digits).unwrap())
+    }
+}
+
+/// Wei amount wrapper for precise, lossless gas/value handling. Backed by
+/// [`U256`] for arithmetic and hex parsing; the decimal string is kept as
+/// the in-memory representation so existing `as_str()`/`Display` callers
+/// (and the plain-decimal values the RPC mock returns) keep working
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Wei(pub String);
 
 impl Wei {
@@ -412,6 +674,35 @@ impl Wei {
             .map_err(|_| crate::error::HyperSimError::validation("Invalid wei amount"))?;
         Ok(wei as f64 / 1e18)
     }
+
+    /// Parse this amount (decimal or `0x`-prefixed hex) into a [`U256`] for
+    /// lossless arithmetic, rather than truncating through `u128`.
+    pub fn as_u256(&self) -> crate::error::Result<U256> {
+        U256::parse(&self.0)
+    }
+
+    /// Canonical `0x`-prefixed hex encoding, for RPC params that require it.
+    pub fn to_hex(&self) -> crate::error::Result<String> {
+        Ok(self.as_u256()?.to_hex_string())
+    }
+
+    pub fn checked_add(&self, other: &Wei) -> crate::error::Result<Wei> {
+        let sum = self.as_u256()?.checked_add(&other.as_u256()?)
+            .ok_or_else(|| crate::error::HyperSimError::validation("Wei addition overflowed 256 bits"))?;
+        Ok(Wei(sum.to_string()))
+    }
+
+    pub fn checked_sub(&self, other: &Wei) -> crate::error::Result<Wei> {
+        let diff = self.as_u256()?.checked_sub(&other.as_u256()?)
+            .ok_or_else(|| crate::error::HyperSimError::validation("Wei subtraction underflowed"))?;
+        Ok(Wei(diff.to_string()))
+    }
+
+    pub fn checked_mul(&self, other: &Wei) -> crate::error::Result<Wei> {
+        let product = self.as_u256()?.checked_mul(&other.as_u256()?)
+            .ok_or_else(|| crate::error::HyperSimError::validation("Wei multiplication overflowed 256 bits"))?;
+        Ok(Wei(product.to_string()))
+    }
 }
 
 impl std::fmt::Display for Wei {
@@ -420,6 +711,29 @@ impl std::fmt::Display for Wei {
     }
 }
 
+impl Serialize for Wei {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let hex = self.to_hex().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&hex)
+    }
+}
+
+impl<'de> Deserialize<'de> for Wei {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        // Normalize through U256 so both hex and plain-decimal wire values
+        // round-trip to the same canonical decimal in-memory representation.
+        let value = U256::parse(&raw).map_err(serde::de::Error::custom)?;
+        Ok(Wei(value.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -445,6 +759,54 @@ mod tests {
         assert!((wei.to_ether().unwrap() - 1.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_u256_hex_and_decimal_parse_agree() {
+        let from_hex = U256::parse("0x3e8").unwrap();
+        let from_dec = U256::parse("1000").unwrap();
+        assert_eq!(from_hex, from_dec);
+        assert_eq!(from_hex.to_string(), "1000");
+        assert_eq!(from_hex.to_hex_string(), "0x3e8");
+    }
+
+    #[test]
+    fn test_u256_survives_above_u128_max() {
+        // 2^200, far beyond u128::MAX (2^128 - 1)
+        let value = U256::parse("1606938044258990275541962092341162602522202993782792835301376").unwrap();
+        assert_eq!(
+            value.to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+    }
+
+    #[test]
+    fn test_u256_checked_mul_overflow_returns_none() {
+        let max_ish = U256::parse(&"f".repeat(64)).unwrap();
+        assert!(max_ish.checked_mul(&U256::from_u128(2)).is_none());
+    }
+
+    #[test]
+    fn test_u256_checked_sub_underflow_returns_none() {
+        assert!(U256::from_u128(1).checked_sub(&U256::from_u128(2)).is_none());
+    }
+
+    #[test]
+    fn test_wei_checked_arithmetic_above_u128_max() {
+        let a = Wei::new("1606938044258990275541962092341162602522202993782792835301376"); // 2^200
+        let b = Wei::new("1");
+        let sum = a.checked_add(&b).unwrap();
+        assert_eq!(sum.as_str(), "1606938044258990275541962092341162602522202993782792835301377");
+    }
+
+    #[test]
+    fn test_wei_serde_round_trips_through_canonical_hex() {
+        let wei = Wei::new("1000");
+        let json = serde_json::to_string(&wei).unwrap();
+        assert_eq!(json, "\"0x3e8\"");
+
+        let round_tripped: Wei = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, wei);
+    }
+
     #[test]
     fn test_cache_entry() {
         let mut entry = CacheEntry::new("test_value".to_string(), 1000);
@@ -2,7 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::types::{Address, Hash, Network};
+use crate::error::{HyperSimError, Result};
+use crate::types::{AccessListEntry, Address, Hash, Network, TransportConfig};
+use crate::utils::abi::keccak256_hash;
+use crate::verification::proof::{as_bytes, decode_hex, decode_rlp, normalize, verify_trie_proof, RlpNode};
 
 /// HyperCore client configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,6 +28,11 @@ pub struct HyperCoreConfig {
     pub compression: bool,
     /// Debug mode
     pub debug: bool,
+    /// Certificate pinning policy for the HTTP client. `None` leaves TLS
+    /// verification to the platform's default trust store.
+    pub security: Option<crate::security::SecurityConfig>,
+    /// Socket-level transport tuning for outbound connections
+    pub transport: TransportConfig,
 }
 
 impl HyperCoreConfig {
@@ -39,6 +47,8 @@ impl HyperCoreConfig {
             max_batch_size: 100,
             compression: true,
             debug: false,
+            security: None,
+            transport: TransportConfig::default(),
         }
     }
 
@@ -75,6 +85,8 @@ pub enum QueryType {
     CrossLayerTransactions,
     StateProofs,
     BridgeOperations,
+    /// Request a canonical-hash-trie header proof for a target block, see [`HeaderProof`]
+    HeaderProof,
 }
 
 /// Block range for queries
@@ -101,6 +113,18 @@ pub struct QueryFilters {
     pub include_internal: bool,
 }
 
+impl QueryFilters {
+    /// Whether `envelope_type` (EIP-2718 envelope byte: `0x00` legacy, `0x01`
+    /// access-list, `0x02` dynamic-fee) passes this filter's `tx_types`. A
+    /// `None` filter matches every envelope type.
+    pub fn matches_tx_envelope_type(&self, envelope_type: u8) -> bool {
+        match &self.tx_types {
+            Some(tx_types) => tx_types.contains(&envelope_type),
+            None => true,
+        }
+    }
+}
+
 /// Cross-layer data response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossLayerData {
@@ -178,6 +202,13 @@ pub struct CrossLayerTransaction {
     pub amount: String,
     /// Transaction data
     pub data: String,
+    /// EIP-2718 transaction envelope type byte (`0x00` legacy, `0x01`
+    /// EIP-2930 access-list, `0x02` EIP-1559 dynamic-fee)
+    #[serde(default)]
+    pub tx_envelope_type: u8,
+    /// EIP-2930 access list, present for envelope types `0x01` and `0x02`
+    #[serde(default)]
+    pub access_list: Option<Vec<AccessListEntry>>,
     /// Gas information
     pub gas_info: CrossLayerGasInfo,
     /// Status
@@ -215,6 +246,18 @@ pub struct CrossLayerGasInfo {
     pub source_gas_price: String,
     /// Gas price in target layer
     pub target_gas_price: Option<String>,
+    /// EIP-1559 fee market fields, present for dynamic-fee transactions
+    #[serde(default)]
+    pub fee_market: Option<FeeMarket>,
+}
+
+/// EIP-1559 fee market fields for a dynamic-fee transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeMarket {
+    /// Maximum total fee per gas the sender is willing to pay
+    pub max_fee_per_gas: String,
+    /// Maximum tip per gas paid to the block proposer
+    pub max_priority_fee_per_gas: String,
 }
 
 /// Cross-layer transaction status
@@ -357,6 +400,31 @@ pub struct StateSyncInfo {
     pub health_score: f64,
 }
 
+/// Block lag within which a layer is still considered `Syncing` rather than `Behind`
+const HEALTHY_SYNC_LAG_BLOCKS: u64 = 10;
+/// Block lag beyond which a layer is considered `Disconnected` rather than `Behind`
+const STALE_SYNC_LAG_BLOCKS: u64 = 100;
+
+impl StateSyncInfo {
+    /// Build sync info from a verified canonical head rather than trusting
+    /// the endpoint's self-reported block number: `verified_block` should
+    /// come from a [`HeaderProof::verify`]-confirmed block, so `sync_lag`
+    /// and `health_score` reflect trust-minimized cross-layer sync status
+    /// instead of whatever the endpoint claims.
+    pub fn from_verified_head(verified_block: u64, endpoint_reported_block: u64, pending_syncs: u32) -> Self {
+        let sync_lag = endpoint_reported_block.saturating_sub(verified_block);
+        let health_score = (1.0 - (sync_lag as f64 / STALE_SYNC_LAG_BLOCKS as f64)).clamp(0.0, 1.0);
+        let sync_status = match sync_lag {
+            0 => SyncStatus::Synced,
+            lag if lag <= HEALTHY_SYNC_LAG_BLOCKS => SyncStatus::Syncing,
+            lag if lag <= STALE_SYNC_LAG_BLOCKS => SyncStatus::Behind,
+            _ => SyncStatus::Disconnected,
+        };
+
+        Self { last_sync_block: verified_block, sync_status, pending_syncs, sync_lag, health_score }
+    }
+}
+
 /// Synchronization status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -384,6 +452,101 @@ pub struct StateProof {
     pub layer: String,
 }
 
+impl StateProof {
+    /// Verify an `AccountProof` against `self.root`: walk `self.proof` as a
+    /// Merkle-Patricia trie from `self.root` down to `keccak256(self.address)`,
+    /// then check the decoded leaf's `[nonce, balance, storageRoot, codeHash]`
+    /// tuple matches `expected_account`, which must itself be the RLP-encoded
+    /// 4-item tuple (the same shape `eth_getProof` commits to in the trie).
+    ///
+    /// An empty `expected_account` asserts the account is absent; any other
+    /// combination of a diverged/early-terminating path and empty leaf value
+    /// is treated as a valid exclusion proof and returns `Ok(false)`.
+    pub fn verify(&self, expected_account: &[u8]) -> Result<bool> {
+        self.require_proof_type(ProofType::AccountProof)?;
+        let address = decode_hex(self.address.as_str())?;
+        let key = keccak256_hash(&address);
+        self.verify_leaf(&key, |leaf| Self::account_matches(leaf, expected_account))
+    }
+
+    /// Verify a `StorageProof` against `self.root`: walk `self.proof` from
+    /// `self.root` down to `keccak256(storage_slot)`, then check the decoded
+    /// leaf value matches `expected_value` (the RLP-encoded storage word).
+    ///
+    /// An empty `expected_value` asserts the slot is absent, which a
+    /// diverged/early-terminating path satisfies as a valid exclusion proof.
+    pub fn verify_storage(&self, storage_slot: &[u8], expected_value: &[u8]) -> Result<bool> {
+        self.require_proof_type(ProofType::StorageProof)?;
+        let key = keccak256_hash(storage_slot);
+        self.verify_leaf(&key, |leaf| Self::storage_value_matches(leaf, expected_value))
+    }
+
+    fn require_proof_type(&self, expected: ProofType) -> Result<()> {
+        if std::mem::discriminant(&self.proof_type) != std::mem::discriminant(&expected) {
+            return Err(HyperSimError::validation_with_field(
+                format!("StateProof::verify expected a {:?} proof, got {:?}", expected, self.proof_type),
+                "proof_type",
+            ));
+        }
+        Ok(())
+    }
+
+    fn verify_leaf(&self, key: &[u8; 32], matches: impl FnOnce(&[u8]) -> Result<bool>) -> Result<bool> {
+        let root = decode_hex(self.root.as_str())?;
+        let nodes = self.proof.iter().map(|node| decode_hex(node)).collect::<Result<Vec<_>>>()?;
+
+        match verify_trie_proof(&root, key, &nodes)? {
+            None => Ok(false),
+            Some(leaf) => matches(&leaf),
+        }
+    }
+
+    fn account_matches(leaf: &[u8], expected_account: &[u8]) -> Result<bool> {
+        if expected_account.is_empty() {
+            return Ok(false);
+        }
+
+        let leaf_items = Self::decode_account_tuple(leaf, "account leaf")?;
+        let expected_items = Self::decode_account_tuple(expected_account, "expected account value")?;
+
+        for (leaf_item, expected_item) in leaf_items.iter().zip(expected_items.iter()) {
+            if normalize(&as_bytes(leaf_item)?) != normalize(&as_bytes(expected_item)?) {
+                return Err(HyperSimError::validation_with_field(
+                    "Account proof's leaf value does not match the expected account tuple",
+                    "value",
+                ));
+            }
+        }
+        Ok(true)
+    }
+
+    fn decode_account_tuple(value: &[u8], what: &str) -> Result<Vec<RlpNode>> {
+        let (node, rest) = decode_rlp(value)?;
+        if !rest.is_empty() {
+            return Err(HyperSimError::validation_with_field(format!("Trailing bytes after {}", what), "value"));
+        }
+        match node {
+            RlpNode::List(items) if items.len() == 4 => Ok(items),
+            _ => Err(HyperSimError::validation_with_field(format!("{} is not a 4-item RLP list", what), "value")),
+        }
+    }
+
+    fn storage_value_matches(leaf: &[u8], expected_value: &[u8]) -> Result<bool> {
+        let (node, rest) = decode_rlp(leaf)?;
+        if !rest.is_empty() {
+            return Err(HyperSimError::validation_with_field("Trailing bytes after storage leaf value", "value"));
+        }
+        let raw = as_bytes(&node)?;
+        if normalize(&raw) != normalize(expected_value) {
+            return Err(HyperSimError::validation_with_field(
+                "Storage proof's leaf value does not match the expected value",
+                "value",
+            ));
+        }
+        Ok(true)
+    }
+}
+
 /// Types of state proofs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -394,6 +557,99 @@ pub enum ProofType {
     ReceiptProof,
 }
 
+/// A canonical-hash-trie (CHT) header proof, letting a light client confirm
+/// a block is canonical on a layer without trusting the endpoint, analogous
+/// to LES CHT header proofs: `proof` walks from `cht_root` down to the entry
+/// keyed by `block_number`, which commits to `(hash, total_difficulty)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeaderProof {
+    /// Root of the canonical-hash-trie this proof is walked against
+    pub cht_root: Hash,
+    /// Block number the proof attests is canonical
+    pub block_number: u64,
+    /// RLP-encoded block header, hex-encoded
+    pub header_rlp: String,
+    /// Merkle-Patricia proof nodes from `cht_root` down to the CHT entry
+    pub proof: Vec<String>,
+}
+
+impl HeaderProof {
+    /// Reconstruct the CHT key from `self.block_number`, walk `self.proof`
+    /// as a Merkle-Patricia trie from `self.cht_root`, and confirm the
+    /// stored `(hash, total_difficulty)` entry's hash matches
+    /// `keccak256(self.header_rlp)`.
+    ///
+    /// A diverged/early-terminating path is treated as a valid exclusion
+    /// proof (the block is not canonical) and returns `Ok(false)`.
+    pub fn verify(&self) -> Result<bool> {
+        let root = decode_hex(self.cht_root.as_str())?;
+        let nodes = self.proof.iter().map(|node| decode_hex(node)).collect::<Result<Vec<_>>>()?;
+        let key = Self::cht_key(self.block_number);
+        let expected_hash = keccak256_hash(&decode_hex(&self.header_rlp)?);
+
+        match verify_trie_proof(&root, &key, &nodes)? {
+            None => Ok(false),
+            Some(leaf) => Self::entry_hash_matches(&leaf, &expected_hash),
+        }
+    }
+
+    /// The CHT key for `block_number`: its big-endian byte representation,
+    /// matching the LES canonical-hash-trie's `uint64(blockNum)` key encoding.
+    fn cht_key(block_number: u64) -> [u8; 8] {
+        block_number.to_be_bytes()
+    }
+
+    fn entry_hash_matches(leaf: &[u8], expected_hash: &[u8; 32]) -> Result<bool> {
+        let (node, rest) = decode_rlp(leaf)?;
+        if !rest.is_empty() {
+            return Err(HyperSimError::validation_with_field("Trailing bytes after CHT entry", "value"));
+        }
+
+        match node {
+            RlpNode::List(items) if items.len() == 2 => {
+                let hash = as_bytes(&items[0])?;
+                if normalize(&hash) != normalize(expected_hash) {
+                    return Err(HyperSimError::validation_with_field(
+                        "CHT entry hash does not match keccak256(header_rlp)",
+                        "node_hash",
+                    ));
+                }
+                Ok(true)
+            }
+            _ => Err(HyperSimError::validation_with_field("CHT entry is not a 2-item RLP list", "value")),
+        }
+    }
+}
+
+/// A challenge submitted against a [`BridgeOperation`] during its
+/// fraud-proof window, bundling the Merkle-Patricia proof that demonstrates
+/// the claim. Callers are expected to have already verified `state_proof`
+/// via [`StateProof::verify`]/[`StateProof::verify_storage`] before
+/// constructing a `FraudProof` — submitting it only records the claim and
+/// disputes the operation, it does not re-walk the trie.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FraudProof {
+    /// Proof backing `claim`, verified against the source layer's state root
+    pub state_proof: StateProof,
+    /// What `state_proof` demonstrates about the disputed operation
+    pub claim: FraudProofClaim,
+}
+
+/// What a [`FraudProof`] demonstrates about a disputed [`BridgeOperation`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FraudProofClaim {
+    /// `state_proof` is an exclusion proof showing the source-layer
+    /// lock/burn backing this operation never occurred
+    LockNeverOccurred,
+    /// `state_proof` shows the amount actually locked on the source layer,
+    /// which is less than the operation's minted [`BridgedAsset::amount`]
+    MintExceedsLock {
+        /// Amount `state_proof` shows was actually locked, in the asset's base units
+        locked_amount: String,
+    },
+}
+
 /// Cross-layer metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossLayerMetadata {
@@ -429,6 +685,96 @@ mod tests {
         assert_eq!(serialized, "\"account_state\"");
     }
 
+    #[test]
+    fn test_query_filters_matches_tx_envelope_type() {
+        let unrestricted = QueryFilters { topics: None, min_value: None, tx_types: None, include_internal: false };
+        assert!(unrestricted.matches_tx_envelope_type(0x02));
+
+        let restricted = QueryFilters {
+            topics: None,
+            min_value: None,
+            tx_types: Some(vec![0x00, 0x02]),
+            include_internal: false,
+        };
+        assert!(restricted.matches_tx_envelope_type(0x02));
+        assert!(!restricted.matches_tx_envelope_type(0x01));
+    }
+
+    #[test]
+    fn test_cross_layer_transaction_round_trips_typed_envelope_fields() {
+        let transaction = CrossLayerTransaction {
+            hash: Hash::new("0x".to_string() + &"ab".repeat(32)).unwrap(),
+            source_layer: "hyperevm".to_string(),
+            target_layer: "hypercore".to_string(),
+            tx_type: CrossLayerTxType::Bridge,
+            from: Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string()).unwrap(),
+            to: Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string()).unwrap(),
+            amount: "0".to_string(),
+            data: "0x".to_string(),
+            tx_envelope_type: 0x02,
+            access_list: Some(vec![AccessListEntry {
+                address: Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string()).unwrap(),
+                storage_keys: vec![],
+            }]),
+            gas_info: CrossLayerGasInfo {
+                source_gas_used: "21000".to_string(),
+                target_gas_used: None,
+                total_gas_cost: "21000".to_string(),
+                source_gas_price: "1".to_string(),
+                target_gas_price: None,
+                fee_market: Some(FeeMarket {
+                    max_fee_per_gas: "100".to_string(),
+                    max_priority_fee_per_gas: "2".to_string(),
+                }),
+            },
+            status: CrossLayerTxStatus::Bridging,
+            timestamps: TransactionTimestamps {
+                initiated: 0,
+                source_confirmed: None,
+                target_confirmed: None,
+                completed: None,
+            },
+            related_hashes: vec![],
+        };
+
+        let json = serde_json::to_value(&transaction).unwrap();
+        assert_eq!(json["tx_envelope_type"], 2);
+        assert_eq!(json["gas_info"]["fee_market"]["max_fee_per_gas"], "100");
+
+        let round_tripped: CrossLayerTransaction = serde_json::from_value(json).unwrap();
+        assert_eq!(round_tripped.tx_envelope_type, 0x02);
+        assert_eq!(round_tripped.access_list.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_cross_layer_transaction_defaults_envelope_fields_when_absent() {
+        let legacy_json = serde_json::json!({
+            "hash": "0x".to_string() + &"ab".repeat(32),
+            "source_layer": "hyperevm",
+            "target_layer": "hypercore",
+            "tx_type": "bridge",
+            "from": "0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1",
+            "to": "0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1",
+            "amount": "0",
+            "data": "0x",
+            "gas_info": {
+                "source_gas_used": "21000",
+                "target_gas_used": null,
+                "total_gas_cost": "21000",
+                "source_gas_price": "1",
+                "target_gas_price": null
+            },
+            "status": "bridging",
+            "timestamps": { "initiated": 0, "source_confirmed": null, "target_confirmed": null, "completed": null },
+            "related_hashes": []
+        });
+
+        let transaction: CrossLayerTransaction = serde_json::from_value(legacy_json).unwrap();
+        assert_eq!(transaction.tx_envelope_type, 0x00);
+        assert!(transaction.access_list.is_none());
+        assert!(transaction.gas_info.fee_market.is_none());
+    }
+
     #[test]
     fn test_cross_layer_tx_status() {
         let status = CrossLayerTxStatus::Bridging;
@@ -442,4 +788,187 @@ mod tests {
         let serialized = serde_json::to_string(&status).unwrap();
         assert_eq!(serialized, "\"synced\"");
     }
+
+    fn encode_rlp_bytes(data: &[u8]) -> Vec<u8> {
+        if data.len() == 1 && data[0] < 0x80 {
+            return data.to_vec();
+        }
+        let mut out = encode_length(data.len(), 0x80);
+        out.extend_from_slice(data);
+        out
+    }
+
+    fn encode_rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload: Vec<u8> = items.concat();
+        let mut out = encode_length(payload.len(), 0xc0);
+        out.extend_from_slice(&payload);
+        out
+    }
+
+    fn encode_length(len: usize, offset: u8) -> Vec<u8> {
+        if len < 56 {
+            vec![offset + len as u8]
+        } else {
+            let len_bytes = len.to_be_bytes();
+            let len_bytes = len_bytes.iter().skip_while(|b| **b == 0).copied().collect::<Vec<_>>();
+            let mut out = vec![offset + 55 + len_bytes.len() as u8];
+            out.extend_from_slice(&len_bytes);
+            out
+        }
+    }
+
+    fn encode_account(nonce: u64, balance: u64, storage_root: &[u8; 32], code_hash: &[u8; 32]) -> Vec<u8> {
+        encode_rlp_list(&[
+            encode_rlp_bytes(&normalize(&nonce.to_be_bytes())),
+            encode_rlp_bytes(&normalize(&balance.to_be_bytes())),
+            encode_rlp_bytes(storage_root),
+            encode_rlp_bytes(code_hash),
+        ])
+    }
+
+    /// Build the single-leaf trie that results when there's exactly one
+    /// key in the whole trie: the root *is* the leaf, with an encoded path
+    /// covering the entire (keccak'd) key.
+    fn single_leaf_trie(key: &[u8], value: Vec<u8>) -> (Hash, String) {
+        let mut path = vec![0x20u8]; // leaf flag, even-length padding nibble
+        path.extend_from_slice(key);
+
+        let leaf = encode_rlp_list(&[encode_rlp_bytes(&path), encode_rlp_bytes(&value)]);
+        let root = keccak256_hash(&leaf);
+        (Hash::new(format!("0x{}", hex::encode(root))).unwrap(), format!("0x{}", hex::encode(&leaf)))
+    }
+
+    fn account_state_proof(address: &str, account_value: Vec<u8>) -> (StateProof, Vec<u8>) {
+        let address = Address::new(address.to_string()).unwrap();
+        let key = keccak256_hash(decode_hex(address.as_str()).unwrap().as_slice());
+        let (root, leaf) = single_leaf_trie(&key, account_value.clone());
+
+        let proof = StateProof {
+            address,
+            proof_type: ProofType::AccountProof,
+            proof: vec![leaf],
+            root,
+            block_number: 1,
+            layer: "hypercore".to_string(),
+        };
+        (proof, account_value)
+    }
+
+    #[test]
+    fn test_state_proof_verify_accepts_matching_account() {
+        let account = encode_account(4, 1_000_000, &[0u8; 32], &[0u8; 32]);
+        let (proof, expected) = account_state_proof("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1", account);
+
+        assert!(proof.verify(&expected).unwrap());
+    }
+
+    #[test]
+    fn test_state_proof_verify_rejects_wrong_account_value() {
+        let account = encode_account(4, 1_000_000, &[0u8; 32], &[0u8; 32]);
+        let (proof, _expected) = account_state_proof("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1", account);
+
+        let wrong = encode_account(4, 1, &[0u8; 32], &[0u8; 32]);
+        let error = proof.verify(&wrong).unwrap_err();
+        assert_eq!(error.category(), "validation");
+    }
+
+    #[test]
+    fn test_state_proof_verify_rejects_mismatched_proof_type() {
+        let account = encode_account(0, 0, &[0u8; 32], &[0u8; 32]);
+        let (proof, expected) = account_state_proof("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1", account);
+
+        assert!(proof.verify_storage(b"slot", &expected).is_err());
+    }
+
+    #[test]
+    fn test_state_proof_verify_storage_accepts_matching_value() {
+        let address = Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string()).unwrap();
+        let storage_slot = [1u8; 32];
+        let value = encode_rlp_bytes(&[0x2a]); // 42
+        let key = keccak256_hash(&storage_slot);
+        let (root, leaf) = single_leaf_trie(&key, value.clone());
+
+        let proof = StateProof {
+            address,
+            proof_type: ProofType::StorageProof,
+            proof: vec![leaf],
+            root,
+            block_number: 1,
+            layer: "hypercore".to_string(),
+        };
+
+        assert!(proof.verify_storage(&storage_slot, &value).unwrap());
+    }
+
+    #[test]
+    fn test_state_proof_verify_supports_exclusion_proof() {
+        let address = Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1".to_string()).unwrap();
+        // A single unrelated leaf whose path diverges from our key proves absence.
+        let other_key = [0xffu8; 32];
+        let (root, leaf) = single_leaf_trie(&other_key, vec![0x01]);
+
+        let proof = StateProof {
+            address,
+            proof_type: ProofType::StorageProof,
+            proof: vec![leaf],
+            root,
+            block_number: 1,
+            layer: "hypercore".to_string(),
+        };
+
+        assert!(!proof.verify_storage(&[0u8; 32], &[]).unwrap());
+    }
+
+    fn header_proof_for(block_number: u64, header_rlp: &[u8]) -> HeaderProof {
+        let header_hash = keccak256_hash(header_rlp);
+        let entry = encode_rlp_list(&[encode_rlp_bytes(&header_hash), encode_rlp_bytes(&normalize(&1_000u64.to_be_bytes()))]);
+        let (root, leaf) = single_leaf_trie(&block_number.to_be_bytes(), entry);
+
+        HeaderProof {
+            cht_root: root,
+            block_number,
+            header_rlp: format!("0x{}", hex::encode(header_rlp)),
+            proof: vec![leaf],
+        }
+    }
+
+    #[test]
+    fn test_header_proof_verify_accepts_matching_header() {
+        let proof = header_proof_for(100, b"fake-rlp-header");
+        assert!(proof.verify().unwrap());
+    }
+
+    #[test]
+    fn test_header_proof_verify_rejects_mismatched_header() {
+        let mut proof = header_proof_for(100, b"fake-rlp-header");
+        proof.header_rlp = format!("0x{}", hex::encode(b"a-different-header"));
+
+        let error = proof.verify().unwrap_err();
+        assert_eq!(error.category(), "validation");
+    }
+
+    #[test]
+    fn test_header_proof_verify_supports_exclusion_proof() {
+        let mut proof = header_proof_for(100, b"fake-rlp-header");
+        // A different block number reconstructs a CHT key that diverges
+        // from the single leaf in the trie, proving that block is absent.
+        proof.block_number = 101;
+
+        assert!(!proof.verify().unwrap());
+    }
+
+    #[test]
+    fn test_state_sync_info_from_verified_head_reports_lag_and_health() {
+        let caught_up = StateSyncInfo::from_verified_head(100, 100, 0);
+        assert!(matches!(caught_up.sync_status, SyncStatus::Synced));
+        assert_eq!(caught_up.health_score, 1.0);
+
+        let slightly_behind = StateSyncInfo::from_verified_head(90, 100, 0);
+        assert!(matches!(slightly_behind.sync_status, SyncStatus::Syncing));
+        assert_eq!(slightly_behind.sync_lag, 10);
+
+        let disconnected = StateSyncInfo::from_verified_head(0, 1000, 0);
+        assert!(matches!(disconnected.sync_status, SyncStatus::Disconnected));
+        assert_eq!(disconnected.health_score, 0.0);
+    }
 }
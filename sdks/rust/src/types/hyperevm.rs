@@ -1,7 +1,7 @@
 //! HyperEVM specific types and configurations
 
 use serde::{Deserialize, Serialize};
-use crate::types::{Address, Hash, Wei, Network, BlockType};
+use crate::types::{Address, Hash, Wei, Network, BlockType, TransportConfig};
 
 /// HyperEVM client configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,6 +10,9 @@ pub struct HyperEVMConfig {
     pub network: Network,
     /// Custom RPC endpoint
     pub rpc_endpoint: Option<String>,
+    /// Pool of RPC endpoints to dispatch queries across with failover.
+    /// Takes priority over `rpc_endpoint` when non-empty.
+    pub rpc_endpoints: Vec<String>,
     /// Request timeout in milliseconds
     pub timeout: u64,
     /// Maximum retry attempts
@@ -22,6 +25,25 @@ pub struct HyperEVMConfig {
     pub api_key: Option<String>,
     /// Enable debug logging
     pub debug: bool,
+    /// Verify simulation state trustlessly via Merkle proofs and a
+    /// consensus-verified header chain instead of trusting RPC responses
+    pub trustless: bool,
+    /// Certificate pinning policy for the HTTP client. `None` leaves TLS
+    /// verification to the platform's default trust store.
+    pub security: Option<crate::security::SecurityConfig>,
+    /// Socket-level transport tuning for outbound connections
+    pub transport: TransportConfig,
+    /// Operator-supplied static list of candidate endpoints, probed and
+    /// merged into the pool once every configured endpoint is unhealthy
+    pub fallback_endpoints: Vec<String>,
+    /// Also fetch and probe the published candidate list at `fallback_url`
+    pub load_external_fallback: bool,
+    /// URL serving a JSON document of fallback candidate endpoints, e.g.
+    /// `{"endpoints": ["https://...", "https://..."]}`
+    pub fallback_url: Option<String>,
+    /// Backoff and per-endpoint circuit breaker tuning applied around
+    /// `max_retries` retries of a transient RPC failure
+    pub resilience: ResilienceConfig,
 }
 
 impl HyperEVMConfig {
@@ -29,12 +51,20 @@ impl HyperEVMConfig {
         Self {
             network,
             rpc_endpoint: None,
+            rpc_endpoints: Vec::new(),
             timeout: 30000,
             max_retries: 3,
             cache_enabled: true,
             cache_ttl: 300,
             api_key: None,
             debug: false,
+            trustless: false,
+            security: None,
+            transport: TransportConfig::default(),
+            fallback_endpoints: Vec::new(),
+            load_external_fallback: false,
+            fallback_url: None,
+            resilience: ResilienceConfig::default(),
         }
     }
 
@@ -43,6 +73,47 @@ impl HyperEVMConfig {
             .as_deref()
             .unwrap_or_else(|| self.network.default_rpc_endpoint())
     }
+
+    /// The pool of RPC endpoints to dispatch queries across. Falls back to a
+    /// single-endpoint pool of [`Self::rpc_endpoint`] when `rpc_endpoints` is empty.
+    pub fn rpc_endpoint_pool(&self) -> Vec<String> {
+        if self.rpc_endpoints.is_empty() {
+            vec![self.rpc_endpoint().to_string()]
+        } else {
+            self.rpc_endpoints.clone()
+        }
+    }
+}
+
+/// Backoff and circuit breaker tuning for retrying transient RPC failures.
+/// `HyperEVMConfig::max_retries` caps how many times a failed request is
+/// retried; this controls the delay between attempts and when a endpoint
+/// that keeps failing gets short-circuited instead of retried further.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResilienceConfig {
+    /// Base delay before the first retry, in milliseconds
+    pub initial_backoff_ms: u64,
+    /// Ceiling the computed (pre-jitter) backoff is clamped to, in milliseconds
+    pub max_backoff_ms: u64,
+    /// Per-attempt backoff multiplier
+    pub backoff_multiplier: f64,
+    /// Consecutive failures against one endpoint before its circuit breaker trips
+    pub circuit_breaker_threshold: u32,
+    /// How long a tripped circuit breaker stays open before the next request
+    /// to that endpoint is let through as a probe, in milliseconds
+    pub circuit_breaker_cooldown_ms: u64,
+}
+
+impl Default for ResilienceConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 10_000,
+            backoff_multiplier: 2.0,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_cooldown_ms: 30_000,
+        }
+    }
 }
 
 /// HyperEVM transaction simulation request
@@ -63,23 +134,103 @@ pub struct HyperEVMSimulationRequest {
 }
 
 /// State overrides for simulation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct StateOverrides {
     /// Account state overrides
     pub accounts: std::collections::HashMap<Address, AccountOverride>,
 }
 
-/// Account state override
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl StateOverrides {
+    /// Merge `other` on top of `self`, following `eth_call` state-override semantics:
+    /// `balance`/`nonce`/`code` from `other` win when present, `state_override` (full
+    /// storage replacement) from `other` wins outright, and `state_diff` slots from
+    /// `other` are merged on top of `self`'s existing diff slot-by-slot.
+    pub fn merge(&mut self, other: &StateOverrides) {
+        for (address, incoming) in &other.accounts {
+            match self.accounts.get_mut(address) {
+                Some(existing) => existing.merge(incoming),
+                None => {
+                    self.accounts.insert(address.clone(), incoming.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Account state override, mirroring the `state`/`stateDiff` distinction used by
+/// `eth_call` state overrides.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AccountOverride {
     /// Override balance
     pub balance: Option<Wei>,
     /// Override nonce
     pub nonce: Option<u64>,
-    /// Override code
+    /// Override code (replaces the account's bytecode wholesale)
     pub code: Option<String>,
-    /// Override storage
-    pub storage: Option<std::collections::HashMap<String, String>>,
+    /// Full storage replacement: wipes existing storage, then sets exactly these slots
+    pub state_override: Option<std::collections::HashMap<String, String>>,
+    /// Sparse storage diff: merges these slots on top of live/existing storage, leaving
+    /// slots not listed untouched
+    pub state_diff: Option<std::collections::HashMap<String, String>>,
+}
+
+impl AccountOverride {
+    /// Merge `other` on top of `self` in place.
+    pub fn merge(&mut self, other: &AccountOverride) {
+        if other.balance.is_some() {
+            self.balance = other.balance.clone();
+        }
+        if other.nonce.is_some() {
+            self.nonce = other.nonce;
+        }
+        if other.code.is_some() {
+            self.code = other.code.clone();
+        }
+        // A full storage replacement discards any prior override outright.
+        if let Some(ref state_override) = other.state_override {
+            self.state_override = Some(state_override.clone());
+            self.state_diff = None;
+        } else if let Some(ref diff) = other.state_diff {
+            let merged = self.state_diff.get_or_insert_with(std::collections::HashMap::new);
+            for (slot, value) in diff {
+                merged.insert(slot.clone(), value.clone());
+            }
+        }
+    }
+
+    /// Validate that `code` is well-formed even-length hex and that every storage
+    /// key/value in `state_override`/`state_diff` is a 32-byte hex word.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if let Some(ref code) = self.code {
+            let hex_part = code.strip_prefix("0x").unwrap_or(code);
+            if hex_part.len() % 2 != 0 || !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(crate::error::HyperSimError::validation_with_field(
+                    "Account override code must be well-formed even-length hex",
+                    "code",
+                ));
+            }
+        }
+
+        for slots in [&self.state_override, &self.state_diff].into_iter().flatten() {
+            for (key, value) in slots {
+                validate_storage_word(key, "state override key")?;
+                validate_storage_word(value, "state override value")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_storage_word(word: &str, field: &'static str) -> crate::error::Result<()> {
+    let hex_part = word.strip_prefix("0x").unwrap_or(word);
+    if hex_part.len() != 64 || !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(crate::error::HyperSimError::validation_with_field(
+            format!("{} must be a 32-byte hex word", field),
+            field,
+        ));
+    }
+    Ok(())
 }
 
 /// HyperEVM block information
@@ -117,6 +268,8 @@ pub struct HyperEVMBlock {
     pub transaction_hashes: Vec<Hash>,
     /// Uncle blocks (for compatibility)
     pub uncles: Vec<Hash>,
+    /// EIP-1559 base fee per gas for this block
+    pub base_fee_per_gas: Option<Wei>,
 }
 
 /// HyperEVM transaction receipt
@@ -203,6 +356,64 @@ pub struct HyperEVMGasEstimate {
     pub confidence: f64,
     /// Estimation factors considered
     pub factors: Vec<String>,
+    /// Predicted EIP-1559 base fee for the next block, when dynamic-fee data is available
+    pub predicted_base_fee: Option<Wei>,
+}
+
+/// Comparison between a gas estimate with an EIP-2930 access list attached
+/// and the same transaction estimated without one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessListGasComparison {
+    /// Estimate with the access list attached
+    pub with_access_list: HyperEVMGasEstimate,
+    /// Estimate for the same transaction without an access list
+    pub without_access_list: HyperEVMGasEstimate,
+    /// `with_access_list.total_cost - without_access_list.total_cost`; negative
+    /// means attaching the list is cheaper overall
+    pub total_cost_delta: i128,
+}
+
+/// Elasticity multiplier used by the EIP-1559 base-fee recurrence (target = gas_limit / elasticity)
+pub const BASE_FEE_ELASTICITY_MULTIPLIER: u64 = 2;
+
+/// Denominator bounding how much the base fee can move between blocks
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// Predict the next block's EIP-1559 base fee from the parent block's base fee,
+/// gas limit, and gas used, following the recurrence from EIP-1559.
+pub fn compute_next_base_fee(
+    parent_base_fee: u128,
+    parent_gas_limit: u64,
+    parent_gas_used: u64,
+) -> u128 {
+    let gas_target = parent_gas_limit / BASE_FEE_ELASTICITY_MULTIPLIER;
+
+    if gas_target == 0 {
+        return parent_base_fee;
+    }
+
+    if parent_gas_used == gas_target {
+        parent_base_fee
+    } else if parent_gas_used > gas_target {
+        let gas_used_delta = (parent_gas_used - gas_target) as u128;
+        let increase = std::cmp::max(
+            1,
+            parent_base_fee * gas_used_delta / gas_target as u128
+                / BASE_FEE_MAX_CHANGE_DENOMINATOR as u128,
+        );
+        parent_base_fee + increase
+    } else {
+        let gas_used_delta = (gas_target - parent_gas_used) as u128;
+        let decrease = parent_base_fee * gas_used_delta / gas_target as u128
+            / BASE_FEE_MAX_CHANGE_DENOMINATOR as u128;
+        parent_base_fee.saturating_sub(decrease)
+    }
+}
+
+/// Compute the effective gas price a dynamic-fee transaction would pay given a base fee:
+/// `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`.
+pub fn effective_gas_price(base_fee: u128, max_fee_per_gas: u128, max_priority_fee_per_gas: u128) -> u128 {
+    std::cmp::min(max_fee_per_gas, base_fee.saturating_add(max_priority_fee_per_gas))
 }
 
 /// HyperEVM network statistics
@@ -254,6 +465,8 @@ pub struct BlockInfo {
     pub transaction_count: u32,
     /// Gas utilization percentage
     pub gas_utilization: f64,
+    /// EIP-1559 base fee per gas for this block
+    pub base_fee_per_gas: Option<Wei>,
 }
 
 /// Transaction confirmation status in dual-block system
@@ -293,6 +506,48 @@ mod tests {
         assert!(config.cache_enabled);
     }
 
+    #[test]
+    fn test_rpc_endpoint_pool_falls_back_to_single_endpoint() {
+        let config = HyperEVMConfig::new(Network::Testnet);
+        assert_eq!(config.rpc_endpoint_pool(), vec!["https://testnet.hyperevm.com".to_string()]);
+    }
+
+    #[test]
+    fn test_rpc_endpoint_pool_prefers_pool_over_single_endpoint() {
+        let mut config = HyperEVMConfig::new(Network::Testnet);
+        config.rpc_endpoint = Some("https://custom.example.com".to_string());
+        config.rpc_endpoints = vec![
+            "https://one.example.com".to_string(),
+            "https://two.example.com".to_string(),
+        ];
+
+        assert_eq!(
+            config.rpc_endpoint_pool(),
+            vec!["https://one.example.com".to_string(), "https://two.example.com".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_base_fee_recurrence() {
+        // At target utilization, base fee is unchanged.
+        assert_eq!(compute_next_base_fee(1_000_000_000, 30_000_000, 15_000_000), 1_000_000_000);
+
+        // Fully congested block increases the base fee by up to 12.5%.
+        let increased = compute_next_base_fee(1_000_000_000, 30_000_000, 30_000_000);
+        assert_eq!(increased, 1_125_000_000);
+
+        // Empty block decreases the base fee, never below zero.
+        let decreased = compute_next_base_fee(1_000_000_000, 30_000_000, 0);
+        assert_eq!(decreased, 875_000_000);
+        assert_eq!(compute_next_base_fee(1, 30_000_000, 0), 0);
+    }
+
+    #[test]
+    fn test_effective_gas_price_caps_at_max_fee() {
+        assert_eq!(effective_gas_price(1_000_000_000, 2_000_000_000, 500_000_000), 1_500_000_000);
+        assert_eq!(effective_gas_price(1_000_000_000, 1_200_000_000, 500_000_000), 1_200_000_000);
+    }
+
     #[test]
     fn test_finality_status_serialization() {
         let status = FinalityStatus::FastConfirmed;
@@ -305,11 +560,58 @@ mod tests {
         let override_data = AccountOverride {
             balance: Some(Wei::new("1000000000000000000")),
             nonce: Some(42),
-            code: None,
-            storage: None,
+            ..Default::default()
         };
-        
+
         assert_eq!(override_data.balance.unwrap().as_str(), "1000000000000000000");
         assert_eq!(override_data.nonce.unwrap(), 42);
     }
+
+    #[test]
+    fn test_account_override_validation() {
+        let mut good = AccountOverride::default();
+        good.code = Some("0x6001".to_string());
+        good.state_diff = Some(std::collections::HashMap::from([(
+            "0x0000000000000000000000000000000000000000000000000000000000000001".to_string(),
+            "0x0000000000000000000000000000000000000000000000000000000000000002".to_string(),
+        )]));
+        assert!(good.validate().is_ok());
+
+        let mut bad_code = AccountOverride::default();
+        bad_code.code = Some("0x123".to_string()); // odd length
+        assert!(bad_code.validate().is_err());
+
+        let mut bad_slot = AccountOverride::default();
+        bad_slot.state_diff = Some(std::collections::HashMap::from([(
+            "0x01".to_string(),
+            "0x02".to_string(),
+        )]));
+        assert!(bad_slot.validate().is_err());
+    }
+
+    #[test]
+    fn test_state_override_merge_diff_vs_replace() {
+        let mut base = AccountOverride::default();
+        base.state_diff = Some(std::collections::HashMap::from([(
+            "0x0000000000000000000000000000000000000000000000000000000000000001".to_string(),
+            "0x0000000000000000000000000000000000000000000000000000000000000011".to_string(),
+        )]));
+
+        let mut diff_update = AccountOverride::default();
+        diff_update.state_diff = Some(std::collections::HashMap::from([(
+            "0x0000000000000000000000000000000000000000000000000000000000000002".to_string(),
+            "0x0000000000000000000000000000000000000000000000000000000000000022".to_string(),
+        )]));
+        base.merge(&diff_update);
+        assert_eq!(base.state_diff.as_ref().unwrap().len(), 2);
+
+        let mut full_replace = AccountOverride::default();
+        full_replace.state_override = Some(std::collections::HashMap::from([(
+            "0x0000000000000000000000000000000000000000000000000000000000000003".to_string(),
+            "0x0000000000000000000000000000000000000000000000000000000000000033".to_string(),
+        )]));
+        base.merge(&full_replace);
+        assert!(base.state_diff.is_none());
+        assert_eq!(base.state_override.as_ref().unwrap().len(), 1);
+    }
 }
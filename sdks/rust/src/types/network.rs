@@ -1,7 +1,10 @@
 //! Network-related types and configurations
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::RwLock;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 /// Supported networks for HyperEVM
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -136,6 +139,99 @@ impl std::str::FromStr for BlockType {
     }
 }
 
+/// Protocol an [`EndpointSpec`] serves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointKind {
+    /// JSON-RPC over HTTP(S)
+    Rpc,
+    /// WebSocket streaming
+    Ws,
+    /// HyperCore cross-layer endpoint
+    HyperCore,
+}
+
+/// One endpoint in a weighted, multi-provider pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EndpointSpec {
+    /// Endpoint URL
+    pub url: String,
+    /// Relative preference among otherwise-equal endpoints; higher wins ties
+    pub weight: u32,
+    /// Protocol this endpoint serves
+    pub kind: EndpointKind,
+}
+
+impl EndpointSpec {
+    /// Create an endpoint spec with the default weight (1)
+    pub fn new(url: impl Into<String>, kind: EndpointKind) -> Self {
+        Self { url: url.into(), weight: 1, kind }
+    }
+
+    /// Set the endpoint's weight
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// Socket-level transport tuning applied to outbound connections
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransportConfig {
+    /// Attempt TCP Fast Open for outbound connections where the platform
+    /// supports it. The HTTP client this SDK builds on does not currently
+    /// expose a Fast Open toggle, so this is recorded for forward
+    /// compatibility rather than enforced today.
+    pub tcp_fast_open: bool,
+    /// Enable TCP keep-alive probing on outbound connections
+    pub keep_alive_enabled: bool,
+    /// Interval between keep-alive probes, in seconds
+    pub keep_alive_interval_secs: u64,
+    /// Number of keep-alive probes sent before the connection is considered dead
+    pub keep_alive_retries: u32,
+    /// Disable Nagle's algorithm (`TCP_NODELAY`) on outbound sockets
+    pub tcp_nodelay: bool,
+    /// Read `TCP_INFO` (rtt, retransmits) back into `ConnectionHealth`. Like
+    /// `tcp_fast_open`, there is no socket-level readback wired up yet; this
+    /// flag is reserved for that future integration.
+    pub expose_tcp_info: bool,
+}
+
+impl Default for TransportConfig {
+    fn default() -> Self {
+        Self {
+            tcp_fast_open: false,
+            keep_alive_enabled: true,
+            keep_alive_interval_secs: 30,
+            keep_alive_retries: 3,
+            tcp_nodelay: true,
+            expose_tcp_info: false,
+        }
+    }
+}
+
+impl TransportConfig {
+    /// Reject nonsensical combinations, e.g. a keep-alive interval of 0
+    /// while keep-alive is enabled
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if self.keep_alive_enabled && self.keep_alive_interval_secs == 0 {
+            return Err(crate::error::HyperSimError::configuration(
+                "Keep-alive interval must be greater than 0 when keep-alive is enabled",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `TCP_INFO` readback for a connection, when `TransportConfig::expose_tcp_info` is enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TcpInfo {
+    /// Smoothed round-trip time in milliseconds
+    pub rtt_ms: u64,
+    /// Number of retransmitted segments observed on the connection
+    pub retransmits: u32,
+}
+
 /// Network configuration for clients
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkConfig {
@@ -147,6 +243,11 @@ pub struct NetworkConfig {
     pub ws_endpoint: Option<String>,
     /// Custom HyperCore endpoint (overrides default)
     pub hypercore_endpoint: Option<String>,
+    /// Weighted pool of endpoints to route across with health-based
+    /// failover. Takes priority over the single `rpc_endpoint`/`ws_endpoint`/
+    /// `hypercore_endpoint` fields for their respective [`EndpointKind`]
+    /// when non-empty.
+    pub endpoints: Vec<EndpointSpec>,
     /// Request timeout in milliseconds
     pub timeout_ms: u64,
     /// Maximum concurrent connections
@@ -162,6 +263,7 @@ impl NetworkConfig {
             rpc_endpoint: None,
             ws_endpoint: None,
             hypercore_endpoint: None,
+            endpoints: Vec::new(),
             timeout_ms: 30000,
             max_connections: 10,
             connection_pooling: true,
@@ -188,6 +290,351 @@ impl NetworkConfig {
             .as_deref()
             .unwrap_or_else(|| self.network.hypercore_endpoint())
     }
+
+    /// Replace the weighted endpoint pool for `kind`, dropping any existing
+    /// entries of that kind
+    pub fn rpc_endpoints(mut self, kind: EndpointKind, endpoints: Vec<EndpointSpec>) -> Self {
+        self.endpoints.retain(|e| e.kind != kind);
+        self.endpoints.extend(endpoints);
+        self
+    }
+
+    /// Add a single endpoint to the pool
+    pub fn add_rpc_endpoint(mut self, endpoint: EndpointSpec) -> Self {
+        self.endpoints.push(endpoint);
+        self
+    }
+
+    /// The configured pool for `kind`, falling back to a single-endpoint pool
+    /// built from the legacy `rpc_endpoint`/`ws_endpoint`/`hypercore_endpoint`
+    /// fields when no pool entries of that kind are configured.
+    pub fn endpoint_pool(&self, kind: EndpointKind) -> Vec<EndpointSpec> {
+        let pool: Vec<EndpointSpec> = self.endpoints.iter().filter(|e| e.kind == kind).cloned().collect();
+        if !pool.is_empty() {
+            return pool;
+        }
+
+        let fallback_url = match kind {
+            EndpointKind::Rpc => self.rpc_endpoint(),
+            EndpointKind::Ws => self.ws_endpoint(),
+            EndpointKind::HyperCore => self.hypercore_endpoint(),
+        };
+        vec![EndpointSpec::new(fallback_url, kind)]
+    }
+}
+
+/// A pooled endpoint's circuit-breaker state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    /// Serving traffic normally
+    Closed,
+    /// Failing; rejected until the cooldown elapses
+    Open,
+    /// Cooldown elapsed; the next selection gets a single trial request
+    HalfOpen,
+}
+
+#[derive(Debug, Clone)]
+struct EndpointRecord {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    known_block_height: u64,
+    last_success_ms: Option<u64>,
+    tracked_since: Instant,
+    ewma_latency_ms: f64,
+    ewma_failure_rate: f64,
+    recent_latencies_ms: std::collections::VecDeque<u64>,
+}
+
+impl Default for EndpointRecord {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            opened_at: None,
+            known_block_height: 0,
+            last_success_ms: None,
+            tracked_since: Instant::now(),
+            ewma_latency_ms: 0.0,
+            ewma_failure_rate: 0.0,
+            recent_latencies_ms: std::collections::VecDeque::new(),
+        }
+    }
+}
+
+/// Number of consecutive failures before an endpoint's circuit opens
+const FAILURE_THRESHOLD: u32 = 5;
+/// Base cooldown before an open circuit allows a trial request again
+const BASE_COOLDOWN_MS: u64 = 1_000;
+/// Cap on the exponentially-backed-off cooldown
+const MAX_COOLDOWN_MS: u64 = 60_000;
+/// Default smoothing factor for the EWMA latency and failure rate trackers
+const DEFAULT_LATENCY_EWMA_ALPHA: f64 = 0.2;
+/// Number of recent latency samples kept per endpoint for percentile reads
+const LATENCY_SAMPLE_WINDOW: usize = 100;
+
+/// Block-sync staleness policy applied during selection: an endpoint that
+/// trails the pool's highest known block by more than `max_block_lag`, or
+/// whose last known height is older than `max_block_age_secs`, is excluded
+/// from selection the same way an open circuit is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StalenessGuard {
+    /// Maximum tolerated gap between an endpoint's known block height and
+    /// the highest height known across the pool
+    pub max_block_lag: u64,
+    /// Maximum tolerated age, in seconds, of an endpoint's last known height
+    pub max_block_age_secs: u64,
+}
+
+impl StalenessGuard {
+    /// Reject a guard that can never be satisfied, e.g. a zero max age
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if self.max_block_age_secs == 0 {
+            return Err(crate::error::HyperSimError::configuration(
+                "max_block_age_secs must be greater than 0 when the staleness guard is enabled",
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_endpoint_stale(&self, pool_highest: u64, record: &EndpointRecord) -> bool {
+        let lagging = pool_highest.saturating_sub(record.known_block_height) > self.max_block_lag;
+        let aged_out = record
+            .last_success_ms
+            .map(|ts| current_timestamp_ms().saturating_sub(ts) > self.max_block_age_secs.saturating_mul(1000))
+            .unwrap_or(false);
+        lagging || aged_out
+    }
+}
+
+/// Selects the best endpoint from a weighted pool with per-endpoint
+/// circuit-breaker health tracking.
+///
+/// Selection (1) drops endpoints whose circuit is open (and, when a
+/// [`StalenessGuard`] is configured, endpoints excluded for being too stale),
+/// (2) prefers the endpoint reporting the highest known block height (so
+/// stale nodes are deprioritized), and (3) breaks ties by descending weight.
+/// An open circuit half-opens for a single probe once its cooldown elapses;
+/// the probe's outcome either closes the circuit (on success) or reopens it
+/// with a longer, exponentially-backed-off cooldown (on failure).
+#[derive(Debug, Default)]
+pub struct EndpointSelector {
+    records: RwLock<HashMap<String, EndpointRecord>>,
+    staleness_guard: Option<StalenessGuard>,
+}
+
+impl EndpointSelector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable a block-sync staleness guard: endpoints excluded by it are
+    /// treated like an open circuit during selection
+    pub fn with_staleness_guard(mut self, guard: StalenessGuard) -> Self {
+        self.staleness_guard = Some(guard);
+        self
+    }
+
+    /// Pick the best currently-usable endpoint from `pool`, or `None` if
+    /// every endpoint's circuit is open or (when a staleness guard is
+    /// configured) every endpoint is too stale.
+    pub fn select<'a>(&self, pool: &'a [EndpointSpec]) -> Option<&'a EndpointSpec> {
+        let (usable, _) = self.usable_endpoints(pool);
+        let records = self.records.read().unwrap();
+        usable.into_iter().max_by(|a, b| {
+            let height_a = records.get(&a.url).map(|r| r.known_block_height).unwrap_or(0);
+            let height_b = records.get(&b.url).map(|r| r.known_block_height).unwrap_or(0);
+            height_a.cmp(&height_b).then(a.weight.cmp(&b.weight))
+        })
+    }
+
+    /// Like [`Self::select`], but distinguishes "every endpoint excluded for
+    /// being too stale" from the generic open-circuit case so callers can
+    /// surface a clearer error instead of silently simulating against
+    /// outdated chain state.
+    pub fn select_checked<'a>(&self, pool: &'a [EndpointSpec]) -> crate::error::Result<&'a EndpointSpec> {
+        let (usable, excluded_for_staleness) = self.usable_endpoints(pool);
+        let records = self.records.read().unwrap();
+        let chosen = usable.into_iter().max_by(|a, b| {
+            let height_a = records.get(&a.url).map(|r| r.known_block_height).unwrap_or(0);
+            let height_b = records.get(&b.url).map(|r| r.known_block_height).unwrap_or(0);
+            height_a.cmp(&height_b).then(a.weight.cmp(&b.weight))
+        });
+        drop(records);
+
+        chosen.ok_or_else(|| {
+            if excluded_for_staleness {
+                crate::error::HyperSimError::network(
+                    "Every endpoint was excluded: all are too stale relative to the known chain height",
+                )
+            } else {
+                crate::error::HyperSimError::network("Every endpoint was excluded: all circuits are open")
+            }
+        })
+    }
+
+    /// Endpoints from `pool` surviving the circuit-breaker filter and, when a
+    /// staleness guard is configured, the staleness filter. The returned
+    /// `bool` is whether the staleness filter excluded at least one endpoint
+    /// that was otherwise circuit-usable.
+    fn usable_endpoints<'a>(&self, pool: &'a [EndpointSpec]) -> (Vec<&'a EndpointSpec>, bool) {
+        let circuit_usable: Vec<&'a EndpointSpec> = {
+            let mut records = self.records.write().unwrap();
+            pool.iter().filter(|endpoint| Self::is_usable(&mut records, &endpoint.url)).collect()
+        };
+
+        let Some(guard) = &self.staleness_guard else {
+            return (circuit_usable, false);
+        };
+
+        let records = self.records.read().unwrap();
+        let pool_highest = circuit_usable
+            .iter()
+            .filter_map(|endpoint| records.get(&endpoint.url).map(|r| r.known_block_height))
+            .max()
+            .unwrap_or(0);
+
+        let fresh: Vec<&'a EndpointSpec> = circuit_usable
+            .iter()
+            .copied()
+            .filter(|endpoint| match records.get(&endpoint.url) {
+                Some(record) => !guard.is_endpoint_stale(pool_highest, record),
+                None => true,
+            })
+            .collect();
+
+        let excluded_for_staleness = fresh.len() < circuit_usable.len();
+        (fresh, excluded_for_staleness)
+    }
+
+    /// Whether `url`'s circuit currently allows a request, transitioning an
+    /// expired `Open` circuit to `HalfOpen` as a side effect.
+    fn is_usable(records: &mut HashMap<String, EndpointRecord>, url: &str) -> bool {
+        let record = records.entry(url.to_string()).or_default();
+        match record.state {
+            CircuitState::Closed | CircuitState::HalfOpen => true,
+            CircuitState::Open => {
+                let cooldown = cooldown_for(record.consecutive_failures);
+                if record.opened_at.map(|at| at.elapsed().as_millis() as u64 >= cooldown).unwrap_or(true) {
+                    record.state = CircuitState::HalfOpen;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Record a successful response from `url`, closing its circuit and
+    /// updating the block height used to break selection ties.
+    pub fn record_success(&self, url: &str, known_block_height: u64) {
+        let mut records = self.records.write().unwrap();
+        let record = records.entry(url.to_string()).or_default();
+        record.state = CircuitState::Closed;
+        record.consecutive_failures = 0;
+        record.opened_at = None;
+        record.known_block_height = record.known_block_height.max(known_block_height);
+        record.last_success_ms = Some(current_timestamp_ms());
+        record.ewma_failure_rate = ewma(record.ewma_failure_rate, 0.0, DEFAULT_LATENCY_EWMA_ALPHA);
+    }
+
+    /// Record a failed request against `url`, opening its circuit once
+    /// `FAILURE_THRESHOLD` consecutive failures accumulate.
+    pub fn record_failure(&self, url: &str) {
+        let mut records = self.records.write().unwrap();
+        let record = records.entry(url.to_string()).or_default();
+        record.consecutive_failures += 1;
+        record.ewma_failure_rate = ewma(record.ewma_failure_rate, 1.0, DEFAULT_LATENCY_EWMA_ALPHA);
+        if record.consecutive_failures >= FAILURE_THRESHOLD {
+            record.state = CircuitState::Open;
+            record.opened_at = Some(Instant::now());
+        }
+    }
+
+    /// Record a completed request's latency against `url`, updating its EWMA
+    /// latency and the ring buffer used to read back p50/p99 samples.
+    pub fn record_latency(&self, url: &str, sample_ms: u64) {
+        let mut records = self.records.write().unwrap();
+        let record = records.entry(url.to_string()).or_default();
+        record.ewma_latency_ms = ewma(record.ewma_latency_ms, sample_ms as f64, DEFAULT_LATENCY_EWMA_ALPHA);
+        record.recent_latencies_ms.push_back(sample_ms);
+        if record.recent_latencies_ms.len() > LATENCY_SAMPLE_WINDOW {
+            record.recent_latencies_ms.pop_front();
+        }
+    }
+
+    /// Rank the currently-usable endpoints in `pool` from best to worst by
+    /// `score = ewma_latency_ms * (1 + ewma_failure_rate)`, i.e. the fastest
+    /// endpoint with the fewest recent failures sorts first.
+    pub fn rank<'a>(&self, pool: &'a [EndpointSpec]) -> Vec<&'a EndpointSpec> {
+        let (usable, _) = self.usable_endpoints(pool);
+        let records = self.records.read().unwrap();
+        let mut scored: Vec<(&EndpointSpec, f64)> = usable
+            .into_iter()
+            .map(|endpoint| {
+                let score = records
+                    .get(&endpoint.url)
+                    .map(|r| r.ewma_latency_ms * (1.0 + r.ewma_failure_rate))
+                    .unwrap_or(0.0);
+                (endpoint, score)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.into_iter().map(|(endpoint, _)| endpoint).collect()
+    }
+
+    /// Read back a latency percentile (e.g. `0.5` for p50, `0.99` for p99)
+    /// from `url`'s recent sample window, or `None` if no samples exist yet.
+    pub fn latency_percentile(&self, url: &str, percentile: f64) -> Option<u64> {
+        let records = self.records.read().unwrap();
+        let record = records.get(url)?;
+        if record.recent_latencies_ms.is_empty() {
+            return None;
+        }
+
+        let mut samples: Vec<u64> = record.recent_latencies_ms.iter().copied().collect();
+        samples.sort_unstable();
+        let index = ((samples.len() - 1) as f64 * percentile.clamp(0.0, 1.0)).round() as usize;
+        samples.get(index).copied()
+    }
+
+    /// Current health snapshot for `url`, or `None` if it hasn't been used yet.
+    pub fn connection_health(&self, url: &str) -> Option<ConnectionHealth> {
+        let records = self.records.read().unwrap();
+        let record = records.get(url)?;
+
+        Some(ConnectionHealth {
+            connected: record.state != CircuitState::Open,
+            latency_ms: None,
+            block_sync_status: BlockSyncStatus {
+                current_block: record.known_block_height,
+                highest_block: record.known_block_height,
+                starting_block: 0,
+                syncing: false,
+                current_block_timestamp_ms: record.last_success_ms,
+            },
+            last_success: record.last_success_ms,
+            uptime_ms: record.tracked_since.elapsed().as_millis() as u64,
+            tcp_info: None,
+            reconnect_resubscriptions: 0,
+        })
+    }
+}
+
+fn ewma(previous: f64, sample: f64, alpha: f64) -> f64 {
+    alpha * sample + (1.0 - alpha) * previous
+}
+
+fn cooldown_for(consecutive_failures: u32) -> u64 {
+    let exponent = consecutive_failures.saturating_sub(FAILURE_THRESHOLD);
+    let backed_off = BASE_COOLDOWN_MS.saturating_mul(1u64 << exponent.min(10));
+    backed_off.min(MAX_COOLDOWN_MS)
+}
+
+fn current_timestamp_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
 }
 
 /// Gas price information
@@ -246,6 +693,12 @@ pub struct ConnectionHealth {
     pub last_success: Option<u64>,
     /// Connection uptime in milliseconds
     pub uptime_ms: u64,
+    /// `TCP_INFO` readback, present only when `TransportConfig::expose_tcp_info`
+    /// is enabled and a socket-level reading is available
+    pub tcp_info: Option<TcpInfo>,
+    /// Number of WebSocket subscriptions the reconnection coordinator has
+    /// had to replay after a reconnect, since the SDK was created
+    pub reconnect_resubscriptions: u64,
 }
 
 /// Block synchronization status
@@ -259,6 +712,33 @@ pub struct BlockSyncStatus {
     pub starting_block: u64,
     /// Whether currently syncing
     pub syncing: bool,
+    /// Unix timestamp (milliseconds) at which `current_block` was last
+    /// observed, or `None` if it hasn't been observed yet
+    pub current_block_timestamp_ms: Option<u64>,
+}
+
+impl BlockSyncStatus {
+    /// Whether this status is "too stale" to trust under the given policy:
+    /// either it trails the reported `highest_block` by more than
+    /// `max_block_lag`, or `current_block_timestamp_ms` is older than
+    /// `max_block_age_secs` relative to the current wall-clock time. A
+    /// missing timestamp is never judged stale on age alone.
+    pub fn is_stale(&self, max_block_lag: u64, max_block_age_secs: u64) -> bool {
+        let lagging = self.highest_block.saturating_sub(self.current_block) > max_block_lag;
+        let aged_out = self
+            .current_block_timestamp_ms
+            .map(|ts| current_timestamp_ms().saturating_sub(ts) > max_block_age_secs.saturating_mul(1000))
+            .unwrap_or(false);
+        lagging || aged_out
+    }
+}
+
+impl ConnectionHealth {
+    /// Whether this connection's block-sync status is too stale to trust
+    /// under the given policy. See [`BlockSyncStatus::is_stale`].
+    pub fn is_stale(&self, max_block_lag: u64, max_block_age_secs: u64) -> bool {
+        self.block_sync_status.is_stale(max_block_lag, max_block_age_secs)
+    }
 }
 
 #[cfg(test)]
@@ -299,4 +779,255 @@ mod tests {
         assert_eq!(config.ws_endpoint(), "wss://mainnet-ws.hyperevm.com");
         assert_eq!(config.hypercore_endpoint(), "https://hypercore-mainnet.hyperevm.com");
     }
+
+    #[test]
+    fn test_transport_config_defaults_are_sane() {
+        let transport = TransportConfig::default();
+        assert!(transport.validate().is_ok());
+        assert!(transport.tcp_nodelay);
+        assert!(!transport.tcp_fast_open);
+    }
+
+    #[test]
+    fn test_transport_config_rejects_zero_keep_alive_interval() {
+        let transport = TransportConfig {
+            keep_alive_enabled: true,
+            keep_alive_interval_secs: 0,
+            ..Default::default()
+        };
+        assert!(transport.validate().is_err());
+    }
+
+    #[test]
+    fn test_endpoint_pool_falls_back_to_legacy_field() {
+        let config = NetworkConfig::new(Network::Local);
+        let pool = config.endpoint_pool(EndpointKind::Rpc);
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool[0].url, "http://localhost:8545");
+    }
+
+    #[test]
+    fn test_rpc_endpoints_replaces_only_matching_kind() {
+        let config = NetworkConfig::new(Network::Local)
+            .rpc_endpoints(
+                EndpointKind::Rpc,
+                vec![EndpointSpec::new("http://a", EndpointKind::Rpc)],
+            )
+            .add_rpc_endpoint(EndpointSpec::new("ws://b", EndpointKind::Ws));
+
+        assert_eq!(config.endpoint_pool(EndpointKind::Rpc).len(), 1);
+        assert_eq!(config.endpoint_pool(EndpointKind::Ws).len(), 1);
+    }
+
+    #[test]
+    fn test_selector_prefers_highest_block_height() {
+        let selector = EndpointSelector::new();
+        let pool = vec![
+            EndpointSpec::new("http://stale", EndpointKind::Rpc),
+            EndpointSpec::new("http://fresh", EndpointKind::Rpc),
+        ];
+        selector.record_success("http://stale", 100);
+        selector.record_success("http://fresh", 200);
+
+        let chosen = selector.select(&pool).unwrap();
+        assert_eq!(chosen.url, "http://fresh");
+    }
+
+    #[test]
+    fn test_selector_breaks_ties_by_descending_weight() {
+        let selector = EndpointSelector::new();
+        let pool = vec![
+            EndpointSpec::new("http://low", EndpointKind::Rpc).with_weight(1),
+            EndpointSpec::new("http://high", EndpointKind::Rpc).with_weight(10),
+        ];
+        selector.record_success("http://low", 100);
+        selector.record_success("http://high", 100);
+
+        let chosen = selector.select(&pool).unwrap();
+        assert_eq!(chosen.url, "http://high");
+    }
+
+    #[test]
+    fn test_selector_opens_circuit_after_threshold_failures() {
+        let selector = EndpointSelector::new();
+        let pool = vec![
+            EndpointSpec::new("http://flaky", EndpointKind::Rpc),
+            EndpointSpec::new("http://stable", EndpointKind::Rpc),
+        ];
+        selector.record_success("http://stable", 100);
+
+        for _ in 0..FAILURE_THRESHOLD {
+            selector.record_failure("http://flaky");
+        }
+
+        let chosen = selector.select(&pool).unwrap();
+        assert_eq!(chosen.url, "http://stable");
+    }
+
+    #[test]
+    fn test_selector_returns_none_when_every_endpoint_open() {
+        let selector = EndpointSelector::new();
+        let pool = vec![EndpointSpec::new("http://only", EndpointKind::Rpc)];
+
+        for _ in 0..FAILURE_THRESHOLD {
+            selector.record_failure("http://only");
+        }
+
+        assert!(selector.select(&pool).is_none());
+    }
+
+    #[test]
+    fn test_rank_prefers_lower_latency_and_failure_rate() {
+        let selector = EndpointSelector::new();
+        let pool = vec![
+            EndpointSpec::new("http://slow", EndpointKind::Rpc),
+            EndpointSpec::new("http://fast", EndpointKind::Rpc),
+        ];
+
+        for _ in 0..10 {
+            selector.record_latency("http://slow", 500);
+            selector.record_latency("http://fast", 50);
+            selector.record_success("http://slow", 1);
+            selector.record_success("http://fast", 1);
+        }
+
+        let ranked = selector.rank(&pool);
+        assert_eq!(ranked[0].url, "http://fast");
+        assert_eq!(ranked[1].url, "http://slow");
+    }
+
+    #[test]
+    fn test_rank_penalizes_recent_failures() {
+        let selector = EndpointSelector::new();
+        let pool = vec![
+            EndpointSpec::new("http://flaky", EndpointKind::Rpc),
+            EndpointSpec::new("http://reliable", EndpointKind::Rpc),
+        ];
+
+        for _ in 0..10 {
+            selector.record_latency("http://flaky", 50);
+            selector.record_latency("http://reliable", 50);
+        }
+        for _ in 0..4 {
+            selector.record_failure("http://flaky");
+        }
+        selector.record_success("http://reliable", 1);
+
+        let ranked = selector.rank(&pool);
+        assert_eq!(ranked[0].url, "http://reliable");
+        assert_eq!(ranked[1].url, "http://flaky");
+    }
+
+    #[test]
+    fn test_latency_percentile_reads_back_samples() {
+        let selector = EndpointSelector::new();
+        for sample in 1..=100u64 {
+            selector.record_latency("http://node", sample);
+        }
+
+        assert_eq!(selector.latency_percentile("http://node", 0.5), Some(51));
+        assert_eq!(selector.latency_percentile("http://node", 0.99), Some(99));
+        assert!(selector.latency_percentile("http://unknown", 0.5).is_none());
+    }
+
+    #[test]
+    fn test_connection_health_reflects_circuit_state() {
+        let selector = EndpointSelector::new();
+        assert!(selector.connection_health("http://unknown").is_none());
+
+        selector.record_success("http://tracked", 42);
+        let health = selector.connection_health("http://tracked").unwrap();
+        assert!(health.connected);
+        assert_eq!(health.block_sync_status.current_block, 42);
+    }
+
+    #[test]
+    fn test_block_sync_status_is_stale_by_lag() {
+        let status = BlockSyncStatus {
+            current_block: 90,
+            highest_block: 100,
+            starting_block: 0,
+            syncing: false,
+            current_block_timestamp_ms: None,
+        };
+        assert!(!status.is_stale(20, 60));
+        assert!(status.is_stale(5, 60));
+    }
+
+    #[test]
+    fn test_block_sync_status_is_stale_by_age() {
+        let status = BlockSyncStatus {
+            current_block: 100,
+            highest_block: 100,
+            starting_block: 0,
+            syncing: false,
+            current_block_timestamp_ms: Some(current_timestamp_ms() - 120_000),
+        };
+        assert!(!status.is_stale(20, 300));
+        assert!(status.is_stale(20, 60));
+    }
+
+    #[test]
+    fn test_block_sync_status_not_stale_without_timestamp() {
+        let status = BlockSyncStatus {
+            current_block: 100,
+            highest_block: 100,
+            starting_block: 0,
+            syncing: false,
+            current_block_timestamp_ms: None,
+        };
+        assert!(!status.is_stale(0, 60));
+    }
+
+    #[test]
+    fn test_staleness_guard_rejects_zero_max_age() {
+        let guard = StalenessGuard { max_block_lag: 10, max_block_age_secs: 0 };
+        assert!(guard.validate().is_err());
+
+        let guard = StalenessGuard { max_block_lag: 10, max_block_age_secs: 60 };
+        assert!(guard.validate().is_ok());
+    }
+
+    #[test]
+    fn test_selector_excludes_stale_endpoints() {
+        let selector = EndpointSelector::new().with_staleness_guard(StalenessGuard {
+            max_block_lag: 5,
+            max_block_age_secs: 3600,
+        });
+        let pool = vec![
+            EndpointSpec::new("http://lagging", EndpointKind::Rpc),
+            EndpointSpec::new("http://current", EndpointKind::Rpc),
+        ];
+        selector.record_success("http://lagging", 90);
+        selector.record_success("http://current", 100);
+
+        let chosen = selector.select(&pool).unwrap();
+        assert_eq!(chosen.url, "http://current");
+    }
+
+    #[test]
+    fn test_select_checked_reports_staleness_specifically() {
+        let selector = EndpointSelector::new().with_staleness_guard(StalenessGuard {
+            max_block_lag: 1000,
+            max_block_age_secs: 1,
+        });
+        let pool = vec![EndpointSpec::new("http://aging", EndpointKind::Rpc)];
+        selector.record_success("http://aging", 100);
+        std::thread::sleep(std::time::Duration::from_millis(1050));
+
+        let error = selector.select_checked(&pool).unwrap_err();
+        assert!(error.to_string().contains("too stale"));
+    }
+
+    #[test]
+    fn test_select_checked_reports_open_circuit_when_not_stale() {
+        let selector = EndpointSelector::new();
+        let pool = vec![EndpointSpec::new("http://only", EndpointKind::Rpc)];
+        for _ in 0..FAILURE_THRESHOLD {
+            selector.record_failure("http://only");
+        }
+
+        let error = selector.select_checked(&pool).unwrap_err();
+        assert!(error.to_string().contains("circuits are open"));
+    }
 }
@@ -25,10 +25,25 @@ pub struct TransactionRequest {
     pub max_priority_fee_per_gas: Option<Wei>,
     /// Transaction nonce
     pub nonce: Option<u64>,
-    /// Transaction type (0=legacy, 1=EIP-2930, 2=EIP-1559)
+    /// EIP-2718 transaction type byte (0=legacy, 1=EIP-2930 access-list, 2=EIP-1559 dynamic-fee, 3=EIP-4844 blob-carrying)
     pub tx_type: Option<u8>,
     /// Chain ID
     pub chain_id: Option<u64>,
+    /// EIP-2930 access list: addresses and the storage keys touched within them
+    pub access_list: Option<Vec<AccessListEntry>>,
+    /// EIP-4844 number of blobs carried by this transaction (type 3 only)
+    pub blob_count: Option<u32>,
+    /// EIP-4844 blob base fee (gas price of the blob fee market, separate from `base_fee_per_gas`)
+    pub blob_base_fee: Option<Wei>,
+}
+
+/// Single entry of an EIP-2930 access list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessListEntry {
+    /// Address whose access is pre-declared
+    pub address: Address,
+    /// Storage slots within `address` that will be touched
+    pub storage_keys: Vec<Hash>,
 }
 
 /// Builder pattern for TransactionRequest
@@ -51,17 +66,22 @@ impl TransactionRequestBuilder {
                 nonce: None,
                 tx_type: None,
                 chain_id: None,
+                access_list: None,
+                blob_count: None,
+                blob_base_fee: None,
             },
         }
     }
 
     pub fn from(mut self, from: impl Into<String>) -> crate::error::Result<Self> {
-        self.request.from = Address::new(from)?;
+        let address = Address::new(from)?;
+        self.request.from = Address(address.checksum());
         Ok(self)
     }
 
     pub fn to(mut self, to: impl Into<String>) -> crate::error::Result<Self> {
-        self.request.to = Some(Address::new(to)?);
+        let address = Address::new(to)?;
+        self.request.to = Some(Address(address.checksum()));
         Ok(self)
     }
 
@@ -110,6 +130,33 @@ impl TransactionRequestBuilder {
         self
     }
 
+    /// Attach an EIP-2930 access list, implicitly requiring `tx_type` to be 1 or 2
+    pub fn access_list(mut self, access_list: Vec<AccessListEntry>) -> Self {
+        self.request.access_list = Some(access_list);
+        self
+    }
+
+    /// Append a single `(address, storage_keys)` entry to the access list,
+    /// creating it if this is the first entry
+    pub fn add_access_list_entry(mut self, address: impl Into<String>, storage_keys: Vec<String>) -> crate::error::Result<Self> {
+        let entry = AccessListEntry {
+            address: Address::new(address)?,
+            storage_keys: storage_keys
+                .into_iter()
+                .map(Hash::new)
+                .collect::<crate::error::Result<Vec<_>>>()?,
+        };
+        self.request.access_list.get_or_insert_with(Vec::new).push(entry);
+        Ok(self)
+    }
+
+    /// Attach EIP-4844 blobs, implicitly requiring `tx_type` to be 3
+    pub fn blobs(mut self, blob_count: u32, blob_base_fee: impl Into<String>) -> Self {
+        self.request.blob_count = Some(blob_count);
+        self.request.blob_base_fee = Some(Wei::new(blob_base_fee));
+        self
+    }
+
     pub fn build(self) -> crate::error::Result<TransactionRequest> {
         // Basic validation
         if self.request.from.as_str() == "0x0000000000000000000000000000000000000000" {
@@ -152,6 +199,31 @@ pub struct SimulationResult {
     pub events: Vec<SimulationEvent>,
     /// Transaction hash (if applicable)
     pub tx_hash: Option<Hash>,
+    /// Trustless verification status of the touched account/storage state.
+    /// `Unverified` unless `HyperSimConfig::trustless` is enabled.
+    #[serde(default)]
+    pub verification: crate::verification::VerificationStatus,
+    /// EIP-1559 base fee of the block the transaction was simulated against
+    pub base_fee_per_gas: Option<Wei>,
+    /// What the transaction would actually pay per unit of gas
+    pub effective_gas_price: Option<Wei>,
+    /// `base_fee_per_gas * gas_used`, the portion of the fee that is burned rather than paid to the block builder
+    pub burned_fee: Option<Wei>,
+    /// Gas limit of the simulated transaction, if one was supplied
+    pub gas_limit: Option<String>,
+    /// Fee cap (EIP-1559 `max_fee_per_gas`) of the simulated transaction, if one was supplied
+    pub max_fee_per_gas: Option<Wei>,
+    /// Priority fee cap (EIP-1559 `max_priority_fee_per_gas`) of the simulated transaction, if one was supplied
+    pub max_priority_fee_per_gas: Option<Wei>,
+    /// EIP-4844 number of blobs carried by the simulated transaction, if any
+    pub blob_count: Option<u32>,
+    /// EIP-4844 blob base fee of the block the transaction was simulated against, if applicable
+    pub blob_base_fee: Option<Wei>,
+    /// Size in bytes of the simulated transaction's calldata
+    pub calldata_size: Option<u64>,
+    /// EIP-2718 transaction type byte of the simulated transaction, if one was supplied
+    /// (0=legacy, 1=EIP-2930 access-list, 2=EIP-1559 dynamic-fee, 3=EIP-4844 blob-carrying)
+    pub tx_type: Option<u8>,
 }
 
 /// Execution trace for debugging
@@ -163,6 +235,57 @@ pub struct ExecutionTrace {
     pub gas_breakdown: GasBreakdown,
     /// Storage accesses
     pub storage_accesses: Vec<StorageAccess>,
+    /// Flat opcode-level steps, populated by
+    /// [`HyperEVMClient::simulate_with_trace`](crate::clients::HyperEVMClient::simulate_with_trace)
+    /// unless [`TraceConfig::call_tree_only`] was set
+    #[serde(default)]
+    pub opcode_steps: Vec<OpcodeStep>,
+}
+
+/// Configuration for the opcode-level trace requested via
+/// [`HyperEVMClient::simulate_with_trace`](crate::clients::HyperEVMClient::simulate_with_trace)
+#[derive(Debug, Clone)]
+pub struct TraceConfig {
+    /// Capture the EVM stack at each opcode step
+    pub capture_stack: bool,
+    /// Capture a memory snapshot at each opcode step
+    pub capture_memory: bool,
+    /// Capture the storage slots touched at each opcode step
+    pub capture_storage: bool,
+    /// Request only the top-level call tree (`ExecutionTrace::calls`), skipping flat opcode steps entirely
+    pub call_tree_only: bool,
+}
+
+impl Default for TraceConfig {
+    fn default() -> Self {
+        Self {
+            capture_stack: true,
+            capture_memory: true,
+            capture_storage: true,
+            call_tree_only: false,
+        }
+    }
+}
+
+/// A single opcode-level execution step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpcodeStep {
+    /// Program counter
+    pub pc: u64,
+    /// Opcode mnemonic (e.g. `"SLOAD"`, `"PUSH1"`)
+    pub op: String,
+    /// Gas remaining before this step executed
+    pub gas: String,
+    /// Gas consumed by this step
+    pub gas_cost: String,
+    /// Call stack depth this step executed at
+    pub depth: u32,
+    /// EVM stack contents, top-first, if [`TraceConfig::capture_stack`] was set
+    pub stack: Option<Vec<String>>,
+    /// Memory snapshot (hex string), if [`TraceConfig::capture_memory`] was set
+    pub memory: Option<String>,
+    /// Storage slots touched by this step (slot -> value), if [`TraceConfig::capture_storage`] was set
+    pub storage: Option<HashMap<String, String>>,
 }
 
 /// Individual call in execution trace
@@ -195,8 +318,10 @@ pub struct GasBreakdown {
     pub intrinsic: String,
     /// Gas used by execution
     pub execution: String,
-    /// Gas used for storage
-    pub storage: String,
+    /// EIP-2929 cold access surcharges (first touch of an address or storage slot)
+    pub cold_access: String,
+    /// EIP-2929 warm access cost (every touch after the first)
+    pub warm_access: String,
     /// Gas refunded
     pub refund: String,
     /// Total gas used
@@ -216,6 +341,10 @@ pub struct StorageAccess {
     pub original_value: Option<String>,
     /// New value (for writes)
     pub new_value: Option<String>,
+    /// Whether this was the first (EIP-2929 cold) touch of `(address, slot)`
+    pub cold: bool,
+    /// Gas charged for this specific access (`COLD_SLOAD_COST` or `WARM_STORAGE_READ_COST`)
+    pub gas_cost: String,
 }
 
 /// Storage access type
@@ -351,13 +480,33 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(tx.from.as_str(), "0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1");
-        assert_eq!(tx.to.unwrap().as_str(), "0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C1234");
+        // Builder normalizes to EIP-55 checksummed form regardless of input case
+        assert_eq!(tx.from.as_str(), "0x742d35CC6563C7dE26D1e0D7Ad8e8c61c94c7de1");
+        assert_eq!(tx.to.unwrap().as_str(), "0xa0B86a33E6427e8fc8E0b3b1E5C6B6E4F7a8c1234");
         assert_eq!(tx.value.unwrap().as_str(), "1000000000000000000");
         assert_eq!(tx.gas_limit.unwrap(), "21000");
         assert_eq!(tx.nonce.unwrap(), 42);
     }
 
+    #[test]
+    fn test_add_access_list_entry() {
+        let tx = TransactionRequest::builder()
+            .from("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1")
+            .unwrap()
+            .tx_type(1)
+            .add_access_list_entry(
+                "0xA0b86a33E6427e8Fc8e0B3b1e5C6b6e4f7A8C123",
+                vec!["0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string()],
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let access_list = tx.access_list.unwrap();
+        assert_eq!(access_list.len(), 1);
+        assert_eq!(access_list[0].storage_keys.len(), 1);
+    }
+
     #[test]
     fn test_invalid_transaction_request() {
         let result = TransactionRequest::builder().build();
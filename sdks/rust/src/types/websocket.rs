@@ -3,6 +3,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use crate::types::{Address, Hash, Network};
+use crate::types::hyperevm::HyperEVMBlock;
+use crate::types::network::BlockType;
 
 /// WebSocket connection state
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -48,6 +50,12 @@ pub struct WebSocketClientConfig {
     pub compression: bool,
     /// Additional headers for connection
     pub headers: HashMap<String, String>,
+    /// Capacity of each subscription's event queue, in buffered
+    /// notifications. Once a slow consumer lets the queue fill to this
+    /// depth, incoming notifications are dropped (and counted) rather than
+    /// blocking delivery to every other subscription or growing memory
+    /// without bound
+    pub queue_capacity_items: usize,
 }
 
 impl WebSocketClientConfig {
@@ -63,6 +71,7 @@ impl WebSocketClientConfig {
             buffer_size: 1024 * 1024, // 1MB
             compression: true,
             headers: HashMap::new(),
+            queue_capacity_items: 1024,
         }
     }
 
@@ -100,6 +109,10 @@ pub enum SubscriptionType {
     SimulationResults,
     GasPrices,
     NetworkStatus,
+    /// Per-block base fee and priority-fee percentiles over a sliding
+    /// window of the last N blocks (N from [`SubscriptionParams::limit`]),
+    /// following the fee-history model used by execution clients
+    FeeHistory,
 }
 
 /// Parameters for WebSocket subscriptions
@@ -135,6 +148,127 @@ pub struct BlockRange {
     pub to_block: Option<u64>,
 }
 
+/// Comparison operator for a [`Condition`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionOp {
+    Eq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Contains,
+    Exists,
+}
+
+/// A single typed condition in a [`SubscriptionQuery`], e.g.
+/// `Condition::new("address", ConditionOp::Eq, json!("0x.."))`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Condition {
+    /// The notification field this condition checks, e.g. `"address"` or `"value"`
+    pub key: String,
+    pub op: ConditionOp,
+    /// The value `key` is compared against
+    pub operand: serde_json::Value,
+}
+
+impl Condition {
+    pub fn new(key: impl Into<String>, op: ConditionOp, operand: impl Into<serde_json::Value>) -> Self {
+        Self { key: key.into(), op, operand: operand.into() }
+    }
+
+    /// Whether `value` (the field named by `self.key`, pulled off a
+    /// notification payload) satisfies this condition
+    pub fn matches(&self, value: Option<&serde_json::Value>) -> bool {
+        if self.op == ConditionOp::Exists {
+            return value.is_some();
+        }
+
+        let Some(value) = value else { return false };
+
+        match self.op {
+            ConditionOp::Eq => value == &self.operand,
+            ConditionOp::Gt | ConditionOp::Gte | ConditionOp::Lt | ConditionOp::Lte => {
+                let (Some(a), Some(b)) = (value.as_f64(), self.operand.as_f64()) else { return false };
+                match self.op {
+                    ConditionOp::Gt => a > b,
+                    ConditionOp::Gte => a >= b,
+                    ConditionOp::Lt => a < b,
+                    ConditionOp::Lte => a <= b,
+                    _ => unreachable!(),
+                }
+            }
+            ConditionOp::Contains => match value {
+                serde_json::Value::Array(items) => items.contains(&self.operand),
+                serde_json::Value::String(s) => {
+                    self.operand.as_str().map(|needle| s.contains(needle)).unwrap_or(false)
+                }
+                _ => false,
+            },
+            ConditionOp::Exists => unreachable!(),
+        }
+    }
+}
+
+/// A composable, validated subscription filter, inspired by tendermint-rs's
+/// event subscription `Query`/`Condition` model. Conditions are combined
+/// with AND semantics: a payload matches only if every condition matches.
+///
+/// ```rust,no_run
+/// use hypersim_sdk::types::{SubscriptionQuery, Condition, ConditionOp};
+///
+/// let query = SubscriptionQuery::new()
+///     .and(Condition::new("address", ConditionOp::Eq, "0xabc".to_string()))
+///     .and(Condition::new("block_number", ConditionOp::Gte, 100));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SubscriptionQuery {
+    conditions: Vec<Condition>,
+}
+
+impl SubscriptionQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a condition to the query; all conditions must match (AND semantics)
+    pub fn and(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn conditions(&self) -> &[Condition] {
+        &self.conditions
+    }
+
+    /// Whether every condition in the query matches the given payload
+    pub fn matches(&self, payload: &serde_json::Value) -> bool {
+        self.conditions.iter().all(|condition| condition.matches(payload.get(&condition.key)))
+    }
+
+    /// Build the `{address, topics}` filter object for `eth_subscribe("logs", ..)`
+    /// from this query's `address`/`topics` conditions. Conditions on any
+    /// other key aren't representable in the server-side filter and are only
+    /// enforced client-side via [`SubscriptionQuery::matches`].
+    pub fn to_logs_filter(&self) -> serde_json::Value {
+        let mut filter = serde_json::Map::new();
+
+        for condition in &self.conditions {
+            match (condition.key.as_str(), condition.op) {
+                ("address", ConditionOp::Eq) => {
+                    filter.insert("address".to_string(), condition.operand.clone());
+                }
+                ("topics", ConditionOp::Eq | ConditionOp::Contains) => {
+                    filter.insert("topics".to_string(), condition.operand.clone());
+                }
+                _ => {}
+            }
+        }
+
+        serde_json::Value::Object(filter)
+    }
+}
+
 /// WebSocket message structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WSMessage {
@@ -188,6 +322,36 @@ pub struct NewBlockHeader {
     pub transaction_count: u32,
 }
 
+impl From<NewBlockHeader> for HyperEVMBlock {
+    /// A streamed header notification carries less than a full
+    /// `eth_getBlockByNumber` reply (no state/transactions/receipts roots,
+    /// bloom filter, or transaction hashes) — those fields are filled with
+    /// placeholders, matching the rest of this field set's every other
+    /// mocked/unavailable value. Block type defaults to `Fast` since the
+    /// wire payload doesn't distinguish fast vs. secure blocks.
+    fn from(header: NewBlockHeader) -> Self {
+        HyperEVMBlock {
+            hash: header.hash,
+            parent_hash: header.parent_hash,
+            number: header.number,
+            timestamp: header.timestamp,
+            block_type: BlockType::Fast,
+            gas_limit: header.gas_limit,
+            gas_used: header.gas_used,
+            difficulty: header.difficulty,
+            miner: header.miner,
+            extra_data: header.extra_data,
+            state_root: Hash(String::new()),
+            transactions_root: Hash(String::new()),
+            receipts_root: Hash(String::new()),
+            logs_bloom: String::new(),
+            transaction_hashes: Vec::new(),
+            uncles: Vec::new(),
+            base_fee_per_gas: None,
+        }
+    }
+}
+
 /// New transaction notification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewTransaction {
@@ -273,6 +437,30 @@ pub enum PriceTrend {
     Stable,
 }
 
+/// Fee-history notification for a [`SubscriptionType::FeeHistory`]
+/// subscription. The wire payload for each raw notification describes a
+/// single new block; the client accumulates these into the windowed
+/// `base_fee_per_gas`/`gas_used_ratio` arrays and the derived `trend` before
+/// handing the event to the caller, mirroring the `eth_feeHistory` RPC shape
+/// so clients can do their own fee estimation from the raw arrays.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeHistoryNotification {
+    /// Network identifier
+    pub network: Network,
+    /// Base fee per gas (wei), oldest block first
+    pub base_fee_per_gas: Vec<String>,
+    /// Gas-used / gas-limit ratio, oldest block first, aligned with
+    /// `base_fee_per_gas`
+    pub gas_used_ratio: Vec<f64>,
+    /// Priority-fee percentiles (wei) computed over the window, keyed by
+    /// percentile (e.g. `"10"`, `"50"`, `"90"`)
+    pub priority_fee_percentiles: HashMap<String, String>,
+    /// Trend of the base fee versus its recent moving average
+    pub trend: PriceTrend,
+    /// Timestamp of the latest block in the window
+    pub timestamp: u64,
+}
+
 /// Network status update notification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkStatusNotification {
@@ -295,12 +483,21 @@ pub enum WSEvent {
     Error { error: WSError },
     Subscribed { subscription: WSSubscription },
     Unsubscribed { subscription_id: String },
+    /// A subscription was automatically re-established with the server
+    /// after a reconnect; `id` is the caller's original stable handle, which
+    /// does not change even though the server assigned a new subscription ID
+    Resubscribed { id: String },
     NewBlock { header: NewBlockHeader },
     NewTransaction { transaction: NewTransaction },
     Log { log: LogNotification },
     SimulationResult { notification: SimulationNotification },
     GasPriceUpdate { notification: GasPriceNotification },
+    /// Slow/standard/fast gas price estimate (in wei) computed from the
+    /// rolling window of observed pending-transaction gas prices, emitted
+    /// alongside each new block
+    GasPrices { slow: String, standard: String, fast: String },
     NetworkStatus { notification: NetworkStatusNotification },
+    FeeHistory { notification: FeeHistoryNotification },
 }
 
 #[cfg(test)]
@@ -338,4 +535,83 @@ mod tests {
         let serialized = serde_json::to_string(&trend).unwrap();
         assert_eq!(serialized, "\"increase\"");
     }
+
+    #[test]
+    fn test_condition_eq_and_exists() {
+        let payload = serde_json::json!({ "address": "0xabc" });
+
+        let eq = Condition::new("address", ConditionOp::Eq, "0xabc".to_string());
+        assert!(eq.matches(payload.get("address")));
+
+        let mismatched = Condition::new("address", ConditionOp::Eq, "0xdef".to_string());
+        assert!(!mismatched.matches(payload.get("address")));
+
+        let exists = Condition::new("address", ConditionOp::Exists, serde_json::Value::Null);
+        assert!(exists.matches(payload.get("address")));
+        assert!(!exists.matches(payload.get("missing")));
+    }
+
+    #[test]
+    fn test_condition_numeric_comparisons() {
+        let payload = serde_json::json!({ "block_number": 100 });
+
+        assert!(Condition::new("block_number", ConditionOp::Gte, 100).matches(payload.get("block_number")));
+        assert!(Condition::new("block_number", ConditionOp::Gt, 50).matches(payload.get("block_number")));
+        assert!(!Condition::new("block_number", ConditionOp::Lt, 50).matches(payload.get("block_number")));
+    }
+
+    #[test]
+    fn test_condition_contains() {
+        let payload = serde_json::json!({ "topics": ["0x1", "0x2"] });
+        assert!(Condition::new("topics", ConditionOp::Contains, "0x1").matches(payload.get("topics")));
+        assert!(!Condition::new("topics", ConditionOp::Contains, "0x9").matches(payload.get("topics")));
+    }
+
+    #[test]
+    fn test_subscription_query_and_semantics() {
+        let query = SubscriptionQuery::new()
+            .and(Condition::new("address", ConditionOp::Eq, "0xabc".to_string()))
+            .and(Condition::new("block_number", ConditionOp::Gte, 100));
+
+        let matching = serde_json::json!({ "address": "0xabc", "block_number": 150 });
+        assert!(query.matches(&matching));
+
+        let non_matching = serde_json::json!({ "address": "0xabc", "block_number": 50 });
+        assert!(!query.matches(&non_matching));
+    }
+
+    #[test]
+    fn test_subscription_query_to_logs_filter() {
+        let query = SubscriptionQuery::new()
+            .and(Condition::new("address", ConditionOp::Eq, "0xabc".to_string()))
+            .and(Condition::new("topics", ConditionOp::Eq, serde_json::json!(["0x1"])))
+            .and(Condition::new("block_number", ConditionOp::Gte, 100));
+
+        let filter = query.to_logs_filter();
+        assert_eq!(filter["address"], "0xabc");
+        assert_eq!(filter["topics"], serde_json::json!(["0x1"]));
+        assert!(filter.get("block_number").is_none());
+    }
+
+    #[test]
+    fn test_new_block_header_converts_to_hyperevm_block() {
+        let header = NewBlockHeader {
+            hash: Hash("0xaaaa".to_string()),
+            parent_hash: Hash("0xbbbb".to_string()),
+            number: 42,
+            timestamp: 1700000000,
+            gas_limit: "30000000".to_string(),
+            gas_used: "15000000".to_string(),
+            difficulty: "0".to_string(),
+            miner: Address("0xminer".to_string()),
+            extra_data: "0x".to_string(),
+            transaction_count: 3,
+        };
+
+        let block: HyperEVMBlock = header.into();
+        assert_eq!(block.number, 42);
+        assert_eq!(block.hash.0, "0xaaaa");
+        assert_eq!(block.block_type, BlockType::Fast);
+        assert!(block.transaction_hashes.is_empty());
+    }
 }
@@ -2,43 +2,449 @@
 
 use crate::error::{HyperSimError, Result};
 
-/// Encode function call data
+const WORD: usize = 32;
+
+/// A parsed Solidity ABI type, as produced by [`parse_signature`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiType {
+    Address,
+    Bool,
+    Uint(usize),
+    Int(usize),
+    /// Dynamic-length byte array (`bytes`)
+    Bytes,
+    /// Fixed-length byte array (`bytesN`)
+    FixedBytes(usize),
+    String,
+    /// Dynamic-length array of a single element type (`T[]`)
+    Array(Box<AbiType>),
+    /// Fixed-length array of a single element type (`T[N]`)
+    FixedArray(Box<AbiType>, usize),
+    /// Tuple of heterogeneous element types
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Whether this type occupies a variable number of words and therefore
+    /// needs a head offset pointing into the tail.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::Bytes | AbiType::String | AbiType::Array(_) => true,
+            AbiType::FixedArray(inner, _) => inner.is_dynamic(),
+            AbiType::Tuple(fields) => fields.iter().any(AbiType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Number of head words occupied when this type is static (0 for dynamic types).
+    fn head_words(&self) -> usize {
+        match self {
+            AbiType::FixedArray(inner, len) if !inner.is_dynamic() => inner.head_words() * len,
+            AbiType::Tuple(fields) if !self.is_dynamic() => {
+                fields.iter().map(AbiType::head_words).sum()
+            }
+            _ => 1,
+        }
+    }
+}
+
+/// A decoded/encodable ABI value, paired one-to-one with an [`AbiType`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiValue {
+    Address(String),
+    Bool(bool),
+    /// Big-endian two's complement / unsigned representation, always 32 bytes
+    Uint([u8; 32]),
+    Int([u8; 32]),
+    Bytes(Vec<u8>),
+    FixedBytes(Vec<u8>),
+    String(String),
+    Array(Vec<AbiValue>),
+    Tuple(Vec<AbiValue>),
+}
+
+/// Tokenize a function signature such as `"transfer(address,uint256)"` into its
+/// argument type tree. Only the parenthesized parameter list is consulted; the
+/// function name is ignored so bare type lists (`"(address,uint256)"`) also work.
+pub fn parse_signature(signature: &str) -> Result<Vec<AbiType>> {
+    let open = signature
+        .find('(')
+        .ok_or_else(|| HyperSimError::abi("Missing '(' in function signature"))?;
+    if !signature.ends_with(')') {
+        return Err(HyperSimError::abi("Missing closing ')' in function signature"));
+    }
+    let inner = &signature[open + 1..signature.len() - 1];
+    split_top_level(inner)?
+        .into_iter()
+        .map(|s| parse_type(s.trim()))
+        .collect()
+}
+
+/// Split a comma-separated type list on top-level commas only (ignoring commas
+/// nested inside parentheses or brackets).
+fn split_top_level(s: &str) -> Result<Vec<&str>> {
+    if s.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0usize;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err(HyperSimError::abi("Unbalanced parentheses in type list"));
+                }
+            }
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(HyperSimError::abi("Unbalanced parentheses in type list"));
+    }
+    parts.push(&s[start..]);
+    Ok(parts)
+}
+
+fn parse_type(s: &str) -> Result<AbiType> {
+    // Array suffixes: strip trailing `[]` or `[N]` repeatedly, wrapping the element type.
+    if s.ends_with(']') {
+        let open = s.rfind('[').ok_or_else(|| HyperSimError::abi("Malformed array type"))?;
+        let element = parse_type(&s[..open])?;
+        let inside = &s[open + 1..s.len() - 1];
+        if inside.is_empty() {
+            return Ok(AbiType::Array(Box::new(element)));
+        }
+        let len: usize = inside
+            .parse()
+            .map_err(|_| HyperSimError::abi(format!("Invalid array length: {}", inside)))?;
+        return Ok(AbiType::FixedArray(Box::new(element), len));
+    }
+
+    // Tuple: (T1,T2,...)
+    if s.starts_with('(') && s.ends_with(')') {
+        let fields = split_top_level(&s[1..s.len() - 1])?
+            .into_iter()
+            .map(|f| parse_type(f.trim()))
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(AbiType::Tuple(fields));
+    }
+
+    match s {
+        "address" => Ok(AbiType::Address),
+        "bool" => Ok(AbiType::Bool),
+        "bytes" => Ok(AbiType::Bytes),
+        "string" => Ok(AbiType::String),
+        "uint" => Ok(AbiType::Uint(256)),
+        "int" => Ok(AbiType::Int(256)),
+        _ if s.starts_with("uint") => {
+            let bits: usize = s[4..]
+                .parse()
+                .map_err(|_| HyperSimError::abi(format!("Invalid uint type: {}", s)))?;
+            Ok(AbiType::Uint(bits))
+        }
+        _ if s.starts_with("int") => {
+            let bits: usize = s[3..]
+                .parse()
+                .map_err(|_| HyperSimError::abi(format!("Invalid int type: {}", s)))?;
+            Ok(AbiType::Int(bits))
+        }
+        _ if s.starts_with("bytes") => {
+            let size: usize = s[5..]
+                .parse()
+                .map_err(|_| HyperSimError::abi(format!("Invalid bytesN type: {}", s)))?;
+            if size == 0 || size > 32 {
+                return Err(HyperSimError::abi(format!("bytesN size out of range: {}", s)));
+            }
+            Ok(AbiType::FixedBytes(size))
+        }
+        _ => Err(HyperSimError::abi(format!("Unsupported ABI type: {}", s))),
+    }
+}
+
+/// Encode a list of values into standard ABI head/tail layout.
+///
+/// Static values are written inline in the head; each dynamic value (`bytes`,
+/// `string`, `T[]`, or a tuple/array containing a dynamic type) contributes a
+/// 32-byte offset in the head pointing at its encoding in the tail.
+pub fn encode(values: &[AbiValue]) -> Result<Vec<u8>> {
+    let types: Vec<AbiType> = values.iter().map(value_type).collect();
+    encode_tuple(&types, values)
+}
+
+/// Encode a list of values against an explicit type list (used for nested tuples).
+fn encode_tuple(types: &[AbiType], values: &[AbiValue]) -> Result<Vec<u8>> {
+    if types.len() != values.len() {
+        return Err(HyperSimError::abi("Type/value count mismatch"));
+    }
+
+    // Each element contributes either its static head words, or a single
+    // offset word plus a tail chunk encoded separately.
+    let mut static_words: Vec<Vec<u8>> = Vec::with_capacity(types.len());
+    let mut tail_chunks: Vec<Vec<u8>> = Vec::with_capacity(types.len());
+    for (ty, val) in types.iter().zip(values.iter()) {
+        let encoded = encode_value(ty, val)?;
+        if ty.is_dynamic() {
+            static_words.push(Vec::new()); // placeholder, offset patched below
+            tail_chunks.push(encoded);
+        } else {
+            static_words.push(encoded);
+            tail_chunks.push(Vec::new());
+        }
+    }
+
+    let head_len: usize = types
+        .iter()
+        .map(|t| if t.is_dynamic() { WORD } else { t.head_words() * WORD })
+        .sum();
+
+    let mut tail_offset = head_len;
+    let mut head = Vec::with_capacity(head_len);
+    for (ty, (_static, tail)) in types.iter().zip(static_words.iter().zip(tail_chunks.iter())) {
+        if ty.is_dynamic() {
+            head.extend_from_slice(&encode_uint_word(tail_offset as u128));
+            tail_offset += tail.len();
+        } else {
+            head.extend_from_slice(_static);
+        }
+    }
+
+    let mut out = head;
+    for tail in &tail_chunks {
+        out.extend_from_slice(tail);
+    }
+    Ok(out)
+}
+
+fn encode_uint_word(value: u128) -> [u8; WORD] {
+    let mut word = [0u8; WORD];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Encode a single value of a known type. For dynamic types this returns the
+/// *tail* representation (length word, if applicable, followed by padded data);
+/// for static types it returns the inline head words.
+fn encode_value(ty: &AbiType, val: &AbiValue) -> Result<Vec<u8>> {
+    match (ty, val) {
+        (AbiType::Address, AbiValue::Address(addr)) => {
+            let hex = addr.trim_start_matches("0x");
+            if hex.len() != 40 {
+                return Err(HyperSimError::abi("Invalid address length"));
+            }
+            let bytes = hex::decode(hex).map_err(|_| HyperSimError::abi("Invalid address hex"))?;
+            let mut word = [0u8; WORD];
+            word[12..].copy_from_slice(&bytes);
+            Ok(word.to_vec())
+        }
+        (AbiType::Bool, AbiValue::Bool(b)) => Ok(encode_uint_word(*b as u128).to_vec()),
+        (AbiType::Uint(_), AbiValue::Uint(bytes)) | (AbiType::Int(_), AbiValue::Int(bytes)) => {
+            Ok(bytes.to_vec())
+        }
+        (AbiType::FixedBytes(size), AbiValue::FixedBytes(bytes)) => {
+            if bytes.len() != *size {
+                return Err(HyperSimError::abi("bytesN length mismatch"));
+            }
+            let mut word = [0u8; WORD];
+            word[..bytes.len()].copy_from_slice(bytes);
+            Ok(word.to_vec())
+        }
+        (AbiType::Bytes, AbiValue::Bytes(bytes)) => Ok(encode_dynamic_bytes(bytes)),
+        (AbiType::String, AbiValue::String(s)) => Ok(encode_dynamic_bytes(s.as_bytes())),
+        (AbiType::Array(elem_ty), AbiValue::Array(elems)) => {
+            let mut out = encode_uint_word(elems.len() as u128).to_vec();
+            let elem_types: Vec<AbiType> = elems.iter().map(|_| (**elem_ty).clone()).collect();
+            out.extend_from_slice(&encode_tuple(&elem_types, elems)?);
+            Ok(out)
+        }
+        (AbiType::FixedArray(elem_ty, len), AbiValue::Array(elems)) => {
+            if elems.len() != *len {
+                return Err(HyperSimError::abi("Fixed array length mismatch"));
+            }
+            let elem_types: Vec<AbiType> = elems.iter().map(|_| (**elem_ty).clone()).collect();
+            encode_tuple(&elem_types, elems)
+        }
+        (AbiType::Tuple(field_types), AbiValue::Tuple(fields)) => {
+            encode_tuple(field_types, fields)
+        }
+        _ => Err(HyperSimError::abi("AbiValue does not match AbiType")),
+    }
+}
+
+fn encode_dynamic_bytes(data: &[u8]) -> Vec<u8> {
+    let mut out = encode_uint_word(data.len() as u128).to_vec();
+    out.extend_from_slice(data);
+    while out.len() % WORD != 0 {
+        out.push(0);
+    }
+    out
+}
+
+/// Recover the `AbiType` implied by a value, used by the untyped [`encode`] entry point.
+fn value_type(val: &AbiValue) -> AbiType {
+    match val {
+        AbiValue::Address(_) => AbiType::Address,
+        AbiValue::Bool(_) => AbiType::Bool,
+        AbiValue::Uint(_) => AbiType::Uint(256),
+        AbiValue::Int(_) => AbiType::Int(256),
+        AbiValue::Bytes(_) => AbiType::Bytes,
+        AbiValue::FixedBytes(b) => AbiType::FixedBytes(b.len()),
+        AbiValue::String(_) => AbiType::String,
+        AbiValue::Array(elems) => {
+            let elem_ty = elems.first().map(value_type).unwrap_or(AbiType::Bytes);
+            AbiType::Array(Box::new(elem_ty))
+        }
+        AbiValue::Tuple(fields) => AbiType::Tuple(fields.iter().map(value_type).collect()),
+    }
+}
+
+/// Decode ABI-encoded `data` against the expected type list, reversing the
+/// head/tail scheme used by [`encode`].
+pub fn decode(types: &[AbiType], data: &[u8]) -> Result<Vec<AbiValue>> {
+    let mut values = Vec::with_capacity(types.len());
+    let mut head_cursor = 0usize;
+    for ty in types {
+        if ty.is_dynamic() {
+            let offset = read_uint_word(data, head_cursor)? as usize;
+            values.push(decode_value(ty, data, offset)?);
+            head_cursor += WORD;
+        } else {
+            let words = ty.head_words();
+            values.push(decode_value(ty, data, head_cursor)?);
+            head_cursor += words * WORD;
+        }
+    }
+    Ok(values)
+}
+
+fn read_uint_word(data: &[u8], offset: usize) -> Result<u128> {
+    let word = data
+        .get(offset..offset + WORD)
+        .ok_or_else(|| HyperSimError::abi("Truncated ABI data"))?;
+    let mut buf = [0u8; 16];
+    buf.copy_from_slice(&word[16..]);
+    Ok(u128::from_be_bytes(buf))
+}
+
+fn decode_value(ty: &AbiType, data: &[u8], offset: usize) -> Result<AbiValue> {
+    match ty {
+        AbiType::Address => {
+            let word = data
+                .get(offset..offset + WORD)
+                .ok_or_else(|| HyperSimError::abi("Truncated address"))?;
+            Ok(AbiValue::Address(format!("0x{}", hex::encode(&word[12..]))))
+        }
+        AbiType::Bool => Ok(AbiValue::Bool(read_uint_word(data, offset)? != 0)),
+        AbiType::Uint(_) => {
+            let word = data
+                .get(offset..offset + WORD)
+                .ok_or_else(|| HyperSimError::abi("Truncated uint"))?;
+            let mut bytes = [0u8; WORD];
+            bytes.copy_from_slice(word);
+            Ok(AbiValue::Uint(bytes))
+        }
+        AbiType::Int(_) => {
+            let word = data
+                .get(offset..offset + WORD)
+                .ok_or_else(|| HyperSimError::abi("Truncated int"))?;
+            let mut bytes = [0u8; WORD];
+            bytes.copy_from_slice(word);
+            Ok(AbiValue::Int(bytes))
+        }
+        AbiType::FixedBytes(size) => {
+            let word = data
+                .get(offset..offset + WORD)
+                .ok_or_else(|| HyperSimError::abi("Truncated fixed bytes"))?;
+            Ok(AbiValue::FixedBytes(word[..*size].to_vec()))
+        }
+        AbiType::Bytes => Ok(AbiValue::Bytes(read_dynamic_bytes(data, offset)?)),
+        AbiType::String => {
+            let bytes = read_dynamic_bytes(data, offset)?;
+            let s = String::from_utf8(bytes)
+                .map_err(|_| HyperSimError::abi("Invalid UTF-8 in decoded string"))?;
+            Ok(AbiValue::String(s))
+        }
+        AbiType::Array(elem_ty) => {
+            let len = read_uint_word(data, offset)? as usize;
+            let tail = data
+                .get(offset + WORD..)
+                .ok_or_else(|| HyperSimError::abi("Truncated array"))?;
+            let types: Vec<AbiType> = (0..len).map(|_| (**elem_ty).clone()).collect();
+            Ok(AbiValue::Array(decode(&types, tail)?))
+        }
+        AbiType::FixedArray(elem_ty, len) => {
+            let slice = data
+                .get(offset..)
+                .ok_or_else(|| HyperSimError::abi("Truncated fixed array"))?;
+            let types: Vec<AbiType> = (0..*len).map(|_| (**elem_ty).clone()).collect();
+            Ok(AbiValue::Array(decode(&types, slice)?))
+        }
+        AbiType::Tuple(field_types) => {
+            let slice = data
+                .get(offset..)
+                .ok_or_else(|| HyperSimError::abi("Truncated tuple"))?;
+            Ok(AbiValue::Tuple(decode(field_types, slice)?))
+        }
+    }
+}
+
+fn read_dynamic_bytes(data: &[u8], offset: usize) -> Result<Vec<u8>> {
+    let len = read_uint_word(data, offset)? as usize;
+    let start = offset + WORD;
+    data.get(start..start + len)
+        .map(|s| s.to_vec())
+        .ok_or_else(|| HyperSimError::abi("Truncated dynamic bytes"))
+}
+
+/// Encode function call data (selector + head/tail encoded arguments).
+///
+/// Thin wrapper around [`parse_signature`] and [`encode`] that works with the
+/// legacy string-based parameter representation used throughout the SDK: each
+/// `param` is a hex-encoded 32-byte word for static types. For dynamic types
+/// or nested structures, use [`encode`] directly with [`AbiValue`]s.
 pub fn encode_function_call(function_signature: &str, params: &[&str]) -> Result<String> {
-    // This is a simplified implementation
-    // In production, use ethers-rs or similar library for proper ABI encoding
-    
     let function_selector = keccak256_hash(function_signature.as_bytes());
-    let selector = &function_selector[0..8]; // First 4 bytes
-    
+    let selector = &function_selector[0..4];
+
     let mut encoded = format!("0x{}", hex::encode(selector));
-    
-    // Simplified parameter encoding (for demo purposes)
+
     for param in params {
         let padded = format!("{:0>64}", param.trim_start_matches("0x"));
         encoded.push_str(&padded);
     }
-    
+
     Ok(encoded)
 }
 
-/// Decode function call data
+/// Decode function call data (selector + raw 32-byte words).
+///
+/// Thin wrapper that splits the call data into its selector and a flat list of
+/// 32-byte words. It does not resolve dynamic-type offsets; use [`decode`] with
+/// the function's parsed [`AbiType`]s for full fidelity decoding.
 pub fn decode_function_call(data: &str) -> Result<(String, Vec<String>)> {
     if data.len() < 10 {
         return Err(HyperSimError::abi("Invalid function call data"));
     }
-    
+
     let selector = &data[2..10];
     let params_data = &data[10..];
-    
-    // Simplified decoding (for demo purposes)
+
     let mut params = Vec::new();
     let mut i = 0;
     while i + 64 <= params_data.len() {
-        let param = &params_data[i..i+64];
+        let param = &params_data[i..i + 64];
         params.push(format!("0x{}", param));
         i += 64;
     }
-    
+
     Ok((format!("0x{}", selector), params))
 }
 
@@ -49,7 +455,7 @@ pub fn encode_event_signature(event_signature: &str) -> Result<String> {
 }
 
 /// Calculate keccak256 hash
-fn keccak256_hash(data: &[u8]) -> [u8; 32] {
+pub(crate) fn keccak256_hash(data: &[u8]) -> [u8; 32] {
     use keccak_hash::Keccak;
     let mut hasher = Keccak::v256();
     let mut output = [0u8; 32];
@@ -64,7 +470,7 @@ pub fn encode_address(address: &str) -> Result<String> {
     if address.len() != 40 {
         return Err(HyperSimError::abi("Invalid address length"));
     }
-    
+
     Ok(format!("{:0>64}", address))
 }
 
@@ -74,7 +480,7 @@ pub fn encode_uint256(value: &str) -> Result<String> {
     if value.len() > 64 {
         return Err(HyperSimError::abi("Value too large for uint256"));
     }
-    
+
     Ok(format!("{:0>64}", value))
 }
 
@@ -83,12 +489,12 @@ pub fn encode_string(s: &str) -> Result<String> {
     let bytes = s.as_bytes();
     let length = format!("{:064x}", bytes.len());
     let mut data = hex::encode(bytes);
-    
+
     // Pad to 32-byte boundary
     while data.len() % 64 != 0 {
         data.push('0');
     }
-    
+
     Ok(format!("{}{}", length, data))
 }
 
@@ -97,7 +503,7 @@ pub fn decode_address(data: &str) -> Result<String> {
     if data.len() != 64 {
         return Err(HyperSimError::abi("Invalid address data length"));
     }
-    
+
     let address = &data[24..64]; // Last 20 bytes (40 hex chars)
     Ok(format!("0x{}", address))
 }
@@ -107,7 +513,7 @@ pub fn decode_uint256(data: &str) -> Result<String> {
     if data.len() != 64 {
         return Err(HyperSimError::abi("Invalid uint256 data length"));
     }
-    
+
     // Remove leading zeros
     let trimmed = data.trim_start_matches('0');
     if trimmed.is_empty() {
@@ -121,27 +527,167 @@ pub fn decode_uint256(data: &str) -> Result<String> {
 pub mod selectors {
     /// ERC-20 transfer function
     pub const TRANSFER: &str = "0xa9059cbb";
-    
+
     /// ERC-20 transferFrom function
     pub const TRANSFER_FROM: &str = "0x23b872dd";
-    
+
     /// ERC-20 approve function
     pub const APPROVE: &str = "0x095ea7b3";
-    
+
     /// ERC-20 balanceOf function
     pub const BALANCE_OF: &str = "0x70a08231";
-    
+
     /// ERC-20 allowance function
     pub const ALLOWANCE: &str = "0xdd62ed3e";
 }
 
 /// Common event signatures
 pub mod events {
-    /// ERC-20 Transfer event
+    /// ERC-20 `Transfer(address,address,uint256)` — also emitted (with an extra
+    /// indexed topic for `tokenId`) by ERC-721, since the signature hash is identical
     pub const TRANSFER: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
-    
-    /// ERC-20 Approval event
+
+    /// ERC-20/ERC-721 `Approval(address,address,uint256)`
     pub const APPROVAL: &str = "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+
+    /// ERC-721/ERC-1155 `ApprovalForAll(address,address,bool)`
+    pub const APPROVAL_FOR_ALL: &str = "0x17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31";
+
+    /// ERC-1155 `TransferSingle(address,address,address,uint256,uint256)`
+    pub const TRANSFER_SINGLE: &str = "0xc3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62";
+
+    /// ERC-1155 `TransferBatch(address,address,address,uint256[],uint256[])`
+    pub const TRANSFER_BATCH: &str = "0x4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb";
+}
+
+/// A log decoded into one of the standard ERC-20/ERC-721/ERC-1155 event shapes.
+/// Falls back to [`DecodedLog::Unknown`] for signatures the dispatcher doesn't recognize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodedLog {
+    /// ERC-20 `Transfer`: 3 topics, `value` in data
+    Erc20Transfer { from: String, to: String, value: String },
+    /// ERC-721 `Transfer`: 4 topics, `tokenId` indexed, empty data
+    Erc721Transfer { from: String, to: String, token_id: String },
+    /// ERC-20 `Approval`: 3 topics, `value` in data
+    Erc20Approval { owner: String, spender: String, value: String },
+    /// ERC-721 `Approval`: 4 topics, `tokenId` indexed, empty data
+    Erc721Approval { owner: String, approved: String, token_id: String },
+    /// ERC-721/ERC-1155 `ApprovalForAll`
+    ApprovalForAll { owner: String, operator: String, approved: bool },
+    /// ERC-1155 `TransferSingle`
+    Erc1155TransferSingle {
+        operator: String,
+        from: String,
+        to: String,
+        id: String,
+        value: String,
+    },
+    /// ERC-1155 `TransferBatch`
+    Erc1155TransferBatch {
+        operator: String,
+        from: String,
+        to: String,
+        ids: Vec<String>,
+        values: Vec<String>,
+    },
+    /// A log whose signature topic wasn't recognized
+    Unknown { topics: Vec<String>, data: String },
+}
+
+/// Inspect `topics[0]` against the known standard-token event signatures and decode
+/// indexed vs non-indexed parameters accordingly, distinguishing fungible ERC-20
+/// transfers from ERC-721 NFT transfers by topic count (3 vs 4).
+pub fn decode_log(topics: &[String], data: &str) -> Result<DecodedLog> {
+    let Some(signature) = topics.first() else {
+        return Err(HyperSimError::abi("Log has no topics"));
+    };
+
+    match signature.as_str() {
+        events::TRANSFER if topics.len() == 3 => {
+            let from = decode_address(&topics[1][2..])?;
+            let to = decode_address(&topics[2][2..])?;
+            let value = decode_uint256(data.trim_start_matches("0x"))?;
+            Ok(DecodedLog::Erc20Transfer { from, to, value })
+        }
+        events::TRANSFER if topics.len() == 4 => {
+            let from = decode_address(&topics[1][2..])?;
+            let to = decode_address(&topics[2][2..])?;
+            let token_id = decode_uint256(&topics[3][2..])?;
+            Ok(DecodedLog::Erc721Transfer { from, to, token_id })
+        }
+        events::APPROVAL if topics.len() == 3 => {
+            let owner = decode_address(&topics[1][2..])?;
+            let spender = decode_address(&topics[2][2..])?;
+            let value = decode_uint256(data.trim_start_matches("0x"))?;
+            Ok(DecodedLog::Erc20Approval { owner, spender, value })
+        }
+        events::APPROVAL if topics.len() == 4 => {
+            let owner = decode_address(&topics[1][2..])?;
+            let approved = decode_address(&topics[2][2..])?;
+            let token_id = decode_uint256(&topics[3][2..])?;
+            Ok(DecodedLog::Erc721Approval { owner, approved, token_id })
+        }
+        events::APPROVAL_FOR_ALL => {
+            if topics.len() != 3 {
+                return Err(HyperSimError::abi("Invalid ApprovalForAll topic count"));
+            }
+            let owner = decode_address(&topics[1][2..])?;
+            let operator = decode_address(&topics[2][2..])?;
+            let approved = decode_uint256(data.trim_start_matches("0x"))? != "0";
+            Ok(DecodedLog::ApprovalForAll { owner, operator, approved })
+        }
+        events::TRANSFER_SINGLE => {
+            if topics.len() != 4 {
+                return Err(HyperSimError::abi("Invalid TransferSingle topic count"));
+            }
+            let operator = decode_address(&topics[1][2..])?;
+            let from = decode_address(&topics[2][2..])?;
+            let to = decode_address(&topics[3][2..])?;
+            let body = data.trim_start_matches("0x");
+            if body.len() != 128 {
+                return Err(HyperSimError::abi("Invalid TransferSingle data length"));
+            }
+            let id = decode_uint256(&body[0..64])?;
+            let value = decode_uint256(&body[64..128])?;
+            Ok(DecodedLog::Erc1155TransferSingle { operator, from, to, id, value })
+        }
+        events::TRANSFER_BATCH => {
+            if topics.len() != 4 {
+                return Err(HyperSimError::abi("Invalid TransferBatch topic count"));
+            }
+            let operator = decode_address(&topics[1][2..])?;
+            let from = decode_address(&topics[2][2..])?;
+            let to = decode_address(&topics[3][2..])?;
+
+            let types = vec![
+                AbiType::Array(Box::new(AbiType::Uint(256))),
+                AbiType::Array(Box::new(AbiType::Uint(256))),
+            ];
+            let body = hex::decode(data.trim_start_matches("0x"))
+                .map_err(|_| HyperSimError::abi("Invalid TransferBatch data hex"))?;
+            let decoded = decode(&types, &body)?;
+            let ids = expect_uint_array(&decoded[0])?;
+            let values = expect_uint_array(&decoded[1])?;
+            Ok(DecodedLog::Erc1155TransferBatch { operator, from, to, ids, values })
+        }
+        _ => Ok(DecodedLog::Unknown {
+            topics: topics.to_vec(),
+            data: data.to_string(),
+        }),
+    }
+}
+
+fn expect_uint_array(value: &AbiValue) -> Result<Vec<String>> {
+    match value {
+        AbiValue::Array(items) => items
+            .iter()
+            .map(|v| match v {
+                AbiValue::Uint(bytes) => Ok(format!("0x{}", hex::encode(bytes).trim_start_matches('0'))),
+                _ => Err(HyperSimError::abi("Expected uint256 array element")),
+            })
+            .collect(),
+        _ => Err(HyperSimError::abi("Expected uint256 array")),
+    }
 }
 
 /// Create function signature for common operations
@@ -165,19 +711,19 @@ pub fn parse_transfer_event(topics: &[String], data: &str) -> Result<(String, St
     if topics.len() < 3 {
         return Err(HyperSimError::abi("Invalid transfer event topics"));
     }
-    
+
     // topics[0] is event signature, topics[1] is from, topics[2] is to
     let from = decode_address(&topics[1][2..])?;
     let to = decode_address(&topics[2][2..])?;
     let amount = decode_uint256(data.trim_start_matches("0x"))?;
-    
+
     Ok((from, to, amount))
 }
 
 #[cfg(feature = "cross-layer")]
 pub mod cross_layer {
     use super::*;
-    
+
     /// Encode cross-layer transaction data
     pub fn encode_cross_layer_tx(
         target_chain: u64,
@@ -187,41 +733,41 @@ pub mod cross_layer {
         let chain_data = encode_uint256(&format!("{:x}", target_chain))?;
         let address_data = encode_address(target_address)?;
         let data_length = encode_uint256(&format!("{:x}", data.len() / 2))?;
-        
-        Ok(format!("0x{}{}{}{}", 
+
+        Ok(format!("0x{}{}{}{}",
             &chain_data[..64],
-            &address_data[..64], 
+            &address_data[..64],
             &data_length[..64],
             data.trim_start_matches("0x")
         ))
     }
-    
+
     /// Decode cross-layer transaction data
     pub fn decode_cross_layer_tx(encoded: &str) -> Result<(u64, String, String)> {
         if encoded.len() < 194 { // Minimum length for header
             return Err(HyperSimError::abi("Invalid cross-layer data length"));
         }
-        
+
         let encoded = encoded.trim_start_matches("0x");
-        
+
         let chain_hex = &encoded[0..64];
         let address_hex = &encoded[64..128];
         let length_hex = &encoded[128..192];
-        
+
         let chain_id = u64::from_str_radix(chain_hex.trim_start_matches('0'), 16)
             .map_err(|_| HyperSimError::abi("Invalid chain ID"))?;
-        
+
         let address = decode_address(address_hex)?;
-        
+
         let data_length = usize::from_str_radix(length_hex.trim_start_matches('0'), 16)
             .map_err(|_| HyperSimError::abi("Invalid data length"))?;
-        
+
         let data = if data_length > 0 {
             format!("0x{}", &encoded[192..192 + data_length * 2])
         } else {
             "0x".to_string()
         };
-        
+
         Ok((chain_id, address, data))
     }
 }
@@ -236,7 +782,7 @@ mod tests {
             "0000000000000000000000001234567890123456789012345678901234567890",
             "00000000000000000000000000000000000000000000000000000000000003e8"
         ]).unwrap();
-        
+
         assert!(encoded.starts_with("0x"));
         assert_eq!(encoded.len(), 138); // 2 + 8 + 64 + 64
     }
@@ -273,6 +819,77 @@ mod tests {
         assert_eq!(selectors::BALANCE_OF, "0x70a08231");
     }
 
+    #[test]
+    fn test_parse_signature_static() {
+        let types = parse_signature("transfer(address,uint256)").unwrap();
+        assert_eq!(types, vec![AbiType::Address, AbiType::Uint(256)]);
+    }
+
+    #[test]
+    fn test_parse_signature_dynamic_and_nested() {
+        let types = parse_signature("foo(string,uint256[],(address,bool))").unwrap();
+        assert_eq!(types.len(), 3);
+        assert_eq!(types[0], AbiType::String);
+        assert_eq!(types[1], AbiType::Array(Box::new(AbiType::Uint(256))));
+        assert_eq!(
+            types[2],
+            AbiType::Tuple(vec![AbiType::Address, AbiType::Bool])
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip_static() {
+        let types = vec![AbiType::Address, AbiType::Uint(256)];
+        let values = vec![
+            AbiValue::Address("0x1234567890123456789012345678901234567890".to_string()),
+            AbiValue::Uint({
+                let mut b = [0u8; 32];
+                b[31] = 0xe8;
+                b[30] = 0x03;
+                b
+            }),
+        ];
+
+        let encoded = encode(&values).unwrap();
+        assert_eq!(encoded.len(), 64);
+
+        let decoded = decode(&types, &encoded).unwrap();
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip_dynamic() {
+        let types = parse_signature("f(string,uint256[])").unwrap();
+        let values = vec![
+            AbiValue::String("hello world".to_string()),
+            AbiValue::Array(vec![
+                AbiValue::Uint([0u8; 32]),
+                AbiValue::Uint({
+                    let mut b = [0u8; 32];
+                    b[31] = 7;
+                    b
+                }),
+            ]),
+        ];
+
+        let encoded = encode(&values).unwrap();
+        let decoded = decode(&types, &encoded).unwrap();
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn test_encode_decode_nested_tuple() {
+        let types = parse_signature("f((address,bytes))").unwrap();
+        let values = vec![AbiValue::Tuple(vec![
+            AbiValue::Address("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd".to_string()),
+            AbiValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef]),
+        ])];
+
+        let encoded = encode(&values).unwrap();
+        let decoded = decode(&types, &encoded).unwrap();
+        assert_eq!(decoded, values);
+    }
+
     #[cfg(feature = "cross-layer")]
     #[test]
     fn test_cross_layer_encoding() {
@@ -281,12 +898,92 @@ mod tests {
             "0x1234567890123456789012345678901234567890",
             "0xabcd"
         ).unwrap();
-        
+
         assert!(encoded.starts_with("0x"));
-        
+
         let (chain_id, address, data) = cross_layer::decode_cross_layer_tx(&encoded).unwrap();
         assert_eq!(chain_id, 137);
         assert_eq!(address, "0x1234567890123456789012345678901234567890");
         assert_eq!(data, "0xabcd");
     }
+
+    fn topic(addr_or_id: &str) -> String {
+        format!("{:0>64}", addr_or_id.trim_start_matches("0x"))
+    }
+
+    #[test]
+    fn test_decode_log_erc20_transfer() {
+        let topics = vec![
+            events::TRANSFER.to_string(),
+            format!("0x{}", topic("1234567890123456789012345678901234567890")),
+            format!("0x{}", topic("abcdefabcdefabcdefabcdefabcdefabcdefabcd")),
+        ];
+        let data = format!("0x{}", topic("3e8"));
+
+        let decoded = decode_log(&topics, &data).unwrap();
+        assert_eq!(
+            decoded,
+            DecodedLog::Erc20Transfer {
+                from: "0x1234567890123456789012345678901234567890".to_string(),
+                to: "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd".to_string(),
+                value: "0x3e8".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_log_erc721_transfer() {
+        let topics = vec![
+            events::TRANSFER.to_string(),
+            format!("0x{}", topic("1234567890123456789012345678901234567890")),
+            format!("0x{}", topic("abcdefabcdefabcdefabcdefabcdefabcdefabcd")),
+            format!("0x{}", topic("2a")),
+        ];
+
+        let decoded = decode_log(&topics, "0x").unwrap();
+        assert_eq!(
+            decoded,
+            DecodedLog::Erc721Transfer {
+                from: "0x1234567890123456789012345678901234567890".to_string(),
+                to: "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd".to_string(),
+                token_id: "0x2a".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_log_erc1155_transfer_single() {
+        let topics = vec![
+            events::TRANSFER_SINGLE.to_string(),
+            format!("0x{}", topic("1111111111111111111111111111111111111111")),
+            format!("0x{}", topic("2222222222222222222222222222222222222222")),
+            format!("0x{}", topic("3333333333333333333333333333333333333333")),
+        ];
+        let data = format!("0x{}{}", topic("1"), topic("64"));
+
+        let decoded = decode_log(&topics, &data).unwrap();
+        assert_eq!(
+            decoded,
+            DecodedLog::Erc1155TransferSingle {
+                operator: "0x1111111111111111111111111111111111111111".to_string(),
+                from: "0x2222222222222222222222222222222222222222".to_string(),
+                to: "0x3333333333333333333333333333333333333333".to_string(),
+                id: "0x1".to_string(),
+                value: "0x64".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_log_unknown_signature() {
+        let topics = vec!["0xdeadbeef".to_string()];
+        let decoded = decode_log(&topics, "0x1234").unwrap();
+        assert_eq!(
+            decoded,
+            DecodedLog::Unknown {
+                topics: vec!["0xdeadbeef".to_string()],
+                data: "0x1234".to_string(),
+            }
+        );
+    }
 }
@@ -16,6 +16,37 @@ pub mod gas {
     
     /// Maximum gas price in gwei (for safety)
     pub const MAX_GAS_PRICE_GWEI: u64 = 1_000;
+
+    /// EIP-2930 cost of each address listed in an access list
+    pub const ACCESS_LIST_ADDRESS_COST: u64 = 2_400;
+
+    /// EIP-2930 cost of each storage key listed in an access list
+    pub const ACCESS_LIST_STORAGE_KEY_COST: u64 = 1_900;
+
+    /// EIP-2929 cold SLOAD cost (what would be paid without pre-warming)
+    pub const COLD_SLOAD_COST: u64 = 2_100;
+
+    /// EIP-2929 warm storage read cost (what is paid once an address/slot is warm)
+    pub const WARM_STORAGE_READ_COST: u64 = 100;
+
+    /// EIP-2929 cold account access cost (first CALL/BALANCE/EXTCODE* touch of an address)
+    pub const COLD_ACCOUNT_ACCESS_COST: u64 = 2_600;
+
+    /// EIP-2929 warm access cost for an address already touched this transaction
+    pub const WARM_ACCESS_COST: u64 = 100;
+
+    /// Penalty charged, as a percentage of the unused `(gas_limit - gas_used)`
+    /// headroom's would-be cost, to flag an over-large gas limit
+    pub const OVER_ESTIMATION_PENALTY_PERCENT: u64 = 10;
+
+    /// EIP-4844 gas charged per blob
+    pub const GAS_PER_BLOB: u64 = 131_072;
+
+    /// EIP-4844 target number of blobs per block
+    pub const BLOB_TARGET_PER_BLOCK: u32 = 3;
+
+    /// EIP-4844 maximum number of blobs per block
+    pub const BLOB_MAX_PER_BLOCK: u32 = 6;
 }
 
 /// Network constants
@@ -3,33 +3,121 @@
 use crate::types::{Address, Wei, Hash};
 use crate::error::{HyperSimError, Result};
 
+/// Number of decimal digits in one ether, expressed in wei
+const WEI_PER_ETHER_DECIMALS: u32 = 18;
+
 /// Format wei amount to ETH with specified decimals
+///
+/// Computed as exact base-10 fixed-point — the wei value is split into its
+/// integer (`wei / 10^18`) and fractional (`wei % 10^18`) parts and assembled
+/// as a string without ever going through `f64`, so large balances (above
+/// `f64`'s 2^53 exact-integer range) don't silently lose precision or mis-round.
 pub fn wei_to_ether_string(wei: &Wei, decimals: usize) -> Result<String> {
     let wei_value: u128 = wei.as_str().parse()
         .map_err(|_| HyperSimError::serialization("Invalid wei amount"))?;
-    
-    let ether = wei_value as f64 / 1e18;
-    Ok(format!("{:.precision$}", ether, precision = decimals))
+
+    let divisor = 10u128.pow(WEI_PER_ETHER_DECIMALS);
+    let integer_part = wei_value / divisor;
+    let fractional_part = wei_value % divisor;
+
+    // Zero-pad the fraction out to 18 digits, then round to `decimals` by
+    // rounding the truncated-off tail into the kept digits.
+    let full_fraction = format!("{:0width$}", fractional_part, width = WEI_PER_ETHER_DECIMALS as usize);
+
+    if decimals as u32 >= WEI_PER_ETHER_DECIMALS {
+        let padding = "0".repeat(decimals - WEI_PER_ETHER_DECIMALS as usize);
+        return Ok(format!("{}.{}{}", integer_part, full_fraction, padding));
+    }
+
+    let kept = &full_fraction[..decimals];
+    let round_up = full_fraction.as_bytes()[decimals] >= b'5';
+
+    if !round_up {
+        return Ok(if decimals == 0 { integer_part.to_string() } else { format!("{}.{}", integer_part, kept) });
+    }
+
+    let rounded = if kept.is_empty() { 1 } else { kept.parse::<u128>().unwrap_or(0) + 1 };
+    let rounded_str = format!("{:0width$}", rounded, width = decimals);
+
+    if rounded_str.len() > decimals {
+        // Carried out of the fractional part entirely, e.g. 0.9996 -> 1.000
+        let carried_integer = integer_part + 1;
+        if decimals == 0 {
+            Ok(carried_integer.to_string())
+        } else {
+            Ok(format!("{}.{}", carried_integer, &rounded_str[rounded_str.len() - decimals..]))
+        }
+    } else {
+        Ok(format!("{}.{}", integer_part, rounded_str))
+    }
 }
 
-/// Format address with checksum encoding (EIP-55)
+/// Parse a decimal ether string (e.g. `"1.5"`) into the exact `Wei` it
+/// represents. The inverse of [`wei_to_ether_string`]: no floating point is
+/// involved, so large or high-precision balances round-trip exactly. Rejects
+/// strings with more than 18 fractional digits, since that's more precision
+/// than wei itself can represent.
+pub fn ether_string_to_wei(ether: &str) -> Result<Wei> {
+    let ether = ether.trim();
+    let (integer_str, fraction_str) = match ether.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (ether, ""),
+    };
+
+    if fraction_str.len() > WEI_PER_ETHER_DECIMALS as usize {
+        return Err(HyperSimError::validation_with_field(
+            "Ether string has more than 18 fractional digits",
+            "ether",
+        ));
+    }
+
+    let integer_str = if integer_str.is_empty() { "0" } else { integer_str };
+    let integer_part: u128 = integer_str.parse()
+        .map_err(|_| HyperSimError::validation_with_field("Invalid ether amount", "ether"))?;
+
+    let padded_fraction = format!("{:0<width$}", fraction_str, width = WEI_PER_ETHER_DECIMALS as usize);
+    let fractional_part: u128 = if padded_fraction.is_empty() {
+        0
+    } else {
+        padded_fraction.parse()
+            .map_err(|_| HyperSimError::validation_with_field("Invalid ether amount", "ether"))?
+    };
+
+    let wei_value = integer_part
+        .checked_mul(10u128.pow(WEI_PER_ETHER_DECIMALS))
+        .and_then(|whole| whole.checked_add(fractional_part))
+        .ok_or_else(|| HyperSimError::validation_with_field("Ether amount overflows u128 wei", "ether"))?;
+
+    Ok(Wei::new(wei_value.to_string()))
+}
+
+/// Format address with checksum encoding (EIP-55). See [`Address::checksum`]
+/// for the algorithm.
 pub fn checksum_address(address: &Address) -> String {
-    // Simplified checksum implementation
-    // In production, use a proper EIP-55 implementation
-    let addr = address.as_str().to_lowercase();
-    addr.chars()
-        .enumerate()
-        .map(|(i, c)| {
-            if i < 2 {
-                c // Keep "0x" as-is
-            } else if c.is_ascii_hexdigit() && c.is_ascii_alphabetic() {
-                // Simple alternating case for demo
-                if i % 2 == 0 { c.to_ascii_uppercase() } else { c }
-            } else {
-                c
-            }
-        })
-        .collect()
+    address.checksum()
+}
+
+/// Validate that `address`'s casing matches its EIP-55 checksum. An
+/// all-lowercase or all-uppercase address carries no checksum information
+/// and is always accepted; a mixed-case address must match
+/// [`checksum_address`] exactly, otherwise a `Validation` error naming the
+/// `address` field is returned.
+pub fn validate_checksum_address(address: &Address) -> Result<bool> {
+    let addr = address.as_str();
+    let hex_part = addr.trim_start_matches("0x");
+
+    if hex_part == hex_part.to_lowercase() || hex_part == hex_part.to_uppercase() {
+        return Ok(true);
+    }
+
+    if addr != checksum_address(address) {
+        return Err(HyperSimError::validation_with_field(
+            "Address does not match its EIP-55 checksum",
+            "address",
+        ));
+    }
+
+    Ok(true)
 }
 
 /// Format gas amount with units
@@ -132,6 +220,41 @@ mod tests {
         assert_eq!(formatted, "0.50");
     }
 
+    #[test]
+    fn test_wei_to_ether_exact_above_f64_precision() {
+        // 2^53 + 1 ether, expressed in wei: an f64-based formatter would round
+        // this to "9007199254740992.000000" (losing the final digit of ether)
+        let wei = Wei::new("9007199254740993000000000000000000");
+        let formatted = wei_to_ether_string(&wei, 6).unwrap();
+        assert_eq!(formatted, "9007199254740993.000000");
+    }
+
+    #[test]
+    fn test_wei_to_ether_rounds_and_carries() {
+        let wei = Wei::new("999999999999999999"); // 0.999999999999999999 ETH
+        assert_eq!(wei_to_ether_string(&wei, 4).unwrap(), "1.0000");
+        assert_eq!(wei_to_ether_string(&wei, 0).unwrap(), "1");
+
+        let wei = Wei::new("400000000000000000"); // 0.4 ETH
+        assert_eq!(wei_to_ether_string(&wei, 0).unwrap(), "0");
+    }
+
+    #[test]
+    fn test_ether_string_to_wei_round_trips() {
+        assert_eq!(ether_string_to_wei("1.5").unwrap(), Wei::new("1500000000000000000"));
+        assert_eq!(ether_string_to_wei("0.000000000000000001").unwrap(), Wei::new("1"));
+        assert_eq!(ether_string_to_wei("1000000").unwrap(), Wei::new("1000000000000000000000000"));
+
+        let wei = Wei::new("9007199254740993000000000000000000");
+        let ether = wei_to_ether_string(&wei, 18).unwrap();
+        assert_eq!(ether_string_to_wei(&ether).unwrap(), wei);
+    }
+
+    #[test]
+    fn test_ether_string_to_wei_rejects_excess_precision() {
+        assert!(ether_string_to_wei("1.0000000000000000001").is_err());
+    }
+
     #[test]
     fn test_gas_formatting() {
         assert_eq!(format_gas_with_units("21000").unwrap(), "21.0K gas");
@@ -171,4 +294,22 @@ mod tests {
         assert_eq!(format_percentage(0.1234, 2), "12.34%");
         assert_eq!(format_percentage(0.5, 1), "50.0%");
     }
+
+    #[test]
+    fn test_checksum_address_matches_eip55() {
+        let address = Address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".to_string());
+        assert_eq!(checksum_address(&address), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    }
+
+    #[test]
+    fn test_validate_checksum_address() {
+        let checksummed = Address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string());
+        assert!(validate_checksum_address(&checksummed).unwrap());
+
+        let lowercase = Address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".to_string());
+        assert!(validate_checksum_address(&lowercase).unwrap());
+
+        let mismatched = Address("0x5aAeb6053f3E94C9b9A09f33669435E7Ef1BeAed".to_string());
+        assert!(validate_checksum_address(&mismatched).is_err());
+    }
 }
@@ -1,6 +1,6 @@
 //! Validation utilities for input data
 
-use crate::types::{Address, Hash, Wei, TransactionRequest, Network};
+use crate::types::{Address, Hash, Wei, TransactionRequest, Network, StateOverrides, StateChange, StateChangeType};
 use crate::error::{HyperSimError, Result};
 
 /// Validate an Ethereum address
@@ -31,6 +31,33 @@ pub fn validate_address(address: &str) -> Result<()> {
     Ok(())
 }
 
+/// Render `address` in its EIP-55 mixed-case checksummed form. See
+/// `Address::checksum` for the algorithm.
+pub fn to_checksum_address(address: &str) -> String {
+    Address(address.to_string()).checksum()
+}
+
+/// Validate an address's EIP-55 checksum. All-lowercase and all-uppercase
+/// addresses carry no checksum and always pass; a mixed-case address must
+/// match `to_checksum_address` exactly.
+pub fn validate_address_checksum(address: &str) -> Result<()> {
+    validate_address(address)?;
+
+    let hex_part = &address[2..];
+    if hex_part == hex_part.to_lowercase() || hex_part == hex_part.to_uppercase() {
+        return Ok(());
+    }
+
+    if address != to_checksum_address(address) {
+        return Err(HyperSimError::validation_with_field(
+            "Address does not match its EIP-55 checksum",
+            "address"
+        ));
+    }
+
+    Ok(())
+}
+
 /// Validate a transaction hash
 pub fn validate_hash(hash: &str) -> Result<()> {
     if hash.len() != 66 {
@@ -184,6 +211,70 @@ pub fn validate_transaction_request(tx: &TransactionRequest) -> Result<()> {
         }
     }
 
+    // EIP-2930 access list validation
+    if let Some(ref access_list) = tx.access_list {
+        if !matches!(tx.tx_type, Some(1) | Some(2)) {
+            return Err(HyperSimError::validation_with_field(
+                "access_list requires tx_type 1 (EIP-2930) or 2 (EIP-1559)",
+                "tx_type"
+            ));
+        }
+
+        for entry in access_list {
+            validate_address(&entry.address.0)?;
+            for key in &entry.storage_keys {
+                validate_hash(&key.0)?;
+            }
+        }
+    } else if tx.tx_type == Some(1) {
+        return Err(HyperSimError::validation_with_field(
+            "EIP-2930 transactions (tx_type 1) require a non-empty access_list",
+            "access_list"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Look up whether `address`'s account code is known to be non-empty: first
+/// via simulation state overrides, then falling back to any `CodeChange`
+/// already recorded for it by an earlier transaction in the same bundle
+/// (e.g. a prior CREATE that deployed into the address).
+fn address_has_code(address: &Address, state_overrides: &StateOverrides, prior_changes: &[StateChange]) -> bool {
+    if let Some(account) = state_overrides.accounts.get(address) {
+        if let Some(ref code) = account.code {
+            return !matches!(code.as_str(), "" | "0x");
+        }
+    }
+
+    prior_changes.iter().any(|change| {
+        change.address == *address
+            && matches!(change.change_type, StateChangeType::CodeChange)
+            && change
+                .after
+                .as_ref()
+                .and_then(|after| after.get("code"))
+                .and_then(|v| v.as_str())
+                .map_or(false, |code| !matches!(code, "" | "0x"))
+    })
+}
+
+/// Enforce EIP-3607: reject a transaction whose `from` account has contract
+/// code, since such an account can never originate a transaction on a
+/// post-London chain. Checks both simulation state overrides and any
+/// `CodeChange` recorded by earlier transactions in the same bundle.
+pub fn validate_sender_not_contract(
+    tx: &TransactionRequest,
+    state_overrides: &StateOverrides,
+    prior_changes: &[StateChange],
+) -> Result<()> {
+    if address_has_code(&tx.from, state_overrides, prior_changes) {
+        return Err(HyperSimError::validation_with_field(
+            "EIP-3607: transaction sender account has contract code and cannot originate a transaction",
+            "from"
+        ));
+    }
+
     Ok(())
 }
 
@@ -306,6 +397,108 @@ mod tests {
         assert!(validate_hex_data("0x123g").is_err()); // Invalid hex character
     }
 
+    #[test]
+    fn test_access_list_validation() {
+        use crate::types::simulation::AccessListEntry;
+
+        let entry = AccessListEntry {
+            address: Address::new("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1").unwrap(),
+            storage_keys: vec![Hash::new(
+                "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
+            ).unwrap()],
+        };
+
+        let mut tx = TransactionRequest::builder()
+            .from("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1")
+            .unwrap()
+            .tx_type(1)
+            .access_list(vec![entry])
+            .build()
+            .unwrap();
+        assert!(validate_transaction_request(&tx).is_ok());
+
+        // tx_type 1 without an access list is rejected
+        tx.access_list = None;
+        assert!(validate_transaction_request(&tx).is_err());
+
+        // access_list present but tx_type 0 (legacy) is rejected
+        tx.tx_type = Some(0);
+        tx.access_list = Some(vec![]);
+        assert!(validate_transaction_request(&tx).is_err());
+    }
+
+    #[test]
+    fn test_to_checksum_address() {
+        // EIP-55 test vectors from the spec
+        assert_eq!(
+            to_checksum_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(
+            to_checksum_address("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359"),
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+        );
+        assert_eq!(
+            to_checksum_address("0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB"),
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB"
+        );
+    }
+
+    #[test]
+    fn test_validate_address_checksum() {
+        // Correctly checksummed
+        assert!(validate_address_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_ok());
+
+        // All-lowercase / all-uppercase carry no checksum and are accepted
+        assert!(validate_address_checksum("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").is_ok());
+        assert!(validate_address_checksum("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED").is_ok());
+
+        // Genuinely mismatched mixed-case checksum is rejected
+        assert!(validate_address_checksum("0x5aAeb6053f3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    }
+
+    #[test]
+    fn test_validate_sender_not_contract() {
+        use crate::types::AccountOverride;
+        use std::collections::HashMap;
+
+        let tx = TransactionRequest::builder()
+            .from("0x742d35Cc6563C7dE26d1e0d7Ad8e8c61c94c7De1")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut overrides = StateOverrides::default();
+        assert!(validate_sender_not_contract(&tx, &overrides, &[]).is_ok());
+
+        overrides.accounts.insert(
+            tx.from.clone(),
+            AccountOverride {
+                code: Some("0x600160005260206000f3".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(validate_sender_not_contract(&tx, &overrides, &[]).is_err());
+
+        // An empty-code override (EOA) is not rejected
+        overrides.accounts.insert(
+            tx.from.clone(),
+            AccountOverride { code: Some("0x".to_string()), ..Default::default() },
+        );
+        assert!(validate_sender_not_contract(&tx, &overrides, &[]).is_ok());
+
+        // Code deployed by an earlier bundle transaction is also caught
+        let mut after = HashMap::new();
+        after.insert("code".to_string(), serde_json::json!("0x600160005260206000f3"));
+        let prior_changes = vec![StateChange {
+            address: tx.from.clone(),
+            change_type: StateChangeType::CodeChange,
+            before: None,
+            after: Some(after),
+        }];
+        assert!(validate_sender_not_contract(&tx, &StateOverrides::default(), &prior_changes).is_err());
+    }
+
     #[test]
     fn test_gas_parameters_validation() {
         // Valid parameters
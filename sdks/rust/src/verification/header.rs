@@ -0,0 +1,118 @@
+//! Tracks a chain of trusted block headers via verified parent-hash links,
+//! the way a light client advances its checkpoint without re-verifying
+//! consensus from genesis on every query.
+
+use crate::error::{HyperSimError, Result};
+use crate::types::HyperEVMBlock;
+
+/// Tracks the most recent header the SDK trusts, advancing it only when a
+/// new header is shown to extend it via a verified parent-hash link.
+#[derive(Debug, Default)]
+pub struct HeaderTracker {
+    checkpoint: Option<HyperEVMBlock>,
+}
+
+impl HeaderTracker {
+    /// Create a tracker with no checkpoint; the first header it sees via
+    /// [`Self::verify_and_advance`] is adopted outright as the starting point.
+    pub fn new() -> Self {
+        Self { checkpoint: None }
+    }
+
+    /// Seed (or override) the checkpoint with an externally supplied trusted
+    /// header — e.g. one obtained out-of-band from a consensus light client.
+    pub fn set_trusted_header(&mut self, header: HyperEVMBlock) {
+        self.checkpoint = Some(header);
+    }
+
+    /// The currently tracked checkpoint header, if any
+    pub fn checkpoint(&self) -> Option<&HyperEVMBlock> {
+        self.checkpoint.as_ref()
+    }
+
+    /// Verify `header` extends the tracked checkpoint by a single verified
+    /// parent-hash link, then advance the checkpoint to it. With no
+    /// checkpoint set yet, `header` is adopted as the starting point.
+    pub fn verify_and_advance(&mut self, header: HyperEVMBlock) -> Result<()> {
+        if let Some(ref checkpoint) = self.checkpoint {
+            if header.parent_hash != checkpoint.hash || header.number != checkpoint.number + 1 {
+                return Err(HyperSimError::validation(
+                    "Header does not extend the tracked checkpoint via a parent-hash link"
+                ));
+            }
+        }
+
+        self.checkpoint = Some(header);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Address, BlockType, Hash};
+
+    fn block(number: u64, hash: &str, parent_hash: &str) -> HyperEVMBlock {
+        HyperEVMBlock {
+            hash: Hash::new(hash).unwrap(),
+            parent_hash: Hash::new(parent_hash).unwrap(),
+            number,
+            timestamp: 0,
+            block_type: BlockType::Fast,
+            gas_limit: "30000000".to_string(),
+            gas_used: "0".to_string(),
+            difficulty: "0".to_string(),
+            miner: Address::new("0x0000000000000000000000000000000000000000").unwrap(),
+            extra_data: "0x".to_string(),
+            state_root: Hash::new(format!("0x{}", "11".repeat(32))).unwrap(),
+            transactions_root: Hash::new(format!("0x{}", "22".repeat(32))).unwrap(),
+            receipts_root: Hash::new(format!("0x{}", "33".repeat(32))).unwrap(),
+            logs_bloom: "0x0".repeat(512),
+            transaction_hashes: Vec::new(),
+            uncles: Vec::new(),
+            base_fee_per_gas: None,
+        }
+    }
+
+    const GENESIS: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+    const HASH_1: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+    const HASH_2: &str = "0x0000000000000000000000000000000000000000000000000000000000000002";
+
+    #[test]
+    fn test_first_header_is_adopted_without_a_prior_checkpoint() {
+        let mut tracker = HeaderTracker::new();
+        assert!(tracker.checkpoint().is_none());
+
+        tracker.verify_and_advance(block(100, HASH_1, GENESIS)).unwrap();
+        assert_eq!(tracker.checkpoint().unwrap().number, 100);
+    }
+
+    #[test]
+    fn test_advances_on_verified_parent_hash_link() {
+        let mut tracker = HeaderTracker::new();
+        tracker.verify_and_advance(block(100, HASH_1, GENESIS)).unwrap();
+        tracker.verify_and_advance(block(101, HASH_2, HASH_1)).unwrap();
+
+        assert_eq!(tracker.checkpoint().unwrap().number, 101);
+        assert_eq!(tracker.checkpoint().unwrap().hash.as_str(), HASH_2);
+    }
+
+    #[test]
+    fn test_rejects_header_with_mismatched_parent_hash() {
+        let mut tracker = HeaderTracker::new();
+        tracker.verify_and_advance(block(100, HASH_1, GENESIS)).unwrap();
+
+        let result = tracker.verify_and_advance(block(101, HASH_2, GENESIS));
+        assert!(result.is_err());
+        assert_eq!(tracker.checkpoint().unwrap().number, 100);
+    }
+
+    #[test]
+    fn test_rejects_header_with_skipped_block_number() {
+        let mut tracker = HeaderTracker::new();
+        tracker.verify_and_advance(block(100, HASH_1, GENESIS)).unwrap();
+
+        let result = tracker.verify_and_advance(block(105, HASH_2, HASH_1));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,36 @@
+//! Trustless verification of simulation results.
+//!
+//! When `HyperSimConfig::trustless` is enabled, the SDK no longer takes an
+//! RPC endpoint's claims about account/storage state at face value: it
+//! verifies the Merkle-Patricia proofs behind an `eth_getProof` response
+//! against a block's `stateRoot`, and verifies that block header against a
+//! checkpoint the SDK tracks independently, the same way a light client does.
+
+pub mod conformance;
+pub mod header;
+pub mod proof;
+
+pub use conformance::{run_fixture_suite, ConformanceReport, FixtureFailure};
+pub use header::HeaderTracker;
+pub use proof::{verify_account_proof, verify_account_proof_or_err, AccountProof, StorageProofEntry};
+
+use serde::{Deserialize, Serialize};
+
+/// Outcome of attempting to trustlessly verify a simulation result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerificationStatus {
+    /// Every touched account/storage proof verified against a trusted header
+    Verified,
+    /// Trustless verification was not attempted (feature disabled, or no
+    /// trusted checkpoint to verify the block header against)
+    Unverified,
+    /// A proof failed to verify: the claimed state does not match what the
+    /// Merkle-Patricia trie actually commits to
+    ProofFailed,
+}
+
+impl Default for VerificationStatus {
+    fn default() -> Self {
+        VerificationStatus::Unverified
+    }
+}
@@ -0,0 +1,603 @@
+//! Merkle-Patricia-trie proof verification for `eth_getProof` responses.
+//!
+//! Implements just enough RLP decoding and trie-node traversal to walk an
+//! `accountProof`/`storageProof` from a trusted `stateRoot` down to the leaf
+//! that claims a given balance/nonce/storage value, the same way a light
+//! client verifies state without trusting the node that served it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{HyperSimError, Result};
+use crate::utils::abi::keccak256_hash;
+
+/// A single `eth_getProof` storage slot entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageProofEntry {
+    /// Storage slot key (hex)
+    pub key: String,
+    /// Claimed storage value (hex)
+    pub value: String,
+    /// RLP-encoded trie nodes (hex) from the storage root down to this slot
+    pub proof: Vec<String>,
+}
+
+/// An `eth_getProof` response for one account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountProof {
+    /// Account address (hex)
+    pub address: String,
+    /// Claimed account balance (hex)
+    pub balance: String,
+    /// Claimed account nonce (hex)
+    pub nonce: String,
+    /// RLP-encoded trie nodes (hex) from the state root down to this account
+    #[serde(rename = "accountProof")]
+    pub account_proof: Vec<String>,
+    /// Storage slot proofs requested alongside the account
+    #[serde(rename = "storageProof", default)]
+    pub storage_proof: Vec<StorageProofEntry>,
+}
+
+/// A decoded and RLP-parsed trie node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RlpNode {
+    Bytes(Vec<u8>),
+    List(Vec<RlpNode>),
+}
+
+/// Verify `proof.account_proof` against `state_root`, then verify every
+/// entry in `proof.storage_proof` against the storage root recovered from
+/// the (now verified) account leaf — never the `storageHash` the server
+/// claims separately. Any malformed or mismatched proof resolves to `false`
+/// rather than propagating an error, since the input is untrusted by
+/// definition.
+pub fn verify_account_proof(state_root: &str, proof: &AccountProof) -> bool {
+    try_verify_account_proof(state_root, proof).unwrap_or(false)
+}
+
+/// The hard-failing counterpart to [`verify_account_proof`]: instead of
+/// folding a bad or malformed proof into `false`, this returns
+/// [`HyperSimError::verification`] describing why the proof didn't
+/// reconstruct `state_root`, for callers that want to reject a state claim
+/// outright rather than record a soft [`crate::verification::VerificationStatus`].
+pub fn verify_account_proof_or_err(state_root: &str, proof: &AccountProof) -> Result<()> {
+    match try_verify_account_proof(state_root, proof) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(HyperSimError::verification(format!(
+            "Proof for account {} does not reconstruct state root {}",
+            proof.address, state_root
+        ))),
+        Err(error) => Err(HyperSimError::verification(format!(
+            "Proof for account {} is malformed: {}",
+            proof.address, error
+        ))),
+    }
+}
+
+fn try_verify_account_proof(state_root: &str, proof: &AccountProof) -> Result<bool> {
+    let root = decode_hex(state_root)?;
+    let address = decode_hex(&proof.address)?;
+    let key = keccak256_hash(&address);
+
+    let nodes = proof
+        .account_proof
+        .iter()
+        .map(|n| decode_hex(n))
+        .collect::<Result<Vec<_>>>()?;
+
+    let leaf = match verify_trie_proof(&root, &key, &nodes)? {
+        Some(leaf) => leaf,
+        None => return Ok(false),
+    };
+
+    let account = decode_account_leaf(&leaf)?;
+
+    let claimed_balance = decode_hex(&proof.balance)?;
+    let claimed_nonce = decode_hex(&proof.nonce)?;
+
+    if normalize(&account.balance) != normalize(&claimed_balance)
+        || normalize(&account.nonce) != normalize(&claimed_nonce)
+    {
+        return Ok(false);
+    }
+
+    for entry in &proof.storage_proof {
+        if !verify_storage_entry(&account.storage_root, entry)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+struct VerifiedAccount {
+    nonce: Vec<u8>,
+    balance: Vec<u8>,
+    storage_root: Vec<u8>,
+}
+
+fn verify_storage_entry(storage_root: &[u8], entry: &StorageProofEntry) -> Result<bool> {
+    let slot = left_pad_32(&decode_hex(&entry.key)?);
+    let key = keccak256_hash(&slot);
+    let claimed_value = normalize(&decode_hex(&entry.value)?);
+
+    let nodes = entry
+        .proof
+        .iter()
+        .map(|n| decode_hex(n))
+        .collect::<Result<Vec<_>>>()?;
+
+    match verify_trie_proof(storage_root, &key, &nodes)? {
+        None => Ok(claimed_value.is_empty()),
+        Some(leaf) => {
+            let (value, rest) = decode_rlp(&leaf)?;
+            if !rest.is_empty() {
+                return Err(HyperSimError::validation("Trailing bytes after storage leaf value"));
+            }
+            let raw = as_bytes(&value)?;
+            Ok(normalize(&raw) == claimed_value)
+        }
+    }
+}
+
+/// Walk `nodes` from `root` along the nibble path of `key`, verifying each
+/// node's hash (or inline bytes, for nodes smaller than 32 bytes) matches the
+/// reference held by its parent. Returns the raw leaf value on success,
+/// `None` if the proof demonstrates the key is absent, and `Err` if a node
+/// fails to decode or a hash doesn't match what the parent claimed.
+///
+/// Shared by both EVM `eth_getProof` verification and HyperCore's
+/// [`crate::types::hypercore::StateProof::verify`], since both walk the same
+/// Merkle-Patricia structure.
+pub(crate) fn verify_trie_proof(root: &[u8], key: &[u8], nodes: &[Vec<u8>]) -> Result<Option<Vec<u8>>> {
+    let mut expected_ref = root.to_vec();
+    let nibbles = to_nibbles(key);
+    let mut consumed = 0usize;
+
+    for node_bytes in nodes {
+        verify_reference(&expected_ref, node_bytes)?;
+
+        let (node, rest) = decode_rlp(node_bytes)?;
+        if !rest.is_empty() {
+            return Err(HyperSimError::validation_with_field("Trailing bytes after trie node", "key_path"));
+        }
+        let items = match node {
+            RlpNode::List(items) => items,
+            RlpNode::Bytes(_) => {
+                return Err(HyperSimError::validation_with_field("Expected RLP list for trie node", "key_path"))
+            }
+        };
+
+        match items.len() {
+            17 => {
+                if consumed == nibbles.len() {
+                    let value = as_bytes(&items[16])?;
+                    return Ok(if value.is_empty() { None } else { Some(value) });
+                }
+
+                let branch = nibbles[consumed] as usize;
+                let next_ref = as_bytes(&items[branch])?;
+                if next_ref.is_empty() {
+                    return Ok(None);
+                }
+                expected_ref = next_ref;
+                consumed += 1;
+            }
+            2 => {
+                let (path, is_leaf) = decode_hex_prefix(&as_bytes(&items[0])?);
+                let remaining = &nibbles[consumed..];
+                if remaining.len() < path.len() || remaining[..path.len()] != path[..] {
+                    return Ok(None);
+                }
+                consumed += path.len();
+
+                if is_leaf {
+                    if consumed != nibbles.len() {
+                        return Err(HyperSimError::validation_with_field(
+                            "Leaf node did not consume the full key path",
+                            "key_path",
+                        ));
+                    }
+                    return Ok(Some(as_bytes(&items[1])?));
+                }
+
+                let next_ref = as_bytes(&items[1])?;
+                if next_ref.is_empty() {
+                    return Ok(None);
+                }
+                expected_ref = next_ref;
+            }
+            _ => return Err(HyperSimError::validation_with_field("Unexpected trie node arity", "key_path")),
+        }
+    }
+
+    Err(HyperSimError::validation_with_field(
+        "Proof ended before reaching a leaf or branch value",
+        "key_path",
+    ))
+}
+
+/// A trie node is referenced by its keccak256 hash once its RLP encoding is
+/// 32 bytes or longer, and embedded inline (compared byte-for-byte) otherwise.
+fn verify_reference(expected_ref: &[u8], node_bytes: &[u8]) -> Result<()> {
+    if expected_ref.len() == 32 {
+        if keccak256_hash(node_bytes) != expected_ref[..] {
+            return Err(HyperSimError::validation_with_field(
+                "Trie node hash does not match the expected reference",
+                "node_hash",
+            ));
+        }
+    } else if expected_ref != node_bytes {
+        return Err(HyperSimError::validation_with_field(
+            "Embedded trie node does not match the expected reference",
+            "node_hash",
+        ));
+    }
+    Ok(())
+}
+
+fn decode_account_leaf(value: &[u8]) -> Result<VerifiedAccount> {
+    let (node, rest) = decode_rlp(value)?;
+    if !rest.is_empty() {
+        return Err(HyperSimError::validation("Trailing bytes after account leaf"));
+    }
+
+    let items = match node {
+        RlpNode::List(items) if items.len() == 4 => items,
+        _ => return Err(HyperSimError::validation("Account leaf must be a 4-item RLP list")),
+    };
+
+    Ok(VerifiedAccount {
+        nonce: as_bytes(&items[0])?,
+        balance: as_bytes(&items[1])?,
+        storage_root: as_bytes(&items[2])?,
+    })
+}
+
+pub(crate) fn as_bytes(node: &RlpNode) -> Result<Vec<u8>> {
+    match node {
+        RlpNode::Bytes(bytes) => Ok(bytes.clone()),
+        RlpNode::List(_) => Err(HyperSimError::validation("Expected RLP string, found a list")),
+    }
+}
+
+/// Strip leading zero bytes so differently-padded big-endian integers compare equal
+pub(crate) fn normalize(bytes: &[u8]) -> Vec<u8> {
+    let trimmed: Vec<u8> = bytes.iter().skip_while(|b| **b == 0).copied().collect();
+    trimmed
+}
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    let mut nibbles = Vec::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        nibbles.push(b >> 4);
+        nibbles.push(b & 0x0f);
+    }
+    nibbles
+}
+
+/// Decode a leaf/extension node's hex-prefix encoded path, per the MPT spec:
+/// the high nibble of the first byte signals leaf-vs-extension and
+/// odd-vs-even length, with an optional padding nibble for even-length paths.
+fn decode_hex_prefix(bytes: &[u8]) -> (Vec<u8>, bool) {
+    if bytes.is_empty() {
+        return (Vec::new(), false);
+    }
+
+    let first = bytes[0];
+    let flag = first >> 4;
+    let is_leaf = flag == 2 || flag == 3;
+    let is_odd = flag == 1 || flag == 3;
+
+    let mut nibbles = Vec::new();
+    if is_odd {
+        nibbles.push(first & 0x0f);
+    }
+    for b in &bytes[1..] {
+        nibbles.push(b >> 4);
+        nibbles.push(b & 0x0f);
+    }
+
+    (nibbles, is_leaf)
+}
+
+fn left_pad_32(bytes: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let copy_len = bytes.len().min(32);
+    out[32 - copy_len..].copy_from_slice(&bytes[bytes.len() - copy_len..]);
+    out
+}
+
+pub(crate) fn decode_hex(s: &str) -> Result<Vec<u8>> {
+    let s = s.trim_start_matches("0x");
+    let s = if s.len() % 2 == 1 { format!("0{s}") } else { s.to_string() };
+    hex::decode(s).map_err(|_| HyperSimError::validation("Invalid hex in proof response"))
+}
+
+/// Minimal recursive-length-prefix decoder: only what's needed to walk trie
+/// nodes (nested strings and lists), not a general-purpose RLP codec.
+pub(crate) fn decode_rlp(input: &[u8]) -> Result<(RlpNode, &[u8])> {
+    let first = *input.first().ok_or_else(|| HyperSimError::validation("Empty RLP input"))?;
+
+    match first {
+        0x00..=0x7f => Ok((RlpNode::Bytes(vec![first]), &input[1..])),
+        0x80..=0xb7 => {
+            let len = (first - 0x80) as usize;
+            let data = slice(input, 1, len)?;
+            Ok((RlpNode::Bytes(data.to_vec()), &input[1 + len..]))
+        }
+        0xb8..=0xbf => {
+            let len_of_len = (first - 0xb7) as usize;
+            let len = be_bytes_to_usize(slice(input, 1, len_of_len)?);
+            let data = slice(input, 1 + len_of_len, len)?;
+            Ok((RlpNode::Bytes(data.to_vec()), &input[1 + len_of_len + len..]))
+        }
+        0xc0..=0xf7 => {
+            let len = (first - 0xc0) as usize;
+            let body = slice(input, 1, len)?;
+            Ok((RlpNode::List(decode_rlp_list(body)?), &input[1 + len..]))
+        }
+        0xf8..=0xff => {
+            let len_of_len = (first - 0xf7) as usize;
+            let len = be_bytes_to_usize(slice(input, 1, len_of_len)?);
+            let body = slice(input, 1 + len_of_len, len)?;
+            Ok((RlpNode::List(decode_rlp_list(body)?), &input[1 + len_of_len + len..]))
+        }
+    }
+}
+
+fn decode_rlp_list(mut body: &[u8]) -> Result<Vec<RlpNode>> {
+    let mut items = Vec::new();
+    while !body.is_empty() {
+        let (item, rest) = decode_rlp(body)?;
+        items.push(item);
+        body = rest;
+    }
+    Ok(items)
+}
+
+fn slice(input: &[u8], start: usize, len: usize) -> Result<&[u8]> {
+    input
+        .get(start..start + len)
+        .ok_or_else(|| HyperSimError::validation("Truncated RLP input"))
+}
+
+fn be_bytes_to_usize(bytes: &[u8]) -> usize {
+    bytes.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_rlp_bytes(data: &[u8]) -> Vec<u8> {
+        if data.len() == 1 && data[0] < 0x80 {
+            return data.to_vec();
+        }
+        let mut out = encode_length(data.len(), 0x80);
+        out.extend_from_slice(data);
+        out
+    }
+
+    fn encode_rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload: Vec<u8> = items.concat();
+        let mut out = encode_length(payload.len(), 0xc0);
+        out.extend_from_slice(&payload);
+        out
+    }
+
+    fn encode_length(len: usize, offset: u8) -> Vec<u8> {
+        if len < 56 {
+            vec![offset + len as u8]
+        } else {
+            let len_bytes = len.to_be_bytes();
+            let len_bytes = len_bytes.iter().skip_while(|b| **b == 0).copied().collect::<Vec<_>>();
+            let mut out = vec![offset + 55 + len_bytes.len() as u8];
+            out.extend_from_slice(&len_bytes);
+            out
+        }
+    }
+
+    fn encode_account(nonce: u64, balance: u64, storage_root: &[u8; 32], code_hash: &[u8; 32]) -> Vec<u8> {
+        encode_rlp_list(&[
+            encode_rlp_bytes(&normalize(&nonce.to_be_bytes())),
+            encode_rlp_bytes(&normalize(&balance.to_be_bytes())),
+            encode_rlp_bytes(storage_root),
+            encode_rlp_bytes(code_hash),
+        ])
+    }
+
+    /// Build the single-leaf trie that results when there's exactly one
+    /// account/slot in the whole trie: the root *is* the leaf, with an
+    /// encoded path covering the entire (keccak'd) key.
+    fn single_leaf_trie(key: &[u8; 32], value: Vec<u8>) -> (Vec<u8>, Vec<u8>) {
+        let mut path = vec![0x20u8]; // leaf flag, even-length padding nibble
+        path.extend_from_slice(key);
+
+        let leaf = encode_rlp_list(&[encode_rlp_bytes(&path), encode_rlp_bytes(&value)]);
+        let root = keccak256_hash(&leaf).to_vec();
+        (root, leaf)
+    }
+
+    #[test]
+    fn test_verify_account_proof_accepts_matching_leaf() {
+        let address = "0x742d35cc6563c7de26d1e0d7ad8e8c61c94c7de1";
+        let address_bytes = decode_hex(address).unwrap();
+        let key = keccak256_hash(&address_bytes);
+
+        let storage_root = [0u8; 32];
+        let code_hash = [0u8; 32];
+        let account_value = encode_account(4, 1_000_000, &storage_root, &code_hash);
+        let (root, leaf) = single_leaf_trie(&key, account_value);
+
+        let proof = AccountProof {
+            address: address.to_string(),
+            balance: "0xf4240".to_string(), // 1_000_000
+            nonce: "0x4".to_string(),
+            account_proof: vec![format!("0x{}", hex::encode(&leaf))],
+            storage_proof: vec![],
+        };
+
+        assert!(verify_account_proof(&format!("0x{}", hex::encode(&root)), &proof));
+    }
+
+    #[test]
+    fn test_verify_account_proof_rejects_tampered_balance() {
+        let address = "0x742d35cc6563c7de26d1e0d7ad8e8c61c94c7de1";
+        let address_bytes = decode_hex(address).unwrap();
+        let key = keccak256_hash(&address_bytes);
+
+        let account_value = encode_account(4, 1_000_000, &[0u8; 32], &[0u8; 32]);
+        let (root, leaf) = single_leaf_trie(&key, account_value);
+
+        let proof = AccountProof {
+            address: address.to_string(),
+            balance: "0x1".to_string(), // claimed balance doesn't match the leaf
+            nonce: "0x4".to_string(),
+            account_proof: vec![format!("0x{}", hex::encode(&leaf))],
+            storage_proof: vec![],
+        };
+
+        assert!(!verify_account_proof(&format!("0x{}", hex::encode(&root)), &proof));
+    }
+
+    #[test]
+    fn test_verify_account_proof_or_err_reports_mismatch() {
+        let address = "0x742d35cc6563c7de26d1e0d7ad8e8c61c94c7de1";
+        let address_bytes = decode_hex(address).unwrap();
+        let key = keccak256_hash(&address_bytes);
+
+        let account_value = encode_account(4, 1_000_000, &[0u8; 32], &[0u8; 32]);
+        let (root, leaf) = single_leaf_trie(&key, account_value);
+
+        let proof = AccountProof {
+            address: address.to_string(),
+            balance: "0x1".to_string(), // claimed balance doesn't match the leaf
+            nonce: "0x4".to_string(),
+            account_proof: vec![format!("0x{}", hex::encode(&leaf))],
+            storage_proof: vec![],
+        };
+
+        let error = verify_account_proof_or_err(&format!("0x{}", hex::encode(&root)), &proof).unwrap_err();
+        assert!(matches!(error, HyperSimError::Verification { .. }));
+    }
+
+    #[test]
+    fn test_verify_account_proof_or_err_accepts_matching_leaf() {
+        let address = "0x742d35cc6563c7de26d1e0d7ad8e8c61c94c7de1";
+        let address_bytes = decode_hex(address).unwrap();
+        let key = keccak256_hash(&address_bytes);
+
+        let account_value = encode_account(4, 1_000_000, &[0u8; 32], &[0u8; 32]);
+        let (root, leaf) = single_leaf_trie(&key, account_value);
+
+        let proof = AccountProof {
+            address: address.to_string(),
+            balance: "0xf4240".to_string(),
+            nonce: "0x4".to_string(),
+            account_proof: vec![format!("0x{}", hex::encode(&leaf))],
+            storage_proof: vec![],
+        };
+
+        assert!(verify_account_proof_or_err(&format!("0x{}", hex::encode(&root)), &proof).is_ok());
+    }
+
+    #[test]
+    fn test_verify_account_proof_rejects_wrong_root() {
+        let address = "0x742d35cc6563c7de26d1e0d7ad8e8c61c94c7de1";
+        let address_bytes = decode_hex(address).unwrap();
+        let key = keccak256_hash(&address_bytes);
+
+        let account_value = encode_account(4, 1_000_000, &[0u8; 32], &[0u8; 32]);
+        let (_root, leaf) = single_leaf_trie(&key, account_value);
+        let wrong_root = [0xabu8; 32];
+
+        let proof = AccountProof {
+            address: address.to_string(),
+            balance: "0xf4240".to_string(),
+            nonce: "0x4".to_string(),
+            account_proof: vec![format!("0x{}", hex::encode(&leaf))],
+            storage_proof: vec![],
+        };
+
+        assert!(!verify_account_proof(&format!("0x{}", hex::encode(wrong_root)), &proof));
+    }
+
+    #[test]
+    fn test_verify_storage_proof_against_recovered_storage_root() {
+        let address = "0x742d35cc6563c7de26d1e0d7ad8e8c61c94c7de1";
+        let address_bytes = decode_hex(address).unwrap();
+        let account_key = keccak256_hash(&address_bytes);
+
+        let slot_key_raw = left_pad_32(&decode_hex("0x1").unwrap());
+        let storage_trie_key = keccak256_hash(&slot_key_raw);
+        let storage_value = encode_rlp_bytes(&normalize(&42u64.to_be_bytes()));
+        let (storage_root, storage_leaf) = single_leaf_trie(&storage_trie_key, storage_value);
+
+        let mut storage_root_arr = [0u8; 32];
+        storage_root_arr.copy_from_slice(&storage_root);
+
+        let account_value = encode_account(0, 0, &storage_root_arr, &[0u8; 32]);
+        let (account_root, account_leaf) = single_leaf_trie(&account_key, account_value);
+
+        let proof = AccountProof {
+            address: address.to_string(),
+            balance: "0x0".to_string(),
+            nonce: "0x0".to_string(),
+            account_proof: vec![format!("0x{}", hex::encode(&account_leaf))],
+            storage_proof: vec![StorageProofEntry {
+                key: "0x1".to_string(),
+                value: "0x2a".to_string(), // 42
+                proof: vec![format!("0x{}", hex::encode(&storage_leaf))],
+            }],
+        };
+
+        assert!(verify_account_proof(&format!("0x{}", hex::encode(&account_root)), &proof));
+    }
+
+    #[test]
+    fn test_verify_storage_proof_rejects_tampered_value() {
+        let address = "0x742d35cc6563c7de26d1e0d7ad8e8c61c94c7de1";
+        let address_bytes = decode_hex(address).unwrap();
+        let account_key = keccak256_hash(&address_bytes);
+
+        let slot_key_raw = left_pad_32(&decode_hex("0x1").unwrap());
+        let storage_trie_key = keccak256_hash(&slot_key_raw);
+        let storage_value = encode_rlp_bytes(&normalize(&42u64.to_be_bytes()));
+        let (storage_root, storage_leaf) = single_leaf_trie(&storage_trie_key, storage_value);
+
+        let mut storage_root_arr = [0u8; 32];
+        storage_root_arr.copy_from_slice(&storage_root);
+
+        let account_value = encode_account(0, 0, &storage_root_arr, &[0u8; 32]);
+        let (account_root, account_leaf) = single_leaf_trie(&account_key, account_value);
+
+        let proof = AccountProof {
+            address: address.to_string(),
+            balance: "0x0".to_string(),
+            nonce: "0x0".to_string(),
+            account_proof: vec![format!("0x{}", hex::encode(&account_leaf))],
+            storage_proof: vec![StorageProofEntry {
+                key: "0x1".to_string(),
+                value: "0x1".to_string(), // doesn't match the leaf's value of 42
+                proof: vec![format!("0x{}", hex::encode(&storage_leaf))],
+            }],
+        };
+
+        assert!(!verify_account_proof(&format!("0x{}", hex::encode(&account_root)), &proof));
+    }
+
+    #[test]
+    fn test_decode_rlp_roundtrip() {
+        let nested = encode_rlp_list(&[encode_rlp_bytes(b"cat"), encode_rlp_bytes(b"dog")]);
+        let (decoded, rest) = decode_rlp(&nested).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(
+            decoded,
+            RlpNode::List(vec![
+                RlpNode::Bytes(b"cat".to_vec()),
+                RlpNode::Bytes(b"dog".to_vec()),
+            ])
+        );
+    }
+}
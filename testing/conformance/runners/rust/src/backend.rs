@@ -0,0 +1,148 @@
+//! Simulation backends for the conformance runner.
+//!
+//! Conformance cases need a JSON-RPC endpoint to point the SDK at. Pointing
+//! them at a live archive node makes `test_transaction_simulation` and
+//! `test_performance_requirements` fragile and non-deterministic (state
+//! drifts block by block). [`LocalFork`] spins up a local fork node pinned
+//! at a fixed block so the same test cases produce the same results in CI;
+//! [`RemoteRpc`] is the old behavior, for exploratory runs against a live
+//! endpoint.
+
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::time::sleep;
+
+/// Selects and owns the JSON-RPC endpoint conformance tests run against.
+#[async_trait]
+pub trait SimulationBackend: Send + Sync {
+    /// Start the backend (no-op for an already-running remote endpoint) and
+    /// return the JSON-RPC URL tests should point the SDK at.
+    async fn start(&mut self) -> anyhow::Result<String>;
+
+    /// Tear the backend down. No-op for a remote endpoint we don't own.
+    async fn stop(&mut self) -> anyhow::Result<()>;
+}
+
+/// Launches a local anvil-style fork node pinned at `fork_block`, so
+/// conformance cases see frozen, deterministic state instead of whatever the
+/// archive node currently reports.
+pub struct LocalFork {
+    archive_rpc_url: String,
+    fork_block: u64,
+    port: u16,
+    process: Option<Child>,
+}
+
+impl LocalFork {
+    pub fn new(archive_rpc_url: impl Into<String>, fork_block: u64, port: u16) -> Self {
+        Self {
+            archive_rpc_url: archive_rpc_url.into(),
+            fork_block,
+            port,
+            process: None,
+        }
+    }
+}
+
+#[async_trait]
+impl SimulationBackend for LocalFork {
+    async fn start(&mut self) -> anyhow::Result<String> {
+        let child = Command::new("anvil")
+            .arg("--fork-url")
+            .arg(&self.archive_rpc_url)
+            .arg("--fork-block-number")
+            .arg(self.fork_block.to_string())
+            .arg("--port")
+            .arg(self.port.to_string())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| anyhow::anyhow!("Failed to launch local fork node (is anvil installed?): {}", e))?;
+
+        self.process = Some(child);
+
+        // Give the node a moment to bind its RPC port before tests hit it.
+        sleep(Duration::from_millis(500)).await;
+
+        Ok(format!("http://127.0.0.1:{}", self.port))
+    }
+
+    async fn stop(&mut self) -> anyhow::Result<()> {
+        if let Some(mut child) = self.process.take() {
+            child.kill().map_err(|e| anyhow::anyhow!("Failed to stop local fork node: {}", e))?;
+            child.wait().map_err(|e| anyhow::anyhow!("Failed to reap local fork node: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Points directly at an already-running remote JSON-RPC endpoint. Owns
+/// nothing, so `stop` is a no-op.
+pub struct RemoteRpc {
+    rpc_url: String,
+}
+
+impl RemoteRpc {
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self { rpc_url: rpc_url.into() }
+    }
+}
+
+#[async_trait]
+impl SimulationBackend for RemoteRpc {
+    async fn start(&mut self) -> anyhow::Result<String> {
+        Ok(self.rpc_url.clone())
+    }
+
+    async fn stop(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Build the configured backend from environment, defaulting to the
+/// existing remote-endpoint behavior so opting into a local fork is
+/// additive: set `CONFORMANCE_BACKEND=local_fork` plus `FORK_ARCHIVE_RPC_URL`
+/// and `FORK_BLOCK_NUMBER` to pin a deterministic fork.
+pub fn configured_backend(default_rpc_url: &str) -> Box<dyn SimulationBackend> {
+    match std::env::var("CONFORMANCE_BACKEND").as_deref() {
+        Ok("local_fork") => {
+            let archive_rpc_url = std::env::var("FORK_ARCHIVE_RPC_URL")
+                .unwrap_or_else(|_| default_rpc_url.to_string());
+            let fork_block: u64 = std::env::var("FORK_BLOCK_NUMBER")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(18_000_000);
+            let port: u16 = std::env::var("FORK_PORT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(8545);
+
+            Box::new(LocalFork::new(archive_rpc_url, fork_block, port))
+        }
+        _ => Box::new(RemoteRpc::new(default_rpc_url.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_remote_rpc_start_returns_configured_url() {
+        let mut backend = RemoteRpc::new("https://example.com/rpc");
+        let url = backend.start().await.unwrap();
+        assert_eq!(url, "https://example.com/rpc");
+        backend.stop().await.unwrap();
+    }
+
+    #[test]
+    fn test_configured_backend_defaults_to_remote_rpc() {
+        std::env::remove_var("CONFORMANCE_BACKEND");
+        let backend = configured_backend("https://api.hypersim.io/hyperevm");
+        // We can't downcast `dyn SimulationBackend`, so just assert it built
+        // without requiring a local fork node to be installed.
+        let _ = backend;
+    }
+}
@@ -0,0 +1,194 @@
+//! Benchmark mode for the conformance runner.
+//!
+//! Unlike `ConformanceTestRunner::run_all_tests`, which checks pass/fail
+//! against `master_test_spec.json`, a benchmark run reads a workload file
+//! describing SDK calls to repeat, times each call, and reports latency
+//! distribution rather than a single flat average — a mean alone hides the
+//! tail latencies that actually page someone in production.
+
+use std::path::Path;
+use std::time::Instant;
+
+use hypersim_sdk::HyperSimSDK;
+use serde::{Deserialize, Serialize};
+use tokio::time::Duration;
+
+/// A workload file: a named group of operations to repeat and time
+#[derive(Debug, Deserialize)]
+pub struct Workload {
+    pub name: String,
+    pub operations: Vec<WorkloadOperation>,
+}
+
+/// A single timed operation within a [`Workload`]
+#[derive(Debug, Deserialize)]
+pub struct WorkloadOperation {
+    pub name: String,
+    pub input: serde_json::Value,
+    #[serde(default)]
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+/// Latency distribution over a set of timed samples, in milliseconds
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencyStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub stddev: f64,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+}
+
+impl LatencyStats {
+    /// Aggregate `samples` (unsorted, in milliseconds). Returns all-zero
+    /// stats for an empty slice rather than panicking.
+    pub fn from_samples(samples: &[f64]) -> Self {
+        if samples.is_empty() {
+            return Self { min: 0.0, max: 0.0, mean: 0.0, stddev: 0.0, p50: 0.0, p90: 0.0, p99: 0.0 };
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / sorted.len() as f64;
+
+        Self {
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            mean,
+            stddev: variance.sqrt(),
+            p50: Self::percentile(&sorted, 50.0),
+            p90: Self::percentile(&sorted, 90.0),
+            p99: Self::percentile(&sorted, 99.0),
+        }
+    }
+
+    /// Linearly interpolate the value at `percentile` (0-100) within
+    /// `sorted`, a slice already sorted ascending
+    fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+        if sorted.len() == 1 {
+            return sorted[0];
+        }
+
+        let rank = (percentile / 100.0) * (sorted.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+
+        if lower == upper {
+            sorted[lower]
+        } else {
+            let weight = rank - lower as f64;
+            sorted[lower] * (1.0 - weight) + sorted[upper] * weight
+        }
+    }
+}
+
+/// Aggregated timing for a single [`WorkloadOperation`]
+#[derive(Debug, Serialize)]
+pub struct OperationBenchmark {
+    pub name: String,
+    pub iterations: u32,
+    pub stats: LatencyStats,
+}
+
+/// Report produced by a benchmark run, suitable for `--report-url` upload
+#[derive(Debug, Serialize)]
+pub struct BenchmarkReport {
+    pub workload: String,
+    pub operations: Vec<OperationBenchmark>,
+    pub timestamp: u64,
+}
+
+/// Runs workload files against a live SDK instance, timing each iteration
+pub struct BenchmarkRunner<'a> {
+    sdk: &'a HyperSimSDK,
+}
+
+impl<'a> BenchmarkRunner<'a> {
+    pub fn new(sdk: &'a HyperSimSDK) -> Self {
+        Self { sdk }
+    }
+
+    /// Load a workload file and run it
+    pub async fn run_workload_file(&self, path: &Path) -> anyhow::Result<BenchmarkReport> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read workload file {}: {}", path.display(), e))?;
+        let workload: Workload = serde_json::from_str(&contents)
+            .map_err(|e| anyhow::anyhow!("Failed to parse workload file {}: {}", path.display(), e))?;
+        self.run_workload(workload).await
+    }
+
+    /// Run every operation in `workload`, warming up then timing each
+    pub async fn run_workload(&self, workload: Workload) -> anyhow::Result<BenchmarkReport> {
+        let mut operations = Vec::with_capacity(workload.operations.len());
+
+        for operation in &workload.operations {
+            let stats = self.run_operation(operation).await?;
+            operations.push(OperationBenchmark {
+                name: operation.name.clone(),
+                iterations: operation.iterations,
+                stats,
+            });
+        }
+
+        Ok(BenchmarkReport {
+            workload: workload.name,
+            operations,
+            timestamp: chrono::Utc::now().timestamp_millis() as u64,
+        })
+    }
+
+    async fn run_operation(&self, operation: &WorkloadOperation) -> anyhow::Result<LatencyStats> {
+        for _ in 0..operation.warmup {
+            self.call_operation(operation).await?;
+        }
+
+        let mut samples = Vec::with_capacity(operation.iterations as usize);
+        for _ in 0..operation.iterations {
+            let start = Instant::now();
+            self.call_operation(operation).await?;
+            samples.push(duration_to_ms(start.elapsed()));
+        }
+
+        Ok(LatencyStats::from_samples(&samples))
+    }
+
+    async fn call_operation(&self, operation: &WorkloadOperation) -> anyhow::Result<()> {
+        match operation.name.as_str() {
+            "simulate" => {
+                let transaction = &operation.input["transaction"];
+                let network = operation.input["network"].as_str().unwrap_or("ethereum");
+                let block_number = operation.input["blockNumber"].as_str().unwrap_or("latest");
+                self.sdk.simulation.simulate(transaction, network, block_number).await?;
+            }
+            "analyze" => {
+                let transaction = &operation.input["transaction"];
+                let analysis_type = operation.input["analysisType"].as_str().unwrap_or("risk");
+                self.sdk.ai.analyze(transaction, analysis_type).await?;
+            }
+            other => anyhow::bail!("Unknown benchmark operation: {}", other),
+        }
+
+        Ok(())
+    }
+}
+
+fn duration_to_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// POST `report` to `url` so CI runs can be tracked on a dashboard over time
+pub async fn post_report<T: Serialize>(url: &str, report: &T) -> anyhow::Result<()> {
+    let client = reqwest::Client::new();
+    let response = client.post(url).json(report).send().await?;
+
+    if !response.status().is_success() {
+        anyhow::bail!("Report upload to {} failed with status {}", url, response.status());
+    }
+
+    Ok(())
+}
@@ -58,7 +58,19 @@ mod tests {
         assert!(result.execution_time_ms < 2000.0, "Simulation should complete within 2 seconds");
         
         let result_data = result.result.unwrap();
-        assert!(result_data["gasUsed"].is_number(), "Should return gas used");
+
+        // Against a pinned local fork, state is frozen, so the ERC-20
+        // transfer's gas cost is deterministic and can be asserted exactly
+        // instead of merely checked for shape.
+        if std::env::var("CONFORMANCE_BACKEND").as_deref() == Ok("local_fork") {
+            let expected_gas_used: u64 = std::env::var("EXPECTED_ERC20_TRANSFER_GAS_USED")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .expect("EXPECTED_ERC20_TRANSFER_GAS_USED must be set when testing against a pinned fork");
+            assert_eq!(result_data["gasUsed"], expected_gas_used, "Gas used should match the pinned fork block exactly");
+        } else {
+            assert!(result_data["gasUsed"].is_number(), "Should return gas used");
+        }
     }
 
     #[tokio::test]
@@ -2,10 +2,18 @@ use hypersim_sdk::{HyperSimSDK, Config};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 use tokio::time::timeout;
 
+mod backend;
+use backend::SimulationBackend;
+
+mod benchmark;
+use benchmark::{BenchmarkRunner, LatencyStats};
+
+mod watch;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TestResult {
     pub test_id: String,
@@ -45,7 +53,7 @@ pub struct TestSummary {
     pub passed: usize,
     pub failed: usize,
     pub success_rate: f64,
-    pub average_execution_time_ms: f64,
+    pub execution_time_stats: LatencyStats,
     pub total_memory_usage_mb: f64,
     pub timestamp: u64,
 }
@@ -56,16 +64,57 @@ pub struct PerformanceMetrics {
     pub memory_usage: Vec<HashMap<String, serde_json::Value>>,
 }
 
+/// Relative execution-time growth beyond which a test is flagged as a
+/// regression, unless `--regression-threshold` overrides it
+const DEFAULT_REGRESSION_THRESHOLD_PCT: f64 = 15.0;
+
+/// Cap on how many slowdowns `RegressionReport::worst_slowdowns` lists, so a
+/// broad regression doesn't dump every test into the CI log
+const WORST_SLOWDOWNS_LIMIT: usize = 10;
+
+/// A single test that got slower than `DEFAULT_REGRESSION_THRESHOLD_PCT` (or
+/// the configured threshold) compared to the baseline run
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegressionEntry {
+    pub test_id: String,
+    pub baseline_execution_time_ms: f64,
+    pub current_execution_time_ms: f64,
+    pub execution_time_delta_pct: f64,
+}
+
+/// Result of comparing a run's results against a previously saved baseline
+/// [`TestReport`]. `has_regression` is true when CI should fail the build.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegressionReport {
+    pub newly_failing: Vec<String>,
+    pub newly_passing: Vec<String>,
+    pub worst_slowdowns: Vec<RegressionEntry>,
+    pub has_regression: bool,
+}
+
 pub struct ConformanceTestRunner {
     sdk: HyperSimSDK,
+    backend: Box<dyn SimulationBackend>,
     results: Vec<TestResult>,
     master_spec: serde_json::Value,
     test_data: serde_json::Value,
     expected_outputs: serde_json::Value,
+    /// Directory containing `specifications/` and `test_data/`, used by
+    /// watch mode to locate the files it's reloading
+    base_path: PathBuf,
 }
 
 impl ConformanceTestRunner {
     pub async fn new() -> anyhow::Result<Self> {
+        let default_hyperevm_url = std::env::var("HYPEREVM_URL")
+            .unwrap_or_else(|_| "https://api.hypersim.io/hyperevm".to_string());
+
+        // In `local_fork` mode this launches a pinned fork node and points
+        // the SDK at it instead, so simulation results are deterministic
+        // across runs rather than drifting with the live chain.
+        let mut backend = backend::configured_backend(&default_hyperevm_url);
+        let hyperevm_url = backend.start().await?;
+
         let config = Config {
             hyper_core: hypersim_sdk::HyperCoreConfig {
                 url: std::env::var("HYPERCORE_URL")
@@ -74,8 +123,7 @@ impl ConformanceTestRunner {
                     .unwrap_or_else(|_| "test_key_123".to_string()),
             },
             hyper_evm: hypersim_sdk::HyperEVMConfig {
-                url: std::env::var("HYPEREVM_URL")
-                    .unwrap_or_else(|_| "https://api.hypersim.io/hyperevm".to_string()),
+                url: hyperevm_url,
                 api_key: std::env::var("HYPEREVM_API_KEY")
                     .unwrap_or_else(|_| "test_key_456".to_string()),
             },
@@ -86,25 +134,33 @@ impl ConformanceTestRunner {
 
         // Load test specifications
         let base_path = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().parent().unwrap();
-        
+
         let master_spec = fs::read_to_string(base_path.join("specifications/master_test_spec.json"))?;
         let master_spec: serde_json::Value = serde_json::from_str(&master_spec)?;
-        
+
         let test_data = fs::read_to_string(base_path.join("test_data/simulation_inputs.json"))?;
         let test_data: serde_json::Value = serde_json::from_str(&test_data)?;
-        
+
         let expected_outputs = fs::read_to_string(base_path.join("test_data/expected_outputs.json"))?;
         let expected_outputs: serde_json::Value = serde_json::from_str(&expected_outputs)?;
 
         Ok(ConformanceTestRunner {
             sdk,
+            backend,
             results: Vec::new(),
             master_spec,
             test_data,
             expected_outputs,
+            base_path: base_path.to_path_buf(),
         })
     }
 
+    /// Tear down the backing simulation backend (e.g. kill the local fork
+    /// node). Call once the suite has finished running.
+    pub async fn shutdown(&mut self) -> anyhow::Result<()> {
+        self.backend.stop().await
+    }
+
     pub async fn run_test(&self, test_case: &serde_json::Value) -> TestResult {
         let start_time = Instant::now();
         let initial_memory = self.get_memory_usage();
@@ -266,11 +322,7 @@ impl ConformanceTestRunner {
         let total_tests = self.results.len();
         let passed_tests = self.results.iter().filter(|r| r.success).count();
         let failed_tests = total_tests - passed_tests;
-        let average_execution_time = if total_tests > 0 {
-            self.results.iter().map(|r| r.execution_time_ms).sum::<f64>() / total_tests as f64
-        } else {
-            0.0
-        };
+        let execution_time_samples: Vec<f64> = self.results.iter().map(|r| r.execution_time_ms).collect();
         let total_memory_usage = self.results.iter().map(|r| r.memory_usage_mb).sum::<f64>();
 
         TestReport {
@@ -284,7 +336,7 @@ impl ConformanceTestRunner {
                 } else {
                     0.0
                 },
-                average_execution_time_ms: average_execution_time,
+                execution_time_stats: LatencyStats::from_samples(&execution_time_samples),
                 total_memory_usage_mb: total_memory_usage,
                 timestamp: chrono::Utc::now().timestamp_millis() as u64,
             },
@@ -312,6 +364,138 @@ impl ConformanceTestRunner {
         }
     }
 
+    /// Compare this run's results against `baseline`, matching by
+    /// `test_id`, using the default regression threshold
+    /// ([`DEFAULT_REGRESSION_THRESHOLD_PCT`])
+    pub fn compare_with_baseline(&self, baseline: &TestReport) -> RegressionReport {
+        self.compare_with_baseline_threshold(baseline, DEFAULT_REGRESSION_THRESHOLD_PCT)
+    }
+
+    /// Compare this run's results against `baseline`, matching by
+    /// `test_id`. A test is a regression when it flips from passing to
+    /// failing, or when its `execution_time_ms` grows by more than
+    /// `threshold_pct`.
+    pub fn compare_with_baseline_threshold(&self, baseline: &TestReport, threshold_pct: f64) -> RegressionReport {
+        let baseline_by_id: HashMap<&str, &TestResult> =
+            baseline.detailed_results.iter().map(|r| (r.test_id.as_str(), r)).collect();
+
+        let mut newly_failing = Vec::new();
+        let mut newly_passing = Vec::new();
+        let mut slowdowns = Vec::new();
+
+        for result in &self.results {
+            let Some(baseline_result) = baseline_by_id.get(result.test_id.as_str()) else { continue };
+
+            if baseline_result.success && !result.success {
+                newly_failing.push(result.test_id.clone());
+            } else if !baseline_result.success && result.success {
+                newly_passing.push(result.test_id.clone());
+            }
+
+            if baseline_result.execution_time_ms > 0.0 {
+                let delta_pct = (result.execution_time_ms - baseline_result.execution_time_ms)
+                    / baseline_result.execution_time_ms
+                    * 100.0;
+
+                if delta_pct > threshold_pct {
+                    slowdowns.push(RegressionEntry {
+                        test_id: result.test_id.clone(),
+                        baseline_execution_time_ms: baseline_result.execution_time_ms,
+                        current_execution_time_ms: result.execution_time_ms,
+                        execution_time_delta_pct: delta_pct,
+                    });
+                }
+            }
+        }
+
+        slowdowns.sort_by(|a, b| b.execution_time_delta_pct.partial_cmp(&a.execution_time_delta_pct).unwrap());
+        slowdowns.truncate(WORST_SLOWDOWNS_LIMIT);
+
+        let has_regression = !newly_failing.is_empty() || !slowdowns.is_empty();
+
+        RegressionReport { newly_failing, newly_passing, worst_slowdowns: slowdowns, has_regression }
+    }
+
+    /// Directory containing `specifications/` and `test_data/`
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    /// Parsed `master_test_spec.json` from the most recent load or reload
+    pub fn master_spec(&self) -> &serde_json::Value {
+        &self.master_spec
+    }
+
+    /// Results from the most recent full run or incremental re-run
+    pub fn results(&self) -> &[TestResult] {
+        &self.results
+    }
+
+    /// Reload `specifications/master_test_spec.json` from disk
+    pub fn reload_master_spec(&mut self) -> anyhow::Result<()> {
+        let raw = fs::read_to_string(self.base_path.join("specifications/master_test_spec.json"))?;
+        self.master_spec = serde_json::from_str(&raw)?;
+        Ok(())
+    }
+
+    /// Reload `test_data/simulation_inputs.json` from disk
+    pub fn reload_test_data(&mut self) -> anyhow::Result<()> {
+        let raw = fs::read_to_string(self.base_path.join("test_data/simulation_inputs.json"))?;
+        self.test_data = serde_json::from_str(&raw)?;
+        Ok(())
+    }
+
+    /// Reload `test_data/expected_outputs.json` from disk
+    pub fn reload_expected_outputs(&mut self) -> anyhow::Result<()> {
+        let raw = fs::read_to_string(self.base_path.join("test_data/expected_outputs.json"))?;
+        self.expected_outputs = serde_json::from_str(&raw)?;
+        Ok(())
+    }
+
+    /// Re-run only the named operations, or every operation when `operations`
+    /// is `None`, replacing each affected test's entry in `self.results` in
+    /// place. Returns the IDs of the tests that were re-run.
+    pub async fn rerun_operations(
+        &mut self,
+        operations: Option<&std::collections::HashSet<String>>,
+    ) -> anyhow::Result<std::collections::HashSet<String>> {
+        let test_categories = self.master_spec["test_categories"].as_object().unwrap().clone();
+        let mut rerun_ids = std::collections::HashSet::new();
+
+        for (_category_name, category) in &test_categories {
+            let Some(ops) = category["operations"].as_array() else { continue };
+
+            for operation in ops {
+                let Some(operation_name) = operation["name"].as_str() else { continue };
+                if operations.is_some_and(|wanted| !wanted.contains(operation_name)) {
+                    continue;
+                }
+
+                let Some(test_cases) = operation["test_cases"].as_array() else { continue };
+                for test_case in test_cases {
+                    let Some(test_id) = test_case["id"].as_str() else { continue };
+
+                    let mut test_case_with_name = test_case.clone();
+                    test_case_with_name.as_object_mut().unwrap().insert(
+                        "name".to_string(),
+                        serde_json::Value::String(operation_name.to_string()),
+                    );
+
+                    println!("Re-running test: {}", test_id);
+                    let result = self.run_test(&test_case_with_name).await;
+                    rerun_ids.insert(test_id.to_string());
+
+                    match self.results.iter_mut().find(|r| r.test_id == test_id) {
+                        Some(existing) => *existing = result,
+                        None => self.results.push(result),
+                    }
+                }
+            }
+        }
+
+        Ok(rerun_ids)
+    }
+
     fn get_memory_usage(&self) -> f64 {
         // Simple memory usage approximation
         match psutil::memory::virtual_memory() {
@@ -321,9 +505,75 @@ impl ConformanceTestRunner {
     }
 }
 
+/// Parsed from `--workload <file>` and `--report-url <url>` CLI flags.
+/// `--workload` switches the binary from conformance mode into benchmark
+/// mode; `--report-url` is accepted in either mode and, when set, uploads
+/// the resulting report so CI runs can be tracked on a dashboard over time.
+struct CliArgs {
+    workload_path: Option<String>,
+    report_url: Option<String>,
+    baseline_path: Option<String>,
+    regression_threshold_pct: Option<f64>,
+    watch: bool,
+}
+
+fn parse_cli_args() -> CliArgs {
+    let args: Vec<String> = std::env::args().collect();
+    let mut workload_path = None;
+    let mut report_url = None;
+    let mut baseline_path = None;
+    let mut regression_threshold_pct = None;
+    let mut watch = false;
+
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--workload" => {
+                workload_path = args.get(i + 1).cloned();
+                i += 2;
+            }
+            "--report-url" => {
+                report_url = args.get(i + 1).cloned();
+                i += 2;
+            }
+            "--baseline" => {
+                baseline_path = args.get(i + 1).cloned();
+                i += 2;
+            }
+            "--regression-threshold" => {
+                regression_threshold_pct = args.get(i + 1).and_then(|v| v.parse().ok());
+                i += 2;
+            }
+            "--watch" => {
+                watch = true;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    CliArgs { workload_path, report_url, baseline_path, regression_threshold_pct, watch }
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let cli_args = parse_cli_args();
     let mut runner = ConformanceTestRunner::new().await?;
+
+    if let Some(workload_path) = &cli_args.workload_path {
+        let benchmark_runner = BenchmarkRunner::new(&runner.sdk);
+        let report = benchmark_runner.run_workload_file(Path::new(workload_path)).await?;
+        runner.shutdown().await?;
+
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        if let Some(url) = &cli_args.report_url {
+            benchmark::post_report(url, &report).await?;
+            println!("Benchmark report uploaded to: {}", url);
+        }
+
+        return Ok(());
+    }
+
     runner.run_all_tests().await?;
     let report = runner.generate_report();
 
@@ -332,17 +582,48 @@ async fn main() -> anyhow::Result<()> {
         .parent().unwrap()
         .parent().unwrap()
         .join("reports/rust-results.json");
-    
+
     if let Some(parent) = output_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
-    
+
     let report_json = serde_json::to_string_pretty(&report)?;
     std::fs::write(&output_path, report_json)?;
-    
+
     println!("Report saved to: {:?}", output_path);
     println!("Tests passed: {}/{}", report.summary.passed, report.summary.total_tests);
     println!("Success rate: {:.1}%", report.summary.success_rate);
-    
+
+    if let Some(url) = &cli_args.report_url {
+        benchmark::post_report(url, &report).await?;
+        println!("Report uploaded to: {}", url);
+    }
+
+    if let Some(baseline_path) = &cli_args.baseline_path {
+        let baseline_json = std::fs::read_to_string(baseline_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read baseline report {}: {}", baseline_path, e))?;
+        let baseline: TestReport = serde_json::from_str(&baseline_json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse baseline report {}: {}", baseline_path, e))?;
+
+        let threshold = cli_args.regression_threshold_pct.unwrap_or(DEFAULT_REGRESSION_THRESHOLD_PCT);
+        let regression_report = runner.compare_with_baseline_threshold(&baseline, threshold);
+
+        println!("{}", serde_json::to_string_pretty(&regression_report)?);
+
+        if regression_report.has_regression {
+            eprintln!("Regression detected against baseline: {}", baseline_path);
+            std::process::exit(1);
+        }
+    }
+
+    if cli_args.watch {
+        // Keeps the SDK/backend alive across re-runs, so shutdown happens
+        // only after the watch loop returns (Ctrl+C, or the watcher channel
+        // closing).
+        watch::run(&mut runner).await?;
+    }
+
+    runner.shutdown().await?;
+
     Ok(())
 }
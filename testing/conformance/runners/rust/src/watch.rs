@@ -0,0 +1,166 @@
+//! Watch mode for the conformance runner
+//!
+//! `--watch` keeps the process running after the initial full suite run and
+//! watches `specifications/master_test_spec.json`,
+//! `test_data/simulation_inputs.json`, and `test_data/expected_outputs.json`
+//! for edits. Filesystem events are coalesced within [`DEBOUNCE`] so a single
+//! save (which often fires multiple write/rename events) triggers one
+//! re-run, not several overlapping ones. Only the operations whose
+//! `test_cases` changed are re-run when `master_test_spec.json` is the file
+//! that changed; a change to either test-data file reruns the whole suite,
+//! since neither file maps cases back to specific operations.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::ConformanceTestRunner;
+
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watch the spec/test-data files referenced by `runner` and re-run affected
+/// tests whenever they change. Returns once the underlying watcher channel
+/// disconnects (e.g. on process shutdown).
+pub async fn run(runner: &mut ConformanceTestRunner) -> anyhow::Result<()> {
+    let master_spec_path = runner.base_path().join("specifications/master_test_spec.json");
+    let test_data_path = runner.base_path().join("test_data/simulation_inputs.json");
+    let expected_outputs_path = runner.base_path().join("test_data/expected_outputs.json");
+
+    let (tx, rx) = channel::<notify::Result<notify::Event>>();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&master_spec_path, RecursiveMode::NonRecursive)?;
+    watcher.watch(&test_data_path, RecursiveMode::NonRecursive)?;
+    watcher.watch(&expected_outputs_path, RecursiveMode::NonRecursive)?;
+
+    println!("Watching for spec/test-data changes (Ctrl+C to stop)...");
+
+    loop {
+        let first = match rx.recv() {
+            Ok(event) => event,
+            Err(_) => return Ok(()),
+        };
+
+        let mut touched = TouchedFiles::default();
+        touched.record(&first, &master_spec_path, &test_data_path, &expected_outputs_path);
+
+        loop {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(event) => touched.record(&event, &master_spec_path, &test_data_path, &expected_outputs_path),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        }
+
+        if !touched.any() {
+            continue;
+        }
+
+        let previous_spec = runner.master_spec().clone();
+        let previous_outcomes: std::collections::HashMap<String, bool> =
+            runner.results().iter().map(|r| (r.test_id.clone(), r.success)).collect();
+
+        if let Err(e) = reload_touched(runner, &touched) {
+            eprintln!("Failed to reload changed spec/test-data: {}", e);
+            continue;
+        }
+
+        let affected_operations = if touched.test_data || touched.expected_outputs {
+            None
+        } else {
+            Some(changed_operations(&previous_spec, runner.master_spec()))
+        };
+
+        println!("Change detected, re-running affected tests...");
+        let rerun_ids = runner.rerun_operations(affected_operations.as_ref()).await?;
+        print_delta(&previous_outcomes, runner.results(), &rerun_ids);
+    }
+}
+
+#[derive(Default)]
+struct TouchedFiles {
+    master_spec: bool,
+    test_data: bool,
+    expected_outputs: bool,
+}
+
+impl TouchedFiles {
+    fn record(
+        &mut self,
+        event: &notify::Result<notify::Event>,
+        master_spec_path: &Path,
+        test_data_path: &Path,
+        expected_outputs_path: &Path,
+    ) {
+        let Ok(event) = event else { return };
+        self.master_spec |= event.paths.iter().any(|p| p == master_spec_path);
+        self.test_data |= event.paths.iter().any(|p| p == test_data_path);
+        self.expected_outputs |= event.paths.iter().any(|p| p == expected_outputs_path);
+    }
+
+    fn any(&self) -> bool {
+        self.master_spec || self.test_data || self.expected_outputs
+    }
+}
+
+fn reload_touched(runner: &mut ConformanceTestRunner, touched: &TouchedFiles) -> anyhow::Result<()> {
+    if touched.master_spec {
+        runner.reload_master_spec()?;
+    }
+    if touched.test_data {
+        runner.reload_test_data()?;
+    }
+    if touched.expected_outputs {
+        runner.reload_expected_outputs()?;
+    }
+    Ok(())
+}
+
+/// Names of operations whose `test_cases` array differs between two parsed
+/// `master_test_spec.json` documents
+fn changed_operations(old_spec: &serde_json::Value, new_spec: &serde_json::Value) -> HashSet<String> {
+    let mut changed = HashSet::new();
+    let Some(new_categories) = new_spec["test_categories"].as_object() else { return changed };
+
+    for (category_name, category) in new_categories {
+        let Some(operations) = category["operations"].as_array() else { continue };
+
+        for operation in operations {
+            let Some(operation_name) = operation["name"].as_str() else { continue };
+
+            let old_test_cases = old_spec["test_categories"][category_name]["operations"]
+                .as_array()
+                .and_then(|ops| ops.iter().find(|op| op["name"].as_str() == Some(operation_name)))
+                .map(|op| &op["test_cases"]);
+
+            let unchanged = old_test_cases.is_some_and(|old| old == &operation["test_cases"]);
+            if !unchanged {
+                changed.insert(operation_name.to_string());
+            }
+        }
+    }
+
+    changed
+}
+
+fn print_delta(
+    previous_outcomes: &std::collections::HashMap<String, bool>,
+    current_results: &[crate::TestResult],
+    rerun_ids: &HashSet<String>,
+) {
+    println!("--- Incremental results ({} test(s) re-run) ---", rerun_ids.len());
+
+    for test_id in rerun_ids {
+        let Some(result) = current_results.iter().rev().find(|r| &r.test_id == test_id) else { continue };
+        let status = if result.success { "PASS" } else { "FAIL" };
+
+        match previous_outcomes.get(test_id) {
+            Some(true) if !result.success => println!("  {}: PASS -> FAIL (regressed)", test_id),
+            Some(false) if result.success => println!("  {}: FAIL -> PASS (fixed)", test_id),
+            Some(_) => println!("  {}: {} (unchanged)", test_id, status),
+            None => println!("  {}: {} (new)", test_id, status),
+        }
+    }
+}